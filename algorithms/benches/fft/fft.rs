@@ -14,7 +14,7 @@
 
 extern crate criterion;
 
-use snarkvm_algorithms::fft::{DensePolynomial, EvaluationDomain};
+use snarkvm_algorithms::fft::{DensePolynomial, EvaluationDomain, Evaluations};
 use snarkvm_curves::bls12_377::Fr as Bls12_377_Fr;
 use snarkvm_fields::PrimeField;
 use snarkvm_utilities::TestRng;
@@ -99,6 +99,33 @@ fn bench_coset_ifft_in_place<F: PrimeField>(b: &mut Bencher, degree: &usize) {
     });
 }
 
+fn bench_divide_by_vanishing_poly<F: PrimeField>(b: &mut Bencher, degree: &usize) {
+    let (domain, _) = create_evaluation_domain::<F>(*degree);
+    let p = DensePolynomial::<F>::rand(2 * degree - 2, &mut TestRng::default());
+
+    b.iter(|| p.divide_by_vanishing_poly(domain).unwrap());
+}
+
+fn bench_divide_by_vanishing_poly_on_coset<F: PrimeField>(b: &mut Bencher, degree: &usize) {
+    let (domain, _) = create_evaluation_domain::<F>(*degree);
+    let coset_domain = EvaluationDomain::new(2 * degree).unwrap();
+    let p = DensePolynomial::<F>::rand(2 * degree - 2, &mut TestRng::default());
+    let evals = Evaluations::evaluate_over_coset(&p, coset_domain);
+
+    b.iter(|| evals.clone().divide_by_vanishing_poly_on_coset(domain).unwrap());
+}
+
+fn bench_evaluation_domain_new<F: PrimeField>(c: &mut Criterion, name: &str) {
+    // Constructs 1000 domains of the same size, which are served from the thread-local cache after the first.
+    c.bench_function(&format!("{name:?} - evaluation_domain_new_1000"), |b| {
+        b.iter(|| {
+            for _ in 0..1000 {
+                EvaluationDomain::<F>::new(BENCHMARK_MIN_DEGREE).unwrap();
+            }
+        })
+    });
+}
+
 fn fft_benches<F: PrimeField>(c: &mut Criterion, name: &str) {
     let description = format!("{name:?} - subgroup_fft_in_place");
     setup_bench(c, &description, bench_fft_in_place::<F>);
@@ -108,6 +135,11 @@ fn fft_benches<F: PrimeField>(c: &mut Criterion, name: &str) {
     setup_bench(c, &description, bench_coset_fft_in_place::<F>);
     let description = format!("{name:?} - coset_ifft_in_place");
     setup_bench(c, &description, bench_coset_ifft_in_place::<F>);
+    let description = format!("{name:?} - divide_by_vanishing_poly");
+    setup_bench(c, &description, bench_divide_by_vanishing_poly::<F>);
+    let description = format!("{name:?} - divide_by_vanishing_poly_on_coset");
+    setup_bench(c, &description, bench_divide_by_vanishing_poly_on_coset::<F>);
+    bench_evaluation_domain_new::<F>(c, name);
 }
 
 fn bench_bls12_377(c: &mut Criterion) {
@@ -0,0 +1,68 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#[macro_use]
+extern crate criterion;
+
+use snarkvm_algorithms::r1cs::SplitSparseMatrix;
+use snarkvm_curves::bls12_377::Fr;
+use snarkvm_fields::One;
+use snarkvm_utilities::{TestRng, Uniform};
+
+use criterion::Criterion;
+use rand::Rng;
+
+/// The number of rows in the benchmarked matrix.
+const NUM_ROWS: usize = 1 << 18;
+/// The number of public variables in the benchmarked assignment.
+const NUM_PUBLIC_VARIABLES: usize = 1 << 6;
+/// The number of private variables in the benchmarked assignment.
+const NUM_PRIVATE_VARIABLES: usize = 1 << 18;
+/// The average number of non-zero entries per row.
+const ENTRIES_PER_ROW: usize = 3;
+
+fn sparse_matrix_vector_product_bench(c: &mut Criterion) {
+    let rng = &mut TestRng::default();
+
+    let num_variables = NUM_PUBLIC_VARIABLES + NUM_PRIVATE_VARIABLES;
+    let public_variables = (0..NUM_PUBLIC_VARIABLES).map(|_| Fr::rand(rng)).collect::<Vec<_>>();
+    let private_variables = (0..NUM_PRIVATE_VARIABLES).map(|_| Fr::rand(rng)).collect::<Vec<_>>();
+    // Sample a matrix where most coefficients are one, as is typical for R1CS matrices.
+    let matrix = (0..NUM_ROWS)
+        .map(|_| {
+            (0..rng.gen_range(1..=2 * ENTRIES_PER_ROW - 1))
+                .map(|_| {
+                    let coefficient = if rng.gen_bool(0.75) { Fr::one() } else { Fr::rand(rng) };
+                    (coefficient, rng.gen_range(0..num_variables))
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+
+    c.bench_function("split_sparse_matrix", |b| b.iter(|| SplitSparseMatrix::new(&matrix)));
+
+    // The split is computed once at index time, so only the product is measured here.
+    let split = SplitSparseMatrix::new(&matrix);
+    c.bench_function("sparse_matrix_vector_product", |b| {
+        b.iter(|| split.vector_product(&public_variables, &private_variables))
+    });
+}
+
+criterion_group! {
+    name = r1cs;
+    config = Criterion::default().sample_size(10);
+    targets = sparse_matrix_vector_product_bench
+}
+
+criterion_main!(r1cs);
@@ -17,7 +17,13 @@ extern crate criterion;
 
 use snarkvm_algorithms::{
     crypto_hash::PoseidonSponge,
-    snark::varuna::{ahp::AHPForR1CS, CircuitVerifyingKey, TestCircuit, VarunaHidingMode, VarunaSNARK},
+    snark::varuna::{
+        ahp::{indexer::Index, AHPForR1CS},
+        CircuitVerifyingKey,
+        TestCircuit,
+        VarunaHidingMode,
+        VarunaSNARK,
+    },
     AlgebraicSponge,
     SNARK,
 };
@@ -57,6 +63,32 @@ fn snark_circuit_setup(c: &mut Criterion) {
     }
 }
 
+fn snark_index_file(c: &mut Criterion) {
+    let rng = &mut TestRng::default();
+
+    let mut group = c.benchmark_group("snark_index_file");
+    for size in [100, 1_000, 10_000] {
+        let num_constraints = size;
+        let num_variables = size;
+        let mul_depth = 1;
+        let (circuit, _) = TestCircuit::gen_rand(mul_depth, num_constraints, num_variables, rng);
+
+        // Write the index file once, to compare loading it against re-indexing the circuit.
+        let index = AHPForR1CS::<Fr, VarunaHidingMode>::index(&circuit).unwrap();
+        let path = std::env::temp_dir().join(format!("varuna_bench_index_{size}.bin"));
+        index.to_index_file(&path).unwrap();
+
+        group.bench_function(format!("index_{size}"), |b| {
+            b.iter(|| AHPForR1CS::<Fr, VarunaHidingMode>::index(&circuit).unwrap())
+        });
+        group.bench_function(format!("load_{size}"), |b| {
+            b.iter(|| Index::<Fr, VarunaHidingMode>::from_index_file(&path).unwrap())
+        });
+        std::fs::remove_file(&path).unwrap();
+    }
+    group.finish();
+}
+
 fn snark_prove(c: &mut Criterion) {
     c.bench_function("snark_prove", move |b| {
         let num_constraints = 100;
@@ -312,7 +344,7 @@ fn snark_certificate_verify(c: &mut Criterion) {
 criterion_group! {
     name = varuna_snark;
     config = Criterion::default().sample_size(10);
-    targets = snark_universal_setup, snark_circuit_setup, snark_prove, snark_verify, snark_batch_prove, snark_batch_verify, snark_vk_serialize, snark_vk_deserialize, snark_certificate_prove, snark_certificate_verify,
+    targets = snark_universal_setup, snark_circuit_setup, snark_index_file, snark_prove, snark_verify, snark_batch_prove, snark_batch_verify, snark_vk_serialize, snark_vk_deserialize, snark_certificate_prove, snark_certificate_verify,
 }
 
 criterion_main!(varuna_snark);
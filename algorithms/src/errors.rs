@@ -50,7 +50,10 @@ pub enum SNARKError {
 
 impl From<AHPError> for SNARKError {
     fn from(err: AHPError) -> Self {
-        SNARKError::Crate("AHPError", format!("{err:?}"))
+        match err {
+            AHPError::Terminated => SNARKError::Terminated,
+            err => SNARKError::Crate("AHPError", format!("{err:?}")),
+        }
     }
 }
 
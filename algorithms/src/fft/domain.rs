@@ -29,7 +29,7 @@ use crate::{
     cfg_into_iter,
     cfg_iter,
     cfg_iter_mut,
-    fft::{DomainCoeff, SparsePolynomial},
+    fft::{statistics::record_fft, DomainCoeff, SparsePolynomial},
 };
 use snarkvm_fields::{batch_inversion, FftField, FftParameters, Field};
 #[cfg(not(feature = "serial"))]
@@ -37,7 +37,13 @@ use snarkvm_utilities::max_available_threads;
 use snarkvm_utilities::{execute_with_max_available_threads, serialize::*};
 
 use rand::Rng;
-use std::{borrow::Cow, fmt};
+use std::{
+    any::{Any, TypeId},
+    borrow::Cow,
+    cell::RefCell,
+    collections::HashMap,
+    fmt,
+};
 
 use anyhow::{ensure, Result};
 
@@ -75,10 +81,19 @@ pub fn log2(x: usize) -> u32 {
 #[cfg(not(feature = "serial"))]
 const MIN_PARALLEL_CHUNK_SIZE: usize = 1 << 7;
 
+/// The maximum number of evaluation domains cached per thread.
+const MAX_CACHED_DOMAINS: usize = 64;
+
+thread_local! {
+    /// A per-thread cache of evaluation domains, keyed by the field type and the domain size.
+    static DOMAIN_CACHE: RefCell<HashMap<(TypeId, u64), Box<dyn Any>>> = RefCell::new(HashMap::new());
+}
+
 /// Defines a domain over which finite field (I)FFTs can be performed. Works
 /// only for fields that have a large multiplicative subgroup of size that is
 /// a power-of-2.
 #[derive(Copy, Clone, Hash, Eq, PartialEq, CanonicalSerialize, CanonicalDeserialize)]
+#[cfg_attr(feature = "checkpoint", derive(serde::Serialize, serde::Deserialize))]
 pub struct EvaluationDomain<F: FftField> {
     /// The size of the domain.
     pub size: u64,
@@ -117,6 +132,27 @@ impl<F: FftField> EvaluationDomain<F> {
     pub fn new(num_coeffs: usize) -> Option<Self> {
         // Compute the size of our evaluation domain
         let size = num_coeffs.checked_next_power_of_two()? as u64;
+
+        // Return the domain from the cache, if it has already been constructed on this thread.
+        let key = (TypeId::of::<F>(), size);
+        let cached =
+            DOMAIN_CACHE.with(|cache| cache.borrow().get(&key).and_then(|d| d.downcast_ref::<Self>()).copied());
+        if let Some(domain) = cached {
+            return Some(domain);
+        }
+
+        let domain = Self::new_uncached(size)?;
+        DOMAIN_CACHE.with(|cache| {
+            let mut cache = cache.borrow_mut();
+            if cache.len() < MAX_CACHED_DOMAINS {
+                cache.insert(key, Box::new(domain));
+            }
+        });
+        Some(domain)
+    }
+
+    /// Construct a domain of the given power-of-two `size`, without consulting the cache.
+    fn new_uncached(size: u64) -> Option<Self> {
         let log_size_of_group = size.trailing_zeros();
 
         // libfqfft uses > https://github.com/scipr-lab/libfqfft/blob/e0183b2cef7d4c5deb21a6eaf3fe3b586d738fe0/libfqfft/evaluation_domain/domains/basic_radix2_domain.tcc#L33
@@ -316,6 +352,25 @@ impl<F: FftField> EvaluationDomain<F> {
         cfg_iter_mut!(evals).for_each(|eval| *eval *= &i);
     }
 
+    /// Returns the inverses of the evaluations of the vanishing polynomial of `self`
+    /// over the coset `g * other`, where `g` is the multiplicative generator.
+    /// As `self` is a subgroup of `other`, these evaluations repeat with a period of
+    /// `other.size() / self.size()`, so only the first period is returned.
+    /// Returns `None` if `other` is smaller than `self`.
+    pub fn inverse_vanishing_polynomial_evals_on_coset(&self, other: &Self) -> Option<Vec<F>> {
+        if other.size < self.size {
+            return None;
+        }
+        let period = (other.size / self.size) as usize;
+        // The evaluation at `g * w^i` is `g^|self| * (w^|self|)^i - 1`.
+        let coset_offset = F::multiplicative_generator().pow([self.size]);
+        let root = other.group_gen.pow([self.size]);
+        let mut evals = compute_powers_and_mul_by_const_serial(period, root, coset_offset);
+        cfg_iter_mut!(evals).for_each(|eval| *eval -= F::one());
+        batch_inversion(&mut evals);
+        Some(evals)
+    }
+
     /// Given an index in the `other` subdomain, return an index into this domain `self`
     /// This assumes the `other`'s elements are also `self`'s first elements
     pub fn reindex_by_subdomain(&self, other: &Self, index: usize) -> Result<usize> {
@@ -382,6 +437,7 @@ impl<F: FftField> EvaluationDomain<F> {
                 snarkvm_algorithms_cuda::NTTType::Standard,
             );
             if result.is_ok() {
+                record_fft(self.size as usize);
                 return;
             }
         }
@@ -411,6 +467,7 @@ impl<F: FftField> EvaluationDomain<F> {
                 snarkvm_algorithms_cuda::NTTType::Standard,
             );
             if result.is_ok() {
+                record_fft(self.size as usize);
                 return;
             }
         }
@@ -432,6 +489,7 @@ impl<F: FftField> EvaluationDomain<F> {
                 snarkvm_algorithms_cuda::NTTType::Coset,
             );
             if result.is_ok() {
+                record_fft(self.size as usize);
                 return;
             }
         }
@@ -459,6 +517,7 @@ impl<F: FftField> EvaluationDomain<F> {
                 snarkvm_algorithms_cuda::NTTType::Standard,
             );
             if result.is_ok() {
+                record_fft(self.size as usize);
                 return;
             }
         }
@@ -490,6 +549,7 @@ impl<F: FftField> EvaluationDomain<F> {
                 snarkvm_algorithms_cuda::NTTType::Standard,
             );
             if result.is_ok() {
+                record_fft(self.size as usize);
                 return;
             }
         }
@@ -524,6 +584,7 @@ impl<F: FftField> EvaluationDomain<F> {
                 snarkvm_algorithms_cuda::NTTType::Coset,
             );
             if result.is_ok() {
+                record_fft(self.size as usize);
                 return;
             }
         }
@@ -542,6 +603,7 @@ impl<F: FftField> EvaluationDomain<F> {
         use FFTOrder::*;
         let pc = pre_comp.precomputation_for_subdomain(self).unwrap();
 
+        record_fft(x_s.len());
         let log_len = log2(x_s.len());
 
         if ord == OI {
@@ -567,6 +629,7 @@ impl<F: FftField> EvaluationDomain<F> {
         use FFTOrder::*;
         let pc = pre_comp.precomputation_for_subdomain(self).unwrap();
 
+        record_fft(x_s.len());
         let log_len = log2(x_s.len());
 
         if ord == II {
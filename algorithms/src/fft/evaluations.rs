@@ -70,6 +70,28 @@ impl<F: PrimeField> Evaluations<F> {
         DensePolynomial::from_coefficients_vec(evals)
     }
 
+    /// Evaluate `poly` over the coset `g * domain`, where `g` is the multiplicative generator.
+    pub fn evaluate_over_coset(poly: &DensePolynomial<F>, domain: EvaluationDomain<F>) -> Self {
+        Self::from_vec_and_domain(domain.coset_fft(&poly.coeffs), domain)
+    }
+
+    /// Interpolate a polynomial from a list of evaluations over the coset `g * self.domain()`.
+    pub fn interpolate_over_coset(self) -> DensePolynomial<F> {
+        let Self { evaluations: mut evals, domain } = self;
+        domain.coset_ifft_in_place(&mut evals);
+        DensePolynomial::from_coefficients_vec(evals)
+    }
+
+    /// Divides `self` by the vanishing polynomial of `vanishing_domain`,
+    /// where `self` holds evaluations over the coset `g * self.domain()`.
+    /// The result is only meaningful if the vanishing polynomial divides the interpolated polynomial.
+    /// Returns `None` if `vanishing_domain` is larger than `self.domain()`.
+    pub fn divide_by_vanishing_poly_on_coset(mut self, vanishing_domain: EvaluationDomain<F>) -> Option<Self> {
+        let inverses = vanishing_domain.inverse_vanishing_polynomial_evals_on_coset(&self.domain)?;
+        cfg_iter_mut!(self.evaluations).enumerate().for_each(|(i, eval)| *eval *= inverses[i % inverses.len()]);
+        Some(self)
+    }
+
     /// Returns the evaluations of `self`.
     pub fn evaluations(&self) -> &[F] {
         &self.evaluations
@@ -24,6 +24,9 @@ pub use evaluations::Evaluations;
 pub mod polynomial;
 pub use polynomial::{DensePolynomial, Polynomial, SparsePolynomial};
 
+pub mod statistics;
+pub use statistics::FFTRecorder;
+
 #[cfg(test)]
 mod tests;
 
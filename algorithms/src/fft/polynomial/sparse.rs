@@ -14,7 +14,7 @@
 
 //! A sparse polynomial represented in coefficient form.
 
-use crate::fft::{EvaluationDomain, Evaluations, Polynomial};
+use crate::fft::{DensePolynomial, EvaluationDomain, Evaluations, Polynomial};
 use snarkvm_fields::{Field, PrimeField};
 use snarkvm_utilities::serialize::*;
 
@@ -110,6 +110,13 @@ impl<F: Field> SparsePolynomial<F> {
     }
 }
 
+impl<F: Field> From<DensePolynomial<F>> for SparsePolynomial<F> {
+    /// Converts the dense polynomial into a sparse polynomial, dropping the zero coefficients.
+    fn from(other: DensePolynomial<F>) -> Self {
+        Self::from_coefficients(other.coeffs.into_iter().enumerate())
+    }
+}
+
 impl<F: PrimeField> SparsePolynomial<F> {
     /// Evaluate `self` over `domain`.
     pub fn evaluate_over_domain_by_ref(&self, domain: EvaluationDomain<F>) -> Evaluations<F> {
@@ -173,7 +180,8 @@ impl<'a, F: PrimeField> core::ops::AddAssign<(F, &'a Self)> for SparsePolynomial
 mod tests {
     use crate::fft::{DensePolynomial, EvaluationDomain, SparsePolynomial};
     use snarkvm_curves::bls12_377::Fr;
-    use snarkvm_fields::One;
+    use snarkvm_fields::{One, Zero};
+    use snarkvm_utilities::rand::{TestRng, Uniform};
 
     #[test]
     fn evaluate_over_domain() {
@@ -191,4 +199,31 @@ mod tests {
             assert_eq!(evals2.interpolate(), dense_poly);
         }
     }
+
+    #[test]
+    fn dense_round_trip() {
+        let rng = &mut TestRng::default();
+        for degree in 0..20 {
+            // Sample a dense polynomial, with every third coefficient set to zero.
+            let mut dense = DensePolynomial::<Fr>::rand(degree, rng);
+            dense.coeffs.iter_mut().step_by(3).for_each(|coeff| *coeff = Fr::zero());
+            let dense = DensePolynomial::from_coefficients_vec(dense.coeffs);
+
+            // Ensure the zero coefficients are dropped.
+            let sparse = SparsePolynomial::from(dense.clone());
+            assert!(sparse.coeffs().all(|(_, coeff)| !coeff.is_zero()));
+            assert_eq!(sparse.coeffs().count(), dense.coeffs.iter().filter(|coeff| !coeff.is_zero()).count());
+
+            // Ensure the round trips recover the polynomials.
+            assert_eq!(DensePolynomial::from(sparse.clone()), dense);
+            assert_eq!(SparsePolynomial::from(DensePolynomial::from(sparse.clone())), sparse);
+            let point = Fr::rand(rng);
+            assert_eq!(sparse.evaluate(point), dense.evaluate(point));
+        }
+
+        // Ensure the zero polynomial round trips.
+        let zero = SparsePolynomial::<Fr>::from(DensePolynomial::zero());
+        assert!(zero.is_zero());
+        assert!(DensePolynomial::from(zero).is_zero());
+    }
 }
@@ -0,0 +1,73 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::{
+    collections::BTreeMap,
+    sync::atomic::{AtomicU64, AtomicUsize, Ordering},
+};
+
+/// The number of active `FFTRecorder`s. FFTs are only counted while this is nonzero.
+static NUM_RECORDERS: AtomicUsize = AtomicUsize::new(0);
+
+#[allow(clippy::declare_interior_mutable_const)]
+const ZERO: AtomicU64 = AtomicU64::new(0);
+/// The number of FFTs performed for each domain size, indexed by the base-2 logarithm of the size.
+static NUM_FFTS: [AtomicU64; 64] = [ZERO; 64];
+
+/// Records an FFT (or inverse FFT) over a domain of the given size, if any `FFTRecorder` is active.
+#[inline]
+pub(crate) fn record_fft(size: usize) {
+    if NUM_RECORDERS.load(Ordering::Relaxed) > 0 {
+        if let Some(num_ffts) = NUM_FFTS.get(size.trailing_zeros() as usize) {
+            num_ffts.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+}
+
+/// Records the FFTs performed by this process since its creation, for profiling.
+/// Note that FFTs performed concurrently by unrelated work, such as another prover, are recorded as well.
+pub struct FFTRecorder {
+    /// The number of FFTs performed for each domain size, when the recording started.
+    start: [u64; 64],
+}
+
+impl FFTRecorder {
+    /// Starts recording FFTs.
+    pub fn start() -> Self {
+        NUM_RECORDERS.fetch_add(1, Ordering::SeqCst);
+        Self { start: Self::counts() }
+    }
+
+    /// Returns the number of FFTs recorded so far, for each domain size.
+    pub fn num_ffts(&self) -> BTreeMap<usize, u64> {
+        Self::counts()
+            .into_iter()
+            .zip(self.start)
+            .enumerate()
+            .filter(|(_, (count, start))| count > start)
+            .map(|(log_size, (count, start))| (1 << log_size, count - start))
+            .collect()
+    }
+
+    /// Returns the number of FFTs performed so far, for each domain size.
+    fn counts() -> [u64; 64] {
+        core::array::from_fn(|i| NUM_FFTS[i].load(Ordering::Relaxed))
+    }
+}
+
+impl Drop for FFTRecorder {
+    fn drop(&mut self) {
+        NUM_RECORDERS.fetch_sub(1, Ordering::SeqCst);
+    }
+}
@@ -14,7 +14,7 @@
 
 use crate::fft::{domain::*, DensePolynomial};
 use rand::Rng;
-use snarkvm_curves::bls12_377::{Fr, G1Projective};
+use snarkvm_curves::bls12_377::{Fq, Fr, G1Projective};
 use snarkvm_fields::{FftField, Field, One, Zero};
 use snarkvm_utilities::rand::{TestRng, Uniform};
 
@@ -43,6 +43,23 @@ fn vanishing_polynomial_vanishes_on_domain() {
     }
 }
 
+#[test]
+fn cached_domain_matches_uncached() {
+    for log_size in 0..20 {
+        let size = 1u64 << log_size;
+        let first = EvaluationDomain::<Fr>::new(size as usize).unwrap();
+        let second = EvaluationDomain::<Fr>::new(size as usize).unwrap();
+        assert_eq!(first, second);
+        assert_eq!(first, EvaluationDomain::<Fr>::new_uncached(size).unwrap());
+    }
+    // Domains over different fields with the same size must not collide in the cache.
+    let domain = EvaluationDomain::<Fr>::new(16).unwrap();
+    let other = EvaluationDomain::<Fq>::new(16).unwrap();
+    assert_eq!(domain.size, other.size);
+    assert_eq!(domain.group_gen.pow([16]), Fr::one());
+    assert_eq!(other.group_gen.pow([16]), Fq::one());
+}
+
 #[test]
 fn size_of_elements() {
     for coeffs in 1..10 {
@@ -341,3 +358,38 @@ fn evaluate_over_domain() {
         }
     }
 }
+
+#[test]
+fn divide_by_vanishing_poly_on_coset() {
+    use crate::fft::Evaluations;
+
+    let rng = &mut TestRng::default();
+
+    for log_vanishing_size in 0..6 {
+        let vanishing_domain = EvaluationDomain::<Fr>::new(1 << log_vanishing_size).unwrap();
+        let vanishing_poly = DensePolynomial::from(vanishing_domain.vanishing_polynomial());
+        for log_coset_size in (log_vanishing_size + 1)..(log_vanishing_size + 4) {
+            let coset_domain = EvaluationDomain::<Fr>::new(1 << log_coset_size).unwrap();
+            // Sample a quotient such that the dividend fits within the coset domain.
+            let degree = coset_domain.size() - vanishing_domain.size() - 1;
+            let quotient = DensePolynomial::rand(degree, rng);
+            let dividend = &quotient * &vanishing_poly;
+
+            let (expected, remainder) = dividend.divide_by_vanishing_poly(vanishing_domain).unwrap();
+            assert!(remainder.is_zero());
+
+            let candidate = Evaluations::evaluate_over_coset(&dividend, coset_domain)
+                .divide_by_vanishing_poly_on_coset(vanishing_domain)
+                .unwrap()
+                .interpolate_over_coset();
+            assert_eq!(candidate, expected);
+            assert_eq!(candidate, quotient);
+        }
+    }
+
+    // The vanishing domain must not be larger than the coset domain.
+    let small_domain = EvaluationDomain::<Fr>::new(4).unwrap();
+    let large_domain = EvaluationDomain::<Fr>::new(8).unwrap();
+    let evals = Evaluations::from_vec_and_domain(vec![Fr::one(); 4], small_domain);
+    assert!(evals.divide_by_vanishing_poly_on_coset(large_domain).is_none());
+}
@@ -97,6 +97,29 @@ pub enum PCError {
 
 impl snarkvm_utilities::error::Error for PCError {}
 
+/// The error type for `SonicKZG10::try_trim`.
+#[derive(Debug)]
+pub enum TrimError {
+    /// The requested degree is larger than the maximum degree supported by the SRS.
+    DegreeTooLarge { requested_degree: usize, available_degree: usize },
+    /// Trimming failed for a reason unrelated to the requested degree.
+    Other(anyhow::Error),
+}
+
+impl snarkvm_utilities::error::Error for TrimError {}
+
+impl core::fmt::Display for TrimError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::DegreeTooLarge { requested_degree, available_degree } => write!(
+                f,
+                "the degree provided to `trim` ({requested_degree}) is larger than the maximum degree of the SRS ({available_degree})"
+            ),
+            Self::Other(error) => write!(f, "{error}"),
+        }
+    }
+}
+
 impl From<anyhow::Error> for PCError {
     fn from(other: anyhow::Error) -> Self {
         Self::AnyhowError(other)
@@ -15,7 +15,7 @@
 use crate::{
     fft::DensePolynomial,
     msm::variable_base::VariableBase,
-    polycommit::{kzg10, optional_rng::OptionalRng, PCError},
+    polycommit::{kzg10, optional_rng::OptionalRng, PCError, TrimError},
     srs::{UniversalProver, UniversalVerifier},
     AlgebraicSponge,
 };
@@ -58,12 +58,58 @@ impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
         kzg10::KZG10::load_srs(max_degree).map_err(Into::into)
     }
 
+    #[deprecated(note = "use `SonicKZG10::try_trim` instead")]
     pub fn trim(
         pp: &UniversalParams<E>,
         supported_degree: usize,
         supported_lagrange_sizes: impl IntoIterator<Item = usize>,
         supported_hiding_bound: usize,
         enforced_degree_bounds: Option<&[usize]>,
+    ) -> Result<(CommitterKey<E>, UniversalVerifier<E>)> {
+        Ok(Self::try_trim(
+            pp,
+            supported_degree,
+            supported_lagrange_sizes,
+            supported_hiding_bound,
+            enforced_degree_bounds,
+        )?)
+    }
+
+    /// Trims the universal parameters down to the given `supported_degree`.
+    ///
+    /// Returns `TrimError::DegreeTooLarge` if `supported_degree`, or any of the
+    /// `enforced_degree_bounds`, exceeds the maximum degree of the SRS.
+    pub fn try_trim(
+        pp: &UniversalParams<E>,
+        supported_degree: usize,
+        supported_lagrange_sizes: impl IntoIterator<Item = usize>,
+        supported_hiding_bound: usize,
+        enforced_degree_bounds: Option<&[usize]>,
+    ) -> Result<(CommitterKey<E>, UniversalVerifier<E>), TrimError> {
+        let available_degree = pp.max_degree();
+        let requested_degree = enforced_degree_bounds
+            .and_then(|bounds| bounds.iter().max().copied())
+            .map_or(supported_degree, |bound| bound.max(supported_degree));
+        if requested_degree > available_degree {
+            return Err(TrimError::DegreeTooLarge { requested_degree, available_degree });
+        }
+
+        Self::trim_unchecked(
+            pp,
+            supported_degree,
+            supported_lagrange_sizes,
+            supported_hiding_bound,
+            enforced_degree_bounds,
+        )
+        .map_err(TrimError::Other)
+    }
+
+    fn trim_unchecked(
+        pp: &UniversalParams<E>,
+        supported_degree: usize,
+        supported_lagrange_sizes: impl IntoIterator<Item = usize>,
+        supported_hiding_bound: usize,
+        enforced_degree_bounds: Option<&[usize]>,
     ) -> Result<(CommitterKey<E>, UniversalVerifier<E>)> {
         let trim_time = start_timer!(|| "Trimming public parameters");
         let max_degree = pp.max_degree();
@@ -707,7 +753,10 @@ mod tests {
     #![allow(non_camel_case_types)]
 
     use super::{CommitterKey, SonicKZG10};
-    use crate::{crypto_hash::PoseidonSponge, polycommit::test_templates::*};
+    use crate::{
+        crypto_hash::PoseidonSponge,
+        polycommit::{test_templates::*, TrimError},
+    };
     use snarkvm_curves::bls12_377::{Bls12_377, Fq};
     use snarkvm_utilities::{rand::TestRng, FromBytes, ToBytes};
 
@@ -726,7 +775,8 @@ mod tests {
 
         let pp = PC_Bls12_377::load_srs(max_degree).unwrap();
 
-        let (ck, _vk) = PC_Bls12_377::trim(&pp, supported_degree, [lagrange_size(supported_degree)], 0, None).unwrap();
+        let (ck, _vk) =
+            PC_Bls12_377::try_trim(&pp, supported_degree, [lagrange_size(supported_degree)], 0, None).unwrap();
 
         let ck_bytes = ck.to_bytes_le().unwrap();
         let ck_recovered: CommitterKey<Bls12_377> = FromBytes::read_le(&ck_bytes[..]).unwrap();
@@ -735,6 +785,28 @@ mod tests {
         assert_eq!(&ck_bytes, &ck_recovered_bytes);
     }
 
+    #[test]
+    fn test_try_trim_degree_too_large() {
+        let pp = PC_Bls12_377::load_srs(64).unwrap();
+        let max_degree = pp.max_degree();
+
+        // Trimming beyond the maximum degree of the SRS returns an error carrying both degrees.
+        match PC_Bls12_377::try_trim(&pp, max_degree + 1, [], 0, None) {
+            Err(TrimError::DegreeTooLarge { requested_degree, available_degree }) => {
+                assert_eq!(requested_degree, max_degree + 1);
+                assert_eq!(available_degree, max_degree);
+            }
+            _ => panic!("Expected `TrimError::DegreeTooLarge`"),
+        }
+        // An enforced degree bound beyond the maximum degree is rejected in the same way.
+        assert!(matches!(
+            PC_Bls12_377::try_trim(&pp, 8, [], 0, Some(&[max_degree + 1])),
+            Err(TrimError::DegreeTooLarge { requested_degree, .. }) if requested_degree == max_degree + 1
+        ));
+        // Trimming within the maximum degree succeeds.
+        assert!(PC_Bls12_377::try_trim(&pp, 8, [], 0, Some(&[4])).is_ok());
+    }
+
     #[test]
     fn test_single_poly() {
         single_poly_test::<Bls12_377, Sponge>().expect("test failed for bls12-377");
@@ -93,7 +93,7 @@ pub fn bad_degree_bound_test<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>>() -
 
         println!("supported degree: {supported_degree:?}");
         let (ck, vk) =
-            SonicKZG10::<E, S>::trim(&pp, supported_degree, None, supported_degree, Some(degree_bounds.as_slice()))
+            SonicKZG10::<E, S>::try_trim(&pp, supported_degree, None, supported_degree, Some(degree_bounds.as_slice()))
                 .unwrap();
         println!("Trimmed");
 
@@ -179,7 +179,7 @@ pub fn lagrange_test_template<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>>()
         println!("supported degree: {supported_degree:?}");
         println!("supported hiding bound: {supported_hiding_bound:?}");
         println!("num_points_in_query_set: {num_points_in_query_set:?}");
-        let (ck, vk_orig) = SonicKZG10::<E, S>::trim(
+        let (ck, vk_orig) = SonicKZG10::<E, S>::try_trim(
             &pp,
             supported_degree,
             supported_lagrange_sizes,
@@ -316,7 +316,7 @@ where
         println!("supported hiding bound: {supported_hiding_bound:?}");
         println!("num_points_in_query_set: {num_points_in_query_set:?}");
         let (ck, vk_orig) =
-            SonicKZG10::<E, S>::trim(&pp, supported_degree, None, supported_hiding_bound, degree_bounds.as_deref())
+            SonicKZG10::<E, S>::try_trim(&pp, supported_degree, None, supported_hiding_bound, degree_bounds.as_deref())
                 .unwrap();
         println!("Trimmed");
 
@@ -449,7 +449,7 @@ fn equation_test_template<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>>(
         println!("{enforce_degree_bounds}");
 
         let (ck, vk_orig) =
-            SonicKZG10::<E, S>::trim(&pp, supported_degree, None, supported_hiding_bound, degree_bounds.as_deref())
+            SonicKZG10::<E, S>::try_trim(&pp, supported_degree, None, supported_hiding_bound, degree_bounds.as_deref())
                 .unwrap();
         println!("Trimmed");
 
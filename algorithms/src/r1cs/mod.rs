@@ -36,6 +36,9 @@ pub use namespace::*;
 mod optional_vec;
 pub use optional_vec::*;
 
+mod sparse_matrix;
+pub use sparse_matrix::*;
+
 mod test_constraint_system;
 pub use test_constraint_system::{Fr, TestConstraintSystem};
 
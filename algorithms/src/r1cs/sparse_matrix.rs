@@ -0,0 +1,175 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use snarkvm_fields::Field;
+use snarkvm_utilities::cfg_iter;
+
+#[cfg(not(feature = "serial"))]
+use rayon::prelude::*;
+
+/// A sparse matrix whose rows are split into their unit-coefficient and general-coefficient entries.
+///
+/// The split is computed once, at index time, so that the matrix-vector product can accumulate
+/// the unit-coefficient entries without a multiplication and without branching on each coefficient;
+/// R1CS matrices are dominated by such entries. The original matrix is left untouched, since its
+/// entry order feeds into the index polynomials.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SplitSparseMatrix<F: Field> {
+    /// The columns of the unit-coefficient entries of each row.
+    unit_rows: Vec<Vec<usize>>,
+    /// The `(coefficient, column)` pairs of the remaining entries of each row.
+    general_rows: Vec<Vec<(F, usize)>>,
+}
+
+impl<F: Field> SplitSparseMatrix<F> {
+    /// Splits each row of the given sparse `matrix` into its unit-coefficient and general-coefficient entries.
+    pub fn new(matrix: &[Vec<(F, usize)>]) -> Self {
+        let (unit_rows, general_rows) = cfg_iter!(matrix)
+            .map(|row| {
+                let (unit, general): (Vec<_>, Vec<_>) = row.iter().partition(|(coefficient, _)| coefficient.is_one());
+                (unit.into_iter().map(|(_, column)| *column).collect(), general.into_iter().copied().collect())
+            })
+            .unzip();
+        Self { unit_rows, general_rows }
+    }
+
+    /// Returns the number of rows in the matrix.
+    pub fn num_rows(&self) -> usize {
+        self.unit_rows.len()
+    }
+
+    /// Returns the product of the matrix with the assignment vector `(public_variables || private_variables)`.
+    ///
+    /// The first `public_variables.len()` columns index into the public variables, and the remaining
+    /// columns index into the private variables. The rows are evaluated in parallel.
+    pub fn vector_product(&self, public_variables: &[F], private_variables: &[F]) -> Vec<F> {
+        let num_public_variables = public_variables.len();
+        // Fetch the variable at the given column.
+        let variable = |i: usize| match i < num_public_variables {
+            true => public_variables[i],
+            false => private_variables[i - num_public_variables],
+        };
+
+        cfg_iter!(self.unit_rows)
+            .zip(cfg_iter!(self.general_rows))
+            .map(|(unit_row, general_row)| {
+                let unit_sum = unit_row.iter().map(|i| variable(*i)).sum::<F>();
+                let general_sum = general_row.iter().map(|(coefficient, i)| variable(*i) * coefficient).sum::<F>();
+                unit_sum + general_sum
+            })
+            .collect()
+    }
+}
+
+/// Returns the product of the sparse `matrix` with the assignment vector `(public_variables || private_variables)`.
+///
+/// This splits the matrix on every call; callers that evaluate the same matrix repeatedly
+/// should construct a [`SplitSparseMatrix`] once and reuse it.
+pub fn sparse_matrix_vector_product<F: Field>(
+    matrix: &[Vec<(F, usize)>],
+    public_variables: &[F],
+    private_variables: &[F],
+) -> Vec<F> {
+    SplitSparseMatrix::new(matrix).vector_product(public_variables, private_variables)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_curves::bls12_377::Fr;
+    use snarkvm_fields::{One, Zero};
+    use snarkvm_utilities::{TestRng, Uniform};
+
+    use rand::Rng;
+
+    const ITERATIONS: usize = 100;
+
+    /// Computes the matrix-vector product by materializing the full assignment vector.
+    fn naive_product(matrix: &[Vec<(Fr, usize)>], public_variables: &[Fr], private_variables: &[Fr]) -> Vec<Fr> {
+        let assignment = public_variables.iter().chain(private_variables).copied().collect::<Vec<_>>();
+        matrix.iter().map(|row| row.iter().map(|(coefficient, i)| *coefficient * assignment[*i]).sum()).collect()
+    }
+
+    /// Samples a random sparse matrix, where roughly half of the coefficients are one.
+    fn sample_matrix(num_rows: usize, num_columns: usize, rng: &mut TestRng) -> Vec<Vec<(Fr, usize)>> {
+        (0..num_rows)
+            .map(|_| {
+                let num_entries = rng.gen_range(0..6);
+                (0..num_entries)
+                    .map(|_| {
+                        let coefficient = if rng.gen() { Fr::one() } else { Fr::rand(rng) };
+                        (coefficient, rng.gen_range(0..num_columns))
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_sparse_matrix_vector_product() {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            let num_public = rng.gen_range(1..16);
+            let num_private = rng.gen_range(0..64);
+            let num_rows = rng.gen_range(0..64);
+
+            let public_variables = (0..num_public).map(|_| Fr::rand(&mut rng)).collect::<Vec<_>>();
+            let private_variables = (0..num_private).map(|_| Fr::rand(&mut rng)).collect::<Vec<_>>();
+            let matrix = sample_matrix(num_rows, num_public + num_private, &mut rng);
+
+            let expected = naive_product(&matrix, &public_variables, &private_variables);
+            let candidate = sparse_matrix_vector_product(&matrix, &public_variables, &private_variables);
+            assert_eq!(expected, candidate);
+        }
+    }
+
+    #[test]
+    fn test_split_sparse_matrix_reuse() {
+        let mut rng = TestRng::default();
+
+        let (num_public, num_private, num_rows) = (8, 32, 48);
+        let matrix = sample_matrix(num_rows, num_public + num_private, &mut rng);
+
+        // Split the matrix once, and evaluate it against several assignments.
+        let split = SplitSparseMatrix::new(&matrix);
+        assert_eq!(split.num_rows(), num_rows);
+        for _ in 0..ITERATIONS {
+            let public_variables = (0..num_public).map(|_| Fr::rand(&mut rng)).collect::<Vec<_>>();
+            let private_variables = (0..num_private).map(|_| Fr::rand(&mut rng)).collect::<Vec<_>>();
+
+            let expected = naive_product(&matrix, &public_variables, &private_variables);
+            assert_eq!(expected, split.vector_product(&public_variables, &private_variables));
+        }
+    }
+
+    #[test]
+    fn test_split_sparse_matrix_segments() {
+        let two = Fr::one().double();
+        let matrix = vec![vec![(Fr::one(), 0), (two, 1), (Fr::one(), 2)], vec![(two, 0)], vec![]];
+
+        let split = SplitSparseMatrix::new(&matrix);
+        assert_eq!(split.unit_rows, vec![vec![0, 2], vec![], vec![]]);
+        assert_eq!(split.general_rows, vec![vec![(two, 1)], vec![(two, 0)], vec![]]);
+    }
+
+    #[test]
+    fn test_sparse_matrix_vector_product_empty_rows() {
+        let public_variables = [Fr::one(), Fr::one().double()];
+        let matrix = vec![vec![], vec![(Fr::one(), 1)], vec![]];
+
+        let candidate = sparse_matrix_vector_product(&matrix, &public_variables, &[]);
+        assert_eq!(candidate, vec![Fr::zero(), Fr::one().double(), Fr::zero()]);
+    }
+}
@@ -20,12 +20,12 @@ use crate::{
     polycommit::sonic_pc::{LCTerm, LabeledPolynomial, LinearCombination},
     r1cs::SynthesisError,
     snark::varuna::{
-        ahp::{verifier, AHPError, CircuitId, CircuitInfo},
+        ahp::{verifier, AHPError, CircuitId, CircuitInfo, InvalidPublicInput},
         prover,
         SNARKMode,
     },
 };
-use snarkvm_fields::{Field, PrimeField};
+use snarkvm_fields::{FftParameters, Field, PrimeField};
 
 use core::{borrow::Borrow, marker::PhantomData};
 use itertools::Itertools;
@@ -50,6 +50,17 @@ pub(crate) fn witness_label(circuit_id: CircuitId, poly: &str, i: usize) -> Stri
     format!("circuit_{circuit_id}_{poly}_{i:0>8}")
 }
 
+/// The largest domain sizes supported by an SRS of a given degree.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct SupportedDomainSizes {
+    /// The largest supported constraint domain size.
+    pub constraint_domain_size: usize,
+    /// The largest supported variable domain size.
+    pub variable_domain_size: usize,
+    /// The largest supported non-zero domain size.
+    pub non_zero_domain_size: usize,
+}
+
 pub(crate) struct NonZeroDomains<F: PrimeField> {
     pub(crate) max_non_zero_domain: Option<EvaluationDomain<F>>,
     pub(crate) domain_a: EvaluationDomain<F>,
@@ -68,15 +79,34 @@ impl<F: PrimeField, MM: SNARKMode> AHPForR1CS<F, MM> {
 
     /// Check that the (formatted) public input is of the form 2^n for some integer n.
     pub fn num_formatted_public_inputs_is_admissible(num_inputs: usize) -> Result<(), AHPError> {
-        match num_inputs.count_ones() == 1 {
-            true => Ok(()),
-            false => Err(AHPError::InvalidPublicInputLength),
+        match num_inputs {
+            0 => Err(InvalidPublicInput::Empty.into()),
+            n if n.is_power_of_two() => Ok(()),
+            n => Err(InvalidPublicInput::LengthNotPowerOfTwo { expected: n.next_power_of_two(), actual: n }.into()),
         }
     }
 
-    /// Check that the (formatted) public input is of the form 2^n for some integer n.
+    /// Check that the (formatted) public input is of the form 2^n for some integer n,
+    /// and that its first element is the constant one.
     pub fn formatted_public_input_is_admissible(input: &[F]) -> Result<(), AHPError> {
-        Self::num_formatted_public_inputs_is_admissible(input.len())
+        Self::num_formatted_public_inputs_is_admissible(input.len())?;
+        match input[0].is_one() {
+            true => Ok(()),
+            false => Err(InvalidPublicInput::UnexpectedValue {
+                index: 0,
+                expected: F::one().to_string(),
+                actual: input[0].to_string(),
+            }
+            .into()),
+        }
+    }
+
+    /// Formats the given public input by prepending the constant one,
+    /// and padding it with zeros up to the next power of two.
+    pub fn format_public_input(raw: &[F]) -> Vec<F> {
+        let mut input = prover::ConstraintSystem::format_public_input(raw);
+        input.resize(input.len().next_power_of_two(), F::zero());
+        input
     }
 
     /// The maximum degree of polynomials produced by the indexer and prover
@@ -84,7 +114,6 @@ impl<F: PrimeField, MM: SNARKMode> AHPForR1CS<F, MM> {
     /// The number of the variables must include the "one" variable. That is, it
     /// must be with respect to the number of formatted public inputs.
     pub fn max_degree(num_constraints: usize, num_variables: usize, num_non_zero: usize) -> Result<usize, AHPError> {
-        let zk_bound = Self::zk_bound().unwrap_or(0);
         let constraint_domain_size =
             EvaluationDomain::<F>::compute_size_of_domain(num_constraints).ok_or(AHPError::PolynomialDegreeTooLarge)?;
         let variable_domain_size =
@@ -92,8 +121,49 @@ impl<F: PrimeField, MM: SNARKMode> AHPForR1CS<F, MM> {
         let non_zero_domain_size =
             EvaluationDomain::<F>::compute_size_of_domain(num_non_zero).ok_or(AHPError::PolynomialDegreeTooLarge)?;
 
+        Ok(Self::max_degree_for_domain_sizes(constraint_domain_size, variable_domain_size, non_zero_domain_size))
+    }
+
+    /// The SRS degree required to index and prove a circuit of the given size.
+    /// This is the same as `max_degree`, and is provided for capacity planning.
+    pub fn required_srs_degree(
+        num_constraints: usize,
+        num_variables: usize,
+        num_non_zero: usize,
+    ) -> Result<usize, AHPError> {
+        Self::max_degree(num_constraints, num_variables, num_non_zero)
+    }
+
+    /// The largest constraint, variable, and non-zero domain sizes that are supported by an SRS of degree `srs_degree`.
+    /// As each domain contributes to a different set of polynomials, the returned sizes are supported simultaneously.
+    pub fn max_supported_constraints(srs_degree: usize) -> Result<SupportedDomainSizes, AHPError> {
+        // Returns the largest domain size that satisfies the given degree bound.
+        let largest_domain_size = |is_supported: &dyn Fn(usize) -> bool| {
+            (0..=F::FftParameters::TWO_ADICITY)
+                .map(|log_size| 1usize << log_size)
+                .take_while(|size| is_supported(*size))
+                .last()
+                .ok_or(AHPError::PolynomialDegreeTooLarge)
+        };
+        let is_supported = |c, v, n| Self::max_degree_for_domain_sizes(c, v, n) <= srs_degree;
+
+        Ok(SupportedDomainSizes {
+            constraint_domain_size: largest_domain_size(&|size| is_supported(size, 1, 1))?,
+            variable_domain_size: largest_domain_size(&|size| is_supported(1, size, 1))?,
+            non_zero_domain_size: largest_domain_size(&|size| is_supported(1, 1, size))?,
+        })
+    }
+
+    /// The maximum degree of polynomials produced by the indexer and prover for the given domain sizes.
+    fn max_degree_for_domain_sizes(
+        constraint_domain_size: usize,
+        variable_domain_size: usize,
+        non_zero_domain_size: usize,
+    ) -> usize {
+        let zk_bound = Self::zk_bound().unwrap_or(0);
+
         // these should correspond with the bounds set in the <round>.rs files
-        Ok(*[
+        *[
             2 * constraint_domain_size + 2 * zk_bound - 2,
             2 * variable_domain_size + 2 * zk_bound - 2,
             if MM::ZK { variable_domain_size + 3 } else { 0 }, // mask_poly
@@ -103,7 +173,7 @@ impl<F: PrimeField, MM: SNARKMode> AHPForR1CS<F, MM> {
         ]
         .iter()
         .max()
-        .unwrap())
+        .unwrap()
     }
 
     /// Get all the strict degree bounds enforced in the AHP.
@@ -515,10 +585,15 @@ impl<F: PrimeField> SelectorPolynomial<F> for EvaluationDomain<F> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::fft::{DensePolynomial, Evaluations};
+    use crate::{
+        fft::{DensePolynomial, Evaluations},
+        snark::varuna::{VarunaHidingMode, VarunaNonHidingMode},
+    };
     use snarkvm_curves::bls12_377::fr::Fr;
     use snarkvm_fields::{One, Zero};
-    use snarkvm_utilities::rand::TestRng;
+    use snarkvm_utilities::rand::{TestRng, Uniform};
+
+    type AHP = AHPForR1CS<Fr, VarunaHidingMode>;
 
     #[test]
     fn test_summation() {
@@ -566,4 +641,92 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_format_public_input_is_admissible() {
+        let mut rng = TestRng::default();
+
+        for num_inputs in 0..70 {
+            let raw = (0..num_inputs).map(|_| Fr::rand(&mut rng)).collect::<Vec<_>>();
+            let formatted = AHP::format_public_input(&raw);
+            assert!(formatted.len().is_power_of_two());
+            assert_eq!(formatted[1..=num_inputs], raw[..]);
+            assert!(formatted[num_inputs + 1..].iter().all(|x| x.is_zero()));
+            assert!(AHP::formatted_public_input_is_admissible(&formatted).is_ok());
+        }
+    }
+
+    #[test]
+    fn test_public_input_is_empty() {
+        match AHP::formatted_public_input_is_admissible(&[]) {
+            Err(AHPError::InvalidPublicInput(error)) => assert_eq!(error, InvalidPublicInput::Empty),
+            result => panic!("Unexpected result: {result:?}"),
+        }
+    }
+
+    #[test]
+    fn test_public_input_length_is_not_a_power_of_two() {
+        let input = vec![Fr::one(), Fr::zero(), Fr::zero()];
+        match AHP::formatted_public_input_is_admissible(&input) {
+            Err(AHPError::InvalidPublicInput(error)) => {
+                assert_eq!(error, InvalidPublicInput::LengthNotPowerOfTwo { expected: 4, actual: 3 })
+            }
+            result => panic!("Unexpected result: {result:?}"),
+        }
+    }
+
+    #[test]
+    fn test_public_input_first_element_is_not_one() {
+        let input = vec![Fr::zero(), Fr::one()];
+        match AHP::formatted_public_input_is_admissible(&input) {
+            Err(AHPError::InvalidPublicInput(error)) => assert_eq!(error, InvalidPublicInput::UnexpectedValue {
+                index: 0,
+                expected: Fr::one().to_string(),
+                actual: Fr::zero().to_string(),
+            }),
+            result => panic!("Unexpected result: {result:?}"),
+        }
+    }
+
+    fn check_max_supported_constraints<MM: SNARKMode>() {
+        for srs_degree in [4, 5, 100, 1 << 10, (1 << 16) - 1, 1 << 20] {
+            let supported = AHPForR1CS::<Fr, MM>::max_supported_constraints(srs_degree).unwrap();
+            let required = |c, v, n| AHPForR1CS::<Fr, MM>::required_srs_degree(c, v, n).unwrap();
+
+            // Check the boundary for the constraint domain.
+            let max_constraints = supported.constraint_domain_size;
+            assert!(required(max_constraints, 1, 1) <= srs_degree);
+            assert!(required(max_constraints + 1, 1, 1) > srs_degree);
+
+            // Check the boundary for the variable domain.
+            let max_variables = supported.variable_domain_size;
+            assert!(required(1, max_variables, 1) <= srs_degree);
+            assert!(required(1, max_variables + 1, 1) > srs_degree);
+
+            // Check the boundary for the non-zero domain.
+            let max_non_zero = supported.non_zero_domain_size;
+            assert!(required(1, 1, max_non_zero) <= srs_degree);
+            assert!(required(1, 1, max_non_zero + 1) > srs_degree);
+
+            // Ensure the maximum sizes are supported simultaneously.
+            assert!(required(max_constraints, max_variables, max_non_zero) <= srs_degree);
+        }
+    }
+
+    #[test]
+    fn test_max_supported_constraints() {
+        check_max_supported_constraints::<VarunaHidingMode>();
+        check_max_supported_constraints::<VarunaNonHidingMode>();
+
+        // Ensure an SRS that cannot support any circuit is rejected.
+        assert!(AHPForR1CS::<Fr, VarunaHidingMode>::max_supported_constraints(3).is_err());
+
+        // Ensure the constraint polynomials of the largest circuits accepted by the prover fit within the SRS,
+        // which allows half as many constraints as the SRS has powers, or a quarter of them in hiding mode.
+        type NonHidingAHP = AHPForR1CS<Fr, VarunaNonHidingMode>;
+        let supported = NonHidingAHP::max_supported_constraints(VarunaNonHidingMode::MAX_SRS_DEGREE).unwrap();
+        assert_eq!(supported.constraint_domain_size, 1 << 27);
+        let supported = AHP::max_supported_constraints(VarunaHidingMode::MAX_SRS_DEGREE).unwrap();
+        assert_eq!(supported.constraint_domain_size, 1 << 26);
+    }
 }
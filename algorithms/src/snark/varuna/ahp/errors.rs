@@ -19,18 +19,42 @@ pub enum AHPError {
     Anyhow(anyhow::Error),
     /// The batch size is zero.
     BatchSizeIsZero,
+    /// The circuit has more constraints than the prover accepts.
+    CircuitTooLarge { constraint_count: usize, max: usize },
     /// An error occurred during constraint generation.
     ConstraintSystemError(crate::r1cs::errors::SynthesisError),
     /// The instance generated during proving does not match that in the index.
     InstanceDoesNotMatchIndex,
-    /// The number of public inputs is incorrect.
-    InvalidPublicInputLength,
+    /// The formatted public input is not admissible.
+    InvalidPublicInput(InvalidPublicInput),
     /// During verification, a required evaluation is missing
     MissingEval(String),
     /// Currently we only support square constraint matrices.
     NonSquareMatrix,
     /// During synthesis, our polynomials ended up being too high of degree
     PolynomialDegreeTooLarge,
+    /// The prover was terminated before it could finish.
+    Terminated,
+}
+
+/// Describes why a formatted public input is not admissible.
+#[derive(Clone, Debug, PartialEq, Eq, Error)]
+pub enum InvalidPublicInput {
+    /// The formatted public input is empty.
+    #[error("The formatted public input is empty")]
+    Empty,
+    /// The number of formatted public inputs is not a power of two.
+    #[error("The number of formatted public inputs is {actual}, but must be a power of two (e.g. {expected})")]
+    LengthNotPowerOfTwo { expected: usize, actual: usize },
+    /// The formatted public input at `index` does not have the expected value.
+    #[error("The formatted public input at index {index} is {actual}, but must be {expected}")]
+    UnexpectedValue { index: usize, expected: String, actual: String },
+}
+
+impl From<InvalidPublicInput> for AHPError {
+    fn from(other: InvalidPublicInput) -> Self {
+        AHPError::InvalidPublicInput(other)
+    }
 }
 
 impl From<crate::r1cs::errors::SynthesisError> for AHPError {
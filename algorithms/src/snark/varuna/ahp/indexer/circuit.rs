@@ -20,14 +20,33 @@ use crate::{
         EvaluationDomain,
     },
     polycommit::sonic_pc::LabeledPolynomial,
-    snark::varuna::{ahp::matrices::MatrixArithmetization, AHPForR1CS, CircuitInfo, Matrix, SNARKMode},
+    r1cs::SplitSparseMatrix,
+    snark::varuna::{
+        ahp::matrices::MatrixArithmetization,
+        AHPForR1CS,
+        CircuitInfo,
+        CircuitSummary,
+        Matrix,
+        SNARKMode,
+    },
 };
+use anyhow::{ensure, Context};
 use blake2::Digest;
 use hex::FromHex;
 use snarkvm_fields::PrimeField;
 use snarkvm_utilities::{serialize::*, SerializationError};
+use std::path::Path;
+
+/// The magic bytes at the start of an index file.
+const INDEX_FILE_MAGIC: [u8; 4] = *b"VIDX";
+/// The version of the index file format.
+const INDEX_FILE_VERSION: u16 = 2;
+/// The length of the index file header: the magic bytes, the version, the circuit ID,
+/// and the SHA-256 hash of the payload.
+const INDEX_FILE_HEADER_LEN: usize = INDEX_FILE_MAGIC.len() + 2 + 32 + 32;
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Ord, PartialOrd, CanonicalSerialize, CanonicalDeserialize)]
+#[cfg_attr(feature = "checkpoint", derive(serde::Serialize, serde::Deserialize))]
 pub struct CircuitId(pub [u8; 32]);
 
 impl std::fmt::Display for CircuitId {
@@ -66,6 +85,12 @@ pub struct Circuit<F: PrimeField, MM: SNARKMode> {
     /// The C matrix for the R1CS instance
     pub c: Matrix<F>,
 
+    /// The A, B, and C matrices split into unit and general coefficients, for evaluating `z_A`, `z_B`, and `z_C`.
+    /// These are derived from the matrices above, and are not serialized.
+    pub a_split: SplitSparseMatrix<F>,
+    pub b_split: SplitSparseMatrix<F>,
+    pub c_split: SplitSparseMatrix<F>,
+
     /// Joint arithmetization of the A, B, and C matrices.
     pub a_arith: MatrixArithmetization<F>,
     pub b_arith: MatrixArithmetization<F>,
@@ -111,6 +136,13 @@ impl<F: PrimeField, MM: SNARKMode> Circuit<F, MM> {
         Ok(CircuitId(blake2.finalize().into()))
     }
 
+    /// Returns the commitment to the indexed circuit, i.e. the BLAKE2s hash of the circuit info and the
+    /// constraint matrices. This is the circuit ID stored in the verifying key, which the certificate of the
+    /// verifying key is checked against, so the certificate format does not carry a separate copy of it.
+    pub fn commitment_to_index(&self) -> [u8; 32] {
+        self.id.0
+    }
+
     /// The maximum degree required to represent polynomials of this index.
     pub fn max_degree(&self) -> usize {
         self.index_info.max_degree::<F, MM>()
@@ -126,6 +158,19 @@ impl<F: PrimeField, MM: SNARKMode> Circuit<F, MM> {
         crate::fft::EvaluationDomain::<F>::new(self.index_info.num_variables).unwrap().size()
     }
 
+    /// Returns a summary of the size of this R1CS instance.
+    pub fn info(&self) -> CircuitSummary {
+        CircuitSummary {
+            num_constraints: self.index_info.num_constraints,
+            num_variables: self.index_info.num_variables,
+            num_non_zero_a: self.index_info.num_non_zero_a,
+            num_non_zero_b: self.index_info.num_non_zero_b,
+            num_non_zero_c: self.index_info.num_non_zero_c,
+            constraint_domain_size: self.constraint_domain_size(),
+            variable_domain_size: self.variable_domain_size(),
+        }
+    }
+
     /// Iterate over the indexed polynomials.
     pub fn iter(&self) -> impl Iterator<Item = &LabeledPolynomial<F>> {
         // Alphabetical order
@@ -146,6 +191,55 @@ impl<F: PrimeField, MM: SNARKMode> Circuit<F, MM> {
         .into_iter()
     }
 
+    /// Writes the indexed circuit to the given file, so that it can be loaded with `from_index_file`
+    /// instead of re-indexing the circuit.
+    ///
+    /// The file consists of a header, with the format version, the circuit ID, and the SHA-256 hash of the payload,
+    /// followed by the compressed serialization of the circuit.
+    pub fn to_index_file<P: AsRef<Path>>(&self, path: P) -> anyhow::Result<()> {
+        // Serialize the circuit.
+        let mut payload = Vec::with_capacity(self.compressed_size());
+        self.serialize_compressed(&mut payload)?;
+        // Prepare the file contents.
+        let mut bytes = Vec::with_capacity(INDEX_FILE_HEADER_LEN + payload.len());
+        bytes.extend_from_slice(&INDEX_FILE_MAGIC);
+        bytes.extend_from_slice(&INDEX_FILE_VERSION.to_le_bytes());
+        bytes.extend_from_slice(&self.id.0);
+        bytes.extend_from_slice(&crate::crypto_hash::sha256(&payload));
+        bytes.extend_from_slice(&payload);
+        // Write the file.
+        let path = path.as_ref();
+        std::fs::write(path, bytes).with_context(|| format!("Failed to write the index file '{}'", path.display()))
+    }
+
+    /// Loads an indexed circuit from a file written by `to_index_file`.
+    ///
+    /// The SHA-256 hash only detects a corrupted payload, so the circuit ID is also recomputed from the loaded
+    /// constraint matrices, and the file is rejected unless it matches the circuit ID in the header.
+    /// Callers should compare the circuit ID against the one in their verifying key.
+    pub fn from_index_file<P: AsRef<Path>>(path: P) -> anyhow::Result<Self> {
+        // Read the file.
+        let path = path.as_ref();
+        let bytes =
+            std::fs::read(path).with_context(|| format!("Failed to read the index file '{}'", path.display()))?;
+        ensure!(bytes.len() >= INDEX_FILE_HEADER_LEN, "The index file is too short");
+        // Split the file into its header and payload.
+        let (magic, rest) = bytes.split_at(INDEX_FILE_MAGIC.len());
+        let (version, rest) = rest.split_at(2);
+        let (id, rest) = rest.split_at(32);
+        let (hash, payload) = rest.split_at(32);
+        // Check the header.
+        ensure!(magic == INDEX_FILE_MAGIC, "The file is not an index file");
+        let version = u16::from_le_bytes([version[0], version[1]]);
+        ensure!(version == INDEX_FILE_VERSION, "Unsupported index file version {version}");
+        ensure!(hash == crate::crypto_hash::sha256(payload), "The index file is corrupted");
+        // Deserialize the circuit, which recomputes its circuit ID from the constraint matrices.
+        let circuit = Self::deserialize_compressed(payload)?;
+        // Ensure the constraint matrices are those of the circuit in the header.
+        ensure!(circuit.id.0 == id, "The index file does not contain the circuit '{}'", hex::encode(id));
+        Ok(circuit)
+    }
+
     /// After indexing, we drop these evaluations to save space in the ProvingKey.
     pub fn prune_row_col_evals(&mut self) {
         self.a_arith.evals_on_K.row_col = None;
@@ -224,11 +318,15 @@ impl<F: PrimeField, MM: SNARKMode> CanonicalDeserialize for Circuit<F, MM> {
         let b = CanonicalDeserialize::deserialize_with_mode(&mut reader, compress, validate)?;
         let c = CanonicalDeserialize::deserialize_with_mode(&mut reader, compress, validate)?;
         let id = Self::hash(&index_info, &a, &b, &c)?;
+        let [a_split, b_split, c_split] = [&a, &b, &c].map(|matrix| SplitSparseMatrix::new(matrix));
         Ok(Circuit {
             index_info,
             a,
             b,
             c,
+            a_split,
+            b_split,
+            c_split,
             a_arith: CanonicalDeserialize::deserialize_with_mode(&mut reader, compress, validate)?,
             b_arith: CanonicalDeserialize::deserialize_with_mode(&mut reader, compress, validate)?,
             c_arith: CanonicalDeserialize::deserialize_with_mode(&mut reader, compress, validate)?,
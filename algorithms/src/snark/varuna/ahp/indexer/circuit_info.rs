@@ -54,3 +54,43 @@ impl ToBytes for CircuitInfo {
         (self.num_non_zero_c as u64).write_le(&mut w)
     }
 }
+
+/// A human-readable summary of the size of an indexed circuit.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct CircuitSummary {
+    /// The number of constraints.
+    pub num_constraints: usize,
+    /// The total number of variables in the constraint system.
+    pub num_variables: usize,
+    /// The number of non-zero entries in the A matrix.
+    pub num_non_zero_a: usize,
+    /// The number of non-zero entries in the B matrix.
+    pub num_non_zero_b: usize,
+    /// The number of non-zero entries in the C matrix.
+    pub num_non_zero_c: usize,
+    /// The size of the constraint domain.
+    pub constraint_domain_size: usize,
+    /// The size of the variable domain.
+    pub variable_domain_size: usize,
+}
+
+impl core::fmt::Display for CircuitSummary {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        let rows = [
+            ("Constraints", self.num_constraints),
+            ("Variables", self.num_variables),
+            ("Non-zero entries in A", self.num_non_zero_a),
+            ("Non-zero entries in B", self.num_non_zero_b),
+            ("Non-zero entries in C", self.num_non_zero_c),
+            ("Constraint domain size", self.constraint_domain_size),
+            ("Variable domain size", self.variable_domain_size),
+        ];
+        for (i, (name, value)) in rows.iter().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            write!(f, "{name:<24}{value:>12}")?;
+        }
+        Ok(())
+    }
+}
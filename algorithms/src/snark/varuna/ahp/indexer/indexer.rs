@@ -15,7 +15,7 @@
 use crate::{
     fft::EvaluationDomain,
     polycommit::sonic_pc::{PolynomialInfo, PolynomialLabel},
-    r1cs::{errors::SynthesisError, ConstraintSynthesizer, ConstraintSystem},
+    r1cs::{errors::SynthesisError, ConstraintSynthesizer, ConstraintSystem, SplitSparseMatrix},
     snark::varuna::{
         ahp::{
             indexer::{Circuit, CircuitId, CircuitInfo, ConstraintSystem as IndexerConstraintSystem},
@@ -87,11 +87,16 @@ impl<F: PrimeField, MM: SNARKMode> AHPForR1CS<F, MM> {
         .ok_or(anyhow!("The polynomial degree is too large"))?;
         end_timer!(fft_precomp_time);
 
+        let [a_split, b_split, c_split] = [&a, &b, &c].map(|matrix| SplitSparseMatrix::new(matrix));
+
         Ok(Circuit {
             index_info,
             a,
             b,
             c,
+            a_split,
+            b_split,
+            c_split,
             a_arith,
             b_arith,
             c_arith,
@@ -16,6 +16,8 @@
 
 mod circuit;
 pub(crate) use circuit::*;
+/// The indexed circuit, as returned by `AHPForR1CS::index`.
+pub use circuit::Circuit as Index;
 
 mod circuit_info;
 pub(crate) use circuit_info::*;
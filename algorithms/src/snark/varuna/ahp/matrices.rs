@@ -27,7 +27,6 @@ use snarkvm_fields::{Field, PrimeField};
 use snarkvm_utilities::{cfg_iter, cfg_iter_mut, serialize::*};
 
 use anyhow::{ensure, Result};
-use itertools::Itertools;
 use std::collections::BTreeMap;
 
 #[cfg(not(feature = "serial"))]
@@ -55,32 +54,27 @@ pub(crate) fn to_matrix_helper<F: Field>(matrix: &[Vec<(F, VarIndex)>], num_inpu
 
 /// Adds variables to randomize each z_M and preserve zero-knowledge
 /// When no random assignments are passed, we use F::one()
-pub(crate) fn add_randomizing_variables<F: PrimeField, CS: ConstraintSystem<F>>(
+pub fn add_randomizing_variables<F: PrimeField, CS: ConstraintSystem<F>>(
     cs: &mut CS,
     rand_assignments: Option<[F; 3]>,
 ) {
-    let mut assignments = [F::one(); 3];
-    if let Some(r) = rand_assignments {
-        assignments = r;
-    }
+    let [a, b, c] = rand_assignments.unwrap_or([F::one(); 3]);
 
-    let zk_vars = assignments
-        .into_iter()
-        .enumerate()
-        .map(|(i, assignment)| cs.alloc(|| format!("random_{i}"), || Ok(assignment)).unwrap())
-        .collect_vec();
-    cs.enforce(|| "constraint zk", |lc| lc + zk_vars[0], |lc| lc + zk_vars[1], |lc| lc + zk_vars[2]);
+    let a = cs.alloc(|| "random_0", || Ok(a)).unwrap();
+    let b = cs.alloc(|| "random_1", || Ok(b)).unwrap();
+    let c = cs.alloc(|| "random_2", || Ok(c)).unwrap();
+    cs.enforce(|| "constraint zk", |lc| lc + a, |lc| lc + b, |lc| lc + c);
 }
 
 /// Pads the public variables up to the closest power of two.
-pub(crate) fn pad_input_for_indexer_and_prover<F: PrimeField, CS: ConstraintSystem<F>>(cs: &mut CS) {
+pub fn pad_input_for_indexer_and_prover<F: PrimeField, CS: ConstraintSystem<F>>(cs: &mut CS) {
     let num_public_variables = cs.num_public_variables();
 
-    let power_of_two = EvaluationDomain::<F>::new(num_public_variables);
-    assert!(power_of_two.is_some());
+    let padded_size = EvaluationDomain::<F>::compute_size_of_domain(num_public_variables);
+    assert!(padded_size.is_some());
 
     // Allocated `zero` variables to pad the public input up to the next power of two.
-    let padded_size = power_of_two.unwrap().size();
+    let padded_size = padded_size.unwrap();
     if padded_size > num_public_variables {
         for i in 0..(padded_size - num_public_variables) {
             cs.alloc_input(|| format!("pad_input_{i}"), || Ok(F::zero())).unwrap();
@@ -235,11 +229,138 @@ pub(crate) fn transpose<F: PrimeField>(
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::snark::varuna::num_non_zero;
+    use crate::{
+        r1cs::ConstraintSynthesizer,
+        snark::varuna::{
+            ahp::{
+                indexer::ConstraintSystem as IndexerConstraintSystem,
+                prover::ConstraintSystem as ProverConstraintSystem,
+            },
+            num_non_zero,
+            TestCircuit,
+        },
+    };
     use snarkvm_curves::bls12_377::Fr as F;
     use snarkvm_fields::{One, Zero};
+    use snarkvm_utilities::{TestRng, Uniform};
+
+    use rand::Rng;
     use std::{borrow::Cow, collections::HashMap};
 
+    /// The previous implementation of `add_randomizing_variables`, against which the current one is checked.
+    fn add_randomizing_variables_reference<F: PrimeField, CS: ConstraintSystem<F>>(
+        cs: &mut CS,
+        rand_assignments: Option<[F; 3]>,
+    ) {
+        let mut assignments = [F::one(); 3];
+        if let Some(r) = rand_assignments {
+            assignments = r;
+        }
+
+        let zk_vars = assignments
+            .into_iter()
+            .enumerate()
+            .map(|(i, assignment)| cs.alloc(|| format!("random_{i}"), || Ok(assignment)).unwrap())
+            .collect::<Vec<_>>();
+        cs.enforce(|| "constraint zk", |lc| lc + zk_vars[0], |lc| lc + zk_vars[1], |lc| lc + zk_vars[2]);
+    }
+
+    /// The previous implementation of `pad_input_for_indexer_and_prover`, against which the current one is checked.
+    fn pad_input_reference<F: PrimeField, CS: ConstraintSystem<F>>(cs: &mut CS) {
+        let num_public_variables = cs.num_public_variables();
+
+        let power_of_two = EvaluationDomain::<F>::new(num_public_variables);
+        assert!(power_of_two.is_some());
+
+        let padded_size = power_of_two.unwrap().size();
+        if padded_size > num_public_variables {
+            for i in 0..(padded_size - num_public_variables) {
+                cs.alloc_input(|| format!("pad_input_{i}"), || Ok(F::zero())).unwrap();
+            }
+        }
+    }
+
+    /// Samples a random test circuit.
+    fn sample_circuit(rng: &mut TestRng) -> TestCircuit<F> {
+        let mul_depth = rng.gen_range(1..10);
+        let num_constraints = rng.gen_range(mul_depth..50);
+        let num_variables = rng.gen_range(mul_depth + 2..50);
+        TestCircuit::gen_rand(mul_depth, num_constraints, num_variables, rng).0
+    }
+
+    #[test]
+    fn check_padding_matches_reference() {
+        let rng = &mut TestRng::default();
+
+        for _ in 0..20 {
+            let circuit = sample_circuit(rng);
+            for rand_assignments in [None, Some([F::rand(rng), F::rand(rng), F::rand(rng)])] {
+                // Check the matrices of the indexer.
+                let mut expected = IndexerConstraintSystem::new();
+                circuit.generate_constraints(&mut expected).unwrap();
+                add_randomizing_variables_reference(&mut expected, rand_assignments);
+                pad_input_reference(&mut expected);
+
+                let mut candidate = IndexerConstraintSystem::new();
+                circuit.generate_constraints(&mut candidate).unwrap();
+                add_randomizing_variables(&mut candidate, rand_assignments);
+                pad_input_for_indexer_and_prover(&mut candidate);
+
+                assert_eq!(expected.a_matrix(), candidate.a_matrix());
+                assert_eq!(expected.b_matrix(), candidate.b_matrix());
+                assert_eq!(expected.c_matrix(), candidate.c_matrix());
+                assert_eq!(expected.num_public_variables, candidate.num_public_variables);
+                assert_eq!(expected.num_private_variables, candidate.num_private_variables);
+                assert_eq!(expected.num_constraints, candidate.num_constraints);
+
+                // Check the assignments of the prover, with capacity reserved as per the indexer.
+                let (num_public_variables, num_private_variables) =
+                    (candidate.num_public_variables, candidate.num_private_variables);
+
+                let mut expected = ProverConstraintSystem::new();
+                circuit.generate_constraints(&mut expected).unwrap();
+                add_randomizing_variables_reference(&mut expected, rand_assignments);
+                pad_input_reference(&mut expected);
+
+                let mut candidate = ProverConstraintSystem::with_capacity(num_public_variables, num_private_variables);
+                circuit.generate_constraints(&mut candidate).unwrap();
+                add_randomizing_variables(&mut candidate, rand_assignments);
+                pad_input_for_indexer_and_prover(&mut candidate);
+
+                assert_eq!(expected.public_variables, candidate.public_variables);
+                assert_eq!(expected.private_variables, candidate.private_variables);
+                assert_eq!(expected.num_public_variables, candidate.num_public_variables);
+                assert_eq!(expected.num_private_variables, candidate.num_private_variables);
+                assert_eq!(expected.num_constraints, candidate.num_constraints);
+            }
+        }
+    }
+
+    #[test]
+    fn check_padding_does_not_reallocate() {
+        let rng = &mut TestRng::default();
+
+        // Use 4 public inputs, so that the public variables are padded from 5 to 8.
+        let (circuit, _) = TestCircuit::<F>::gen_rand(4, 20, 30, rng);
+        let rand_assignments = Some([F::rand(rng), F::rand(rng), F::rand(rng)]);
+
+        // Determine the final number of variables from the indexer.
+        let mut ics = IndexerConstraintSystem::new();
+        circuit.generate_constraints(&mut ics).unwrap();
+        add_randomizing_variables(&mut ics, rand_assignments);
+        pad_input_for_indexer_and_prover(&mut ics);
+
+        // Ensure randomizing and padding do not grow the variables once the final capacity is reserved.
+        // Note: That the helpers do not allocate by themselves is checked by `tests/padding_allocations.rs`.
+        let mut pcs = ProverConstraintSystem::with_capacity(ics.num_public_variables, ics.num_private_variables);
+        circuit.generate_constraints(&mut pcs).unwrap();
+        let capacities = (pcs.public_variables.capacity(), pcs.private_variables.capacity());
+        add_randomizing_variables(&mut pcs, rand_assignments);
+        pad_input_for_indexer_and_prover(&mut pcs);
+        assert_eq!((pcs.public_variables.capacity(), pcs.private_variables.capacity()), capacities);
+        assert_eq!(pcs.public_variables.len(), 8);
+    }
+
     fn entry(matrix: &Matrix<F>, row: usize, col: usize) -> F {
         matrix[row].iter().find_map(|(f, i)| (i == &col).then_some(*f)).unwrap_or_else(F::zero)
     }
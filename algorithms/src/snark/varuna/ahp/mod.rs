@@ -25,6 +25,9 @@ pub mod indexer;
 pub(crate) use indexer::*;
 
 pub(crate) mod matrices;
+// Note: These are exposed for the allocation tests, which install their own global allocator.
+#[doc(hidden)]
+pub use matrices::{add_randomizing_variables, pad_input_for_indexer_and_prover};
 
 /// Describes data structures and the algorithms used by the AHP prover.
 pub mod prover;
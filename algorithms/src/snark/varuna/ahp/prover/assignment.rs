@@ -0,0 +1,42 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::ConstraintSystem;
+use snarkvm_fields::Field;
+
+/// The variables assigned by synthesizing a circuit for a single instance, which allow proving
+/// the instance without synthesizing the circuit again.
+/// This excludes the randomizing and padding variables, which are added by the prover.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct VariableAssignment<F: Field> {
+    /// The public variables, starting with the constant `one`.
+    pub public_variables: Vec<F>,
+    /// The private variables.
+    pub private_variables: Vec<F>,
+}
+
+impl<F: Field> VariableAssignment<F> {
+    /// Initializes the assignment from the given public inputs and private variables,
+    /// prepending the constant `one` to the public inputs.
+    pub fn new(public_inputs: &[F], private_variables: Vec<F>) -> Self {
+        Self { public_variables: ConstraintSystem::format_public_input(public_inputs), private_variables }
+    }
+}
+
+impl<F: Field> From<ConstraintSystem<F>> for VariableAssignment<F> {
+    /// Returns the variables assigned by the given constraint system.
+    fn from(cs: ConstraintSystem<F>) -> Self {
+        Self { public_variables: cs.public_variables, private_variables: cs.private_variables }
+    }
+}
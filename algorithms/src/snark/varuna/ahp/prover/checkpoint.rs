@@ -0,0 +1,147 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::{CircuitSpecificState, FirstOracles, State, Terminator};
+use crate::{
+    fft::EvaluationDomain,
+    snark::varuna::{AHPError, AHPForR1CS, Circuit, CircuitId, SNARKMode},
+};
+use snarkvm_fields::PrimeField;
+use snarkvm_utilities::serialize::{CanonicalDeserialize, CanonicalSerialize};
+
+use serde::{
+    de::{self, DeserializeSeed},
+    ser,
+    Deserialize,
+    Deserializer,
+    Serialize,
+    Serializer,
+};
+use anyhow::anyhow;
+use bincode::Options;
+use std::{collections::BTreeMap, sync::Arc};
+
+impl<F: PrimeField, MM: SNARKMode> AHPForR1CS<F, MM> {
+    /// Serializes the prover state between two rounds, so that the prover can later be resumed from it.
+    /// Note that the oracles returned by the rounds so far, other than the first, must be persisted by the caller.
+    pub fn checkpoint(state: &State<F, MM>) -> Result<Vec<u8>, AHPError> {
+        bincode::DefaultOptions::new()
+            .serialize(state)
+            .map_err(|e| anyhow!("Failed to checkpoint the prover: {e}").into())
+    }
+
+    /// Resumes the prover from a checkpoint of the prover state for the given `circuits`.
+    pub fn resume<'a>(
+        bytes: &[u8],
+        circuits: Vec<&'a Circuit<F, MM>>,
+        terminator: Terminator,
+    ) -> Result<State<'a, F, MM>, AHPError> {
+        let mut deserializer = bincode::Deserializer::from_slice(bytes, bincode::DefaultOptions::new());
+        StateSeed { circuits, terminator }
+            .deserialize(&mut deserializer)
+            .map_err(|e| anyhow!("Failed to resume the prover: {e}").into())
+    }
+}
+
+/// The serialized form of the prover `State`, in which each circuit is referred to by its ID.
+/// The circuit states are stored as a list, as not all formats support non-string map keys.
+#[derive(Serialize)]
+struct StateRef<'b, F: PrimeField> {
+    circuit_specific_states: Vec<(CircuitId, &'b CircuitSpecificState<F>)>,
+    #[serde(with = "canonical")]
+    first_round_oracles: Option<Arc<FirstOracles<F>>>,
+    max_non_zero_domain: EvaluationDomain<F>,
+    max_constraint_domain: EvaluationDomain<F>,
+    max_variable_domain: EvaluationDomain<F>,
+    total_instances: usize,
+}
+
+/// The deserialized form of the prover `State`, in which each circuit is referred to by its ID.
+#[derive(Deserialize)]
+#[serde(bound = "F: PrimeField")]
+struct StateOwned<F: PrimeField> {
+    circuit_specific_states: Vec<(CircuitId, CircuitSpecificState<F>)>,
+    #[serde(with = "canonical")]
+    first_round_oracles: Option<Arc<FirstOracles<F>>>,
+    max_non_zero_domain: EvaluationDomain<F>,
+    max_constraint_domain: EvaluationDomain<F>,
+    max_variable_domain: EvaluationDomain<F>,
+    total_instances: usize,
+}
+
+impl<'a, F: PrimeField, MM: SNARKMode> Serialize for State<'a, F, MM> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        StateRef {
+            circuit_specific_states: self.circuit_specific_states.iter().map(|(c, s)| (c.id, s)).collect(),
+            first_round_oracles: self.first_round_oracles.clone(),
+            max_non_zero_domain: self.max_non_zero_domain,
+            max_constraint_domain: self.max_constraint_domain,
+            max_variable_domain: self.max_variable_domain,
+            total_instances: self.total_instances,
+        }
+        .serialize(serializer)
+    }
+}
+
+/// Deserializes a prover `State` from a checkpoint.
+/// As the state only stores the IDs of its circuits, the circuits must be provided to resume from a checkpoint.
+pub struct StateSeed<'a, F: PrimeField, MM: SNARKMode> {
+    /// The circuits being proved.
+    pub circuits: Vec<&'a Circuit<F, MM>>,
+    /// The cancellation token for the resumed prover.
+    pub terminator: Terminator,
+}
+
+impl<'a, 'de, F: PrimeField, MM: SNARKMode> DeserializeSeed<'de> for StateSeed<'a, F, MM> {
+    type Value = State<'a, F, MM>;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        let state = StateOwned::<F>::deserialize(deserializer)?;
+        let circuits = self.circuits.into_iter().map(|c| (c.id, c)).collect::<BTreeMap<_, _>>();
+        let circuit_specific_states = state
+            .circuit_specific_states
+            .into_iter()
+            .map(|(id, s)| match circuits.get(&id) {
+                Some(circuit) => Ok((*circuit, s)),
+                None => Err(<D::Error as de::Error>::custom(format!("Missing the circuit {id} for the checkpoint"))),
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(State {
+            circuit_specific_states,
+            first_round_oracles: state.first_round_oracles,
+            max_non_zero_domain: state.max_non_zero_domain,
+            max_constraint_domain: state.max_constraint_domain,
+            max_variable_domain: state.max_variable_domain,
+            total_instances: state.total_instances,
+            terminator: self.terminator,
+        })
+    }
+}
+
+/// Serializes a value through its uncompressed canonical encoding.
+mod canonical {
+    use super::*;
+
+    pub fn serialize<T: CanonicalSerialize, S: Serializer>(value: &T, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut bytes = Vec::with_capacity(value.uncompressed_size());
+        value.serialize_uncompressed(&mut bytes).map_err(ser::Error::custom)?;
+        serializer.serialize_bytes(&bytes)
+    }
+
+    pub fn deserialize<'de, T: CanonicalDeserialize, D: Deserializer<'de>>(deserializer: D) -> Result<T, D::Error> {
+        let bytes: Vec<u8> = Deserialize::deserialize(deserializer)?;
+        T::deserialize_uncompressed(&*bytes).map_err(de::Error::custom)
+    }
+}
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use super::VariableAssignment;
 use crate::r1cs::{errors::SynthesisError, ConstraintSystem as CS, Index as VarIndex, LinearCombination, Variable};
 use snarkvm_fields::Field;
 
@@ -25,15 +26,42 @@ pub(crate) struct ConstraintSystem<F: Field> {
 
 impl<F: Field> ConstraintSystem<F> {
     pub(crate) fn new() -> Self {
+        Self::with_capacity(1, 0)
+    }
+
+    /// Initializes a constraint system with capacity for the given numbers of public and private variables,
+    /// which should include the randomizing and padding variables, so that synthesis does not reallocate.
+    pub(crate) fn with_capacity(num_public_variables: usize, num_private_variables: usize) -> Self {
+        let mut public_variables = Vec::with_capacity(num_public_variables.max(1));
+        public_variables.push(F::one());
         Self {
-            public_variables: vec![F::one()],
-            private_variables: Vec::new(),
+            public_variables,
+            private_variables: Vec::with_capacity(num_private_variables),
             num_public_variables: 1usize,
             num_private_variables: 0usize,
             num_constraints: 0usize,
         }
     }
 
+    /// Initializes a constraint system from the variables assigned by synthesizing a circuit,
+    /// reserving capacity for the given total numbers of public and private variables.
+    pub(crate) fn from_assignment(
+        assignment: VariableAssignment<F>,
+        num_public_variables: usize,
+        num_private_variables: usize,
+    ) -> Self {
+        let VariableAssignment { mut public_variables, mut private_variables } = assignment;
+        public_variables.reserve(num_public_variables.saturating_sub(public_variables.len()));
+        private_variables.reserve(num_private_variables.saturating_sub(private_variables.len()));
+        Self {
+            num_public_variables: public_variables.len(),
+            num_private_variables: private_variables.len(),
+            public_variables,
+            private_variables,
+            num_constraints: 0usize,
+        }
+    }
+
     /// Formats the public input according to the requirements of the constraint
     /// system
     pub(crate) fn format_public_input(public_input: &[F]) -> Vec<F> {
@@ -0,0 +1,77 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use rand_core::{CryptoRng, Error, RngCore};
+
+/// An RNG wrapper that counts the number of draws made by the prover.
+///
+/// In deterministic mode there is no underlying RNG, and any draw panics.
+/// This guarantees that the prover output depends only on the circuits and assignments.
+pub struct CountingRng<'a> {
+    /// The underlying RNG, or `None` in deterministic mode.
+    rng: Option<&'a mut dyn RngCore>,
+    /// The number of draws made so far.
+    num_draws: usize,
+}
+
+impl<'a> CountingRng<'a> {
+    /// Initializes a new counting RNG that forwards draws to the given `rng`.
+    pub fn new<R: RngCore + CryptoRng>(rng: &'a mut R) -> Self {
+        Self { rng: Some(rng), num_draws: 0 }
+    }
+
+    /// Initializes a new counting RNG in deterministic mode, which panics on any draw.
+    pub fn deterministic() -> Self {
+        Self { rng: None, num_draws: 0 }
+    }
+
+    /// Returns `true` if the RNG is in deterministic mode.
+    pub fn is_deterministic(&self) -> bool {
+        self.rng.is_none()
+    }
+
+    /// Returns the number of draws made so far.
+    pub fn num_draws(&self) -> usize {
+        self.num_draws
+    }
+
+    /// Records a draw, and returns the underlying RNG.
+    fn draw(&mut self) -> &mut (dyn RngCore + 'a) {
+        self.num_draws += 1;
+        match &mut self.rng {
+            Some(rng) => &mut **rng,
+            None => panic!("Randomness was drawn by the prover in deterministic mode"),
+        }
+    }
+}
+
+impl<'a> RngCore for CountingRng<'a> {
+    fn next_u32(&mut self) -> u32 {
+        self.draw().next_u32()
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.draw().next_u64()
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.draw().fill_bytes(dest)
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        self.draw().try_fill_bytes(dest)
+    }
+}
+
+impl<'a> CryptoRng for CountingRng<'a> {}
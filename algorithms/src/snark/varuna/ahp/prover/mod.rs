@@ -14,9 +14,20 @@
 
 #![allow(non_snake_case)]
 
+#[cfg(feature = "checkpoint")]
+mod checkpoint;
+#[cfg(feature = "checkpoint")]
+pub use checkpoint::*;
+
+mod assignment;
+pub use assignment::*;
+
 mod constraint_system;
 pub(crate) use constraint_system::*;
 
+mod counting_rng;
+pub use counting_rng::*;
+
 mod message;
 pub(crate) use message::*;
 
@@ -27,3 +38,9 @@ mod round_functions;
 
 mod state;
 pub(self) use state::*;
+
+mod statistics;
+pub use statistics::*;
+
+mod terminator;
+pub use terminator::*;
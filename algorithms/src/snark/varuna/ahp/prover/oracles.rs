@@ -15,6 +15,8 @@
 use std::collections::BTreeMap;
 
 use snarkvm_fields::PrimeField;
+#[cfg(feature = "checkpoint")]
+use snarkvm_utilities::serialize::*;
 
 use crate::{
     polycommit::sonic_pc::{LabeledPolynomial, PolynomialInfo, PolynomialLabel},
@@ -23,6 +25,7 @@ use crate::{
 
 /// The first set of prover oracles.
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "checkpoint", derive(CanonicalSerialize, CanonicalDeserialize))]
 pub struct FirstOracles<F: PrimeField> {
     pub(in crate::snark::varuna) batches: BTreeMap<CircuitId, Vec<WitnessPoly<F>>>,
     /// The sum-check hiding polynomial.
@@ -43,6 +46,7 @@ impl<F: PrimeField> FirstOracles<F> {
 
 /// The LDE of `w`.
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "checkpoint", derive(CanonicalSerialize, CanonicalDeserialize))]
 pub(in crate::snark::varuna) struct WitnessPoly<F: PrimeField>(pub(in crate::snark::varuna) LabeledPolynomial<F>);
 
 impl<F: PrimeField> WitnessPoly<F> {
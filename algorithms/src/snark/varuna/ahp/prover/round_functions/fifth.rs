@@ -44,6 +44,7 @@ impl<F: PrimeField, MM: SNARKMode> AHPForR1CS<F, MM> {
         state: prover::State<'_, F, MM>,
         _r: &mut R,
     ) -> Result<prover::FifthOracles<F>, AHPError> {
+        state.terminator.check()?;
         let lhs_sum: DensePolynomial<F> = cfg_reduce!(
             cfg_par_bridge!(verifier_message.into_iter().zip_eq(state.lhs_polys_into_iter())).map(
                 |(delta, mut lhs)| {
@@ -64,7 +64,9 @@ impl<F: PrimeField, MM: SNARKMode> AHPForR1CS<F, MM> {
     ) -> Result<prover::State<'a, F, MM>, AHPError> {
         let round_time = start_timer!(|| "AHP::Prover::FirstRound");
         let mut job_pool = snarkvm_utilities::ExecutionPool::with_capacity(state.total_instances);
+        let terminator = &state.terminator;
         for (circuit, circuit_state) in state.circuit_specific_states.iter_mut() {
+            state.terminator.check()?;
             let batch_size = circuit_state.batch_size;
 
             let private_variables = core::mem::take(&mut circuit_state.private_variables);
@@ -77,11 +79,17 @@ impl<F: PrimeField, MM: SNARKMode> AHPForR1CS<F, MM> {
 
             for (j, (private_vars, x_poly)) in itertools::izip!(private_variables, x_polys).enumerate() {
                 let w_label = witness_label(circuit.id, "w", j);
-                job_pool.add_job(move || Self::calculate_w(w_label, private_vars, x_poly, v_domain, i_domain, circuit));
+                job_pool.add_job(move || {
+                    Self::calculate_w(w_label, private_vars, x_poly, v_domain, i_domain, circuit, terminator)
+                });
             }
         }
-        let mut batches =
-            job_pool.execute_all().into_iter().map(|w_poly| prover::WitnessPoly(w_poly)).collect::<Vec<_>>();
+        state.terminator.check()?;
+        let mut batches = job_pool
+            .execute_all()
+            .into_iter()
+            .map(|w_poly| w_poly.map(prover::WitnessPoly))
+            .collect::<Result<Vec<_>, AHPError>>()?;
         assert_eq!(batches.len(), state.total_instances);
 
         let mut circuit_specific_batches = BTreeMap::new();
@@ -131,11 +139,13 @@ impl<F: PrimeField, MM: SNARKMode> AHPForR1CS<F, MM> {
         variable_domain: EvaluationDomain<F>,
         input_domain: EvaluationDomain<F>,
         circuit: &Circuit<F, MM>,
-    ) -> Witness<F> {
+        terminator: &prover::Terminator,
+    ) -> Result<Witness<F>, AHPError> {
         let mut w_extended = private_variables;
         let ratio = variable_domain.size() / input_domain.size();
         w_extended.resize(variable_domain.size() - input_domain.size(), F::zero());
 
+        terminator.check()?;
         let x_evals = {
             let mut coeffs = x_poly.coeffs;
             coeffs.resize(variable_domain.size(), F::zero());
@@ -150,6 +160,7 @@ impl<F: PrimeField, MM: SNARKMode> AHPForR1CS<F, MM> {
                 _ => w_extended[k - (k / ratio) - 1] - x_evals[k],
             })
             .collect();
+        terminator.check()?;
         let w_poly = EvaluationsOnDomain::from_vec_and_domain(w_poly_evals, variable_domain)
             .interpolate_with_pc(&circuit.ifft_precomputation);
         let (w_poly, remainder) = w_poly.divide_by_vanishing_poly(input_domain).unwrap();
@@ -157,7 +168,7 @@ impl<F: PrimeField, MM: SNARKMode> AHPForR1CS<F, MM> {
 
         assert!(w_poly.degree() < variable_domain.size() - input_domain.size());
         end_timer!(w_poly_time);
-        LabeledPolynomial::new(label, w_poly, None, Self::zk_bound())
+        Ok(LabeledPolynomial::new(label, w_poly, None, Self::zk_bound()))
     }
 }
 
@@ -32,7 +32,7 @@ use crate::{
 use snarkvm_fields::{batch_inversion_and_mul, PrimeField};
 use snarkvm_utilities::{cfg_iter, cfg_iter_mut, ExecutionPool};
 
-use anyhow::Result;
+use anyhow::{anyhow, Result};
 use core::convert::TryInto;
 use itertools::Itertools;
 use rand_core::RngCore;
@@ -76,9 +76,10 @@ impl<F: PrimeField, MM: SNARKMode> AHPForR1CS<F, MM> {
         second_message: &verifier::SecondMessage<F>,
         third_message: &verifier::ThirdMessage<F>,
         mut state: prover::State<'a, F, MM>,
-        _r: &mut R,
+        #[cfg_attr(not(debug_assertions), allow(unused_variables))] rng: &mut R,
     ) -> Result<(prover::FourthMessage<F>, prover::FourthOracles<F>, prover::State<'a, F, MM>), AHPError> {
         let round_time = start_timer!(|| "AHP::Prover::FourthRound");
+        state.terminator.check()?;
 
         let verifier::SecondMessage { alpha, .. } = second_message;
         let verifier::ThirdMessage { beta } = third_message;
@@ -87,7 +88,12 @@ impl<F: PrimeField, MM: SNARKMode> AHPForR1CS<F, MM> {
 
         let max_non_zero_domain_size = state.max_non_zero_domain;
         let matrix_labels = ["g_a", "g_b", "g_c"];
+        let terminator = &state.terminator;
         for (&circuit, state_i) in &state.circuit_specific_states {
+            // Stop scheduling work once the prover has been terminated.
+            if terminator.is_terminated() {
+                break;
+            }
             let v_R_i_at_alpha = state_i.constraint_domain.evaluate_vanishing_polynomial(*alpha);
             let v_C_i_at_beta = state_i.variable_domain.evaluate_vanishing_polynomial(*beta);
             let v_R_i_alpha_v_C_i_beta = v_R_i_at_alpha * v_C_i_at_beta;
@@ -109,17 +115,19 @@ impl<F: PrimeField, MM: SNARKMode> AHPForR1CS<F, MM> {
                         max_non_zero_domain_size,
                         &circuit.fft_precomputation,
                         &circuit.ifft_precomputation,
+                        terminator,
                     );
                     (circuit, result)
                 });
             }
         }
 
+        let results = pool.execute_all();
+        state.terminator.check()?;
+
         let mut sums = Vec::with_capacity(state.circuit_specific_states.len());
         let mut gs = BTreeMap::new();
-        for ((circuit_a, results_a), (circuit_b, results_b), (circuit_c, results_c)) in
-            pool.execute_all().into_iter().tuples()
-        {
+        for ((circuit_a, results_a), (circuit_b, results_b), (circuit_c, results_c)) in results.into_iter().tuples() {
             assert_eq!(circuit_a, circuit_b);
             assert_eq!(circuit_a, circuit_c);
             let (sum_a, lhs_a, g_a) = results_a?;
@@ -139,11 +147,83 @@ impl<F: PrimeField, MM: SNARKMode> AHPForR1CS<F, MM> {
             state.circuit_specific_states.keys().map(|c| (c.id, &c.index_info))
         )));
 
+        #[cfg(debug_assertions)]
+        Self::prover_fourth_round_verify(second_message, third_message, &msg, &oracles, &state, rng)?;
+
         end_timer!(round_time);
 
         Ok((msg, oracles, state))
     }
 
+    /// Checks that the fourth round message and oracles satisfy the matrix sumcheck identity at a random point.
+    /// For each matrix `M`, this checks that `a_M(z) - b_M(z) * (sum_M + z * g_M(z)) = h_M(z) * v_K_M(z)`,
+    /// where `h_M` is recovered from the lhs polynomial computed in the fourth round.
+    /// This is a cheap local check, which catches errors in the prover before its messages are sent.
+    pub fn prover_fourth_round_verify<R: RngCore>(
+        second_message: &verifier::SecondMessage<F>,
+        third_message: &verifier::ThirdMessage<F>,
+        fourth_message: &prover::FourthMessage<F>,
+        fourth_oracles: &prover::FourthOracles<F>,
+        state: &prover::State<F, MM>,
+        rng: &mut R,
+    ) -> Result<(), AHPError> {
+        let verify_time = start_timer!(|| "AHP::Prover::FourthRoundVerify");
+
+        let verifier::SecondMessage { alpha, .. } = second_message;
+        let verifier::ThirdMessage { beta } = third_message;
+        let z = F::rand(rng);
+
+        if fourth_message.sums.len() != state.circuit_specific_states.len() {
+            return Err(anyhow!("The fourth round message has an incorrect number of sums").into());
+        }
+
+        let max_non_zero_domain = state.max_non_zero_domain;
+        for ((circuit, state_i), sums) in state.circuit_specific_states.iter().zip_eq(&fourth_message.sums) {
+            let gs = fourth_oracles
+                .gs
+                .get(&circuit.id)
+                .ok_or_else(|| anyhow!("Missing the fourth round oracles for circuit {}", circuit.id))?;
+            let lhs_polynomials = state_i
+                .lhs_polynomials
+                .as_ref()
+                .ok_or_else(|| anyhow!("Missing the lhs polynomials for circuit {}", circuit.id))?;
+
+            let v_R_i_at_alpha = state_i.constraint_domain.evaluate_vanishing_polynomial(*alpha);
+            let v_C_i_at_beta = state_i.variable_domain.evaluate_vanishing_polynomial(*beta);
+            let v_rc = v_R_i_at_alpha * v_C_i_at_beta;
+            let rc_size =
+                state_i.constraint_domain.size_as_field_element * state_i.variable_domain.size_as_field_element;
+
+            let matrices = [
+                ("a", &circuit.a_arith, state_i.non_zero_a_domain, sums.sum_a, &gs.g_a, &lhs_polynomials[0]),
+                ("b", &circuit.b_arith, state_i.non_zero_b_domain, sums.sum_b, &gs.g_b, &lhs_polynomials[1]),
+                ("c", &circuit.c_arith, state_i.non_zero_c_domain, sums.sum_c, &gs.g_c, &lhs_polynomials[2]),
+            ];
+            for (matrix, arith, non_zero_domain, sum, g, lhs) in matrices {
+                let a_at_z = v_rc * arith.row_col_val.evaluate(z);
+                let b_at_z = rc_size
+                    * (*alpha * beta - *alpha * arith.col.evaluate(z) - *beta * arith.row.evaluate(z)
+                        + arith.row_col.evaluate(z));
+                let f_at_z = sum + z * g.evaluate(z);
+                // The lhs polynomial is `h_M / v_K_M * (|K_M| / |K_max|)`, so we undo the scaling here.
+                let h_at_z = lhs.evaluate(z)
+                    * non_zero_domain.evaluate_vanishing_polynomial(z)
+                    * max_non_zero_domain.size_as_field_element
+                    * non_zero_domain.size_inv;
+                if a_at_z - b_at_z * f_at_z != h_at_z {
+                    return Err(anyhow!(
+                        "The matrix sumcheck for matrix {matrix} of circuit {} does not hold",
+                        circuit.id
+                    )
+                    .into());
+                }
+            }
+        }
+
+        end_timer!(verify_time);
+        Ok(())
+    }
+
     #[allow(clippy::too_many_arguments)]
     fn calculate_matrix_sumcheck_witness(
         label: String,
@@ -157,6 +237,7 @@ impl<F: PrimeField, MM: SNARKMode> AHPForR1CS<F, MM> {
         max_non_zero_domain: EvaluationDomain<F>,
         fft_precomputation: &FFTPrecomputation<F>,
         ifft_precomputation: &IFFTPrecomputation<F>,
+        terminator: &prover::Terminator,
     ) -> Result<(Sum<F>, Lhs<F>, Gpoly<F>)> {
         let mut job_pool = snarkvm_utilities::ExecutionPool::with_capacity(2);
         job_pool.add_job(|| {
@@ -190,6 +271,7 @@ impl<F: PrimeField, MM: SNARKMode> AHPForR1CS<F, MM> {
             b_poly
         });
         let [a_poly, b_poly]: [_; 2] = job_pool.execute_all().try_into().unwrap();
+        terminator.check_job()?;
 
         let f_evals_time = start_timer!(|| format!("Computing f evals on K for {label}"));
         let mut inverses: Vec<_> = cfg_iter!(row_on_K.evaluations)
@@ -208,11 +290,13 @@ impl<F: PrimeField, MM: SNARKMode> AHPForR1CS<F, MM> {
         end_timer!(f_evals_time);
 
         let f_poly_time = start_timer!(|| format!("Computing f poly for {label}"));
+        terminator.check_job()?;
         // we define f as the rational equation for which we're running the sumcheck protocol
         let f = EvaluationsOnDomain::from_vec_and_domain(f_evals_on_K, non_zero_domain)
             .interpolate_with_pc(ifft_precomputation);
 
         end_timer!(f_poly_time);
+        terminator.check_job()?;
         let g = DensePolynomial::from_coefficients_slice(&f.coeffs[1..]);
         let mut h = &a_poly
             - &{
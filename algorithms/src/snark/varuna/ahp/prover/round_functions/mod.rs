@@ -23,15 +23,15 @@ use crate::{
 };
 use snarkvm_fields::PrimeField;
 
-use anyhow::Result;
+use anyhow::{anyhow, Result};
 use itertools::Itertools;
 use rand::Rng;
 use rand_core::CryptoRng;
-use std::collections::BTreeMap;
+use std::{collections::BTreeMap, num::NonZeroUsize};
 
 #[cfg(not(feature = "std"))]
 use snarkvm_utilities::println;
-use snarkvm_utilities::{cfg_iter, cfg_iter_mut};
+use snarkvm_utilities::{cfg_into_iter, cfg_iter, cfg_iter_mut};
 
 #[cfg(not(feature = "serial"))]
 use rayon::prelude::*;
@@ -44,16 +44,104 @@ mod third;
 
 impl<F: PrimeField, MM: SNARKMode> AHPForR1CS<F, MM> {
     /// Initialize the AHP prover.
+    /// The prover returns `AHPError::Terminated` if the given `terminator` is set before it completes.
     pub fn init_prover<'a, C: ConstraintSynthesizer<F>, R: Rng + CryptoRng>(
         circuits_to_constraints: &BTreeMap<&'a Circuit<F, MM>, &[C]>,
+        terminator: &prover::Terminator,
         rng: &mut R,
     ) -> Result<prover::State<'a, F, MM>, AHPError> {
+        Self::init_prover_with_max_concurrent_instances(circuits_to_constraints, None, terminator, rng)
+    }
+
+    /// Initialize the AHP prover, synthesizing at most `max_concurrent_instances` instances at a time.
+    /// This bounds the memory used by the witnesses in flight; `None` synthesizes all instances of a circuit
+    /// concurrently, as in `init_prover`. The resulting prover state does not depend on the limit.
+    /// The prover returns `AHPError::Terminated` if the given `terminator` is set before it completes.
+    pub fn init_prover_with_max_concurrent_instances<'a, C: ConstraintSynthesizer<F>, R: Rng + CryptoRng>(
+        circuits_to_constraints: &BTreeMap<&'a Circuit<F, MM>, &[C]>,
+        max_concurrent_instances: Option<NonZeroUsize>,
+        terminator: &prover::Terminator,
+        rng: &mut R,
+    ) -> Result<prover::State<'a, F, MM>, AHPError> {
+        let circuits_to_assignments =
+            Self::synthesize_assignments(circuits_to_constraints, max_concurrent_instances, terminator)?;
+        Self::init_prover_from_assignments(circuits_to_assignments, terminator, rng)
+    }
+
+    /// Synthesizes each instance, and returns the variables assigned for each circuit, in the order of the instances.
+    /// The instances of each circuit are synthesized in chunks of `max_concurrent_instances`, one chunk at a time,
+    /// or all at once if `None`. With the `serial` feature, the instances are always synthesized one at a time.
+    /// Returns `AHPError::Terminated` if the given `terminator` is set before it completes.
+    pub fn synthesize_assignments<'a, C: ConstraintSynthesizer<F>>(
+        circuits_to_constraints: &BTreeMap<&'a Circuit<F, MM>, &[C]>,
+        max_concurrent_instances: Option<NonZeroUsize>,
+        terminator: &prover::Terminator,
+    ) -> Result<BTreeMap<&'a Circuit<F, MM>, Vec<prover::VariableAssignment<F>>>, AHPError> {
+        // Ensure each circuit fits within the prover, before synthesizing any instance.
+        for circuit in circuits_to_constraints.keys() {
+            Self::ensure_circuit_fits(circuit)?;
+        }
+
+        circuits_to_constraints
+            .iter()
+            .map(|(circuit, constraints)| {
+                let chunk_size = max_concurrent_instances.map_or(constraints.len().max(1), NonZeroUsize::get);
+                let mut assignments = Vec::with_capacity(constraints.len());
+                for (chunk_index, chunk) in constraints.chunks(chunk_size).enumerate() {
+                    let chunk_assignments = cfg_iter!(chunk)
+                        .enumerate()
+                        .map(|(j, instance)| {
+                            terminator.check()?;
+
+                            let _i = chunk_index * chunk_size + j;
+
+                            let constraint_time = start_timer!(|| format!(
+                                "Generating constraints and witnesses for {:?} and index {_i}",
+                                circuit.id
+                            ));
+                            // Reserve the final number of variables up front,
+                            // including the randomizing and padding variables.
+                            let num_public_inputs = circuit.index_info.num_public_inputs;
+                            let num_private_variables =
+                                circuit.index_info.num_variables.saturating_sub(num_public_inputs);
+                            let mut pcs =
+                                prover::ConstraintSystem::with_capacity(num_public_inputs, num_private_variables);
+                            instance.generate_constraints(&mut pcs)?;
+                            end_timer!(constraint_time);
+
+                            // The randomizing constraint is only added to the instance in hiding mode.
+                            if circuit.index_info.num_constraints != pcs.num_constraints + usize::from(MM::ZK) {
+                                return Err(AHPError::InstanceDoesNotMatchIndex);
+                            }
+                            Ok(prover::VariableAssignment::from(pcs))
+                        })
+                        .collect::<Result<Vec<_>, AHPError>>()?;
+                    assignments.extend(chunk_assignments);
+                }
+                Ok((*circuit, assignments))
+            })
+            .collect()
+    }
+
+    /// Initialize the AHP prover from the variables assigned by synthesizing each instance beforehand,
+    /// which avoids synthesizing the circuits again.
+    /// The prover returns `AHPError::Terminated` if the given `terminator` is set before it completes.
+    pub fn init_prover_from_assignments<'a, R: Rng + CryptoRng>(
+        circuits_to_assignments: BTreeMap<&'a Circuit<F, MM>, Vec<prover::VariableAssignment<F>>>,
+        terminator: &prover::Terminator,
+        rng: &mut R,
+    ) -> Result<prover::State<'a, F, MM>, AHPError> {
+        // Ensure each circuit fits within the prover.
+        for circuit in circuits_to_assignments.keys() {
+            Self::ensure_circuit_fits(circuit)?;
+        }
+
         let init_time = start_timer!(|| "AHP::Prover::Init");
 
-        let mut randomizing_assignments = Vec::with_capacity(circuits_to_constraints.len());
-        for constraints in circuits_to_constraints.values() {
-            let mut circuit_assignments = Vec::with_capacity(constraints.len());
-            for _ in 0..constraints.len() {
+        let mut randomizing_assignments = Vec::with_capacity(circuits_to_assignments.len());
+        for assignments in circuits_to_assignments.values() {
+            let mut circuit_assignments = Vec::with_capacity(assignments.len());
+            for _ in 0..assignments.len() {
                 if MM::ZK {
                     let a = F::rand(rng);
                     let b = F::rand(rng);
@@ -66,29 +154,32 @@ impl<F: PrimeField, MM: SNARKMode> AHPForR1CS<F, MM> {
             randomizing_assignments.push(circuit_assignments);
         }
 
-        let indices_and_assignments = circuits_to_constraints
-            .iter()
+        let indices_and_assignments = circuits_to_assignments
+            .into_iter()
             .zip_eq(randomizing_assignments.into_iter())
-            .map(|((circuit, constraints), circuit_rand_assignments)| {
+            .map(|((circuit, assignments), circuit_rand_assignments)| {
                 let num_non_zero_a = circuit.index_info.num_non_zero_a;
                 let num_non_zero_b = circuit.index_info.num_non_zero_b;
                 let num_non_zero_c = circuit.index_info.num_non_zero_c;
 
-                let assignments = cfg_iter!(constraints)
+                let assignments = cfg_into_iter!(assignments)
                     .zip(circuit_rand_assignments)
                     .enumerate()
-                    .map(|(_i, (instance, rand_assignments))| {
-                        let constraint_time = start_timer!(|| format!(
-                            "Generating constraints and witnesses for {:?} and index {_i}",
-                            circuit.id
-                        ));
-                        let mut pcs = prover::ConstraintSystem::new();
-                        instance.generate_constraints(&mut pcs)?;
-                        end_timer!(constraint_time);
+                    .map(|(_i, (assignment, rand_assignments))| {
+                        terminator.check()?;
 
                         let padding_time =
                             start_timer!(|| format!("Padding matrices for {:?} and index {_i}", circuit.id));
 
+                        // Reserve the final number of variables up front, including the randomizing and padding variables.
+                        let num_public_inputs = circuit.index_info.num_public_inputs;
+                        let num_private_variables = circuit.index_info.num_variables.saturating_sub(num_public_inputs);
+                        let mut pcs = prover::ConstraintSystem::from_assignment(
+                            assignment,
+                            num_public_inputs,
+                            num_private_variables,
+                        );
+
                         MM::ZK.then(|| {
                             crate::snark::varuna::ahp::matrices::add_randomizing_variables::<_, _>(
                                 &mut pcs,
@@ -102,70 +193,85 @@ impl<F: PrimeField, MM: SNARKMode> AHPForR1CS<F, MM> {
                         let prover::ConstraintSystem {
                             public_variables: padded_public_variables,
                             private_variables,
-                            num_constraints,
                             num_public_variables,
                             num_private_variables,
                             ..
                         } = pcs;
 
                         assert_eq!(padded_public_variables.len(), num_public_variables);
-                        assert!(padded_public_variables[0].is_one());
                         assert_eq!(private_variables.len(), num_private_variables);
 
                         if cfg!(debug_assertions) {
                             println!("Number of padded public variables in Prover::Init: {num_public_variables}");
                             println!("Number of private variables: {num_private_variables}");
-                            println!("Number of constraints: {num_constraints}");
+                            println!("Number of constraints: {}", circuit.index_info.num_constraints);
                             println!("Number of non-zero entries in A: {num_non_zero_a}");
                             println!("Number of non-zero entries in B: {num_non_zero_b}");
                             println!("Number of non-zero entries in C: {num_non_zero_c}");
                         }
 
-                        if circuit.index_info.num_constraints != num_constraints
+                        if circuit.index_info.num_public_inputs != num_public_variables
                             || circuit.index_info.num_variables != (num_public_variables + num_private_variables)
                         {
                             return Err(AHPError::InstanceDoesNotMatchIndex);
                         }
 
+                        // This also checks that the first public variable is `one`.
                         Self::formatted_public_input_is_admissible(&padded_public_variables)?;
+                        terminator.check()?;
 
                         let eval_z_a_time = start_timer!(|| format!("For {:?}, evaluating z_A_{_i}", circuit.id));
-                        let z_a = cfg_iter!(circuit.a)
-                            .map(|row| {
-                                inner_product(&padded_public_variables, &private_variables, row, num_public_variables)
-                            })
-                            .collect();
+                        let z_a = circuit.a_split.vector_product(&padded_public_variables, &private_variables);
                         end_timer!(eval_z_a_time);
 
                         let eval_z_b_time = start_timer!(|| format!("For {:?}, evaluating z_B_{_i}", circuit.id));
-                        let z_b = cfg_iter!(circuit.b)
-                            .map(|row| {
-                                inner_product(&padded_public_variables, &private_variables, row, num_public_variables)
-                            })
-                            .collect();
+                        let z_b = circuit.b_split.vector_product(&padded_public_variables, &private_variables);
                         end_timer!(eval_z_b_time);
 
                         let eval_z_c_time = start_timer!(|| format!("For {:?}, evaluating z_C_{_i}", circuit.id));
-                        let z_c = cfg_iter!(circuit.c)
-                            .map(|row| {
-                                inner_product(&padded_public_variables, &private_variables, row, num_public_variables)
-                            })
-                            .collect();
+                        let z_c = circuit.c_split.vector_product(&padded_public_variables, &private_variables);
                         end_timer!(eval_z_c_time);
 
-                        end_timer!(init_time);
                         Ok(prover::Assignments::<F>(padded_public_variables, private_variables, z_a, z_b, z_c))
                     })
                     .collect::<Result<Vec<prover::Assignments<F>>, AHPError>>()?;
-                Ok((*circuit, assignments))
+                Ok((circuit, assignments))
             })
             .collect::<Result<BTreeMap<&'a Circuit<F, MM>, Vec<prover::Assignments<F>>>, AHPError>>()?;
 
-        let state = prover::State::initialize(indices_and_assignments)?;
+        let state = prover::State::initialize(indices_and_assignments, terminator.clone())?;
+        end_timer!(init_time);
 
         Ok(state)
     }
 
+    /// Initialize the AHP prover without drawing any randomness.
+    /// This is only supported when `MM::ZK` is disabled, in which case the prover state
+    /// is fully determined by the circuits and their assignments.
+    pub fn init_prover_deterministic<'a, C: ConstraintSynthesizer<F>>(
+        circuits_to_constraints: &BTreeMap<&'a Circuit<F, MM>, &[C]>,
+        terminator: &prover::Terminator,
+    ) -> Result<prover::State<'a, F, MM>, AHPError> {
+        if MM::ZK {
+            return Err(anyhow!("Deterministic proving is not supported in hiding mode").into());
+        }
+        let mut rng = prover::CountingRng::deterministic();
+        let state = Self::init_prover(circuits_to_constraints, terminator, &mut rng)?;
+        assert_eq!(rng.num_draws(), 0);
+        Ok(state)
+    }
+
+    /// Returns `AHPError::CircuitTooLarge` if the given circuit has more constraints than
+    /// an SRS of degree `MM::MAX_SRS_DEGREE` supports.
+    fn ensure_circuit_fits(circuit: &Circuit<F, MM>) -> Result<(), AHPError> {
+        let constraint_count = circuit.index_info.num_constraints;
+        let max = Self::max_supported_constraints(MM::MAX_SRS_DEGREE)?.constraint_domain_size;
+        if constraint_count > max {
+            return Err(AHPError::CircuitTooLarge { constraint_count, max });
+        }
+        Ok(())
+    }
+
     /// Throughout the protocol, we are tasked with computing a zerocheck or sumcheck
     /// of multiple polynomials over different domains.
     /// These can be combined into a single check by taking a random linear combination
@@ -222,27 +328,6 @@ impl<F: PrimeField, MM: SNARKMode> AHPForR1CS<F, MM> {
     }
 }
 
-fn inner_product<F: PrimeField>(
-    public_variables: &[F],
-    private_variables: &[F],
-    row: &[(F, usize)],
-    num_public_variables: usize,
-) -> F {
-    let mut result = F::zero();
-
-    for &(ref coefficient, i) in row {
-        // Fetch the variable.
-        let variable = match i < num_public_variables {
-            true => public_variables[i],
-            false => private_variables[i - num_public_variables],
-        };
-
-        result += if coefficient.is_one() { variable } else { variable * coefficient };
-    }
-
-    result
-}
-
 #[test]
 fn check_division_by_vanishing_poly_preserve_sparseness() {
     use crate::fft::{EvaluationDomain, Evaluations as EvaluationsOnDomain};
@@ -18,7 +18,7 @@ use crate::{
     fft::{polynomial::PolyMultiplier, DensePolynomial, EvaluationDomain, Evaluations as EvaluationsOnDomain},
     polycommit::sonic_pc::{LabeledPolynomial, PolynomialInfo, PolynomialLabel},
     snark::varuna::{
-        ahp::{verifier, AHPForR1CS},
+        ahp::{verifier, AHPError, AHPForR1CS},
         prover,
         witness_label,
         Circuit,
@@ -50,8 +50,9 @@ impl<F: PrimeField, MM: SNARKMode> AHPForR1CS<F, MM> {
         verifier_message: &verifier::FirstMessage<F>,
         mut state: prover::State<'a, F, MM>,
         _r: &mut R,
-    ) -> Result<(prover::SecondOracles<F>, prover::State<'a, F, MM>)> {
+    ) -> Result<(prover::SecondOracles<F>, prover::State<'a, F, MM>), AHPError> {
         let round_time = start_timer!(|| "AHP::Prover::SecondRound");
+        state.terminator.check()?;
 
         let zk_bound = Self::zk_bound();
 
@@ -59,7 +60,10 @@ impl<F: PrimeField, MM: SNARKMode> AHPForR1CS<F, MM> {
 
         let verifier::FirstMessage { batch_combiners, .. } = verifier_message;
 
-        let h_0 = Self::calculate_rowcheck_witness(&mut state, batch_combiners)?;
+        // Check the terminator before the result, as a terminated job fails with a generic error.
+        let h_0 = Self::calculate_rowcheck_witness(&mut state, batch_combiners);
+        state.terminator.check()?;
+        let h_0 = h_0?;
 
         assert!(h_0.degree() <= 2 * max_constraint_domain.size() + 2 * zk_bound.unwrap_or(0) - 2);
 
@@ -77,8 +81,13 @@ impl<F: PrimeField, MM: SNARKMode> AHPForR1CS<F, MM> {
     ) -> Result<DensePolynomial<F>> {
         let mut job_pool = ExecutionPool::with_capacity(state.circuit_specific_states.len());
         let max_constraint_domain = state.max_constraint_domain;
+        let terminator = &state.terminator;
 
         for (circuit, circuit_specific_state) in state.circuit_specific_states.iter_mut() {
+            // Stop scheduling work once the prover has been terminated.
+            if terminator.is_terminated() {
+                break;
+            }
             let z_a = circuit_specific_state.z_a.take().unwrap();
             let z_b = circuit_specific_state.z_b.take().unwrap();
             let z_c = circuit_specific_state.z_c.take().unwrap();
@@ -99,9 +108,13 @@ impl<F: PrimeField, MM: SNARKMode> AHPForR1CS<F, MM> {
                     let za_label = witness_label(circuit.id, "z_a", j);
                     let zb_label = witness_label(circuit.id, "z_b", j);
                     let zc_label = witness_label(circuit.id, "z_c", j);
+                    terminator.check_job()?;
                     let z_a = Self::calculate_z_m(za_label, z_a, constraint_domain, circuit);
+                    terminator.check_job()?;
                     let z_b = Self::calculate_z_m(zb_label, z_b, constraint_domain, circuit);
+                    terminator.check_job()?;
                     let z_c = Self::calculate_z_m(zc_label, z_c, constraint_domain, circuit);
+                    terminator.check_job()?;
                     let mut multiplier_2 = PolyMultiplier::new();
                     multiplier_2.add_precomputation(fft_precomputation, ifft_precomputation);
                     multiplier_2.add_polynomial(z_a, "z_a");
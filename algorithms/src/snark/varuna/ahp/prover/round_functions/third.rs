@@ -72,6 +72,7 @@ impl<F: PrimeField, MM: SNARKMode> AHPForR1CS<F, MM> {
         _r: &mut R,
     ) -> Result<(prover::ThirdMessage<F>, prover::ThirdOracles<F>, prover::State<'a, F, MM>), AHPError> {
         let round_time = start_timer!(|| "AHP::Prover::ThirdRound");
+        state.terminator.check()?;
 
         let zk_bound = Self::zk_bound();
 
@@ -81,7 +82,9 @@ impl<F: PrimeField, MM: SNARKMode> AHPForR1CS<F, MM> {
         let verifier::SecondMessage { alpha, eta_b, eta_c } = verifier_second_message;
 
         let assignments = Self::calculate_assignments(&mut state)?;
+        state.terminator.check()?;
         let matrix_transposes = Self::calculate_matrix_transpose(&mut state)?;
+        state.terminator.check()?;
 
         let (h_1, x_g_1_sum, msg) = Self::calculate_lineval_sumcheck_witness(
             &mut state,
@@ -92,6 +95,7 @@ impl<F: PrimeField, MM: SNARKMode> AHPForR1CS<F, MM> {
             eta_b,
             eta_c,
         )?;
+        state.terminator.check()?;
 
         #[cfg(debug_assertions)]
         {
@@ -133,6 +137,7 @@ impl<F: PrimeField, MM: SNARKMode> AHPForR1CS<F, MM> {
         let num_instances = batch_combiners.values().map(|c| c.instance_combiners.len()).collect_vec();
         let total_instances = num_instances.iter().sum::<usize>();
         let max_variable_domain = &state.max_variable_domain;
+        let terminator = &state.terminator;
         let matrix_labels = ["a", "b", "c"];
         let matrix_combiners = [F::one(), *eta_b, *eta_c];
 
@@ -170,6 +175,7 @@ impl<F: PrimeField, MM: SNARKMode> AHPForR1CS<F, MM> {
                             matrix_transpose,
                             *alpha,
                             combiner,
+                            terminator,
                         )
                     });
                 }
@@ -286,6 +292,7 @@ impl<F: PrimeField, MM: SNARKMode> AHPForR1CS<F, MM> {
         matrix_transpose: &Matrix<F>,
         alpha: F,
         combiner: F,
+        terminator: &prover::Terminator,
     ) -> Result<LinevalInstance<F>> {
         let sumcheck_time = start_timer!(|| format!("Compute LHS of sumcheck for {_label}"));
 
@@ -305,12 +312,14 @@ impl<F: PrimeField, MM: SNARKMode> AHPForR1CS<F, MM> {
         end_timer!(m_at_alpha_evals_time);
 
         let z_m_at_alpha_time = start_timer!(|| format!("Compute z_m_at_alpha_time for {_label}"));
+        terminator.check_job()?;
         let m_at_alpha = Evaluations::from_vec_and_domain(m_at_alpha_evals, *variable_domain)
             .interpolate_with_pc(ifft_precomputation);
         let mut multiplier = PolyMultiplier::new();
         multiplier.add_precomputation(fft_precomputation, ifft_precomputation);
         multiplier.add_polynomial(m_at_alpha, "m_at_alpha");
         multiplier.add_polynomial_ref(assignment, "assignment");
+        terminator.check_job()?;
         let mut z_m_at_alpha = multiplier.multiply().unwrap();
         let sum = z_m_at_alpha.evaluate_over_domain_by_ref(*variable_domain).evaluations.into_iter().sum::<F>();
         end_timer!(z_m_at_alpha_time);
@@ -17,12 +17,15 @@ use std::{collections::BTreeMap, sync::Arc};
 use crate::{
     fft::{DensePolynomial, EvaluationDomain, Evaluations as EvaluationsOnDomain},
     r1cs::{SynthesisError, SynthesisResult},
-    snark::varuna::{AHPError, AHPForR1CS, Circuit, SNARKMode},
+    snark::varuna::{AHPError, AHPForR1CS, Circuit, CircuitId, SNARKMode},
 };
 use snarkvm_fields::PrimeField;
 
 /// Circuit Specific State of the Prover
+#[cfg_attr(feature = "checkpoint", derive(serde::Serialize, serde::Deserialize))]
 pub struct CircuitSpecificState<F: PrimeField> {
+    // The evaluation domains are constructed once in `State::initialize`,
+    // and are reused by every prover round instead of being recomputed.
     pub(super) input_domain: EvaluationDomain<F>,
     pub(super) variable_domain: EvaluationDomain<F>,
     pub(super) constraint_domain: EvaluationDomain<F>,
@@ -76,6 +79,8 @@ pub struct State<'a, F: PrimeField, MM: SNARKMode> {
     pub(in crate::snark) max_variable_domain: EvaluationDomain<F>,
     /// The total number of instances we're proving in the batch.
     pub(in crate::snark) total_instances: usize,
+    /// The cancellation token, which is polled between circuits and rounds.
+    pub(in crate::snark) terminator: super::Terminator,
 }
 
 /// The public inputs for a single instance.
@@ -89,6 +94,7 @@ type Zb<F> = Vec<F>;
 /// The z_i_j*C_i vector for a single instance.
 type Zc<F> = Vec<F>;
 /// Assignments for a single instance.
+#[cfg_attr(feature = "checkpoint", derive(serde::Serialize, serde::Deserialize))]
 pub(super) struct Assignments<F>(
     pub(super) PaddedPubInputs<F>,
     pub(super) PrivateInputs<F>,
@@ -100,6 +106,7 @@ pub(super) struct Assignments<F>(
 impl<'a, F: PrimeField, MM: SNARKMode> State<'a, F, MM> {
     pub(super) fn initialize(
         indices_and_assignments: BTreeMap<&'a Circuit<F, MM>, Vec<Assignments<F>>>,
+        terminator: super::Terminator,
     ) -> Result<Self, AHPError> {
         let mut max_non_zero_domain: Option<EvaluationDomain<F>> = None;
         let mut max_num_constraints = 0;
@@ -176,9 +183,15 @@ impl<'a, F: PrimeField, MM: SNARKMode> State<'a, F, MM> {
             circuit_specific_states,
             total_instances,
             first_round_oracles: None,
+            terminator,
         })
     }
 
+    /// Iterate over the IDs of the circuits being proven, in the order of the circuits.
+    pub fn circuit_ids(&self) -> impl Iterator<Item = &'a CircuitId> + '_ {
+        self.circuit_specific_states.keys().map(|&circuit| &circuit.id)
+    }
+
     /// Get the batch size for a given circuit.
     pub fn batch_size(&self, circuit: &Circuit<F, MM>) -> Option<usize> {
         self.circuit_specific_states.get(circuit).map(|s| s.batch_size)
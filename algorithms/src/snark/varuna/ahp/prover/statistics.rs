@@ -0,0 +1,87 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    fft::FFTRecorder,
+    snark::varuna::ahp::{indexer::CircuitInfo, CircuitId},
+};
+
+use std::{
+    collections::BTreeMap,
+    time::{Duration, Instant},
+};
+
+/// Statistics collected while proving a batch, for tuning the hardware of a prover.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ProverStatistics {
+    /// The statistics of each round of the prover, in order.
+    pub rounds: Vec<RoundStatistics>,
+    /// The information of each circuit in the batch, from its index.
+    pub circuit_infos: BTreeMap<CircuitId, CircuitInfo>,
+    /// The number of instances of each circuit in the batch.
+    pub batch_sizes: BTreeMap<CircuitId, usize>,
+    /// An estimate of the peak memory used by the witnesses in bytes, i.e. by the assignments of
+    /// each instance and their products with the `A`, `B`, and `C` matrices.
+    pub witness_memory_estimate: usize,
+    /// The wall time of the prover, from synthesizing the witnesses to opening the commitments.
+    pub total_time: Duration,
+}
+
+impl ProverStatistics {
+    /// Returns the total number of FFTs performed during the rounds.
+    pub fn num_ffts(&self) -> u64 {
+        self.rounds.iter().flat_map(|round| round.ffts.values()).sum()
+    }
+
+    /// Returns the total number of polynomials committed to during the rounds.
+    pub fn num_commitments(&self) -> usize {
+        self.rounds.iter().map(|round| round.num_commitments).sum()
+    }
+}
+
+/// Statistics collected during a single round of the prover.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RoundStatistics {
+    /// The round, from 1 to 5.
+    pub round: usize,
+    /// The wall time of the round, including committing to its oracles.
+    pub time: Duration,
+    /// The number of FFTs performed during the round, for each domain size.
+    /// Note that FFTs performed concurrently by unrelated work are included as well.
+    pub ffts: BTreeMap<usize, u64>,
+    /// The number of polynomials committed to during the round.
+    pub num_commitments: usize,
+}
+
+/// Records the statistics of a single round of the prover.
+pub(crate) struct RoundRecorder {
+    /// The round, from 1 to 5.
+    round: usize,
+    /// The start time of the round.
+    start: Instant,
+    /// The recorder of the FFTs performed during the round.
+    ffts: FFTRecorder,
+}
+
+impl RoundRecorder {
+    /// Starts recording the given round.
+    pub(crate) fn start(round: usize) -> Self {
+        Self { round, start: Instant::now(), ffts: FFTRecorder::start() }
+    }
+
+    /// Finishes recording the round, in which the given number of polynomials were committed to.
+    pub(crate) fn finish(self, num_commitments: usize) -> RoundStatistics {
+        RoundStatistics { round: self.round, time: self.start.elapsed(), ffts: self.ffts.num_ffts(), num_commitments }
+    }
+}
@@ -0,0 +1,64 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::snark::varuna::AHPError;
+
+use std::sync::{
+    atomic::{AtomicBool, Ordering},
+    Arc,
+};
+
+/// A cancellation token for the AHP prover.
+///
+/// The prover polls the token between circuits, between rounds, and before each FFT
+/// within its jobs, and returns `AHPError::Terminated` once it has been set.
+#[derive(Clone, Debug, Default)]
+pub struct Terminator(Arc<AtomicBool>);
+
+impl Terminator {
+    /// Initializes a new terminator, which is not yet set.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Signals the prover to terminate.
+    pub fn terminate(&self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+
+    /// Returns `true` if termination has been signalled.
+    #[inline]
+    pub fn is_terminated(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+
+    /// Returns `AHPError::Terminated` if termination has been signalled.
+    #[inline]
+    pub fn check(&self) -> Result<(), AHPError> {
+        match self.is_terminated() {
+            true => Err(AHPError::Terminated),
+            false => Ok(()),
+        }
+    }
+
+    /// Returns an error if termination has been signalled, for use within jobs that return `anyhow::Error`s.
+    ///
+    /// Each round re-checks the terminator once its jobs are done,
+    /// so the prover still surfaces the termination as `AHPError::Terminated`.
+    #[inline]
+    pub(crate) fn check_job(&self) -> anyhow::Result<()> {
+        anyhow::ensure!(!self.is_terminated(), "The prover was terminated");
+        Ok(())
+    }
+}
@@ -269,6 +269,11 @@ impl<E: PairingEngine> Proof<E> {
         }
         Ok(&self.batch_sizes)
     }
+
+    /// Returns the size in bytes of the compressed proof, without serializing it.
+    pub fn compressed_size(&self) -> usize {
+        CanonicalSerialize::serialized_size(self, Compress::Yes)
+    }
 }
 
 impl<E: PairingEngine> CanonicalSerialize for Proof<E> {
@@ -479,6 +484,11 @@ mod test {
                     evaluations: proof_evaluations,
                 };
                 let proof = Proof { batch_sizes, commitments, evaluations, third_msg, fourth_msg, pc_proof };
+
+                let mut compressed = Vec::new();
+                proof.serialize_compressed(&mut compressed).unwrap();
+                assert_eq!(proof.compressed_size(), compressed.len());
+
                 let combinations = modes();
                 for (compress, validate) in combinations {
                     let size = Proof::serialized_size(&proof, compress);
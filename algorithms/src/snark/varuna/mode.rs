@@ -17,6 +17,10 @@ use core::fmt::Debug;
 /// A trait to specify the SNARK mode.
 pub trait SNARKMode: 'static + Copy + Clone + Debug + PartialEq + Eq + Sync + Send {
     const ZK: bool;
+    /// The maximum degree supported by the universal SRS, which has `2^28` powers.
+    /// The prover only accepts circuits whose constraints fit within this degree,
+    /// as given by `AHPForR1CS::max_supported_constraints`.
+    const MAX_SRS_DEGREE: usize = (1 << 28) - 1;
 }
 
 /// This mode produces a hiding SNARK proof.
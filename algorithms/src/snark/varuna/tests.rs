@@ -62,6 +62,10 @@ mod varuna {
                         assert!($snark_inst::verify_vk(universal_verifier, &fs_parameters, &circ, &index_vk, &certificate).unwrap());
                         println!("verified vk");
 
+                        // Ensure the verifying key commits to the circuit as indexed.
+                        let circuit_commitment = AHPForR1CS::<Fr, $snark_mode>::index(&circ).unwrap().commitment_to_index();
+                        assert_eq!(index_vk.id.0, circuit_commitment);
+
                         let proof = $snark_inst::prove(universal_prover, &fs_parameters, &index_pk, &circ, rng).unwrap();
                         println!("Called prover");
 
@@ -275,6 +279,423 @@ mod varuna {
         SonicPCTest::test_bincode(num_constraints, num_variables);
         SonicPCPoswTest::test_bincode(num_constraints, num_variables);
     }
+
+    #[test]
+    fn prove_deterministic() {
+        let max_degree = AHPForR1CS::<Fr, VarunaNonHidingMode>::max_degree(100, 25, 300).unwrap();
+        let universal_srs = VarunaSonicPoSWInst::universal_setup(max_degree).unwrap();
+        let universal_prover = &universal_srs.to_universal_prover().unwrap();
+        let universal_verifier = &universal_srs.to_universal_verifier().unwrap();
+        let fs_parameters = FS::sample_parameters();
+
+        // Sample a tiny circuit with a fixed assignment.
+        let mul_depth = 2;
+        let (circuit, public_inputs) = TestCircuit::gen_rand(mul_depth, 16, 16, &mut TestRng::fixed(1));
+        let (index_pk, index_vk) = VarunaSonicPoSWInst::circuit_setup(&universal_srs, &circuit).unwrap();
+
+        let mut keys_to_constraints = BTreeMap::new();
+        keys_to_constraints.insert(&index_pk, std::slice::from_ref(&circuit));
+
+        // Ensure repeated proofs are bit-identical.
+        let proof =
+            VarunaSonicPoSWInst::prove_batch_deterministic(universal_prover, &fs_parameters, &keys_to_constraints)
+                .unwrap();
+        let candidate =
+            VarunaSonicPoSWInst::prove_batch_deterministic(universal_prover, &fs_parameters, &keys_to_constraints)
+                .unwrap();
+        assert_eq!(proof, candidate);
+        assert!(
+            VarunaSonicPoSWInst::verify(universal_verifier, &fs_parameters, &index_vk, public_inputs, &proof).unwrap()
+        );
+
+        // Ensure deterministic proving is rejected in hiding mode.
+        let max_degree = AHPForR1CS::<Fr, VarunaHidingMode>::max_degree(100, 25, 300).unwrap();
+        let universal_srs = VarunaSonicInst::universal_setup(max_degree).unwrap();
+        let universal_prover = &universal_srs.to_universal_prover().unwrap();
+        let (index_pk, _) = VarunaSonicInst::circuit_setup(&universal_srs, &circuit).unwrap();
+
+        let mut keys_to_constraints = BTreeMap::new();
+        keys_to_constraints.insert(&index_pk, std::slice::from_ref(&circuit));
+        assert!(
+            VarunaSonicInst::prove_batch_deterministic(universal_prover, &fs_parameters, &keys_to_constraints).is_err()
+        );
+    }
+
+    #[test]
+    fn prove_with_max_concurrent_instances() {
+        use crate::snark::varuna::prover;
+        use std::num::NonZeroUsize;
+
+        let max_degree = AHPForR1CS::<Fr, VarunaNonHidingMode>::max_degree(100, 25, 300).unwrap();
+        let universal_srs = VarunaSonicPoSWInst::universal_setup(max_degree).unwrap();
+        let universal_prover = &universal_srs.to_universal_prover().unwrap();
+        let universal_verifier = &universal_srs.to_universal_verifier().unwrap();
+        let fs_parameters = FS::sample_parameters();
+
+        let rng = &mut TestRng::fixed(1);
+        let (instances, public_inputs): (Vec<_>, Vec<_>) =
+            (0..5).map(|_| TestCircuit::gen_rand(2, 16, 16, rng)).unzip();
+        let (index_pk, index_vk) = VarunaSonicPoSWInst::circuit_setup(&universal_srs, &instances[0]).unwrap();
+
+        let mut keys_to_constraints = BTreeMap::new();
+        keys_to_constraints.insert(&index_pk, instances.as_slice());
+
+        // Ensure the proof does not depend on the limit on the concurrent instances.
+        let expected =
+            VarunaSonicPoSWInst::prove_batch_deterministic(universal_prover, &fs_parameters, &keys_to_constraints)
+                .unwrap();
+        for max_concurrent_instances in [1, 2, instances.len()] {
+            let candidate = VarunaSonicPoSWInst::prove_batch_with_max_concurrent_instances(
+                universal_prover,
+                &fs_parameters,
+                &keys_to_constraints,
+                NonZeroUsize::new(max_concurrent_instances),
+                &prover::Terminator::default(),
+                &mut prover::CountingRng::deterministic(),
+            )
+            .unwrap();
+            assert_eq!(expected, candidate);
+        }
+
+        let mut keys_to_inputs = BTreeMap::new();
+        keys_to_inputs.insert(&index_vk, public_inputs.as_slice());
+        assert!(
+            VarunaSonicPoSWInst::verify_batch(universal_verifier, &fs_parameters, &keys_to_inputs, &expected).unwrap()
+        );
+    }
+
+    #[test]
+    fn index_file_roundtrip() {
+        use snarkvm_fields::One;
+        use snarkvm_utilities::serialize::CanonicalSerialize;
+
+        type AHP = AHPForR1CS<Fr, VarunaNonHidingMode>;
+
+        let rng = &mut TestRng::default();
+
+        // Index a circuit, and write it to an index file.
+        let (circuit, _) = TestCircuit::gen_rand(2, 64, 32, rng);
+        let index = AHP::index(&circuit).unwrap();
+        let path = std::env::temp_dir().join(format!("varuna_index_{}.bin", index.id));
+        index.to_index_file(&path).unwrap();
+
+        // Ensure the loaded circuit matches the indexed circuit.
+        let candidate = crate::snark::varuna::Circuit::<Fr, VarunaNonHidingMode>::from_index_file(&path).unwrap();
+        assert_eq!(index, candidate);
+        let mut expected_bytes = vec![];
+        index.serialize_compressed(&mut expected_bytes).unwrap();
+        let mut candidate_bytes = vec![];
+        candidate.serialize_compressed(&mut candidate_bytes).unwrap();
+        assert_eq!(expected_bytes, candidate_bytes);
+
+        // Ensure a corrupted payload is rejected.
+        let mut bytes = std::fs::read(&path).unwrap();
+        let last = bytes.len() - 1;
+        bytes[last] ^= 1;
+        std::fs::write(&path, &bytes).unwrap();
+        assert!(crate::snark::varuna::Circuit::<Fr, VarunaNonHidingMode>::from_index_file(&path).is_err());
+
+        // Ensure an unknown version is rejected.
+        bytes[last] ^= 1;
+        bytes[4] = bytes[4].wrapping_add(1);
+        std::fs::write(&path, &bytes).unwrap();
+        assert!(crate::snark::varuna::Circuit::<Fr, VarunaNonHidingMode>::from_index_file(&path).is_err());
+        bytes[4] = bytes[4].wrapping_sub(1);
+
+        // Ensure tampered matrices are rejected, even with a valid hash of the payload.
+        let mut tampered = index.clone();
+        let entry = tampered.a.iter_mut().flatten().next().unwrap();
+        entry.0 += Fr::one();
+        let mut payload = vec![];
+        tampered.serialize_compressed(&mut payload).unwrap();
+        let header_len = bytes.len() - expected_bytes.len();
+        let mut tampered_bytes = bytes[..header_len - 32].to_vec();
+        tampered_bytes.extend_from_slice(&crate::crypto_hash::sha256(&payload));
+        tampered_bytes.extend_from_slice(&payload);
+        std::fs::write(&path, &tampered_bytes).unwrap();
+        assert!(crate::snark::varuna::Circuit::<Fr, VarunaNonHidingMode>::from_index_file(&path).is_err());
+
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn prove_from_assignments() {
+        use crate::{r1cs::ConstraintSynthesizer, snark::varuna::prover};
+
+        let rng = &mut TestRng::default();
+
+        let max_degree = AHPForR1CS::<Fr, VarunaHidingMode>::max_degree(100, 25, 300).unwrap();
+        let universal_srs = VarunaSonicPoSWInst::universal_setup(max_degree).unwrap();
+        let universal_prover = &universal_srs.to_universal_prover().unwrap();
+        let universal_verifier = &universal_srs.to_universal_verifier().unwrap();
+        let fs_parameters = FS::sample_parameters();
+
+        let mul_depth = 2;
+        let (circuit, public_inputs) = TestCircuit::gen_rand(mul_depth, 16, 16, rng);
+        let public_inputs = public_inputs.as_slice();
+
+        // Synthesize the circuit once, as a caller evaluating it before proving would.
+        let mut pcs = prover::ConstraintSystem::new();
+        circuit.generate_constraints(&mut pcs).unwrap();
+        let assignment = prover::VariableAssignment::from(pcs);
+        let expected = prover::VariableAssignment::new(public_inputs, assignment.private_variables.clone());
+        assert_eq!(assignment, expected);
+
+        // Ensure the proofs from both paths verify, and are identical in non-hiding mode.
+        let (index_pk, index_vk) = VarunaSonicPoSWInst::circuit_setup(&universal_srs, &circuit).unwrap();
+        let mut keys_to_constraints = BTreeMap::new();
+        keys_to_constraints.insert(&index_pk, std::slice::from_ref(&circuit));
+        let expected =
+            VarunaSonicPoSWInst::prove_batch_deterministic(universal_prover, &fs_parameters, &keys_to_constraints)
+                .unwrap();
+
+        let mut keys_to_assignments = BTreeMap::new();
+        keys_to_assignments.insert(&index_pk, vec![assignment.clone()]);
+        let candidate = VarunaSonicPoSWInst::prove_batch_from_assignments(
+            universal_prover,
+            &fs_parameters,
+            keys_to_assignments,
+            &prover::Terminator::default(),
+            &mut prover::CountingRng::deterministic(),
+        )
+        .unwrap();
+        assert_eq!(expected, candidate);
+        assert!(
+            VarunaSonicPoSWInst::verify(universal_verifier, &fs_parameters, &index_vk, public_inputs, &candidate)
+                .unwrap()
+        );
+
+        // Ensure the proof from the assignment verifies in hiding mode.
+        let (index_pk, index_vk) = VarunaSonicInst::circuit_setup(&universal_srs, &circuit).unwrap();
+        let mut keys_to_assignments = BTreeMap::new();
+        keys_to_assignments.insert(&index_pk, vec![assignment.clone()]);
+        let proof = VarunaSonicInst::prove_batch_from_assignments(
+            universal_prover,
+            &fs_parameters,
+            keys_to_assignments,
+            &prover::Terminator::default(),
+            rng,
+        )
+        .unwrap();
+        assert!(VarunaSonicInst::verify(universal_verifier, &fs_parameters, &index_vk, public_inputs, &proof).unwrap());
+
+        // Ensure an assignment that does not match the circuit is rejected.
+        let mut invalid_assignment = assignment;
+        invalid_assignment.private_variables.pop();
+        let mut keys_to_assignments = BTreeMap::new();
+        keys_to_assignments.insert(&index_pk, vec![invalid_assignment]);
+        assert!(
+            VarunaSonicInst::prove_batch_from_assignments(
+                universal_prover,
+                &fs_parameters,
+                keys_to_assignments,
+                &prover::Terminator::default(),
+                rng,
+            )
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn prover_state_circuit_ids() {
+        use crate::snark::varuna::prover;
+
+        type AHP = AHPForR1CS<Fr, VarunaHidingMode>;
+
+        let rng = &mut TestRng::default();
+
+        let (circuit_0, _) = TestCircuit::gen_rand(1, 16, 16, rng);
+        let (circuit_1, _) = TestCircuit::gen_rand(2, 32, 32, rng);
+        let index_0 = AHP::index(&circuit_0).unwrap();
+        let index_1 = AHP::index(&circuit_1).unwrap();
+
+        let mut circuits_to_constraints = BTreeMap::new();
+        circuits_to_constraints.insert(&index_0, std::slice::from_ref(&circuit_0));
+        circuits_to_constraints.insert(&index_1, std::slice::from_ref(&circuit_1));
+
+        // Ensure the prover state exposes the ID of every circuit in the batch, in the order of the circuits.
+        let state = AHP::init_prover(&circuits_to_constraints, &prover::Terminator::default(), rng).unwrap();
+        let expected = circuits_to_constraints.keys().map(|circuit| circuit.id).collect::<Vec<_>>();
+        assert_eq!(state.circuit_ids().copied().collect::<Vec<_>>(), expected);
+        assert_ne!(index_0.id, index_1.id);
+    }
+
+    #[test]
+    fn prover_max_concurrent_instances() {
+        use crate::snark::varuna::prover;
+        use std::num::NonZeroUsize;
+
+        type AHP = AHPForR1CS<Fr, VarunaHidingMode>;
+
+        let rng = &mut TestRng::default();
+
+        let instances = (0..5).map(|_| TestCircuit::gen_rand(1, 16, 16, rng).0).collect::<Vec<_>>();
+        let index = AHP::index(&instances[0]).unwrap();
+
+        let mut circuits_to_constraints = BTreeMap::new();
+        circuits_to_constraints.insert(&index, instances.as_slice());
+
+        // Ensure the assignments are identical, and in the same order, for any limit on the concurrent instances.
+        let terminator = prover::Terminator::default();
+        let expected = AHP::synthesize_assignments(&circuits_to_constraints, None, &terminator).unwrap();
+        assert_eq!(expected[&index].len(), instances.len());
+        for max_concurrent_instances in [1, 2, instances.len(), 16] {
+            let candidate = AHP::synthesize_assignments(
+                &circuits_to_constraints,
+                NonZeroUsize::new(max_concurrent_instances),
+                &terminator,
+            )
+            .unwrap();
+            assert_eq!(expected[&index], candidate[&index]);
+        }
+
+        // Ensure the prover state does not depend on the limit.
+        let state = AHP::init_prover(&circuits_to_constraints, &terminator, &mut TestRng::fixed(1)).unwrap();
+        let candidate = AHP::init_prover_with_max_concurrent_instances(
+            &circuits_to_constraints,
+            NonZeroUsize::new(1),
+            &terminator,
+            &mut TestRng::fixed(1),
+        )
+        .unwrap();
+        assert_eq!(state.public_inputs(&index), candidate.public_inputs(&index));
+    }
+
+    #[test]
+    fn prove_circuit_too_large() {
+        use crate::snark::varuna::{prover, AHPError};
+
+        /// A mode whose SRS degree only supports circuits with up to 8 constraints.
+        #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+        struct SmallMode;
+
+        impl SNARKMode for SmallMode {
+            const ZK: bool = false;
+            const MAX_SRS_DEGREE: usize = 14;
+        }
+
+        type AHP = AHPForR1CS<Fr, SmallMode>;
+
+        let rng = &mut TestRng::default();
+
+        let mul_depth = 1;
+        let (circuit, _) = TestCircuit::gen_rand(mul_depth, 16, 16, rng);
+        let index = AHP::index(&circuit).unwrap();
+
+        let mut circuits_to_constraints = BTreeMap::new();
+        circuits_to_constraints.insert(&index, std::slice::from_ref(&circuit));
+
+        // Ensure the prover rejects the circuit before synthesizing it.
+        let result = AHP::init_prover(&circuits_to_constraints, &prover::Terminator::default(), rng);
+        match result {
+            Err(AHPError::CircuitTooLarge { constraint_count, max }) => {
+                assert_eq!(constraint_count, index.index_info.num_constraints);
+                assert_eq!(max, 8);
+            }
+            _ => panic!("Expected the circuit to be too large"),
+        }
+    }
+
+    #[cfg(feature = "checkpoint")]
+    #[test]
+    fn prover_state_checkpoint() {
+        use crate::snark::varuna::prover;
+        use serde::de::DeserializeSeed;
+
+        type AHP = AHPForR1CS<Fr, VarunaHidingMode>;
+
+        let rng = &mut TestRng::default();
+
+        let mul_depth = 1;
+        let (circuit, _) = TestCircuit::gen_rand(mul_depth, 16, 16, rng);
+        let index = AHP::index(&circuit).unwrap();
+
+        let mut circuits_to_constraints = BTreeMap::new();
+        circuits_to_constraints.insert(&index, std::slice::from_ref(&circuit));
+
+        // Run the prover up to the end of the first round.
+        let terminator = prover::Terminator::default();
+        let state = AHP::init_prover(&circuits_to_constraints, &terminator, rng).unwrap();
+        let state = AHP::prover_first_round(state, rng).unwrap();
+
+        // Checkpoint the prover state, and resume from it.
+        let expected = serde_json::to_vec(&state).unwrap();
+        let seed = prover::StateSeed { circuits: vec![&index], terminator: terminator.clone() };
+        let candidate = seed.deserialize(&mut serde_json::Deserializer::from_slice(&expected)).unwrap();
+        assert_eq!(expected, serde_json::to_vec(&candidate).unwrap());
+        assert_eq!(state.public_inputs(&index), candidate.public_inputs(&index));
+
+        // Ensure resuming fails if the circuit is not provided.
+        let seed = prover::StateSeed::<Fr, VarunaHidingMode> { circuits: vec![], terminator };
+        assert!(seed.deserialize(&mut serde_json::Deserializer::from_slice(&expected)).is_err());
+    }
+
+    #[cfg(feature = "checkpoint")]
+    #[test]
+    fn prove_with_checkpoints() {
+        use crate::{
+            polycommit::sonic_pc::LabeledPolynomial,
+            snark::varuna::{prover, CircuitId},
+            AlgebraicSponge,
+        };
+
+        type AHP = AHPForR1CS<Fr, VarunaHidingMode>;
+
+        let mul_depth = 1;
+        let (circuit, _) = TestCircuit::gen_rand(mul_depth, 16, 16, &mut TestRng::default());
+        let index = AHP::index(&circuit).unwrap();
+
+        // Runs all prover rounds, optionally checkpointing and resuming the prover after each round,
+        // and returns all of the oracles sent by the prover.
+        let prove = |use_checkpoints: bool| -> Vec<LabeledPolynomial<Fr>> {
+            let rng = &mut TestRng::fixed(1);
+            let sponge = &mut FS::new_with_parameters(&FS::sample_parameters());
+            let terminator = prover::Terminator::default();
+            let roundtrip = |state| match use_checkpoints {
+                true => AHP::resume(&AHP::checkpoint(&state).unwrap(), vec![&index], terminator.clone()).unwrap(),
+                false => state,
+            };
+
+            let mut circuits_to_constraints = BTreeMap::new();
+            circuits_to_constraints.insert(&index, std::slice::from_ref(&circuit));
+            let state = roundtrip(AHP::init_prover(&circuits_to_constraints, &terminator, rng).unwrap());
+
+            let state = roundtrip(AHP::prover_first_round(state, rng).unwrap());
+            let mut oracles = state.first_round_oracles.as_ref().unwrap().iter().cloned().collect::<Vec<_>>();
+            let batch_sizes = BTreeMap::from([(index.id, 1)]);
+            let circuit_infos: BTreeMap<CircuitId, _> = BTreeMap::from([(index.id, &index.index_info)]);
+            let (first_msg, verifier_state) = AHP::verifier_first_round(
+                &batch_sizes,
+                &circuit_infos,
+                state.max_constraint_domain,
+                state.max_variable_domain,
+                state.max_non_zero_domain,
+                sponge,
+            )
+            .unwrap();
+
+            let (second_oracles, state) = AHP::prover_second_round(&first_msg, state, rng).unwrap();
+            let state = roundtrip(state);
+            oracles.extend(second_oracles.iter().cloned());
+            let (second_msg, verifier_state) = AHP::verifier_second_round(verifier_state, sponge).unwrap();
+
+            let (_, third_oracles, state) = AHP::prover_third_round(&first_msg, &second_msg, state, rng).unwrap();
+            let state = roundtrip(state);
+            oracles.extend(third_oracles.iter().cloned());
+            let (third_msg, verifier_state) = AHP::verifier_third_round(verifier_state, sponge).unwrap();
+
+            let (_, fourth_oracles, state) = AHP::prover_fourth_round(&second_msg, &third_msg, state, rng).unwrap();
+            let state = roundtrip(state);
+            oracles.extend(fourth_oracles.iter().cloned());
+            let (fourth_msg, _) = AHP::verifier_fourth_round(verifier_state, sponge).unwrap();
+
+            let fifth_oracles = AHP::prover_fifth_round(fourth_msg, state, rng).unwrap();
+            oracles.extend(fifth_oracles.iter().cloned());
+            oracles
+        };
+
+        assert_eq!(prove(false), prove(true));
+    }
 }
 
 mod varuna_hiding {
@@ -437,6 +858,29 @@ mod varuna_hiding {
         test_circuit_n_times(num_constraints, num_variables, 1);
     }
 
+    #[test]
+    fn check_circuit_info() {
+        let rng = &mut TestRng::default();
+        let mul_depth = 1;
+        let (circuit, _) = TestCircuit::gen_rand(mul_depth, 100, 25, rng);
+
+        let index = AHPForR1CS::<Fr, VarunaHidingMode>::index(&circuit).unwrap();
+        let info = index.info();
+        assert_eq!(info.num_constraints, index.index_info.num_constraints);
+        assert_eq!(info.num_variables, index.index_info.num_variables);
+        assert_eq!(info.num_non_zero_a, index.index_info.num_non_zero_a);
+        assert_eq!(info.num_non_zero_b, index.index_info.num_non_zero_b);
+        assert_eq!(info.num_non_zero_c, index.index_info.num_non_zero_c);
+        assert!(info.constraint_domain_size.is_power_of_two());
+        assert!(info.constraint_domain_size >= info.num_constraints);
+        assert!(info.variable_domain_size.is_power_of_two());
+        assert!(info.variable_domain_size >= info.num_variables);
+
+        let table = info.to_string();
+        assert_eq!(table.lines().count(), 7);
+        assert!(table.lines().next().unwrap().ends_with(&info.num_constraints.to_string()));
+    }
+
     #[test]
     fn check_indexing() {
         let rng = &mut TestRng::default();
@@ -467,6 +911,168 @@ mod varuna_hiding {
         assert!(VarunaInst::verify(universal_verifier, &fs_parameters, &new_vk, public_inputs, &proof).unwrap());
     }
 
+    #[test]
+    fn test_prove_batch_with_terminator() {
+        use crate::{snark::varuna::prover::Terminator, SNARKError};
+
+        let rng = &mut TestRng::default();
+
+        let max_degree = AHPForR1CS::<Fr, VarunaHidingMode>::max_degree(100, 25, 300).unwrap();
+        let universal_srs = VarunaInst::universal_setup(max_degree).unwrap();
+        let universal_prover = &universal_srs.to_universal_prover().unwrap();
+        let fs_parameters = FS::sample_parameters();
+
+        let (circuit, _) = TestCircuit::gen_rand(2, 1 << 10, 1 << 10, rng);
+        let (index_pk, _) = VarunaInst::circuit_setup(&universal_srs, &circuit).unwrap();
+
+        let mut keys_to_constraints = BTreeMap::new();
+        keys_to_constraints.insert(&index_pk, std::slice::from_ref(&circuit));
+
+        // Set the terminator before the prover starts.
+        let terminator = Terminator::new();
+        terminator.terminate();
+
+        let result = VarunaInst::prove_batch_with_terminator(
+            universal_prover,
+            &fs_parameters,
+            &keys_to_constraints,
+            &terminator,
+            rng,
+        );
+        assert!(matches!(result, Err(SNARKError::Terminated)));
+    }
+
+    #[test]
+    fn test_prove_batch_terminated_during_synthesis() {
+        use crate::{
+            r1cs::{ConstraintSynthesizer, ConstraintSystem, SynthesisError},
+            snark::varuna::prover::Terminator,
+            SNARKError,
+        };
+
+        /// A circuit that sets the terminator once it has generated its constraints.
+        struct TerminatingCircuit {
+            circuit: TestCircuit<Fr>,
+            terminator: Terminator,
+        }
+
+        impl ConstraintSynthesizer<Fr> for TerminatingCircuit {
+            fn generate_constraints<CS: ConstraintSystem<Fr>>(&self, cs: &mut CS) -> Result<(), SynthesisError> {
+                self.circuit.generate_constraints(cs)?;
+                self.terminator.terminate();
+                Ok(())
+            }
+        }
+
+        let rng = &mut TestRng::default();
+
+        let max_degree = AHPForR1CS::<Fr, VarunaHidingMode>::max_degree(100, 25, 300).unwrap();
+        let universal_srs = VarunaInst::universal_setup(max_degree).unwrap();
+        let universal_prover = &universal_srs.to_universal_prover().unwrap();
+        let fs_parameters = FS::sample_parameters();
+
+        let (circuit, _) = TestCircuit::gen_rand(2, 1 << 10, 1 << 10, rng);
+        let (index_pk, _) = VarunaInst::circuit_setup(&universal_srs, &circuit).unwrap();
+
+        // The terminator is set by the circuit itself, so the prover is guaranteed to observe it after synthesis.
+        let terminator = Terminator::new();
+        let circuits = [
+            TerminatingCircuit { circuit: circuit.clone(), terminator: terminator.clone() },
+            TerminatingCircuit { circuit, terminator: terminator.clone() },
+        ];
+        let mut keys_to_constraints = BTreeMap::new();
+        keys_to_constraints.insert(&index_pk, &circuits[..]);
+
+        let result = VarunaInst::prove_batch_with_terminator(
+            universal_prover,
+            &fs_parameters,
+            &keys_to_constraints,
+            &terminator,
+            rng,
+        );
+        assert!(terminator.is_terminated());
+        assert!(matches!(result, Err(SNARKError::Terminated)));
+    }
+
+    #[test]
+    fn test_prove_batch_with_statistics() {
+        use crate::snark::varuna::prover::Terminator;
+
+        type AHP = AHPForR1CS<Fr, VarunaHidingMode>;
+
+        let rng = &mut TestRng::default();
+
+        let max_degree = AHP::max_degree(100, 25, 300).unwrap();
+        let universal_srs = VarunaInst::universal_setup(max_degree).unwrap();
+        let universal_prover = &universal_srs.to_universal_prover().unwrap();
+        let universal_verifier = &universal_srs.to_universal_verifier().unwrap();
+        let fs_parameters = FS::sample_parameters();
+
+        // Sample a batch with 2 instances of one circuit, and 1 instance of another.
+        let mul_depth = 2;
+        let (circuit_0, public_inputs_0) = TestCircuit::gen_rand(mul_depth, 16, 16, rng);
+        let (circuit_1, public_inputs_1) = TestCircuit::gen_rand(mul_depth, 32, 25, rng);
+        let (circuit_2, public_inputs_2) = TestCircuit::gen_rand(mul_depth, 32, 25, rng);
+        let (index_pk_0, index_vk_0) = VarunaInst::circuit_setup(&universal_srs, &circuit_0).unwrap();
+        let (index_pk_1, index_vk_1) = VarunaInst::circuit_setup(&universal_srs, &circuit_1).unwrap();
+        let instances_0 = [circuit_0];
+        let instances_1 = [circuit_1, circuit_2];
+
+        let mut keys_to_constraints = BTreeMap::new();
+        keys_to_constraints.insert(&index_pk_0, &instances_0[..]);
+        keys_to_constraints.insert(&index_pk_1, &instances_1[..]);
+
+        let (proof, statistics) = VarunaInst::prove_batch_with_statistics(
+            universal_prover,
+            &fs_parameters,
+            &keys_to_constraints,
+            &Terminator::default(),
+            rng,
+        )
+        .unwrap();
+
+        // Ensure the proof is valid.
+        let inputs_0 = [public_inputs_0];
+        let inputs_1 = [public_inputs_1, public_inputs_2];
+        let mut vks_to_inputs = BTreeMap::new();
+        vks_to_inputs.insert(&index_vk_0, &inputs_0[..]);
+        vks_to_inputs.insert(&index_vk_1, &inputs_1[..]);
+        assert!(VarunaInst::verify_batch(universal_verifier, &fs_parameters, &vks_to_inputs, &proof).unwrap());
+
+        // Ensure all five rounds are present, with the expected number of commitments.
+        let expected_num_commitments = [
+            AHP::num_first_round_oracles(3),
+            AHP::num_second_round_oracles(),
+            AHP::num_third_round_oracles(),
+            AHP::num_fourth_round_oracles(2),
+            AHP::num_fifth_round_oracles(),
+        ];
+        assert_eq!(statistics.rounds.len(), 5);
+        for (i, (round, num_commitments)) in statistics.rounds.iter().zip(expected_num_commitments).enumerate() {
+            assert_eq!(round.round, i + 1);
+            assert_eq!(round.num_commitments, num_commitments);
+            assert!(round.ffts.keys().all(|size| size.is_power_of_two()));
+        }
+        assert_eq!(statistics.num_commitments(), expected_num_commitments.iter().sum::<usize>());
+        assert!(statistics.num_ffts() > 0);
+        assert!(statistics.total_time >= statistics.rounds.iter().map(|round| round.time).sum::<std::time::Duration>());
+
+        // Ensure the circuit statistics match the index.
+        for (index_pk, batch_size) in [(&index_pk_0, 1), (&index_pk_1, 2)] {
+            let circuit = &index_pk.circuit;
+            assert_eq!(statistics.circuit_infos[&circuit.id], circuit.index_info);
+            assert_eq!(statistics.batch_sizes[&circuit.id], batch_size);
+        }
+        let expected_witness_memory_estimate = [(&index_pk_0, 1), (&index_pk_1, 2)]
+            .into_iter()
+            .map(|(index_pk, batch_size)| {
+                let info = &index_pk.circuit.index_info;
+                batch_size * (info.num_variables + 3 * info.num_constraints) * core::mem::size_of::<Fr>()
+            })
+            .sum::<usize>();
+        assert_eq!(statistics.witness_memory_estimate, expected_witness_memory_estimate);
+    }
+
     #[test]
     fn test_srs_downloads() {
         let rng = &mut TestRng::default();
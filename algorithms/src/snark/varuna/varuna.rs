@@ -50,7 +50,7 @@ use anyhow::{anyhow, Result};
 use core::marker::PhantomData;
 use itertools::Itertools;
 use rand::{CryptoRng, Rng};
-use std::{borrow::Borrow, collections::BTreeMap, ops::Deref, sync::Arc};
+use std::{borrow::Borrow, collections::BTreeMap, num::NonZeroUsize, ops::Deref, sync::Arc, time::Instant};
 
 use crate::srs::UniversalProver;
 #[cfg(not(feature = "std"))]
@@ -90,7 +90,7 @@ impl<E: PairingEngine, FS: AlgebraicSponge<E::Fq, 2>, MM: SNARKMode> VarunaSNARK
             // Varuna only needs degree 2 random polynomials.
             let supported_hiding_bound = 1;
             let supported_lagrange_sizes = [].into_iter(); // TODO: consider removing lagrange_bases_at_beta_g from CommitterKey
-            let (committer_key, _) = SonicKZG10::<E, FS>::trim(
+            let (committer_key, _) = SonicKZG10::<E, FS>::try_trim(
                 universal_srs,
                 indexed_circuit.max_degree(),
                 supported_lagrange_sizes,
@@ -186,165 +186,163 @@ impl<E: PairingEngine, FS: AlgebraicSponge<E::Fq, 2>, MM: SNARKMode> VarunaSNARK
     }
 }
 
-impl<E: PairingEngine, FS, MM> SNARK for VarunaSNARK<E, FS, MM>
+impl<E: PairingEngine, FS, MM> VarunaSNARK<E, FS, MM>
 where
     E::Fr: PrimeField,
     E::Fq: PrimeField,
     FS: AlgebraicSponge<E::Fq, 2>,
     MM: SNARKMode,
 {
-    type BaseField = E::Fq;
-    type Certificate = Certificate<E>;
-    type FSParameters = FS::Parameters;
-    type FiatShamirRng = FS;
-    type Proof = Proof<E>;
-    type ProvingKey = CircuitProvingKey<E, MM>;
-    type ScalarField = E::Fr;
-    type UniversalProver = UniversalProver<E>;
-    type UniversalSRS = UniversalSRS<E>;
-    type UniversalVerifier = UniversalVerifier<E>;
-    type VerifierInput = [E::Fr];
-    type VerifyingKey = CircuitVerifyingKey<E>;
-
-    fn universal_setup(max_degree: usize) -> Result<Self::UniversalSRS, SNARKError> {
-        let setup_time = start_timer!(|| { format!("Varuna::UniversalSetup with max_degree {max_degree}",) });
-        let srs = SonicKZG10::<E, FS>::load_srs(max_degree).map_err(Into::into);
-        end_timer!(setup_time);
-        srs
+    /// Creates a batch proof, as in `SNARK::prove_batch`, without drawing any randomness.
+    /// This is only supported in non-hiding mode, in which case identical circuits
+    /// and assignments always produce bit-identical proofs.
+    pub fn prove_batch_deterministic<C: ConstraintSynthesizer<E::Fr>>(
+        universal_prover: &UniversalProver<E>,
+        fs_parameters: &FS::Parameters,
+        keys_to_constraints: &BTreeMap<&CircuitProvingKey<E, MM>, &[C]>,
+    ) -> Result<Proof<E>, SNARKError> {
+        if MM::ZK {
+            return Err(SNARKError::Message("Deterministic proving is not supported in hiding mode".to_string()));
+        }
+        let mut rng = prover::CountingRng::deterministic();
+        let terminator = prover::Terminator::default();
+        let proof = Self::prove_batch_with_terminator(
+            universal_prover,
+            fs_parameters,
+            keys_to_constraints,
+            &terminator,
+            &mut rng,
+        )?;
+        assert_eq!(rng.num_draws(), 0);
+        Ok(proof)
     }
 
-    /// Generates the circuit proving and verifying keys.
-    /// This is a deterministic algorithm that anyone can rerun.
-    fn circuit_setup<C: ConstraintSynthesizer<E::Fr>>(
-        universal_srs: &Self::UniversalSRS,
-        circuit: &C,
-    ) -> Result<(Self::ProvingKey, Self::VerifyingKey)> {
-        let mut circuit_keys = Self::batch_circuit_setup::<C>(universal_srs, &[circuit])?;
-        assert_eq!(circuit_keys.len(), 1);
-        Ok(circuit_keys.pop().unwrap())
+    /// Creates a batch proof, as in `SNARK::prove_batch`, which can be cancelled
+    /// from another thread by setting the given `terminator`.
+    /// In that case, `SNARKError::Terminated` is returned.
+    pub fn prove_batch_with_terminator<C: ConstraintSynthesizer<E::Fr>, R: Rng + CryptoRng>(
+        universal_prover: &UniversalProver<E>,
+        fs_parameters: &FS::Parameters,
+        keys_to_constraints: &BTreeMap<&CircuitProvingKey<E, MM>, &[C]>,
+        terminator: &prover::Terminator,
+        zk_rng: &mut R,
+    ) -> Result<Proof<E>, SNARKError> {
+        Self::prove_batch_with_max_concurrent_instances(
+            universal_prover,
+            fs_parameters,
+            keys_to_constraints,
+            None,
+            terminator,
+            zk_rng,
+        )
     }
 
-    /// Prove that the verifying key indeed includes a part of the reference string,
-    /// as well as the indexed circuit (i.e. the circuit as a set of linear-sized polynomials).
-    fn prove_vk(
-        universal_prover: &Self::UniversalProver,
-        fs_parameters: &Self::FSParameters,
-        verifying_key: &Self::VerifyingKey,
-        proving_key: &Self::ProvingKey,
-    ) -> Result<Self::Certificate, SNARKError> {
-        // Initialize sponge
-        let mut sponge = Self::init_sponge_for_certificate(fs_parameters, &verifying_key.circuit_commitments);
-        // Compute challenges for linear combination, and the point to evaluate the polynomials at.
-        // The linear combination requires `num_polynomials - 1` coefficients
-        // (since the first coeff is 1), and so we squeeze out `num_polynomials` points.
-        let mut challenges = sponge.squeeze_nonnative_field_elements(verifying_key.circuit_commitments.len());
-        let point = challenges.pop().unwrap();
-        let one = E::Fr::one();
-        let linear_combination_challenges = core::iter::once(&one).chain(challenges.iter());
-
-        // We will construct a linear combination and provide a proof of evaluation of the lc at `point`.
-        let mut lc = crate::polycommit::sonic_pc::LinearCombination::empty("circuit_check");
-        for (poly, &c) in proving_key.circuit.iter().zip(linear_combination_challenges) {
-            lc.add(c, poly.label());
-        }
-
-        let circuit_id = std::iter::once(&verifying_key.id);
-        let query_set = QuerySet::from_iter([("circuit_check".into(), ("challenge".into(), point))]);
-        let commitments = verifying_key
-            .iter()
-            .cloned()
-            .zip_eq(AHPForR1CS::<E::Fr, MM>::index_polynomial_info(circuit_id).values())
-            .map(|(c, info)| LabeledCommitment::new_with_info(info, c))
-            .collect::<Vec<_>>();
-
-        let committer_key = CommitterUnionKey::union(std::iter::once(proving_key.committer_key.as_ref()));
-
-        let certificate = SonicKZG10::<E, FS>::open_combinations(
+    /// Creates a batch proof, as in `prove_batch_with_terminator`, synthesizing at most
+    /// `max_concurrent_instances` instances of each circuit at a time, or all of them if `None`.
+    /// This bounds the memory used by the witnesses in flight, and does not change the proof.
+    pub fn prove_batch_with_max_concurrent_instances<C: ConstraintSynthesizer<E::Fr>, R: Rng + CryptoRng>(
+        universal_prover: &UniversalProver<E>,
+        fs_parameters: &FS::Parameters,
+        keys_to_constraints: &BTreeMap<&CircuitProvingKey<E, MM>, &[C]>,
+        max_concurrent_instances: Option<NonZeroUsize>,
+        terminator: &prover::Terminator,
+        zk_rng: &mut R,
+    ) -> Result<Proof<E>, SNARKError> {
+        let proving_keys = keys_to_constraints.keys().copied().collect::<Vec<_>>();
+        let circuits_to_constraints =
+            keys_to_constraints.iter().map(|(pk, constraints)| (pk.circuit.deref(), *constraints)).collect();
+        let init_prover = |rng: &mut R| {
+            AHPForR1CS::<_, MM>::init_prover_with_max_concurrent_instances(
+                &circuits_to_constraints,
+                max_concurrent_instances,
+                terminator,
+                rng,
+            )
+        };
+        let (proof, _) = Self::prove_batch_helper(
             universal_prover,
-            &committer_key,
-            &[lc],
-            proving_key.circuit.iter(),
-            &commitments,
-            &query_set,
-            &proving_key.circuit_commitment_randomness.clone(),
-            &mut sponge,
+            fs_parameters,
+            &proving_keys,
+            init_prover,
+            terminator,
+            zk_rng,
+            false,
         )?;
-
-        Ok(Self::Certificate::new(certificate))
+        Ok(proof)
     }
 
-    /// Verify that the verifying key indeed includes a part of the reference string,
-    /// as well as the indexed circuit (i.e. the circuit as a set of linear-sized polynomials).
-    fn verify_vk<C: ConstraintSynthesizer<Self::ScalarField>>(
-        universal_verifier: &Self::UniversalVerifier,
-        fs_parameters: &Self::FSParameters,
-        circuit: &C,
-        verifying_key: &Self::VerifyingKey,
-        certificate: &Self::Certificate,
-    ) -> Result<bool, SNARKError> {
-        let circuit_id = &verifying_key.id;
-        let info = AHPForR1CS::<E::Fr, MM>::index_polynomial_info(std::iter::once(circuit_id));
-        // Initialize sponge.
-        let mut sponge = Self::init_sponge_for_certificate(fs_parameters, &verifying_key.circuit_commitments);
-        // Compute challenges for linear combination, and the point to evaluate the polynomials at.
-        // The linear combination requires `num_polynomials - 1` coefficients
-        // (since the first coeff is 1), and so we squeeze out `num_polynomials` points.
-        let mut challenges = sponge.squeeze_nonnative_field_elements(verifying_key.circuit_commitments.len());
-        let point = challenges.pop().unwrap();
-
-        let evaluations_at_point = AHPForR1CS::<E::Fr, MM>::evaluate_index_polynomials(circuit, circuit_id, point)?;
-        let one = E::Fr::one();
-        let linear_combination_challenges = core::iter::once(&one).chain(challenges.iter());
-
-        // We will construct a linear combination and provide a proof of evaluation of the lc at `point`.
-        let mut lc = crate::polycommit::sonic_pc::LinearCombination::empty("circuit_check");
-        let mut evaluation = E::Fr::zero();
-        for ((label, &c), eval) in info.keys().zip_eq(linear_combination_challenges).zip_eq(evaluations_at_point) {
-            lc.add(c, label.as_str());
-            evaluation += c * eval;
-        }
-
-        let query_set = QuerySet::from_iter([("circuit_check".into(), ("challenge".into(), point))]);
-        let commitments = verifying_key
-            .iter()
-            .cloned()
-            .zip_eq(info.values())
-            .map(|(c, info)| LabeledCommitment::new_with_info(info, c))
-            .collect::<Vec<_>>();
-        let evaluations = Evaluations::from_iter([(("circuit_check".into(), point), evaluation)]);
-
-        SonicKZG10::<E, FS>::check_combinations(
-            universal_verifier,
-            &[lc],
-            &commitments,
-            &query_set,
-            &evaluations,
-            &certificate.pc_proof,
-            &mut sponge,
-        )
-        .map_err(Into::into)
+    /// Creates a batch proof, as in `prove_batch_with_terminator`, from the variables assigned
+    /// by synthesizing each instance beforehand, which avoids synthesizing the circuits again.
+    pub fn prove_batch_from_assignments<R: Rng + CryptoRng>(
+        universal_prover: &UniversalProver<E>,
+        fs_parameters: &FS::Parameters,
+        keys_to_assignments: BTreeMap<&CircuitProvingKey<E, MM>, Vec<prover::VariableAssignment<E::Fr>>>,
+        terminator: &prover::Terminator,
+        zk_rng: &mut R,
+    ) -> Result<Proof<E>, SNARKError> {
+        let proving_keys = keys_to_assignments.keys().copied().collect::<Vec<_>>();
+        let circuits_to_assignments =
+            keys_to_assignments.into_iter().map(|(pk, assignments)| (pk.circuit.deref(), assignments)).collect();
+        let init_prover =
+            |rng: &mut R| AHPForR1CS::<_, MM>::init_prover_from_assignments(circuits_to_assignments, terminator, rng);
+        let (proof, _) = Self::prove_batch_helper(
+            universal_prover,
+            fs_parameters,
+            &proving_keys,
+            init_prover,
+            terminator,
+            zk_rng,
+            false,
+        )?;
+        Ok(proof)
     }
 
-    #[allow(clippy::only_used_in_recursion)]
-    /// This is the main entrypoint for creating proofs.
-    /// You can find a specification of the prover algorithm in:
-    /// https://github.com/AleoHQ/protocol-docs/tree/main/marlin
-    fn prove_batch<C: ConstraintSynthesizer<E::Fr>, R: Rng + CryptoRng>(
-        universal_prover: &Self::UniversalProver,
-        fs_parameters: &Self::FSParameters,
+    /// Creates a batch proof, as in `prove_batch_with_terminator`,
+    /// along with the statistics collected while proving it.
+    pub fn prove_batch_with_statistics<C: ConstraintSynthesizer<E::Fr>, R: Rng + CryptoRng>(
+        universal_prover: &UniversalProver<E>,
+        fs_parameters: &FS::Parameters,
         keys_to_constraints: &BTreeMap<&CircuitProvingKey<E, MM>, &[C]>,
+        terminator: &prover::Terminator,
         zk_rng: &mut R,
-    ) -> Result<Self::Proof, SNARKError> {
+    ) -> Result<(Proof<E>, prover::ProverStatistics), SNARKError> {
+        let proving_keys = keys_to_constraints.keys().copied().collect::<Vec<_>>();
+        let circuits_to_constraints =
+            keys_to_constraints.iter().map(|(pk, constraints)| (pk.circuit.deref(), *constraints)).collect();
+        let init_prover = |rng: &mut R| AHPForR1CS::<_, MM>::init_prover(&circuits_to_constraints, terminator, rng);
+        let (proof, statistics) = Self::prove_batch_helper(
+            universal_prover,
+            fs_parameters,
+            &proving_keys,
+            init_prover,
+            terminator,
+            zk_rng,
+            true,
+        )?;
+        // Note: The 'unwrap' is safe here, as the statistics are always returned when collected.
+        Ok((proof, statistics.unwrap()))
+    }
+
+    /// Creates a batch proof for the given proving keys, whose prover is initialized by `init_prover`,
+    /// along with the statistics collected while proving it if `collect_stats` is set.
+    /// When `collect_stats` is not set, no statistics are recorded.
+    fn prove_batch_helper<'a, R: Rng + CryptoRng>(
+        universal_prover: &UniversalProver<E>,
+        fs_parameters: &FS::Parameters,
+        proving_keys: &[&'a CircuitProvingKey<E, MM>],
+        init_prover: impl FnOnce(&mut R) -> Result<prover::State<'a, E::Fr, MM>, AHPError>,
+        terminator: &prover::Terminator,
+        zk_rng: &mut R,
+        collect_stats: bool,
+    ) -> Result<(Proof<E>, Option<prover::ProverStatistics>), SNARKError> {
         let prover_time = start_timer!(|| "Varuna::Prover");
-        if keys_to_constraints.is_empty() {
+        let start_time = collect_stats.then(Instant::now);
+        let mut rounds = Vec::new();
+        if proving_keys.is_empty() {
             return Err(SNARKError::EmptyBatch);
         }
 
-        let mut circuits_to_constraints = BTreeMap::new();
-        for (pk, constraints) in keys_to_constraints {
-            circuits_to_constraints.insert(pk.circuit.deref(), *constraints);
-        }
-        let prover_state = AHPForR1CS::<_, MM>::init_prover(&circuits_to_constraints, zk_rng)?;
+        let prover_state = init_prover(zk_rng)?;
 
         // extract information from the prover key and state to consume in further calculations
         let mut batch_sizes = BTreeMap::new();
@@ -352,9 +350,9 @@ where
         let mut inputs_and_batch_sizes = BTreeMap::new();
         let mut total_instances = 0;
         let mut public_inputs = BTreeMap::new(); // inputs need to live longer than the rest of prover_state
-        let num_unique_circuits = keys_to_constraints.len();
+        let num_unique_circuits = proving_keys.len();
         let mut circuit_ids = Vec::with_capacity(num_unique_circuits);
-        for pk in keys_to_constraints.keys() {
+        for pk in proving_keys {
             let batch_size = prover_state.batch_size(&pk.circuit).ok_or(SNARKError::CircuitNotFound)?;
             let public_input = prover_state.public_inputs(&pk.circuit).ok_or(SNARKError::CircuitNotFound)?;
             let padded_public_input =
@@ -369,18 +367,19 @@ where
         }
         assert_eq!(prover_state.total_instances, total_instances);
 
-        let committer_key = CommitterUnionKey::union(keys_to_constraints.keys().map(|pk| pk.committer_key.deref()));
+        let committer_key = CommitterUnionKey::union(proving_keys.iter().map(|pk| pk.committer_key.deref()));
 
-        let circuit_commitments =
-            keys_to_constraints.keys().map(|pk| pk.circuit_verifying_key.circuit_commitments.as_slice());
+        let circuit_commitments = proving_keys.iter().map(|pk| pk.circuit_verifying_key.circuit_commitments.as_slice());
 
         let mut sponge = Self::init_sponge(fs_parameters, &inputs_and_batch_sizes, circuit_commitments.clone());
 
         // --------------------------------------------------------------------
         // First round
 
+        let round_recorder = collect_stats.then(|| prover::RoundRecorder::start(1));
         let mut prover_state = AHPForR1CS::<_, MM>::prover_first_round(prover_state, zk_rng)?;
 
+        terminator.check()?;
         let first_round_comm_time = start_timer!(|| "Committing to first round polys");
         let (first_commitments, first_commitment_randomnesses) = {
             let first_round_oracles = Arc::get_mut(prover_state.first_round_oracles.as_mut().unwrap()).unwrap();
@@ -392,6 +391,7 @@ where
             )?
         };
         end_timer!(first_round_comm_time);
+        rounds.extend(round_recorder.map(|recorder| recorder.finish(first_commitments.len())));
 
         Self::absorb_labeled(&first_commitments, &mut sponge);
 
@@ -410,9 +410,11 @@ where
         // --------------------------------------------------------------------
         // Second round
 
+        let round_recorder = collect_stats.then(|| prover::RoundRecorder::start(2));
         let (second_oracles, prover_state) =
             AHPForR1CS::<_, MM>::prover_second_round(&verifier_first_message, prover_state, zk_rng)?;
 
+        terminator.check()?;
         let second_round_comm_time = start_timer!(|| "Committing to second round polys");
         let (second_commitments, second_commitment_randomnesses) = SonicKZG10::<E, FS>::commit(
             universal_prover,
@@ -421,6 +423,7 @@ where
             MM::ZK.then_some(zk_rng),
         )?;
         end_timer!(second_round_comm_time);
+        rounds.extend(round_recorder.map(|recorder| recorder.finish(second_commitments.len())));
 
         Self::absorb_labeled(&second_commitments, &mut sponge);
 
@@ -431,6 +434,7 @@ where
         // --------------------------------------------------------------------
         // Third round
 
+        let round_recorder = collect_stats.then(|| prover::RoundRecorder::start(3));
         let (prover_third_message, third_oracles, prover_state) = AHPForR1CS::<_, MM>::prover_third_round(
             &verifier_first_message,
             &verifier_second_msg,
@@ -438,6 +442,7 @@ where
             zk_rng,
         )?;
 
+        terminator.check()?;
         let third_round_comm_time = start_timer!(|| "Committing to third round polys");
         let (third_commitments, third_commitment_randomnesses) = SonicKZG10::<E, FS>::commit(
             universal_prover,
@@ -446,6 +451,7 @@ where
             MM::ZK.then_some(zk_rng),
         )?;
         end_timer!(third_round_comm_time);
+        rounds.extend(round_recorder.map(|recorder| recorder.finish(third_commitments.len())));
 
         Self::absorb_labeled_with_sums(
             &third_commitments,
@@ -460,9 +466,11 @@ where
         // --------------------------------------------------------------------
         // Fourth round
 
+        let round_recorder = collect_stats.then(|| prover::RoundRecorder::start(4));
         let (prover_fourth_message, fourth_oracles, prover_state) =
             AHPForR1CS::<_, MM>::prover_fourth_round(&verifier_second_msg, &verifier_third_msg, prover_state, zk_rng)?;
 
+        terminator.check()?;
         let fourth_round_comm_time = start_timer!(|| "Committing to fourth round polys");
         let (fourth_commitments, fourth_commitment_randomnesses) = SonicKZG10::<E, FS>::commit(
             universal_prover,
@@ -471,6 +479,7 @@ where
             MM::ZK.then_some(zk_rng),
         )?;
         end_timer!(fourth_round_comm_time);
+        rounds.extend(round_recorder.map(|recorder| recorder.finish(fourth_commitments.len())));
 
         Self::absorb_labeled_with_sums(&fourth_commitments, &prover_fourth_message.sums, &mut sponge);
 
@@ -481,8 +490,10 @@ where
         // --------------------------------------------------------------------
         // Fifth round
 
+        let round_recorder = collect_stats.then(|| prover::RoundRecorder::start(5));
         let fifth_oracles = AHPForR1CS::<_, MM>::prover_fifth_round(verifier_fourth_msg, prover_state, zk_rng)?;
 
+        terminator.check()?;
         let fifth_round_comm_time = start_timer!(|| "Committing to fifth round polys");
         let (fifth_commitments, fifth_commitment_randomnesses) = SonicKZG10::<E, FS>::commit(
             universal_prover,
@@ -491,6 +502,7 @@ where
             MM::ZK.then_some(zk_rng),
         )?;
         end_timer!(fifth_round_comm_time);
+        rounds.extend(round_recorder.map(|recorder| recorder.finish(fifth_commitments.len())));
 
         Self::absorb_labeled(&fifth_commitments, &mut sponge);
 
@@ -498,8 +510,8 @@ where
         // --------------------------------------------------------------------
 
         // Gather prover polynomials in one vector.
-        let polynomials: Vec<_> = keys_to_constraints
-            .keys()
+        let polynomials: Vec<_> = proving_keys
+            .iter()
             .flat_map(|pk| pk.circuit.iter())
             .chain(first_round_oracles.iter())
             .chain(second_oracles.iter())
@@ -556,8 +568,8 @@ where
             .collect();
 
         // Gather commitment randomness together.
-        let commitment_randomnesses: Vec<Randomness<E>> = keys_to_constraints
-            .keys()
+        let commitment_randomnesses: Vec<Randomness<E>> = proving_keys
+            .iter()
             .flat_map(|pk| pk.circuit_commitment_randomness.clone())
             .chain(first_commitment_randomnesses)
             .chain(second_commitment_randomnesses)
@@ -581,6 +593,7 @@ where
             &verifier_state,
         )?;
 
+        terminator.check()?;
         let eval_time = start_timer!(|| "Evaluating linear combinations over query set");
         let mut evaluations = std::collections::BTreeMap::new();
         for (label, (_, point)) in query_set.to_set() {
@@ -596,6 +609,7 @@ where
 
         sponge.absorb_nonnative_field_elements(evaluations.to_field_elements());
 
+        terminator.check()?;
         let pc_proof = SonicKZG10::<E, FS>::open_combinations(
             universal_prover,
             &committer_key,
@@ -607,6 +621,7 @@ where
             &mut sponge,
         )?;
 
+        let statistics_batch_sizes = collect_stats.then(|| batch_sizes.clone());
         let proof = Proof::<E>::new(
             batch_sizes,
             commitments,
@@ -617,8 +632,185 @@ where
         )?;
         assert_eq!(proof.pc_proof.is_hiding(), MM::ZK);
 
+        let statistics = start_time.zip(statistics_batch_sizes).map(|(start_time, batch_sizes)| {
+            // Estimate the memory used by the assignments of each instance, and their products with the matrices.
+            let witness_memory_estimate = circuit_infos
+                .iter()
+                .map(|(circuit_id, info)| batch_sizes[circuit_id] * (info.num_variables + 3 * info.num_constraints))
+                .sum::<usize>()
+                * core::mem::size_of::<E::Fr>();
+            prover::ProverStatistics {
+                rounds,
+                circuit_infos: circuit_infos.into_iter().map(|(circuit_id, info)| (circuit_id, *info)).collect(),
+                batch_sizes,
+                witness_memory_estimate,
+                total_time: start_time.elapsed(),
+            }
+        });
+
         end_timer!(prover_time);
-        Ok(proof)
+        Ok((proof, statistics))
+    }
+}
+
+impl<E: PairingEngine, FS, MM> SNARK for VarunaSNARK<E, FS, MM>
+where
+    E::Fr: PrimeField,
+    E::Fq: PrimeField,
+    FS: AlgebraicSponge<E::Fq, 2>,
+    MM: SNARKMode,
+{
+    type BaseField = E::Fq;
+    type Certificate = Certificate<E>;
+    type FSParameters = FS::Parameters;
+    type FiatShamirRng = FS;
+    type Proof = Proof<E>;
+    type ProvingKey = CircuitProvingKey<E, MM>;
+    type ScalarField = E::Fr;
+    type UniversalProver = UniversalProver<E>;
+    type UniversalSRS = UniversalSRS<E>;
+    type UniversalVerifier = UniversalVerifier<E>;
+    type VerifierInput = [E::Fr];
+    type VerifyingKey = CircuitVerifyingKey<E>;
+
+    /// Loads the universal SRS from the powers produced by the Aleo setup ceremony.
+    /// Note that the SRS is over BLS12-377 and additionally contains the powers of `beta * gamma * G`
+    /// used for hiding, so transcripts from BLS12-381 Powers-of-Tau ceremonies cannot be used here.
+    fn universal_setup(max_degree: usize) -> Result<Self::UniversalSRS, SNARKError> {
+        let setup_time = start_timer!(|| { format!("Varuna::UniversalSetup with max_degree {max_degree}",) });
+        let srs = SonicKZG10::<E, FS>::load_srs(max_degree).map_err(Into::into);
+        end_timer!(setup_time);
+        srs
+    }
+
+    /// Generates the circuit proving and verifying keys.
+    /// This is a deterministic algorithm that anyone can rerun.
+    fn circuit_setup<C: ConstraintSynthesizer<E::Fr>>(
+        universal_srs: &Self::UniversalSRS,
+        circuit: &C,
+    ) -> Result<(Self::ProvingKey, Self::VerifyingKey)> {
+        let mut circuit_keys = Self::batch_circuit_setup::<C>(universal_srs, &[circuit])?;
+        assert_eq!(circuit_keys.len(), 1);
+        Ok(circuit_keys.pop().unwrap())
+    }
+
+    /// Prove that the verifying key indeed includes a part of the reference string,
+    /// as well as the indexed circuit (i.e. the circuit as a set of linear-sized polynomials).
+    fn prove_vk(
+        universal_prover: &Self::UniversalProver,
+        fs_parameters: &Self::FSParameters,
+        verifying_key: &Self::VerifyingKey,
+        proving_key: &Self::ProvingKey,
+    ) -> Result<Self::Certificate, SNARKError> {
+        // Initialize sponge
+        let mut sponge = Self::init_sponge_for_certificate(fs_parameters, &verifying_key.circuit_commitments);
+        // Compute challenges for linear combination, and the point to evaluate the polynomials at.
+        // The linear combination requires `num_polynomials - 1` coefficients
+        // (since the first coeff is 1), and so we squeeze out `num_polynomials` points.
+        let mut challenges = sponge.squeeze_nonnative_field_elements(verifying_key.circuit_commitments.len());
+        let point = challenges.pop().unwrap();
+        let one = E::Fr::one();
+        let linear_combination_challenges = core::iter::once(&one).chain(challenges.iter());
+
+        // We will construct a linear combination and provide a proof of evaluation of the lc at `point`.
+        let mut lc = crate::polycommit::sonic_pc::LinearCombination::empty("circuit_check");
+        for (poly, &c) in proving_key.circuit.iter().zip(linear_combination_challenges) {
+            lc.add(c, poly.label());
+        }
+
+        let circuit_id = std::iter::once(&verifying_key.id);
+        let query_set = QuerySet::from_iter([("circuit_check".into(), ("challenge".into(), point))]);
+        let commitments = verifying_key
+            .iter()
+            .cloned()
+            .zip_eq(AHPForR1CS::<E::Fr, MM>::index_polynomial_info(circuit_id).values())
+            .map(|(c, info)| LabeledCommitment::new_with_info(info, c))
+            .collect::<Vec<_>>();
+
+        let committer_key = CommitterUnionKey::union(std::iter::once(proving_key.committer_key.as_ref()));
+
+        let certificate = SonicKZG10::<E, FS>::open_combinations(
+            universal_prover,
+            &committer_key,
+            &[lc],
+            proving_key.circuit.iter(),
+            &commitments,
+            &query_set,
+            &proving_key.circuit_commitment_randomness.clone(),
+            &mut sponge,
+        )?;
+
+        Ok(Self::Certificate::new(certificate))
+    }
+
+    /// Verify that the verifying key indeed includes a part of the reference string,
+    /// as well as the indexed circuit (i.e. the circuit as a set of linear-sized polynomials).
+    fn verify_vk<C: ConstraintSynthesizer<Self::ScalarField>>(
+        universal_verifier: &Self::UniversalVerifier,
+        fs_parameters: &Self::FSParameters,
+        circuit: &C,
+        verifying_key: &Self::VerifyingKey,
+        certificate: &Self::Certificate,
+    ) -> Result<bool, SNARKError> {
+        let circuit_id = &verifying_key.id;
+        let info = AHPForR1CS::<E::Fr, MM>::index_polynomial_info(std::iter::once(circuit_id));
+        // Initialize sponge.
+        let mut sponge = Self::init_sponge_for_certificate(fs_parameters, &verifying_key.circuit_commitments);
+        // Compute challenges for linear combination, and the point to evaluate the polynomials at.
+        // The linear combination requires `num_polynomials - 1` coefficients
+        // (since the first coeff is 1), and so we squeeze out `num_polynomials` points.
+        let mut challenges = sponge.squeeze_nonnative_field_elements(verifying_key.circuit_commitments.len());
+        let point = challenges.pop().unwrap();
+
+        let evaluations_at_point = AHPForR1CS::<E::Fr, MM>::evaluate_index_polynomials(circuit, circuit_id, point)?;
+        let one = E::Fr::one();
+        let linear_combination_challenges = core::iter::once(&one).chain(challenges.iter());
+
+        // We will construct a linear combination and provide a proof of evaluation of the lc at `point`.
+        let mut lc = crate::polycommit::sonic_pc::LinearCombination::empty("circuit_check");
+        let mut evaluation = E::Fr::zero();
+        for ((label, &c), eval) in info.keys().zip_eq(linear_combination_challenges).zip_eq(evaluations_at_point) {
+            lc.add(c, label.as_str());
+            evaluation += c * eval;
+        }
+
+        let query_set = QuerySet::from_iter([("circuit_check".into(), ("challenge".into(), point))]);
+        let commitments = verifying_key
+            .iter()
+            .cloned()
+            .zip_eq(info.values())
+            .map(|(c, info)| LabeledCommitment::new_with_info(info, c))
+            .collect::<Vec<_>>();
+        let evaluations = Evaluations::from_iter([(("circuit_check".into(), point), evaluation)]);
+
+        SonicKZG10::<E, FS>::check_combinations(
+            universal_verifier,
+            &[lc],
+            &commitments,
+            &query_set,
+            &evaluations,
+            &certificate.pc_proof,
+            &mut sponge,
+        )
+        .map_err(Into::into)
+    }
+
+    /// This is the main entrypoint for creating proofs.
+    /// You can find a specification of the prover algorithm in:
+    /// https://github.com/AleoHQ/protocol-docs/tree/main/marlin
+    fn prove_batch<C: ConstraintSynthesizer<E::Fr>, R: Rng + CryptoRng>(
+        universal_prover: &Self::UniversalProver,
+        fs_parameters: &Self::FSParameters,
+        keys_to_constraints: &BTreeMap<&CircuitProvingKey<E, MM>, &[C]>,
+        zk_rng: &mut R,
+    ) -> Result<Self::Proof, SNARKError> {
+        Self::prove_batch_with_terminator(
+            universal_prover,
+            fs_parameters,
+            keys_to_constraints,
+            &prover::Terminator::default(),
+            zk_rng,
+        )
     }
 
     /// This is the main entrypoint for verifying proofs.
@@ -0,0 +1,84 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use snarkvm_algorithms::{
+    r1cs::{ConstraintCounter, ConstraintSynthesizer},
+    snark::varuna::{
+        ahp::{add_randomizing_variables, pad_input_for_indexer_and_prover},
+        TestCircuit,
+    },
+};
+use snarkvm_curves::bls12_377::Fr;
+use snarkvm_utilities::{TestRng, Uniform};
+
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    cell::Cell,
+};
+
+thread_local! {
+    /// The number of allocations made by the current thread.
+    static NUM_ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+/// An allocator which counts the allocations made by each thread.
+struct CountingAllocator;
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let _ = NUM_ALLOCATIONS.try_with(|n| n.set(n.get() + 1));
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let _ = NUM_ALLOCATIONS.try_with(|n| n.set(n.get() + 1));
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+// Note: The allocator is installed for this test binary only, so that it does not affect the other tests.
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+fn num_allocations() -> usize {
+    NUM_ALLOCATIONS.with(Cell::get)
+}
+
+#[test]
+fn test_padding_does_not_allocate() {
+    let rng = &mut TestRng::default();
+
+    // Use 4 public inputs, so that the public variables are padded from 5 to 8.
+    let (circuit, _) = TestCircuit::<Fr>::gen_rand(4, 20, 30, rng);
+    let rand_assignments = Some([Fr::rand(rng), Fr::rand(rng), Fr::rand(rng)]);
+
+    // Synthesize the circuit with a constraint system which does not allocate,
+    // counting the constant `one` as a public variable, as the indexer and prover do.
+    let mut cs = ConstraintCounter { num_public_variables: 1, ..Default::default() };
+    circuit.generate_constraints(&mut cs).unwrap();
+    let num_private_variables = cs.num_private_variables;
+
+    // Ensure randomizing and padding do not allocate.
+    let num_allocations_before = num_allocations();
+    add_randomizing_variables(&mut cs, rand_assignments);
+    pad_input_for_indexer_and_prover(&mut cs);
+    assert_eq!(num_allocations(), num_allocations_before);
+
+    assert_eq!(cs.num_public_variables, 8);
+    assert_eq!(cs.num_private_variables, num_private_variables + 3);
+}
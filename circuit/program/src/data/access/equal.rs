@@ -0,0 +1,64 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<A: Aleo> Equal<Self> for Access<A> {
+    type Output = Boolean<A>;
+
+    /// Returns `true` if `self` and `other` are equal.
+    fn is_equal(&self, other: &Self) -> Self::Output {
+        match (self, other) {
+            (Self::Member(a), Self::Member(b)) => a.is_equal(b),
+        }
+    }
+
+    /// Returns `true` if `self` and `other` are *not* equal.
+    fn is_not_equal(&self, other: &Self) -> Self::Output {
+        match (self, other) {
+            (Self::Member(a), Self::Member(b)) => a.is_not_equal(b),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Circuit;
+    use snarkvm_circuit_types::environment::assert_scope;
+
+    fn check_is_equal(first: &str, second: &str, expected: bool) {
+        let first = Access::<Circuit>::from_str(first).unwrap();
+        let second = Access::<Circuit>::from_str(second).unwrap();
+
+        Circuit::scope(format!("{first} == {second}"), || {
+            let candidate = first.is_equal(&second);
+            assert_eq!(expected, candidate.eject_value());
+            assert_scope!(<=2, 0, 0, 0);
+        });
+        Circuit::scope(format!("{first} != {second}"), || {
+            let candidate = first.is_not_equal(&second);
+            assert_eq!(!expected, candidate.eject_value());
+            assert_scope!(<=2, 0, 0, 0);
+        });
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_is_equal() {
+        check_is_equal(".owner", ".owner", true);
+        check_is_equal(".owner", ".microcredits", false);
+        check_is_equal(".foo_bar", ".foo_baz", false);
+    }
+}
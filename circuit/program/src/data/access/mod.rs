@@ -12,9 +12,11 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod equal;
+
 use crate::Identifier;
 use snarkvm_circuit_network::Aleo;
-use snarkvm_circuit_types::environment::prelude::*;
+use snarkvm_circuit_types::{environment::prelude::*, Boolean};
 
 use std::{
     fmt,
@@ -29,6 +31,24 @@ pub enum Access<A: Aleo> {
     Member(Identifier<A>),
 }
 
+impl<A: Aleo> Access<A> {
+    /// Returns the name of the variant of the access as a string (i.e. "member"),
+    /// which allows identifying the variant without matching on it.
+    pub fn variant_name(&self) -> &'static str {
+        match self {
+            Self::Member(..) => "member",
+        }
+    }
+}
+
+impl<A: Aleo> TypeName for Access<A> {
+    /// Returns the type name of the circuit as a string.
+    #[inline]
+    fn type_name() -> &'static str {
+        "access"
+    }
+}
+
 #[cfg(console)]
 impl<A: Aleo> Inject for Access<A> {
     type Primitive = console::Access<A::Network>;
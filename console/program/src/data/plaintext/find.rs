@@ -64,4 +64,84 @@ impl<N: Network> Plaintext<N> {
             }
         }
     }
+
+    /// Returns a reference to the plaintext member at the given access path.
+    /// Halts if the path is empty, if any segment is missing, or if an intermediate segment is not a struct.
+    pub fn find_by_path(&self, path: &[Access<N>]) -> Result<&Plaintext<N>> {
+        // Ensure the path is not empty.
+        ensure!(!path.is_empty(), "Attempted to find a member with an empty path.");
+
+        // Traverse the path, starting from the top-level plaintext.
+        let mut current = self;
+        for (i, access) in path.iter().enumerate() {
+            let Access::Member(identifier) = access;
+            current = match current {
+                // Halts if the value is not a struct.
+                Self::Literal(..) => match i {
+                    0 => bail!("'{self}' is not a struct"),
+                    _ => bail!("'{}' must be a struct", path[i - 1]),
+                },
+                // Retrieve the member for the next iteration.
+                Self::Struct(members, ..) => match members.get(identifier) {
+                    Some(member) => member,
+                    // Halts if the member does not exist.
+                    None => bail!("Failed to locate member '{identifier}' in '{self}'"),
+                },
+            };
+        }
+        Ok(current)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    use core::str::FromStr;
+
+    type CurrentNetwork = Testnet3;
+
+    /// Returns the access path for the given member names.
+    fn sample_path(members: &[&str]) -> Vec<Access<CurrentNetwork>> {
+        members.iter().map(|member| Access::Member(Identifier::from_str(member).unwrap())).collect()
+    }
+
+    #[test]
+    fn test_find_by_path() -> Result<()> {
+        let plaintext =
+            Plaintext::<CurrentNetwork>::from_str("{ a: { b: { c: 1u8, d: 2u16 }, e: 3u32 }, f: { g: { h: 4u64 } } }")?;
+
+        // Retrieve the leaves of the depth-3 nested structs.
+        assert_eq!(plaintext.find_by_path(&sample_path(&["a", "b", "c"]))?, &Plaintext::from_str("1u8")?);
+        assert_eq!(plaintext.find_by_path(&sample_path(&["a", "b", "d"]))?, &Plaintext::from_str("2u16")?);
+        assert_eq!(plaintext.find_by_path(&sample_path(&["f", "g", "h"]))?, &Plaintext::from_str("4u64")?);
+
+        // Retrieve the intermediate members.
+        assert_eq!(plaintext.find_by_path(&sample_path(&["a", "e"]))?, &Plaintext::from_str("3u32")?);
+        assert_eq!(plaintext.find_by_path(&sample_path(&["f", "g"]))?, &Plaintext::from_str("{ h: 4u64 }")?);
+
+        // Ensure the result matches `find`.
+        for path in [["a", "b", "c"], ["a", "b", "d"], ["f", "g", "h"]] {
+            let path = sample_path(&path);
+            assert_eq!(plaintext.find_by_path(&path)?, &plaintext.find(&path)?);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_find_by_path_fails() -> Result<()> {
+        let plaintext = Plaintext::<CurrentNetwork>::from_str("{ a: { b: { c: 1u8 } } }")?;
+
+        // Ensure an empty path fails.
+        assert!(plaintext.find_by_path(&[]).is_err());
+        // Ensure a missing member fails, at every depth.
+        assert!(plaintext.find_by_path(&sample_path(&["x"])).is_err());
+        assert!(plaintext.find_by_path(&sample_path(&["a", "x"])).is_err());
+        assert!(plaintext.find_by_path(&sample_path(&["a", "b", "x"])).is_err());
+        // Ensure accessing a member of a literal fails.
+        assert!(plaintext.find_by_path(&sample_path(&["a", "b", "c", "d"])).is_err());
+        assert!(Plaintext::<CurrentNetwork>::from_str("1u8")?.find_by_path(&sample_path(&["a"])).is_err());
+        Ok(())
+    }
 }
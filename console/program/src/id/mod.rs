@@ -115,6 +115,12 @@ impl<N: Network> ProgramID<N> {
     pub fn is_aleo(&self) -> bool {
         self.network() == &Identifier::from_str("aleo").expect("Failed to parse Aleo domain")
     }
+
+    /// Parses a program ID from a string, after converting it to lowercase (e.g. `Token.Aleo` to `token.aleo`).
+    /// The program ID is otherwise validated as in `ProgramID::from_str`.
+    pub fn try_from_lowercase(program_id: &str) -> Result<Self> {
+        Self::from_str(&program_id.to_ascii_lowercase())
+    }
 }
 
 impl<N: Network> Ord for ProgramID<N> {
@@ -171,4 +177,22 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_try_from_lowercase() -> Result<()> {
+        let expected = ProgramID::<CurrentNetwork>::from_str("token.aleo")?;
+        for candidate in ["token.aleo", "Token.aleo", "TOKEN.ALEO", "toKen.Aleo"] {
+            assert_eq!(ProgramID::<CurrentNetwork>::try_from_lowercase(candidate)?, expected);
+        }
+
+        // Ensure the program ID is still validated.
+        assert!(ProgramID::<CurrentNetwork>::try_from_lowercase("token").is_err());
+        assert!(ProgramID::<CurrentNetwork>::try_from_lowercase("token.eth").is_err());
+        assert!(ProgramID::<CurrentNetwork>::try_from_lowercase("token.aleo.aleo").is_err());
+        assert!(ProgramID::<CurrentNetwork>::try_from_lowercase("0token.aleo").is_err());
+        assert!(ProgramID::<CurrentNetwork>::try_from_lowercase(" token.aleo").is_err());
+        assert!(ProgramID::<CurrentNetwork>::try_from_lowercase(&format!("{}.aleo", "a".repeat(64))).is_err());
+
+        Ok(())
+    }
 }
@@ -0,0 +1,117 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment> Ord for Address<E> {
+    /// Returns the ordering of `self` and `other` by their *x-coordinates*,
+    /// falling back to their *y-coordinates* so that the ordering is consistent with `Eq`.
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.address
+            .to_x_coordinate()
+            .cmp(&other.address.to_x_coordinate())
+            .then_with(|| self.address.to_y_coordinate().cmp(&other.address.to_y_coordinate()))
+    }
+}
+
+impl<E: Environment> PartialOrd for Address<E> {
+    /// Returns the ordering of `self` and `other` by their *x-coordinates*,
+    /// falling back to their *y-coordinates* so that the ordering is consistent with `Eq`.
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<E: Environment> Address<E> {
+    /// Returns `true` if `self` and `other` are equal, comparing their serialized
+    /// coordinates in constant time.
+    ///
+    /// Use this instead of `==` when one side is derived from secret material.
+    pub fn ct_eq(&self, other: &Self) -> bool {
+        // Serializes the affine coordinates of the given address.
+        let to_bytes = |address: &Self| {
+            let (x, y) = address.address.to_xy_coordinates();
+            let mut bytes = x.to_bytes_le().ok()?;
+            bytes.extend(y.to_bytes_le().ok()?);
+            Some(bytes)
+        };
+
+        match (to_bytes(self), to_bytes(other)) {
+            (Some(a), Some(b)) if a.len() == b.len() => {
+                // Accumulate the differences over every byte, without short-circuiting.
+                let difference = a.iter().zip(&b).fold(0u8, |acc, (a, b)| acc | (a ^ b));
+                core::hint::black_box(difference) == 0
+            }
+            _ => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network_environment::Console;
+
+    type CurrentEnvironment = Console;
+
+    const ITERATIONS: u64 = 100;
+
+    #[test]
+    fn test_ord() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        // Sample and sort random addresses.
+        let mut addresses: Vec<Address<CurrentEnvironment>> =
+            (0..ITERATIONS).map(|_| Address::new(Uniform::rand(&mut rng))).collect();
+        addresses.sort();
+
+        // Check the ordering agrees with `Compare` on the x-coordinates.
+        for pair in addresses.windows(2) {
+            assert!(pair[0] <= pair[1]);
+            assert!(*pair[0].is_less_than_or_equal(&pair[1]));
+        }
+
+        // Check the order is preserved through serialization.
+        let bytes = addresses.iter().map(|address| address.to_bytes_le()).collect::<Result<Vec<_>>>()?;
+        let candidates = bytes.iter().map(|bytes| Address::read_le(&bytes[..])).collect::<Result<Vec<_>, _>>()?;
+        assert_eq!(addresses, candidates);
+        let mut sorted = candidates.clone();
+        sorted.sort();
+        assert_eq!(candidates, sorted);
+        Ok(())
+    }
+
+    #[test]
+    fn test_ct_eq() {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            let a = Address::<CurrentEnvironment>::new(Uniform::rand(&mut rng));
+            let b = Address::<CurrentEnvironment>::new(Uniform::rand(&mut rng));
+
+            // Check `ct_eq` agrees with `==`.
+            assert!(a.ct_eq(&a));
+            assert!(a.ct_eq(&Address::new(*a)));
+            assert_eq!(a.ct_eq(&b), a == b);
+            assert_eq!(b.ct_eq(&a), a == b);
+
+            // Check an address is not equal to its negation, which shares its x-coordinate.
+            let negated = Address::new(-*a);
+            assert_eq!(a.ct_eq(&negated), a == negated);
+            assert_eq!(a.cmp(&negated) == Ordering::Equal, a == negated);
+        }
+    }
+}
@@ -17,6 +17,7 @@
 
 mod bitwise;
 mod bytes;
+mod compare;
 mod from_bits;
 mod from_field;
 mod from_fields;
@@ -34,6 +35,11 @@ pub use snarkvm_console_types_boolean::Boolean;
 pub use snarkvm_console_types_field::Field;
 pub use snarkvm_console_types_group::Group;
 
+/// An account address.
+///
+/// The `==` operator compares the underlying group elements and is *not* constant-time;
+/// use [`Address::ct_eq`] when comparing against an address derived from secret material.
+/// Addresses are ordered by their *x-coordinates*, consistent with [`Compare`].
 #[derive(Copy, Clone, PartialEq, Eq, Hash)]
 pub struct Address<E: Environment> {
     /// The underlying address.
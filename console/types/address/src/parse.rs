@@ -56,6 +56,15 @@ impl<E: Environment> FromStr for Address<E> {
     }
 }
 
+impl<E: Environment> TryFrom<&str> for Address<E> {
+    type Error = Error;
+
+    /// Reads in an account address string.
+    fn try_from(address: &str) -> Result<Self, Self::Error> {
+        Self::from_str(address)
+    }
+}
+
 impl<E: Environment> Debug for Address<E> {
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
         Display::fmt(self, f)
@@ -121,6 +130,30 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_try_from_str() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample a new address.
+            let expected = Address::<CurrentEnvironment>::new(Uniform::rand(&mut rng));
+
+            // Ensure both paths recover the same address.
+            let candidate = expected.to_string();
+            assert_eq!(expected, Address::try_from(candidate.as_str())?);
+            assert_eq!(Address::<CurrentEnvironment>::from_str(&candidate)?, Address::try_from(candidate.as_str())?);
+        }
+
+        // Ensure both paths fail with the same error on invalid strings.
+        let valid = Address::<CurrentEnvironment>::new(Uniform::rand(&mut rng)).to_string();
+        for invalid in ["", "aleo1", &valid[..62], valid.replacen("aleo", "elao", 1).as_str()] {
+            let expected = Address::<CurrentEnvironment>::from_str(invalid).unwrap_err();
+            let candidate = Address::<CurrentEnvironment>::try_from(invalid).unwrap_err();
+            assert_eq!(expected.to_string(), candidate.to_string());
+        }
+        Ok(())
+    }
+
     #[test]
     fn test_display() -> Result<()> {
         let mut rng = TestRng::default();
@@ -159,6 +159,8 @@ mod tests {
             let expected_bytes = expected.to_bytes_le()?;
             assert_eq!(expected, Transaction::read_le(&expected_bytes[..])?);
             assert!(Transaction::<CurrentNetwork>::read_le(&expected_bytes[1..]).is_err());
+            // Check the size in bytes.
+            assert_eq!(expected.size_in_bytes()?, u64::try_from(expected_bytes.len())?);
         }
         Ok(())
     }
@@ -0,0 +1,229 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use serde_json::{json, Value as JsonValue};
+
+// The canonical JSON of a transaction is compact, and emits the fields of each object in the order listed below.
+// IDs, field elements, group elements, and state roots are emitted as their string forms, and an absent optional
+// field is emitted as `null` rather than omitted. The canonical JSON is also accepted by the `Deserialize` impls.
+//
+//   transaction: { type, id, owner, deployment, fee }  (deploy)
+//                { type, id, execution, fee }          (execute, with a `null` fee if there is none)
+//                { type, id, fee }                     (fee)
+//   execution:   { transitions, global_state_root, proof }
+//   fee:         { transition, global_state_root, proof }
+//   transition:  { id, program, function, inputs, outputs, finalize, tpk, tcm }
+
+impl<N: Network> Transaction<N> {
+    /// Returns the transaction as canonical JSON, which is stable across serializers.
+    pub fn to_canonical_json(&self) -> Result<String> {
+        Ok(serde_json::to_string(&self.to_canonical_value()?)?)
+    }
+
+    /// Parses a transaction from canonical JSON, ensuring the transaction ID and transition IDs match the contents.
+    pub fn from_canonical_json(json: &str) -> Result<Self> {
+        Ok(serde_json::from_str(json)?)
+    }
+
+    /// Returns the transaction as a canonical JSON value.
+    fn to_canonical_value(&self) -> Result<JsonValue> {
+        Ok(match self {
+            Self::Deploy(id, owner, deployment, fee) => json!({
+                "type": "deploy",
+                "id": id.to_string(),
+                "owner": serde_json::to_value(owner)?,
+                "deployment": serde_json::to_value(deployment)?,
+                "fee": fee.to_canonical_value()?,
+            }),
+            Self::Execute(id, execution, fee) => {
+                let fee = match fee {
+                    Some(fee) => fee.to_canonical_value()?,
+                    None => JsonValue::Null,
+                };
+                json!({
+                    "type": "execute",
+                    "id": id.to_string(),
+                    "execution": execution.to_canonical_value()?,
+                    "fee": fee,
+                })
+            }
+            Self::Fee(id, fee) => json!({
+                "type": "fee",
+                "id": id.to_string(),
+                "fee": fee.to_canonical_value()?,
+            }),
+        })
+    }
+}
+
+impl<N: Network> Execution<N> {
+    /// Returns the execution as canonical JSON, which is stable across serializers.
+    pub fn to_canonical_json(&self) -> Result<String> {
+        Ok(serde_json::to_string(&self.to_canonical_value()?)?)
+    }
+
+    /// Parses an execution from canonical JSON, ensuring the transition IDs match the contents.
+    pub fn from_canonical_json(json: &str) -> Result<Self> {
+        Ok(serde_json::from_str(json)?)
+    }
+
+    /// Returns the execution as a canonical JSON value.
+    fn to_canonical_value(&self) -> Result<JsonValue> {
+        Ok(json!({
+            "transitions": self.transitions().map(Transition::to_canonical_value).collect::<Result<Vec<_>>>()?,
+            "global_state_root": self.global_state_root().to_string(),
+            "proof": self.proof().map(|proof| proof.to_string()),
+        }))
+    }
+}
+
+impl<N: Network> Fee<N> {
+    /// Returns the fee as a canonical JSON value.
+    fn to_canonical_value(&self) -> Result<JsonValue> {
+        Ok(json!({
+            "transition": self.transition().to_canonical_value()?,
+            "global_state_root": self.global_state_root().to_string(),
+            "proof": self.proof().map(|proof| proof.to_string()),
+        }))
+    }
+}
+
+impl<N: Network> Transition<N> {
+    /// Returns the transition as canonical JSON, which is stable across serializers.
+    pub fn to_canonical_json(&self) -> Result<String> {
+        Ok(serde_json::to_string(&self.to_canonical_value()?)?)
+    }
+
+    /// Parses a transition from canonical JSON, ensuring the transition ID matches the contents.
+    pub fn from_canonical_json(json: &str) -> Result<Self> {
+        Ok(serde_json::from_str(json)?)
+    }
+
+    /// Returns the transition as a canonical JSON value.
+    fn to_canonical_value(&self) -> Result<JsonValue> {
+        let finalize = match self.finalize() {
+            Some(finalize) => serde_json::to_value(finalize)?,
+            None => JsonValue::Null,
+        };
+        Ok(json!({
+            "id": self.id().to_string(),
+            "program": self.program_id().to_string(),
+            "function": self.function_name().to_string(),
+            "inputs": serde_json::to_value(self.inputs())?,
+            "outputs": serde_json::to_value(self.outputs())?,
+            "finalize": finalize,
+            "tpk": self.tpk().to_string(),
+            "tcm": self.tcm().to_string(),
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    /// Returns the given JSON value, with each string replaced by `"string"`, to pin the format without the values.
+    fn to_shape(value: &JsonValue) -> JsonValue {
+        match value {
+            JsonValue::String(_) => json!("string"),
+            JsonValue::Array(values) => JsonValue::Array(values.iter().map(to_shape).collect()),
+            JsonValue::Object(map) => JsonValue::Object(map.iter().map(|(k, v)| (k.clone(), to_shape(v))).collect()),
+            value => value.clone(),
+        }
+    }
+
+    #[test]
+    fn test_canonical_json_round_trip() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        for expected in [
+            crate::transaction::test_helpers::sample_deployment_transaction(true, rng),
+            crate::transaction::test_helpers::sample_execution_transaction_with_fee(false, rng),
+            Transaction::from_execution(crate::transaction::execution::test_helpers::sample_execution(rng), None)?,
+            crate::transaction::test_helpers::sample_fee_public_transaction(rng),
+        ] {
+            // Ensure the transaction round trips, and is reserialized identically.
+            let json = expected.to_canonical_json()?;
+            let candidate = Transaction::from_canonical_json(&json)?;
+            assert_eq!(expected, candidate);
+            assert_eq!(json, candidate.to_canonical_json()?);
+            // Ensure the canonical JSON is also accepted by the `Deserialize` impl.
+            assert_eq!(expected, serde_json::from_str(&json)?);
+
+            // Ensure the transitions and the execution round trip.
+            for transition in expected.transitions() {
+                assert_eq!(transition, &Transition::from_canonical_json(&transition.to_canonical_json()?)?);
+            }
+            if let Some(execution) = expected.execution() {
+                assert_eq!(execution, &Execution::from_canonical_json(&execution.to_canonical_json()?)?);
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_canonical_json_rejects_mismatched_ids() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        let transaction = crate::transaction::test_helpers::sample_execution_transaction_with_fee(true, rng);
+        let other = crate::transaction::test_helpers::sample_fee_public_transaction(rng);
+        let json = serde_json::from_str::<JsonValue>(&transaction.to_canonical_json()?)?;
+
+        // Ensure a mismatched transaction ID is rejected.
+        let mut candidate = json.clone();
+        candidate["id"] = json!(other.id().to_string());
+        assert!(Transaction::<CurrentNetwork>::from_canonical_json(&candidate.to_string()).is_err());
+
+        // Ensure a mismatched transition ID is rejected.
+        let mut candidate = json;
+        candidate["execution"]["transitions"][0]["id"] = json!(other.transition_ids().next().unwrap().to_string());
+        assert!(Transaction::<CurrentNetwork>::from_canonical_json(&candidate.to_string()).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_canonical_json_fixture() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Sample a fee transaction, whose format is pinned below.
+        let transaction = crate::transaction::test_helpers::sample_fee_public_transaction(rng);
+        let json = transaction.to_canonical_json()?;
+        let value = serde_json::from_str::<JsonValue>(&json)?;
+
+        // Ensure the values are rendered as their string forms.
+        let transition = transaction.transitions().next().unwrap();
+        assert_eq!(value["type"], "fee");
+        assert_eq!(value["id"], transaction.id().to_string());
+        assert_eq!(value["fee"]["transition"]["id"], transition.id().to_string());
+        assert_eq!(value["fee"]["transition"]["program"], "credits.aleo");
+        assert_eq!(value["fee"]["transition"]["function"], "fee_public");
+        assert_eq!(value["fee"]["transition"]["tpk"], transition.tpk().to_string());
+        assert_eq!(value["fee"]["transition"]["tcm"], transition.tcm().to_string());
+
+        // Ensure the field order and nesting do not drift.
+        let expected = concat!(
+            r#"{"type":"string","id":"string","fee":{"transition":{"id":"string","program":"string","#,
+            r#""function":"string","inputs":[{"type":"string","id":"string","value":"string"},"#,
+            r#"{"type":"string","id":"string","value":"string"}],"outputs":[],"finalize":["string","string"],"#,
+            r#""tpk":"string","tcm":"string"},"global_state_root":"string","proof":"string"}}"#
+        );
+        assert_eq!(to_shape(&value).to_string(), expected);
+        Ok(())
+    }
+}
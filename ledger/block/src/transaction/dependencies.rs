@@ -0,0 +1,102 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use console::program::ProgramID;
+use indexmap::IndexSet;
+
+/// The programs and records a transaction depends on, and the programs and records it provides
+/// to the transactions after it in the same block.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TransactionDependencies<N: Network> {
+    /// The transaction ID.
+    transaction_id: N::TransactionID,
+    /// The program IDs which must be deployed before the transaction.
+    program_ids_required: IndexSet<ProgramID<N>>,
+    /// The commitments of the records consumed by the transaction.
+    commitments_consumed: IndexSet<Field<N>>,
+    /// The commitments of the records produced by the transaction.
+    commitments_produced: IndexSet<Field<N>>,
+    /// The program IDs deployed by the transaction.
+    programs_deployed: IndexSet<ProgramID<N>>,
+}
+
+impl<N: Network> TransactionDependencies<N> {
+    /// Initializes the dependencies of the given transaction.
+    ///
+    /// A transaction reveals the serial numbers of the records it consumes, not their commitments,
+    /// so the consumed commitments are left empty. If the consumed records are known,
+    /// they may be added with `TransactionDependencies::with_consumed_commitments`.
+    pub fn new(transaction: &Transaction<N>) -> Self {
+        // Retrieve the program deployed by the transaction, if any.
+        let program = transaction.deployment().map(|deployment| deployment.program());
+        let programs_deployed = program.map(|program| *program.id()).into_iter().collect::<IndexSet<_>>();
+
+        // A deployment requires its imports, and every transition requires its program.
+        let program_ids_required = program
+            .into_iter()
+            .flat_map(|program| program.imports().keys())
+            .chain(transaction.transitions().map(Transition::program_id))
+            .filter(|program_id| !programs_deployed.contains(*program_id))
+            .copied()
+            .collect();
+
+        Self {
+            transaction_id: transaction.id(),
+            program_ids_required,
+            commitments_consumed: IndexSet::new(),
+            commitments_produced: transaction.commitments().copied().collect(),
+            programs_deployed,
+        }
+    }
+
+    /// Returns the dependencies with the given commitments marked as consumed by the transaction.
+    pub fn with_consumed_commitments(mut self, commitments: impl IntoIterator<Item = Field<N>>) -> Self {
+        self.commitments_consumed.extend(commitments);
+        self
+    }
+
+    /// Returns the transaction ID.
+    pub const fn transaction_id(&self) -> &N::TransactionID {
+        &self.transaction_id
+    }
+
+    /// Returns the program IDs which must be deployed before the transaction.
+    pub const fn program_ids_required(&self) -> &IndexSet<ProgramID<N>> {
+        &self.program_ids_required
+    }
+
+    /// Returns the commitments of the records consumed by the transaction.
+    pub const fn commitments_consumed(&self) -> &IndexSet<Field<N>> {
+        &self.commitments_consumed
+    }
+
+    /// Returns the commitments of the records produced by the transaction.
+    pub const fn commitments_produced(&self) -> &IndexSet<Field<N>> {
+        &self.commitments_produced
+    }
+
+    /// Returns the program IDs deployed by the transaction.
+    pub const fn programs_deployed(&self) -> &IndexSet<ProgramID<N>> {
+        &self.programs_deployed
+    }
+}
+
+impl<N: Network> Transaction<N> {
+    /// Returns the programs and records the transaction depends on, and the programs and records it provides.
+    pub fn dependencies(&self) -> TransactionDependencies<N> {
+        TransactionDependencies::new(self)
+    }
+}
@@ -93,4 +93,31 @@ mod tests {
         assert!(Deployment::<CurrentNetwork>::read_le(&expected_bytes[1..]).is_err());
         Ok(())
     }
+
+    #[test]
+    fn test_program_size_in_bytes() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Construct a new deployment.
+        let deployment = test_helpers::sample_deployment(rng);
+
+        // Ensure the counted size matches the byte representation of the program.
+        let expected = u64::try_from(deployment.program().to_bytes_le()?.len())?;
+        assert_eq!(deployment.program_size_in_bytes()?, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn test_estimate_size() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Construct a new deployment.
+        let deployment = test_helpers::sample_deployment(rng);
+
+        // Ensure the estimate is within 5% of the actual size.
+        let expected = deployment.to_bytes_le()?.len();
+        let candidate = deployment.estimate_size();
+        assert!(candidate.abs_diff(expected) * 20 <= expected, "Estimated {candidate} bytes, found {expected} bytes");
+        Ok(())
+    }
 }
@@ -18,6 +18,9 @@ mod bytes;
 mod serialize;
 mod string;
 
+mod size_report;
+pub use size_report::*;
+
 use crate::Transaction;
 use console::{
     network::prelude::*,
@@ -104,6 +107,31 @@ impl<N: Network> Deployment<N> {
         Ok(u64::try_from(self.to_bytes_le()?.len())?)
     }
 
+    /// Returns the size in bytes of the program, without allocating its byte representation.
+    pub fn program_size_in_bytes(&self) -> Result<u64> {
+        let mut counter = super::ByteCounter::default();
+        self.program.write_le(&mut counter)?;
+        Ok(counter.0)
+    }
+
+    /// Returns an estimate of the size in bytes, without serializing the verifying keys and certificates.
+    pub fn estimate_size(&self) -> usize {
+        // Note: The program is small relative to the verifying keys, so it is serialized to determine its size.
+        let program_size = self.program.to_bytes_le().map_or(0, |bytes| bytes.len());
+        // Sum the sizes of the identifier, verifying key, and certificate of each entry.
+        let entries_size = self
+            .verifying_keys
+            .iter()
+            .map(|(function_name, (verifying_key, certificate))| {
+                1 + usize::from(function_name.size_in_bits() / 8)
+                    + verifying_key.size_in_bytes()
+                    + certificate.size_in_bytes()
+            })
+            .sum::<usize>();
+        // Note: The version, edition, and number of entries take 5 bytes.
+        5 + program_size + entries_size
+    }
+
     /// Returns the edition.
     pub const fn edition(&self) -> u16 {
         self.edition
@@ -119,6 +147,22 @@ impl<N: Network> Deployment<N> {
         self.program.id()
     }
 
+    /// Returns the number of functions.
+    pub fn len(&self) -> usize {
+        self.verifying_keys.len()
+    }
+
+    /// Returns `true` if there are no functions.
+    /// Note: This is always `false` for a well-formed deployment.
+    pub fn is_empty(&self) -> bool {
+        self.verifying_keys.is_empty()
+    }
+
+    /// Returns the function names, in the order of the functions in the program.
+    pub fn function_names(&self) -> impl '_ + ExactSizeIterator<Item = &Identifier<N>> {
+        self.verifying_keys.iter().map(|(function_name, _)| function_name)
+    }
+
     /// Returns the verifying keys.
     pub const fn verifying_keys(&self) -> &Vec<(Identifier<N>, (VerifyingKey<N>, Certificate<N>))> {
         &self.verifying_keys
@@ -0,0 +1,148 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// The size in bytes of each component of a deployment.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DeploymentSizeReport<N: Network> {
+    /// The size of the program.
+    program: u64,
+    /// The size of the verifying key of each function, in the order of the functions in the program.
+    verifying_keys: Vec<(Identifier<N>, u64)>,
+    /// The total size of the certificates.
+    certificates: u64,
+    /// The size of the deployment, which also includes the version, edition, and function names.
+    total: u64,
+}
+
+impl<N: Network> DeploymentSizeReport<N> {
+    /// Initializes a size report from the program, and the verifying key and certificate of each function.
+    pub fn new<'a>(
+        program: &Program<N>,
+        verifying_keys: impl IntoIterator<Item = (&'a Identifier<N>, &'a VerifyingKey<N>, &'a Certificate<N>)>,
+    ) -> Result<Self> {
+        // Compute the size of the program.
+        let program_size = u64::try_from(program.to_bytes_le()?.len())?;
+
+        // Note: The version, edition, and number of functions take 5 bytes.
+        let mut total = 5 + program_size;
+        let mut verifying_key_sizes = Vec::new();
+        let mut certificates = 0u64;
+        for (function_name, verifying_key, certificate) in verifying_keys {
+            let function_name_size = u64::try_from(function_name.to_bytes_le()?.len())?;
+            let verifying_key_size = u64::try_from(verifying_key.to_bytes_le()?.len())?;
+            let certificate_size = u64::try_from(certificate.to_bytes_le()?.len())?;
+
+            verifying_key_sizes.push((*function_name, verifying_key_size));
+            certificates += certificate_size;
+            total += function_name_size + verifying_key_size + certificate_size;
+        }
+
+        Ok(Self { program: program_size, verifying_keys: verifying_key_sizes, certificates, total })
+    }
+
+    /// Returns the size of the program.
+    pub const fn program(&self) -> u64 {
+        self.program
+    }
+
+    /// Returns the size of the verifying key of each function.
+    pub fn verifying_keys(&self) -> &[(Identifier<N>, u64)] {
+        &self.verifying_keys
+    }
+
+    /// Returns the total size of the certificates.
+    pub const fn certificates(&self) -> u64 {
+        self.certificates
+    }
+
+    /// Returns the size of the deployment.
+    pub const fn total(&self) -> u64 {
+        self.total
+    }
+}
+
+impl<N: Network> Serialize for DeploymentSizeReport<N> {
+    /// Serializes the size report into a struct.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut report = serializer.serialize_struct("DeploymentSizeReport", 4)?;
+        report.serialize_field("program", &self.program)?;
+        report.serialize_field("verifying_keys", &self.verifying_keys)?;
+        report.serialize_field("certificates", &self.certificates)?;
+        report.serialize_field("total", &self.total)?;
+        report.end()
+    }
+}
+
+impl<'de, N: Network> Deserialize<'de> for DeploymentSizeReport<N> {
+    /// Deserializes the size report from a struct.
+    /// Note: The report is intended for API responses, so only human-readable formats are supported.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        // Parse the size report from a string into a value.
+        let mut report = serde_json::Value::deserialize(deserializer)?;
+        // Recover the size report.
+        Ok(Self {
+            program: DeserializeExt::take_from_value::<D>(&mut report, "program")?,
+            verifying_keys: DeserializeExt::take_from_value::<D>(&mut report, "verifying_keys")?,
+            certificates: DeserializeExt::take_from_value::<D>(&mut report, "certificates")?,
+            total: DeserializeExt::take_from_value::<D>(&mut report, "total")?,
+        })
+    }
+}
+
+impl<N: Network> Deployment<N> {
+    /// Returns the size in bytes of each component of the deployment.
+    pub fn size_breakdown(&self) -> Result<DeploymentSizeReport<N>> {
+        DeploymentSizeReport::new(
+            &self.program,
+            self.verifying_keys
+                .iter()
+                .map(|(function_name, (verifying_key, certificate))| (function_name, verifying_key, certificate)),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_size_breakdown() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Construct a new deployment.
+        let deployment = test_helpers::sample_deployment(rng);
+        let report = deployment.size_breakdown()?;
+
+        // Ensure the total matches the size of the deployment.
+        assert_eq!(report.total(), deployment.size_in_bytes()?);
+        assert_eq!(report.program(), deployment.program().to_bytes_le()?.len() as u64);
+        assert_eq!(report.verifying_keys().len(), deployment.len());
+
+        // Ensure the components sum to the total, along with the version, edition, and function names.
+        let function_names =
+            deployment.function_names().map(|name| name.to_bytes_le().unwrap().len() as u64).sum::<u64>();
+        let verifying_keys = report.verifying_keys().iter().map(|(_, size)| size).sum::<u64>();
+        assert_eq!(report.total(), 5 + report.program() + verifying_keys + report.certificates() + function_names);
+
+        // Ensure the report serializes.
+        let candidate: DeploymentSizeReport<CurrentNetwork> = serde_json::from_str(&serde_json::to_string(&report)?)?;
+        assert_eq!(report, candidate);
+        Ok(())
+    }
+}
@@ -0,0 +1,85 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use crate::Fee;
+
+/// The itemized fee (in microcredits) of an execution.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ExecutionFeeBreakdown<N: Network> {
+    /// The fee paid by each transition in the execution.
+    pub per_transition: Vec<(N::TransitionID, u64)>,
+    /// The fee paid by the fee transition of the transaction, if one is given.
+    pub additional_fee: Option<u64>,
+    /// The total fee.
+    pub total: u64,
+}
+
+impl<N: Network> Execution<N> {
+    /// Returns the itemized fee (in microcredits) of the execution, given the fee of its transaction.
+    /// Note: Only `fee_private` and `fee_public` transitions pay a fee, all other transitions are listed with zero.
+    pub fn fee_breakdown(&self, fee: Option<&Fee<N>>) -> Result<ExecutionFeeBreakdown<N>> {
+        // Retrieve the fee paid by each transition.
+        let per_transition = self
+            .transitions()
+            .map(|transition| match transition.is_fee_private() || transition.is_fee_public() {
+                true => Ok((*transition.id(), *Fee::amount_of(transition)?)),
+                false => Ok((*transition.id(), 0)),
+            })
+            .collect::<Result<Vec<_>>>()?;
+        // Retrieve the additional fee.
+        let additional_fee = fee.map(|fee| fee.amount().map(|amount| *amount)).transpose()?;
+
+        // Compute the total fee.
+        let mut total = additional_fee.unwrap_or_default();
+        for (_, amount) in &per_transition {
+            total = match total.checked_add(*amount) {
+                Some(total) => total,
+                None => bail!("The total fee of the execution overflowed"),
+            };
+        }
+
+        Ok(ExecutionFeeBreakdown { per_transition, additional_fee, total })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{transaction::test_helpers::sample_execution_transaction_with_fee, Transaction};
+
+    #[test]
+    fn test_fee_breakdown() {
+        let rng = &mut TestRng::default();
+
+        for is_fee_private in [true, false] {
+            // Sample an execution transaction with a fee.
+            let transaction = sample_execution_transaction_with_fee(is_fee_private, rng);
+            let (execution, fee) = match &transaction {
+                Transaction::Execute(_, execution, fee) => (execution, fee.as_ref()),
+                _ => unreachable!(),
+            };
+
+            // Ensure the total is the sum of the fees of the transitions and the additional fee.
+            for fee in [fee, None] {
+                let breakdown = execution.fee_breakdown(fee).unwrap();
+                assert!(breakdown.per_transition.iter().map(|(id, _)| id).eq(execution.transitions().map(|t| t.id())));
+                assert_eq!(breakdown.additional_fee, fee.map(|fee| *fee.amount().unwrap()));
+                let expected = breakdown.per_transition.iter().map(|(_, amount)| amount).sum::<u64>()
+                    + breakdown.additional_fee.unwrap_or_default();
+                assert_eq!(breakdown.total, expected);
+            }
+        }
+    }
+}
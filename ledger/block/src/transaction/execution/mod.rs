@@ -13,9 +13,12 @@
 // limitations under the License.
 
 mod bytes;
+mod fee_breakdown;
 mod serialize;
 mod string;
 
+pub use fee_breakdown::ExecutionFeeBreakdown;
+
 use crate::{Transaction, Transition};
 use console::{account::Field, network::prelude::*};
 use synthesizer_snark::Proof;
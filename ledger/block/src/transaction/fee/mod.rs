@@ -85,11 +85,16 @@ impl<N: Network> Fee<N> {
 
     /// Returns the amount (in microcredits).
     pub fn amount(&self) -> Result<U64<N>> {
+        Self::amount_of(&self.transition)
+    }
+
+    /// Returns the amount (in microcredits) of the given fee transition.
+    pub(crate) fn amount_of(transition: &Transition<N>) -> Result<U64<N>> {
         // Determine the input index for the amount.
         // Note: Checking whether 'finalize' is 'None' is a faster way to determine if the fee is public or private.
-        let input_index = if self.transition.finalize().is_none() { 1 } else { 0 };
+        let input_index = if transition.finalize().is_none() { 1 } else { 0 };
         // Retrieve the amount (in microcredits) as a plaintext value.
-        match self.transition.inputs().get(input_index) {
+        match transition.inputs().get(input_index) {
             Some(Input::Public(_, Some(Plaintext::Literal(Literal::U64(microcredits), _)))) => Ok(*microcredits),
             _ => bail!("Failed to retrieve the fee (in microcredits) from the fee transition"),
         }
@@ -0,0 +1,218 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use console::program::ProgramID;
+use indexmap::IndexSet;
+
+/// The reason two unconfirmed transactions conflict with one another.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ConflictReason<N: Network> {
+    /// Both transactions spend the same serial number.
+    SerialNumber(Field<N>),
+    /// Both transactions deploy the same program.
+    ProgramID(ProgramID<N>),
+    /// Both transactions contain the same transition.
+    TransitionID(N::TransitionID),
+}
+
+impl<N: Network> Display for ConflictReason<N> {
+    /// Prints the conflict reason as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Self::SerialNumber(serial_number) => write!(f, "both transactions spend serial number '{serial_number}'"),
+            Self::ProgramID(program_id) => write!(f, "both transactions deploy program '{program_id}'"),
+            Self::TransitionID(transition_id) => write!(f, "both transactions contain transition '{transition_id}'"),
+        }
+    }
+}
+
+/// The serial numbers, deployed program ID, and transition IDs of a transaction.
+/// A footprint can be computed once per transaction and cached, to check for conflicts against many transactions.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TransactionFootprint<N: Network> {
+    /// The transaction ID.
+    transaction_id: N::TransactionID,
+    /// The serial numbers spent by the transaction.
+    serial_numbers: IndexSet<Field<N>>,
+    /// The program ID, if the transaction is a deployment.
+    program_id: Option<ProgramID<N>>,
+    /// The transition IDs in the transaction.
+    transition_ids: IndexSet<N::TransitionID>,
+}
+
+impl<N: Network> TransactionFootprint<N> {
+    /// Initializes the footprint of the given transaction.
+    pub fn new(transaction: &Transaction<N>) -> Self {
+        Self {
+            transaction_id: transaction.id(),
+            serial_numbers: transaction.serial_numbers().copied().collect(),
+            program_id: transaction.deployment().map(|deployment| *deployment.program_id()),
+            transition_ids: transaction.transition_ids().copied().collect(),
+        }
+    }
+
+    /// Returns the transaction ID.
+    pub const fn transaction_id(&self) -> &N::TransactionID {
+        &self.transaction_id
+    }
+
+    /// Returns the serial numbers spent by the transaction.
+    pub const fn serial_numbers(&self) -> &IndexSet<Field<N>> {
+        &self.serial_numbers
+    }
+
+    /// Returns the program ID, if the transaction is a deployment.
+    pub const fn program_id(&self) -> Option<&ProgramID<N>> {
+        self.program_id.as_ref()
+    }
+
+    /// Returns the transition IDs in the transaction.
+    pub const fn transition_ids(&self) -> &IndexSet<N::TransitionID> {
+        &self.transition_ids
+    }
+
+    /// Returns the first conflict between the two transactions, if any.
+    /// Serial numbers are checked first, followed by the deployed program ID, and then the transition IDs.
+    pub fn conflicts_with(&self, other: &Self) -> Option<ConflictReason<N>> {
+        // Returns the first element of `a` which is also in `b`, iterating over the smaller of the two sets.
+        fn first_shared<T: Copy + Eq + core::hash::Hash>(a: &IndexSet<T>, b: &IndexSet<T>) -> Option<T> {
+            let (smaller, larger) = if a.len() <= b.len() { (a, b) } else { (b, a) };
+            smaller.iter().find(|item| larger.contains(*item)).copied()
+        }
+
+        if let Some(serial_number) = first_shared(&self.serial_numbers, &other.serial_numbers) {
+            return Some(ConflictReason::SerialNumber(serial_number));
+        }
+        if let (Some(program_id), Some(other_program_id)) = (&self.program_id, &other.program_id) {
+            if program_id == other_program_id {
+                return Some(ConflictReason::ProgramID(*program_id));
+            }
+        }
+        first_shared(&self.transition_ids, &other.transition_ids).map(ConflictReason::TransitionID)
+    }
+}
+
+impl<N: Network> Transaction<N> {
+    /// Returns the footprint of the transaction, which may be cached to check for conflicts against many transactions.
+    pub fn footprint(&self) -> TransactionFootprint<N> {
+        TransactionFootprint::new(self)
+    }
+
+    /// Returns the first conflict between this transaction and the given transaction, if any.
+    /// To check a transaction against many others, use `Transaction::footprint` and cache the result.
+    pub fn conflicts_with(&self, other: &Transaction<N>) -> Option<ConflictReason<N>> {
+        self.footprint().conflicts_with(&other.footprint())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_double_spend() {
+        let rng = &mut TestRng::default();
+
+        // Sample two fees which spend the same record, for different deployments or executions.
+        let fee_0 = crate::transaction::fee::test_helpers::sample_fee_private(Field::rand(rng), rng);
+        let fee_1 = crate::transaction::fee::test_helpers::sample_fee_private(Field::rand(rng), rng);
+        let transaction_0 = Transaction::from_fee(fee_0).unwrap();
+        let transaction_1 = Transaction::from_fee(fee_1).unwrap();
+        assert_ne!(transaction_0.id(), transaction_1.id());
+
+        // Ensure the transactions conflict on the shared serial number.
+        let serial_number = *transaction_0.serial_numbers().next().unwrap();
+        assert!(transaction_1.contains_serial_number(&serial_number));
+        assert_eq!(transaction_0.conflicts_with(&transaction_1), Some(ConflictReason::SerialNumber(serial_number)));
+        assert_eq!(transaction_1.conflicts_with(&transaction_0), Some(ConflictReason::SerialNumber(serial_number)));
+    }
+
+    #[test]
+    fn test_duplicate_deployment() {
+        let rng = &mut TestRng::default();
+
+        // Sample two deployments of the same program, with different public fees.
+        let transaction_0 = crate::transaction::test_helpers::sample_deployment_transaction(false, rng);
+        let transaction_1 = crate::transaction::test_helpers::sample_deployment_transaction(false, rng);
+        assert_ne!(transaction_0.id(), transaction_1.id());
+
+        // Ensure the transactions conflict on the program ID.
+        let program_id = *transaction_0.deployment().unwrap().program_id();
+        assert_eq!(transaction_0.conflicts_with(&transaction_1), Some(ConflictReason::ProgramID(program_id)));
+
+        // Ensure a transaction conflicts with itself, and with a transaction containing its fee transition.
+        let transition_id = *transaction_0.transition_ids().next().unwrap();
+        let footprint = transaction_0.footprint();
+        assert_eq!(footprint.conflicts_with(&footprint), Some(ConflictReason::ProgramID(program_id)));
+        let fee = Transaction::from_fee(transaction_0.fee_transition().unwrap()).unwrap();
+        assert_eq!(footprint.conflicts_with(&fee.footprint()), Some(ConflictReason::TransitionID(transition_id)));
+    }
+
+    #[test]
+    fn test_no_conflict() {
+        let rng = &mut TestRng::default();
+
+        // Sample transactions which share no serial numbers, program IDs, or transitions.
+        let transactions = [
+            crate::transaction::test_helpers::sample_deployment_transaction(false, rng),
+            crate::transaction::test_helpers::sample_fee_public_transaction(rng),
+            crate::transaction::test_helpers::sample_private_fee_transaction(rng),
+        ];
+        let footprints = transactions.iter().map(Transaction::footprint).collect::<Vec<_>>();
+
+        for (i, footprint) in footprints.iter().enumerate() {
+            for (j, other) in footprints.iter().enumerate() {
+                if i != j {
+                    assert_eq!(footprint.conflicts_with(other), None);
+                    assert_eq!(transactions[i].conflicts_with(&transactions[j]), None);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_many_transitions() {
+        let rng = &mut TestRng::default();
+
+        // Returns a footprint with the given number of random serial numbers and transition IDs.
+        let sample_footprint = |num_transitions: usize, rng: &mut TestRng| TransactionFootprint::<CurrentNetwork> {
+            transaction_id: Uniform::rand(rng),
+            serial_numbers: (0..num_transitions).map(|_| Field::rand(rng)).collect(),
+            program_id: None,
+            transition_ids: (0..num_transitions).map(|_| Uniform::rand(rng)).collect(),
+        };
+
+        let small = sample_footprint(4, rng);
+        let mut large = sample_footprint(1000, rng);
+        assert_eq!(small.conflicts_with(&large), None);
+        assert_eq!(large.conflicts_with(&small), None);
+
+        // Add a transition of the small footprint to the large footprint.
+        let transition_id = small.transition_ids()[2];
+        large.transition_ids.insert(transition_id);
+        assert_eq!(small.conflicts_with(&large), Some(ConflictReason::TransitionID(transition_id)));
+        assert_eq!(large.conflicts_with(&small), Some(ConflictReason::TransitionID(transition_id)));
+
+        // Add a serial number of the small footprint to the large footprint, which takes precedence.
+        let serial_number = small.serial_numbers()[1];
+        large.serial_numbers.insert(serial_number);
+        assert_eq!(small.conflicts_with(&large), Some(ConflictReason::SerialNumber(serial_number)));
+        assert_eq!(large.conflicts_with(&small), Some(ConflictReason::SerialNumber(serial_number)));
+    }
+}
@@ -12,6 +12,9 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod dependencies;
+pub use dependencies::*;
+
 mod deployment;
 pub use deployment::*;
 
@@ -21,7 +24,14 @@ pub use execution::*;
 mod fee;
 pub use fee::*;
 
+mod footprint;
+pub use footprint::*;
+
+mod structure;
+pub use structure::TransactionStructureError;
+
 mod bytes;
+mod canonical;
 mod merkle;
 mod serialize;
 mod string;
@@ -30,7 +40,7 @@ use crate::Transition;
 use console::{
     network::prelude::*,
     program::{Ciphertext, ProgramOwner, Record, TransactionLeaf, TransactionPath, TransactionTree, TRANSACTION_DEPTH},
-    types::{Field, Group, U64},
+    types::{Address, Field, Group, U64},
 };
 
 #[derive(Clone, PartialEq, Eq)]
@@ -182,6 +192,13 @@ impl<N: Network> Transaction<N> {
         }
     }
 
+    /// Returns the size in bytes, without allocating the byte representation.
+    pub fn size_in_bytes(&self) -> Result<u64> {
+        let mut counter = ByteCounter::default();
+        self.write_le(&mut counter)?;
+        Ok(counter.0)
+    }
+
     /// Returns the transaction fee.
     pub fn fee_amount(&self) -> Result<U64<N>> {
         match self {
@@ -192,6 +209,17 @@ impl<N: Network> Transaction<N> {
         }
     }
 
+    /// Returns the address that paid the fee, if the fee is public.
+    /// The payer of a private fee is the owner of the spent record, which is only revealed by decrypting it.
+    pub fn fee_payer_address(&self) -> Option<Address<N>> {
+        match self {
+            Self::Deploy(_, _, _, fee) => fee.payer(),
+            Self::Execute(_, _, Some(fee)) => fee.payer(),
+            Self::Execute(_, _, None) => None,
+            Self::Fee(_, fee) => fee.payer(),
+        }
+    }
+
     /// Returns the fee transition.
     pub fn fee_transition(&self) -> Option<Fee<N>> {
         match self {
@@ -384,6 +412,21 @@ impl<N: Network> Transaction<N> {
     }
 }
 
+/// A writer that counts the number of bytes written to it, and discards them.
+#[derive(Default)]
+struct ByteCounter(u64);
+
+impl Write for ByteCounter {
+    fn write(&mut self, buf: &[u8]) -> IoResult<usize> {
+        self.0 += buf.len() as u64;
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> IoResult<()> {
+        Ok(())
+    }
+}
+
 #[cfg(test)]
 pub mod test_helpers {
     use super::*;
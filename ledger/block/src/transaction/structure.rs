@@ -0,0 +1,184 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use console::program::ProgramID;
+
+use std::collections::HashSet;
+
+/// The error returned when a transaction fails a structural check.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum TransactionStructureError<N: Network> {
+    /// The transaction contains no transitions.
+    NoTransitions,
+    /// The transaction contains the given transition ID more than once.
+    DuplicateTransitionID(N::TransitionID),
+    /// The transaction contains the given serial number more than once.
+    DuplicateSerialNumber(Field<N>),
+    /// The deployed program exceeds the maximum number of functions or mappings.
+    ProgramTooLarge(ProgramID<N>),
+    /// The execution is missing a fee.
+    MissingFee,
+    /// The transaction ID does not match the root of the transaction.
+    IncorrectTransactionID { expected: Field<N>, found: N::TransactionID },
+}
+
+impl<N: Network> Display for TransactionStructureError<N> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Self::NoTransitions => write!(f, "The transaction contains no transitions"),
+            Self::DuplicateTransitionID(id) => write!(f, "Found a duplicate transition ID '{id}' in the transaction"),
+            Self::DuplicateSerialNumber(serial_number) => {
+                write!(f, "Found a duplicate serial number '{serial_number}' in the transaction")
+            }
+            Self::ProgramTooLarge(program_id) => write!(f, "The program '{program_id}' is too large to deploy"),
+            Self::MissingFee => write!(f, "The execution transaction is missing a fee"),
+            Self::IncorrectTransactionID { expected, found } => {
+                write!(f, "Incorrect transaction ID (expected '{expected}', found '{found}')")
+            }
+        }
+    }
+}
+
+impl<N: Network> std::error::Error for TransactionStructureError<N> {}
+
+impl<N: Network> Transaction<N> {
+    /// Performs the structural checks on the transaction, without verifying any proofs.
+    /// On failure, returns a `TransactionStructureError` describing the first failed check.
+    pub fn check_structure(&self) -> Result<()> {
+        // Ensure the transaction contains transitions.
+        if self.transitions().next().is_none() {
+            bail!(TransactionStructureError::<N>::NoTransitions)
+        }
+        // Ensure the transition IDs are unique.
+        if let Some(transition_id) = find_duplicate(self.transition_ids()) {
+            bail!(TransactionStructureError::<N>::DuplicateTransitionID(*transition_id))
+        }
+        // Ensure the serial numbers are unique.
+        if let Some(serial_number) = find_duplicate(self.serial_numbers()) {
+            bail!(TransactionStructureError::<N>::DuplicateSerialNumber(*serial_number))
+        }
+
+        match self {
+            Self::Deploy(_, _, deployment, _) => {
+                // Ensure the program is within the limits.
+                let program = deployment.program();
+                if program.functions().len() > N::MAX_FUNCTIONS || program.mappings().len() > N::MAX_MAPPINGS {
+                    bail!(TransactionStructureError::<N>::ProgramTooLarge(*program.id()))
+                }
+            }
+            Self::Execute(_, execution, fee) => {
+                // Ensure the fee is present, unless the execution is a single split.
+                if fee.is_none() && !(execution.len() == 1 && self.contains_split()) {
+                    bail!(TransactionStructureError::<N>::MissingFee)
+                }
+            }
+            Self::Fee(..) => (),
+        }
+
+        // Ensure the transaction ID matches the root of the transaction.
+        let expected = self.to_root()?;
+        if *self.id() != expected {
+            bail!(TransactionStructureError::IncorrectTransactionID { expected, found: self.id() })
+        }
+        Ok(())
+    }
+}
+
+/// Returns the first element of the given iterator that was already yielded, if any.
+fn find_duplicate<T: Copy + Eq + core::hash::Hash>(mut iter: impl Iterator<Item = T>) -> Option<T> {
+    let mut seen = HashSet::new();
+    iter.find(|item| !seen.insert(*item))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::transaction::test_helpers;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    /// Returns the structural error of the given transaction.
+    fn check_structure_error(transaction: &Transaction<CurrentNetwork>) -> TransactionStructureError<CurrentNetwork> {
+        let error = transaction.check_structure().unwrap_err();
+        error.downcast::<TransactionStructureError<CurrentNetwork>>().unwrap()
+    }
+
+    #[test]
+    fn test_check_structure() {
+        let rng = &mut TestRng::default();
+
+        // Ensure valid transactions pass the checks.
+        for transaction in [
+            test_helpers::sample_deployment_transaction(true, rng),
+            test_helpers::sample_execution_transaction_with_fee(false, rng),
+            test_helpers::sample_fee_public_transaction(rng),
+        ] {
+            transaction.check_structure().unwrap();
+        }
+    }
+
+    #[test]
+    fn test_check_structure_fails() {
+        let rng = &mut TestRng::default();
+
+        // Sample an execution transaction with a private fee.
+        let transaction = test_helpers::sample_execution_transaction_with_fee(true, rng);
+        let Transaction::Execute(id, execution, Some(fee)) = transaction.clone() else { unreachable!() };
+
+        // Ensure a transaction without transitions is rejected.
+        let candidate = Transaction::Execute(id, Execution::new(), None);
+        assert_eq!(check_structure_error(&candidate), TransactionStructureError::NoTransitions);
+
+        // Ensure a transaction that repeats a transition is rejected.
+        let duplicate = Fee::from_unchecked(execution.get(0).unwrap().clone(), fee.global_state_root(), None);
+        let candidate = Transaction::Execute(id, execution.clone(), Some(duplicate));
+        let transition_id = *execution.get(0).unwrap().id();
+        assert_eq!(check_structure_error(&candidate), TransactionStructureError::DuplicateTransitionID(transition_id));
+
+        // Ensure a transaction that repeats a serial number is rejected.
+        let fee_transition = fee.transition();
+        let transition = Transition::new(
+            *fee_transition.program_id(),
+            *fee_transition.function_name(),
+            fee_transition.inputs().to_vec(),
+            fee_transition.outputs().to_vec(),
+            fee_transition.finalize().cloned(),
+            *fee_transition.tpk(),
+            Field::rand(rng),
+        )
+        .unwrap();
+        let candidate_execution =
+            Execution::from([transition].into_iter(), execution.global_state_root(), None).unwrap();
+        let candidate = Transaction::Execute(id, candidate_execution, Some(fee.clone()));
+        let serial_number = *fee.serial_numbers().next().unwrap();
+        assert_eq!(check_structure_error(&candidate), TransactionStructureError::DuplicateSerialNumber(serial_number));
+
+        // Ensure an execution without a fee is rejected.
+        let candidate = Transaction::Execute(id, execution.clone(), None);
+        assert_eq!(check_structure_error(&candidate), TransactionStructureError::MissingFee);
+
+        // Ensure a transaction with an incorrect ID is rejected.
+        let expected = transaction.to_root().unwrap();
+        let found = Field::<CurrentNetwork>::rand(rng).into();
+        let candidate = Transaction::Execute(found, execution, Some(fee));
+        assert_eq!(
+            check_structure_error(&candidate),
+            TransactionStructureError::IncorrectTransactionID { expected, found }
+        );
+
+        // Note: An oversized program cannot be constructed, as `Program` enforces the same limits when it is built.
+    }
+}
@@ -474,6 +474,44 @@ impl<N: Network> Transition<N> {
     }
 }
 
+impl<N: Network> Transition<N> {
+    /// Returns the public inputs to verify the proof for the transition, in the order the verifier expects them.
+    /// The `callees` are the transitions of the external calls made by this transition, in the order they were called.
+    ///
+    /// The public inputs are the transition public key, the transition commitment, the input IDs (with the tags),
+    /// the input and output IDs of each external call, the output IDs (with the checksums),
+    /// and the checksum of the inputs for finalize, if they exist.
+    pub fn verifier_inputs(&self, callees: &[&Transition<N>]) -> Result<Vec<N::Field>> {
+        // Compute the x- and y-coordinate of `tpk`.
+        let (tpk_x, tpk_y) = self.tpk.to_xy_coordinates();
+
+        // [Inputs] Construct the verifier inputs to verify the proof.
+        let mut inputs = vec![N::Field::one(), *tpk_x, *tpk_y, *self.tcm];
+        // [Inputs] Extend the verifier inputs with the input IDs.
+        inputs.extend(self.inputs.iter().flat_map(|input| input.verifier_inputs()));
+
+        // If there are function calls, append their inputs and outputs.
+        for callee in callees {
+            // [Inputs] Extend the verifier inputs with the input IDs of the external call.
+            inputs.extend(callee.inputs.iter().flat_map(|input| input.verifier_inputs()));
+            // [Inputs] Extend the verifier inputs with the output IDs of the external call.
+            inputs.extend(callee.output_ids().map(|id| **id));
+        }
+
+        // [Inputs] Extend the verifier inputs with the output IDs.
+        inputs.extend(self.outputs.iter().flat_map(|output| output.verifier_inputs()));
+
+        // [Inputs] Extend the verifier inputs with the checksum of the inputs for finalize, if they exist.
+        if let Some(finalize) = &self.finalize {
+            // Convert the finalize inputs into concatenated bits.
+            let finalize_bits = finalize.iter().flat_map(ToBits::to_bits_le).collect::<Vec<_>>();
+            // Compute the checksum of the finalize inputs.
+            inputs.push(*N::hash_bhp1024(&finalize_bits)?);
+        }
+        Ok(inputs)
+    }
+}
+
 #[cfg(test)]
 pub mod test_helpers {
     use super::*;
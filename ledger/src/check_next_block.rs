@@ -14,6 +14,8 @@
 
 use super::*;
 
+use std::collections::BTreeSet;
+
 impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
     /// Checks the given block is valid next block.
     pub fn check_next_block(&self, block: &Block<N>) -> Result<()> {
@@ -130,3 +132,53 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
         Ok(())
     }
 }
+
+impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
+    /// Returns every pair of the given transactions which spend the same serial number, or deploy the same program.
+    /// Each pair is returned once as `(earlier, later)`, in the order the transactions are given.
+    pub fn transaction_conflicts<'a>(
+        transactions: &'a [Transaction<N>],
+    ) -> Vec<(&'a Transaction<N>, &'a Transaction<N>)> {
+        // Adds every pair of indices which share a key, given the `(key, index)` pairs sorted by key.
+        fn insert_conflicts<K: PartialEq>(sorted: &[(K, usize)], conflicts: &mut BTreeSet<(usize, usize)>) {
+            let mut start = 0;
+            while start < sorted.len() {
+                // Find the run of entries with the same key.
+                let end = start + sorted[start..].iter().take_while(|(key, _)| *key == sorted[start].0).count();
+                // Note: The indices in a run are in ascending order, as the entries are sorted by `(key, index)`.
+                for (offset, (_, earlier)) in sorted[start..end].iter().enumerate() {
+                    for (_, later) in &sorted[start + offset + 1..end] {
+                        if earlier != later {
+                            conflicts.insert((*earlier, *later));
+                        }
+                    }
+                }
+                start = end;
+            }
+        }
+
+        // Collect the serial numbers and the deployed program IDs, with the index of their transaction.
+        let mut serial_numbers = transactions
+            .iter()
+            .enumerate()
+            .flat_map(|(index, transaction)| {
+                transaction.serial_numbers().map(move |serial_number| (*serial_number, index))
+            })
+            .collect::<Vec<_>>();
+        let mut program_ids = transactions
+            .iter()
+            .enumerate()
+            .filter_map(|(index, transaction)| Some((*transaction.deployment()?.program_id(), index)))
+            .collect::<Vec<_>>();
+
+        // Sort the entries, so that the transactions sharing a key are adjacent.
+        serial_numbers.sort_unstable();
+        program_ids.sort_unstable();
+
+        // Collect the conflicting pairs, ordered by the indices of their transactions.
+        let mut conflicts = BTreeSet::new();
+        insert_conflicts(&serial_numbers, &mut conflicts);
+        insert_conflicts(&program_ids, &mut conflicts);
+        conflicts.into_iter().map(|(earlier, later)| (&transactions[earlier], &transactions[later])).collect()
+    }
+}
@@ -14,6 +14,28 @@
 
 use super::*;
 
+/// The error returned when `Ledger::get_value_with_path` cannot resolve the access path in a mapping value.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ValuePathError<N: Network> {
+    /// The value at the given depth of the path is not a struct, so it has no members.
+    NotAStruct(usize),
+    /// The member at the given depth of the path does not exist in the struct.
+    MissingMember(usize, Identifier<N>),
+}
+
+impl<N: Network> Display for ValuePathError<N> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Self::NotAStruct(depth) => write!(f, "The value at depth {depth} of the path is not a struct"),
+            Self::MissingMember(depth, member) => {
+                write!(f, "The member '{member}' at depth {depth} of the path does not exist")
+            }
+        }
+    }
+}
+
+impl<N: Network> std::error::Error for ValuePathError<N> {}
+
 impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
     /// Returns the committee for the given `block height`.
     pub fn get_committee(&self, block_height: u32) -> Result<Option<Committee<N>>> {
@@ -47,6 +69,48 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
         EpochChallenge::new(epoch_number, epoch_block_hash, N::COINBASE_PUZZLE_DEGREE)
     }
 
+    /// Returns the sub-value at the given access `path`, of the confirmed value for the given `program ID`,
+    /// `mapping name`, and `key`. If the key does not exist, `None` is returned. If the path is empty,
+    /// the full value is returned. If the path does not resolve in the value, a `ValuePathError` is returned.
+    pub fn get_value_with_path(
+        &self,
+        program_id: &ProgramID<N>,
+        mapping_name: &Identifier<N>,
+        key: &Plaintext<N>,
+        path: &[Access<N>],
+    ) -> Result<Option<Plaintext<N>>> {
+        // Retrieve the value.
+        let plaintext = match self.vm.finalize_store().get_value_confirmed(program_id, mapping_name, key)? {
+            Some(Value::Plaintext(plaintext)) => plaintext,
+            Some(Value::Record(..)) => bail!("The value in mapping '{program_id}/{mapping_name}' is not a plaintext"),
+            None => return Ok(None),
+        };
+        // If the path is empty, return the full value.
+        if path.is_empty() {
+            return Ok(Some(plaintext));
+        }
+        // Resolve the path.
+        match plaintext.find_by_path(path) {
+            Ok(sub_value) => Ok(Some(sub_value.clone())),
+            Err(_) => {
+                // Determine the first member access which fails to resolve.
+                // Note: The path resolves up to (but excluding) this member.
+                let depth = (0..path.len())
+                    .find(|depth| plaintext.find_by_path(&path[..=*depth]).is_err())
+                    .unwrap_or(path.len() - 1);
+                let parent = match depth {
+                    0 => &plaintext,
+                    _ => plaintext.find_by_path(&path[..depth])?,
+                };
+                let Access::Member(member) = &path[depth];
+                match parent {
+                    Plaintext::Literal(..) => Err(ValuePathError::<N>::NotAStruct(depth).into()),
+                    Plaintext::Struct(..) => Err(ValuePathError::MissingMember(depth, *member).into()),
+                }
+            }
+        }
+    }
+
     /// Returns the block for the given block height.
     pub fn get_block(&self, height: u32) -> Result<Block<N>> {
         // If the height is 0, return the genesis block.
@@ -213,6 +277,7 @@ mod tests {
     use super::*;
     use crate::test_helpers::CurrentLedger;
     use console::network::Testnet3;
+    use synthesizer::program::FinalizeStoreTrait;
 
     type CurrentNetwork = Testnet3;
 
@@ -228,4 +293,58 @@ mod tests {
         // Ensure the genesis block matches.
         assert_eq!(genesis, candidate);
     }
+
+    #[test]
+    fn test_get_value_with_path() {
+        // Load the genesis block.
+        let genesis = Block::from_bytes_le(CurrentNetwork::genesis_bytes()).unwrap();
+        // Initialize a new ledger.
+        let ledger = CurrentLedger::load(genesis, None).unwrap();
+
+        // Initialize a mapping, with a value of nested structs.
+        let program_id = ProgramID::from_str("token.aleo").unwrap();
+        let mapping_name = Identifier::from_str("accounts").unwrap();
+        let key = Plaintext::from_str("0u8").unwrap();
+        let value = Plaintext::from_str(
+            "{ id: 1field, balance: { amount: 100u64, locked: { amount: 5u64, until: 10u32 } }, active: true }",
+        )
+        .unwrap();
+        let finalize_store = ledger.vm().finalize_store();
+        finalize_store.initialize_mapping(&program_id, &mapping_name).unwrap();
+        let entry = Value::Plaintext(value.clone());
+        finalize_store.insert_key_value(&program_id, &mapping_name, key.clone(), entry).unwrap();
+
+        // Returns the access path for the given members.
+        let path = |members: &[&str]| {
+            members.iter().map(|member| Access::Member(Identifier::from_str(member).unwrap())).collect::<Vec<_>>()
+        };
+
+        // Ensure the sub-values match the client-side extraction.
+        for members in [&["id"][..], &["balance"], &["balance", "amount"], &["balance", "locked", "until"], &["active"]]
+        {
+            let path = path(members);
+            let candidate = ledger.get_value_with_path(&program_id, &mapping_name, &key, &path).unwrap();
+            assert_eq!(candidate.as_ref(), Some(value.find_by_path(&path).unwrap()));
+        }
+        // Ensure an empty path returns the full value.
+        let candidate = ledger.get_value_with_path(&program_id, &mapping_name, &key, &[]).unwrap();
+        assert_eq!(candidate, Some(value));
+
+        // Ensure a missing key returns `None`.
+        let missing_key = Plaintext::from_str("1u8").unwrap();
+        let candidate = ledger.get_value_with_path(&program_id, &mapping_name, &missing_key, &path(&["id"])).unwrap();
+        assert_eq!(candidate, None);
+
+        // Ensure the paths which do not resolve return a typed error.
+        for (members, expected) in [
+            (&["owner"][..], ValuePathError::MissingMember(0, Identifier::from_str("owner").unwrap())),
+            (&["balance", "owner"], ValuePathError::MissingMember(1, Identifier::from_str("owner").unwrap())),
+            (&["balance", "locked", "owner"], ValuePathError::MissingMember(2, Identifier::from_str("owner").unwrap())),
+            (&["id", "amount"], ValuePathError::NotAStruct(1)),
+            (&["balance", "amount", "low"], ValuePathError::NotAStruct(2)),
+        ] {
+            let error = ledger.get_value_with_path(&program_id, &mapping_name, &key, &path(members)).unwrap_err();
+            assert_eq!(error.downcast_ref::<ValuePathError<CurrentNetwork>>(), Some(&expected));
+        }
+    }
 }
@@ -0,0 +1,92 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use console::{network::Network, program::ProgramID, types::Field};
+use ledger_block::{Transaction, TransactionDependencies};
+
+use anyhow::{bail, Result};
+use indexmap::{IndexMap, IndexSet};
+use std::collections::BTreeSet;
+
+/// Returns the order in which the given transactions must be included in a block, as indices into `transactions`.
+///
+/// A deployment is ordered before the transactions which call or import the deployed program.
+/// Independent transactions keep their input order.
+pub fn sort_by_dependencies<N: Network>(transactions: &[Transaction<N>]) -> Result<Vec<usize>> {
+    sort_dependencies(&transactions.iter().map(Transaction::dependencies).collect::<Vec<_>>())
+}
+
+/// Returns the order in which the transactions with the given dependencies must be included in a block,
+/// as indices into `dependencies`.
+///
+/// A transaction is ordered after every transaction which deploys a program it requires,
+/// or produces a record it consumes. Independent transactions keep their input order.
+/// If the dependencies contain a cycle, an error is returned with the IDs of the transactions
+/// which could not be ordered.
+pub fn sort_dependencies<N: Network>(dependencies: &[TransactionDependencies<N>]) -> Result<Vec<usize>> {
+    // Index the transactions by the programs they deploy and the records they produce.
+    let mut deployers: IndexMap<ProgramID<N>, Vec<usize>> = IndexMap::new();
+    let mut producers: IndexMap<Field<N>, Vec<usize>> = IndexMap::new();
+    for (index, dependency) in dependencies.iter().enumerate() {
+        for program_id in dependency.programs_deployed() {
+            deployers.entry(*program_id).or_default().push(index);
+        }
+        for commitment in dependency.commitments_produced() {
+            producers.entry(*commitment).or_default().push(index);
+        }
+    }
+
+    // For each transaction, count the transactions it must come after, and collect the transactions waiting on it.
+    let mut num_pending = vec![0usize; dependencies.len()];
+    let mut dependents = vec![Vec::new(); dependencies.len()];
+    for (index, dependency) in dependencies.iter().enumerate() {
+        let required = dependency.program_ids_required().iter().filter_map(|program_id| deployers.get(program_id));
+        let consumed = dependency.commitments_consumed().iter().filter_map(|commitment| producers.get(commitment));
+        let predecessors = required
+            .chain(consumed)
+            .flatten()
+            .copied()
+            .filter(|predecessor| *predecessor != index)
+            .collect::<IndexSet<_>>();
+        num_pending[index] = predecessors.len();
+        for predecessor in predecessors {
+            dependents[predecessor].push(index);
+        }
+    }
+
+    // Repeatedly take the earliest transaction whose predecessors have all been ordered.
+    let mut ready = (0..dependencies.len()).filter(|index| num_pending[*index] == 0).collect::<BTreeSet<_>>();
+    let mut order = Vec::with_capacity(dependencies.len());
+    while let Some(index) = ready.pop_first() {
+        order.push(index);
+        for dependent in &dependents[index] {
+            num_pending[*dependent] -= 1;
+            if num_pending[*dependent] == 0 {
+                ready.insert(*dependent);
+            }
+        }
+    }
+
+    // Ensure every transaction was ordered. Otherwise, the remaining transactions are in, or wait on, a cycle.
+    if order.len() != dependencies.len() {
+        let transaction_ids = num_pending
+            .iter()
+            .enumerate()
+            .filter(|(_, num_pending)| **num_pending > 0)
+            .map(|(index, _)| dependencies[index].transaction_id().to_string())
+            .collect::<Vec<_>>();
+        bail!("Found a dependency cycle among the transactions: {}", transaction_ids.join(", "))
+    }
+    Ok(order)
+}
@@ -15,5 +15,8 @@
 mod bft;
 pub use bft::*;
 
+mod dependencies;
+pub use dependencies::*;
+
 mod supply;
 pub use supply::*;
@@ -37,7 +37,10 @@ mod check_transaction_basic;
 mod contains;
 mod find;
 mod get;
+pub use get::*;
 mod iterators;
+mod verify_transaction;
+pub use verify_transaction::*;
 
 #[cfg(test)]
 mod tests;
@@ -46,6 +49,7 @@ use console::{
     account::{Address, GraphKey, PrivateKey, ViewKey},
     network::prelude::*,
     program::{
+        Access,
         Ciphertext,
         Entry,
         Identifier,
@@ -15,13 +15,14 @@
 use crate::{
     test_helpers::{CurrentLedger, CurrentNetwork},
     RecordsFilter,
+    TransactionVerificationError,
 };
 use console::{
     account::PrivateKey,
     network::prelude::*,
     program::{Entry, Identifier, Literal, Plaintext, Value},
 };
-use ledger_block::{ConfirmedTransaction, Rejected, Transaction};
+use ledger_block::{ConfirmedTransaction, Execution, Rejected, Transaction};
 use ledger_store::{helpers::memory::ConsensusMemory, ConsensusStore};
 use synthesizer::{program::Program, vm::VM};
 
@@ -80,6 +81,41 @@ fn test_state_path() {
     let _state_path = ledger.get_state_path_for_commitment(commitment).unwrap();
 }
 
+#[test]
+fn test_verify_transaction() {
+    let rng = &mut TestRng::default();
+
+    // Initialize the test environment.
+    let crate::test_helpers::TestEnv { ledger, private_key, view_key, .. } = crate::test_helpers::sample_test_env(rng);
+
+    // Ensure a transaction that is already in the ledger is rejected.
+    let genesis = ledger.get_block(0).unwrap();
+    let transaction = genesis.transactions().iter().next().unwrap().transaction();
+    let error = ledger.verify_transaction(transaction).unwrap_err();
+    let expected = TransactionVerificationError::DuplicateTransaction(transaction.id());
+    assert_eq!(Some(&expected), error.downcast_ref::<TransactionVerificationError<CurrentNetwork>>());
+
+    // Prepare a `split` execution of an unspent record.
+    let (_, record) = ledger.find_records(&view_key, RecordsFilter::SlowUnspent(private_key)).unwrap().next().unwrap();
+    let inputs = [Value::Record(record), Value::from_str("100u64").unwrap()];
+    let authorization = ledger.vm.authorize(&private_key, "credits.aleo", "split", inputs.into_iter(), rng).unwrap();
+    let transaction = ledger.vm.execute_authorization(authorization, None, None, rng).unwrap();
+
+    // Ensure the new transaction is accepted.
+    ledger.verify_transaction(&transaction).unwrap();
+
+    // Ensure a transaction whose proof does not verify is rejected.
+    let execution = transaction.execution().unwrap();
+    let tampered_execution =
+        Execution::from(execution.transitions().cloned(), execution.global_state_root(), None).unwrap();
+    let tampered_transaction = Transaction::from_execution(tampered_execution, None).unwrap();
+    let error = ledger.verify_transaction(&tampered_transaction).unwrap_err();
+    assert!(matches!(
+        error.downcast_ref::<TransactionVerificationError<CurrentNetwork>>(),
+        Some(TransactionVerificationError::InvalidTransaction(..))
+    ));
+}
+
 #[test]
 fn test_insufficient_fees() {
     let rng = &mut TestRng::default();
@@ -389,3 +425,147 @@ finalize failed_assert:
     // Add the block with the rejected transaction to the ledger.
     ledger.advance_to_next_block(&next_block).unwrap();
 }
+
+#[test]
+fn test_sort_by_dependencies_deploy_then_call() {
+    let rng = &mut TestRng::default();
+
+    // Initialize the test environment.
+    let crate::test_helpers::TestEnv { ledger, private_key, view_key, .. } = crate::test_helpers::sample_test_env(rng);
+
+    // Initialize a test program.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+program dependent.aleo;
+
+function foo:
+    input r0 as u8.private;
+    add r0 r0 into r1;
+    output r1 as u8.private;",
+    )
+    .unwrap();
+
+    // Fetch the unspent records.
+    let records = ledger
+        .find_records(&view_key, RecordsFilter::SlowUnspent(private_key))
+        .unwrap()
+        .map(|(_, record)| record)
+        .collect::<Vec<_>>();
+
+    // Deploy the program, and advance the ledger so the program can be executed.
+    let deployment_transaction =
+        ledger.vm().deploy(&private_key, &program, Some(records[0].clone()), 0, None, rng).unwrap();
+    let block = ledger
+        .prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![deployment_transaction.clone()], rng)
+        .unwrap();
+    ledger.advance_to_next_block(&block).unwrap();
+
+    // Execute the program.
+    let inputs = [Value::<CurrentNetwork>::from_str("1u8").unwrap()].into_iter();
+    let execution_transaction = ledger
+        .vm()
+        .execute(&private_key, ("dependent.aleo", "foo"), inputs, Some(records[1].clone()), 0, None, rng)
+        .unwrap();
+
+    // Ensure the dependencies are derived from the transactions.
+    let program_id = *program.id();
+    assert!(deployment_transaction.dependencies().programs_deployed().contains(&program_id));
+    assert!(execution_transaction.dependencies().program_ids_required().contains(&program_id));
+
+    // Ensure the deployment is ordered first, regardless of the input order.
+    let transactions = [execution_transaction.clone(), deployment_transaction.clone()];
+    assert_eq!(crate::sort_by_dependencies(&transactions).unwrap(), vec![1, 0]);
+    let transactions = [deployment_transaction, execution_transaction];
+    assert_eq!(crate::sort_by_dependencies(&transactions).unwrap(), vec![0, 1]);
+}
+
+#[test]
+fn test_sort_by_dependencies_chained_spend() {
+    // Retrieve the genesis transactions, which are independent of one another.
+    let block = crate::test_helpers::sample_genesis_block();
+    let transactions = block.transactions().iter().map(|confirmed| confirmed.transaction().clone()).collect::<Vec<_>>();
+    assert!(transactions.len() >= 2);
+
+    // Ensure independent transactions keep their input order.
+    assert_eq!(crate::sort_by_dependencies(&transactions).unwrap(), (0..transactions.len()).collect::<Vec<_>>());
+
+    // Spend a record produced by the first transaction in the last transaction, and place the spend first.
+    let last = transactions.len() - 1;
+    let commitment = *transactions[0].commitments().next().unwrap();
+    let mut dependencies = transactions.iter().map(Transaction::dependencies).collect::<Vec<_>>();
+    dependencies[last] = dependencies[last].clone().with_consumed_commitments([commitment]);
+    dependencies.swap(0, last);
+
+    // Ensure the spend is ordered after the transaction producing the record.
+    let mut expected = (1..transactions.len()).collect::<Vec<_>>();
+    expected.push(0);
+    assert_eq!(crate::sort_dependencies(&dependencies).unwrap(), expected);
+}
+
+#[test]
+fn test_sort_by_dependencies_cycle() {
+    // Retrieve the genesis transactions, which are independent of one another.
+    let block = crate::test_helpers::sample_genesis_block();
+    let transactions = block.transactions().iter().map(|confirmed| confirmed.transaction().clone()).collect::<Vec<_>>();
+    assert!(transactions.len() >= 3);
+
+    // Make the first two transactions consume a record produced by the other.
+    let commitment_0 = *transactions[0].commitments().next().unwrap();
+    let commitment_1 = *transactions[1].commitments().next().unwrap();
+    let mut dependencies = transactions.iter().map(Transaction::dependencies).collect::<Vec<_>>();
+    dependencies[0] = dependencies[0].clone().with_consumed_commitments([commitment_1]);
+    dependencies[1] = dependencies[1].clone().with_consumed_commitments([commitment_0]);
+
+    // Ensure the cycle is reported with the offending transaction IDs only.
+    let error = crate::sort_dependencies(&dependencies).unwrap_err().to_string();
+    assert!(error.contains(&transactions[0].id().to_string()));
+    assert!(error.contains(&transactions[1].id().to_string()));
+    assert!(!error.contains(&transactions[2].id().to_string()));
+}
+
+#[test]
+fn test_transaction_conflicts() {
+    let rng = &mut TestRng::default();
+
+    // Initialize the test environment.
+    let crate::test_helpers::TestEnv { ledger, private_key, view_key, .. } = crate::test_helpers::sample_test_env(rng);
+
+    // Returns a test program with the given name.
+    let sample_program = |name: &str| {
+        Program::<CurrentNetwork>::from_str(&format!(
+            "
+program {name}.aleo;
+
+function foo:
+    input r0 as u8.private;
+    output r0 as u8.private;"
+        ))
+        .unwrap()
+    };
+
+    // Fetch the unspent records.
+    let records = ledger
+        .find_records(&view_key, RecordsFilter::SlowUnspent(private_key))
+        .unwrap()
+        .map(|(_, record)| record)
+        .collect::<Vec<_>>();
+
+    // Deploy two programs paying with the same record, and deploy the first program twice.
+    let mut deploy = |name: &str, record: usize| {
+        ledger.vm().deploy(&private_key, &sample_program(name), Some(records[record].clone()), 0, None, rng).unwrap()
+    };
+    let first = deploy("conflict_a", 0);
+    let double_spend = deploy("conflict_b", 0);
+    let independent = deploy("conflict_c", 1);
+    let duplicate_deployment = deploy("conflict_a", 2);
+    let transactions = [first.clone(), double_spend.clone(), independent, duplicate_deployment.clone()];
+
+    // Ensure the double spend and the duplicate deployment are found, and the independent transaction is not.
+    let conflicts = CurrentLedger::transaction_conflicts(&transactions);
+    assert_eq!(conflicts, vec![(&first, &double_spend), (&first, &duplicate_deployment)]);
+
+    // Ensure a transaction given twice conflicts with itself.
+    let transactions = [first.clone(), first.clone()];
+    assert_eq!(CurrentLedger::transaction_conflicts(&transactions), vec![(&first, &first)]);
+    assert!(CurrentLedger::transaction_conflicts(&[]).is_empty());
+}
@@ -0,0 +1,81 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// The error returned when a transaction fails `Ledger::verify_transaction`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum TransactionVerificationError<N: Network> {
+    /// The transaction ID already exists in the ledger.
+    DuplicateTransaction(N::TransactionID),
+    /// The program ID of the deployment already exists in the ledger.
+    DuplicateProgram(ProgramID<N>),
+    /// A transition ID of the transaction already exists in the ledger.
+    DuplicateTransition(N::TransitionID),
+    /// The transaction, including its proofs, failed verification in the VM.
+    InvalidTransaction(N::TransactionID, String),
+}
+
+impl<N: Network> Display for TransactionVerificationError<N> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Self::DuplicateTransaction(transaction_id) => {
+                write!(f, "Transaction '{transaction_id}' already exists in the ledger")
+            }
+            Self::DuplicateProgram(program_id) => write!(f, "Program ID '{program_id}' is already deployed"),
+            Self::DuplicateTransition(transition_id) => {
+                write!(f, "Transition ID '{transition_id}' already exists in the ledger")
+            }
+            Self::InvalidTransaction(transaction_id, reason) => {
+                write!(f, "Transaction '{transaction_id}' is invalid: {reason}")
+            }
+        }
+    }
+}
+
+impl<N: Network> std::error::Error for TransactionVerificationError<N> {}
+
+impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
+    /// Verifies the given transaction against the current state of the ledger.
+    /// On failure, returns a `TransactionVerificationError`, which can be recovered with `downcast_ref`.
+    pub fn verify_transaction(&self, transaction: &Transaction<N>) -> Result<()> {
+        let transaction_id = transaction.id();
+
+        // Ensure the transaction ID does not already exist.
+        if self.contains_transaction_id(&transaction_id)? {
+            return Err(TransactionVerificationError::<N>::DuplicateTransaction(transaction_id).into());
+        }
+
+        // Ensure the program ID of a deployment does not already exist.
+        if let Transaction::Deploy(_, _, deployment, _) = transaction {
+            if self.contains_program_id(deployment.program_id())? {
+                return Err(TransactionVerificationError::<N>::DuplicateProgram(*deployment.program_id()).into());
+            }
+        }
+
+        // Ensure the transition IDs do not already exist.
+        for transition_id in transaction.transition_ids() {
+            if self.contains_transition_id(transition_id)? {
+                return Err(TransactionVerificationError::<N>::DuplicateTransition(*transition_id).into());
+            }
+        }
+
+        // Ensure the transaction, including its proofs, is valid.
+        if let Err(error) = self.vm.check_transaction(transaction, None) {
+            return Err(TransactionVerificationError::<N>::InvalidTransaction(transaction_id, error.to_string()).into());
+        }
+
+        Ok(())
+    }
+}
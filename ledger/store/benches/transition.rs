@@ -0,0 +1,84 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#[macro_use]
+extern crate criterion;
+
+use console::{
+    network::{prelude::*, Testnet3},
+    types::Field,
+};
+use ledger_store::{helpers::memory::TransitionMemory, TransitionStorage, TransitionStore};
+
+use criterion::Criterion;
+
+type CurrentNetwork = Testnet3;
+
+/// Initializes a transition storage with the transitions of sampled transactions.
+fn sample_storage(rng: &mut TestRng) -> TransitionMemory<CurrentNetwork> {
+    let storage = TransitionMemory::open(None).unwrap();
+    for is_fee_private in [true, false] {
+        let transaction = ledger_test_helpers::sample_execution_transaction_with_fee(is_fee_private, rng);
+        for transition in transaction.transitions() {
+            storage.insert(transition).unwrap();
+        }
+    }
+    storage
+}
+
+fn contains_commitment(c: &mut Criterion) {
+    let rng = &mut TestRng::default();
+
+    // Initialize the store, and sample commitments that are not in it.
+    let storage = sample_storage(rng);
+    let store = TransitionStore::from(storage.clone());
+    let commitments = (0..1000).map(|_| Field::<CurrentNetwork>::rand(rng)).collect::<Vec<_>>();
+
+    c.bench_function("TransitionStore::contains_commitment - with filter", |b| {
+        b.iter(|| commitments.iter().for_each(|commitment| assert!(!store.contains_commitment(commitment).unwrap())))
+    });
+    c.bench_function("TransitionStore::contains_commitment - without filter", |b| {
+        b.iter(|| {
+            commitments
+                .iter()
+                .for_each(|commitment| assert!(!storage.output_store().contains_commitment(commitment).unwrap()))
+        })
+    });
+}
+
+fn contains_serial_number(c: &mut Criterion) {
+    let rng = &mut TestRng::default();
+
+    // Initialize the store, and sample serial numbers that are not in it.
+    let storage = sample_storage(rng);
+    let store = TransitionStore::from(storage.clone());
+    let serial_numbers = (0..1000).map(|_| Field::<CurrentNetwork>::rand(rng)).collect::<Vec<_>>();
+
+    c.bench_function("TransitionStore::contains_serial_number - with filter", |b| {
+        b.iter(|| serial_numbers.iter().for_each(|sn| assert!(!store.contains_serial_number(sn).unwrap())))
+    });
+    c.bench_function("TransitionStore::contains_serial_number - without filter", |b| {
+        b.iter(|| {
+            serial_numbers.iter().for_each(|sn| assert!(!storage.input_store().contains_serial_number(sn).unwrap()))
+        })
+    });
+}
+
+criterion_group! {
+    name = transition;
+    config = Criterion::default();
+    targets = contains_commitment, contains_serial_number
+}
+
+criterion_main!(transition);
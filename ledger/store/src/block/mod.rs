@@ -327,7 +327,7 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
                 // Store the block hash and confirmed transaction data.
                 self.confirmed_transactions_map().insert(transaction.id(), (block.hash(), confirmed_type, blob))?;
                 // Store the transaction.
-                self.transaction_store().insert(&transaction)?;
+                self.transaction_store().insert_at_height(&transaction, block.height())?;
             }
 
             // Store the block ratifications.
@@ -0,0 +1,111 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::TransactionType;
+use console::prelude::{fmt, Display, Formatter};
+
+/// The error returned by the transaction, deployment, execution, fee, and transition stores,
+/// which allows callers to distinguish the kind of failure without matching on the message.
+/// The stores return `anyhow::Result`, so existing callers are unaffected, and the kind is
+/// recovered with `error.downcast_ref::<StorageError>()`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum StorageError {
+    /// The requested entry is not in storage.
+    NotFound(String),
+    /// The transaction is in storage, but is not of the requested type.
+    TypeMismatch { transaction_id: String, expected: TransactionType, found: TransactionType },
+    /// The entry is in storage, but an entry that it refers to is missing or malformed.
+    Corrupted(String),
+    /// The storage backend failed to read or write.
+    Io(String),
+    /// The atomic batch was aborted, and none of its writes were committed.
+    AtomicAborted(String),
+    /// A transition output record has the same nonce as a record in the given transition.
+    DuplicateNonce { nonce: String, transition_id: String },
+    /// No verifying key is stored for the function of the deployed program.
+    MissingVerifyingKey { program_id: String, function_name: String },
+    /// The certificate of the function does not match its verifying key.
+    InvalidCertificate { program_id: String, function_name: String },
+    /// The transaction, or a component of a deployment, exceeds its limit in `SizeLimits`.
+    TooLarge { size: u64, limit: u64 },
+    /// The name is not a valid program ID, for the given reason.
+    MalformedProgramName { name: String, reason: String },
+    /// The operation is not supported by the store, for the given reason.
+    Unsupported(String),
+}
+
+impl StorageError {
+    /// Returns the error for a transaction that is not of the `expected` type.
+    pub(crate) fn type_mismatch(
+        transaction_id: impl Display,
+        expected: TransactionType,
+        found: TransactionType,
+    ) -> Self {
+        Self::TypeMismatch { transaction_id: transaction_id.to_string(), expected, found }
+    }
+
+    /// Returns the error for a record `nonce` that already exists in the given transition.
+    pub(crate) fn duplicate_nonce(nonce: impl Display, transition_id: impl Display) -> Self {
+        Self::DuplicateNonce { nonce: nonce.to_string(), transition_id: transition_id.to_string() }
+    }
+
+    /// Returns the error for a missing verifying key of the given program function.
+    pub(crate) fn missing_verifying_key(program_id: impl Display, function_name: impl Display) -> Self {
+        Self::MissingVerifyingKey { program_id: program_id.to_string(), function_name: function_name.to_string() }
+    }
+
+    /// Returns the error for an invalid certificate of the given program function.
+    pub(crate) fn invalid_certificate(program_id: impl Display, function_name: impl Display) -> Self {
+        Self::InvalidCertificate { program_id: program_id.to_string(), function_name: function_name.to_string() }
+    }
+}
+
+impl Display for StorageError {
+    /// Prints the storage error.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Self::NotFound(message)
+            | Self::Corrupted(message)
+            | Self::AtomicAborted(message)
+            | Self::Unsupported(message) => {
+                write!(f, "{message}")
+            }
+            Self::TypeMismatch { transaction_id, expected, found } => {
+                write!(f, "Expected a {expected:?} transaction, but transaction '{transaction_id}' is a {found:?}")
+            }
+            Self::Io(message) => write!(f, "Storage I/O error: {message}"),
+            Self::DuplicateNonce { nonce, transition_id } => {
+                write!(f, "The record nonce '{nonce}' already exists in transition '{transition_id}'")
+            }
+            Self::MissingVerifyingKey { program_id, function_name } => {
+                write!(f, "Missing the verifying key for '{program_id}/{function_name}'")
+            }
+            Self::InvalidCertificate { program_id, function_name } => {
+                write!(f, "Invalid certificate for '{program_id}/{function_name}'")
+            }
+            Self::TooLarge { size, limit } => write!(f, "The size ({size} bytes) exceeds the limit ({limit} bytes)"),
+            Self::MalformedProgramName { name, reason } => write!(f, "Malformed program name '{name}': {reason}"),
+        }
+    }
+}
+
+impl std::error::Error for StorageError {}
+
+#[cfg(feature = "rocks")]
+impl From<rocksdb::Error> for StorageError {
+    /// Returns the I/O error for the given RocksDB error.
+    fn from(error: rocksdb::Error) -> Self {
+        Self::Io(error.to_string())
+    }
+}
@@ -0,0 +1,128 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use console::network::prelude::*;
+
+/// The write access to a map, which is held by an entry until it is dropped.
+pub trait EntryGuard<K, V> {
+    /// Inserts the given key-value pair into the map.
+    fn insert(&mut self, key: K, value: V) -> Result<()>;
+
+    /// Removes the key-value pair for the given key from the map.
+    fn remove(&mut self, key: &K) -> Result<()>;
+}
+
+/// A view into a single entry of a map, which is either vacant or occupied.
+/// The entry holds a lock on the map until it is dropped, so that checking for a key
+/// and inserting it is a single atomic operation. The lock is not reentrant: see `Map::entry`
+/// for the methods of the map that must not be called while the entry is alive.
+pub enum Entry<'a, K, V> {
+    /// An occupied entry.
+    Occupied(OccupiedEntry<'a, K, V>),
+    /// A vacant entry.
+    Vacant(VacantEntry<'a, K, V>),
+}
+
+impl<'a, K, V: Clone> Entry<'a, K, V> {
+    /// Initializes a new entry for the given key, its current value (if any), and the lock on the map.
+    pub fn new(key: K, value: Option<V>, guard: Box<dyn EntryGuard<K, V> + 'a>) -> Self {
+        match value {
+            Some(value) => Self::Occupied(OccupiedEntry { key, value, guard }),
+            None => Self::Vacant(VacantEntry { key, guard }),
+        }
+    }
+
+    /// Returns the key of this entry.
+    pub const fn key(&self) -> &K {
+        match self {
+            Self::Occupied(entry) => entry.key(),
+            Self::Vacant(entry) => entry.key(),
+        }
+    }
+
+    /// Inserts the given `default` value if the entry is vacant, and returns the value of the entry.
+    pub fn or_insert(self, default: V) -> Result<V> {
+        self.or_insert_with(|| default)
+    }
+
+    /// Inserts the value returned by `default` if the entry is vacant, and returns the value of the entry.
+    pub fn or_insert_with<F: FnOnce() -> V>(self, default: F) -> Result<V> {
+        match self {
+            Self::Occupied(entry) => Ok(entry.value),
+            Self::Vacant(entry) => {
+                let value = default();
+                entry.insert(value.clone())?;
+                Ok(value)
+            }
+        }
+    }
+}
+
+/// A view into an occupied entry of a map.
+pub struct OccupiedEntry<'a, K, V> {
+    /// The key of the entry.
+    key: K,
+    /// The current value of the entry.
+    value: V,
+    /// The lock on the map.
+    guard: Box<dyn EntryGuard<K, V> + 'a>,
+}
+
+impl<'a, K, V> OccupiedEntry<'a, K, V> {
+    /// Returns the key of this entry.
+    pub const fn key(&self) -> &K {
+        &self.key
+    }
+
+    /// Returns the current value of this entry.
+    pub const fn get(&self) -> &V {
+        &self.value
+    }
+
+    /// Replaces the value of this entry, and returns the previous value.
+    pub fn insert(&mut self, value: V) -> Result<V>
+    where
+        K: Copy,
+        V: Clone,
+    {
+        self.guard.insert(self.key, value.clone())?;
+        Ok(core::mem::replace(&mut self.value, value))
+    }
+
+    /// Removes this entry from the map, and returns its value.
+    pub fn remove(mut self) -> Result<V> {
+        self.guard.remove(&self.key)?;
+        Ok(self.value)
+    }
+}
+
+/// A view into a vacant entry of a map.
+pub struct VacantEntry<'a, K, V> {
+    /// The key of the entry.
+    key: K,
+    /// The lock on the map.
+    guard: Box<dyn EntryGuard<K, V> + 'a>,
+}
+
+impl<'a, K, V> VacantEntry<'a, K, V> {
+    /// Returns the key of this entry.
+    pub const fn key(&self) -> &K {
+        &self.key
+    }
+
+    /// Inserts the given value into this entry.
+    pub fn insert(mut self, value: V) -> Result<()> {
+        self.guard.insert(self.key, value)
+    }
+}
@@ -0,0 +1,235 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use core::{
+    borrow::Borrow,
+    hash::{BuildHasher, Hash, Hasher},
+    marker::PhantomData,
+};
+use parking_lot::RwLock;
+use std::{collections::hash_map::RandomState, sync::Arc};
+
+/// The number of bits allocated to each key when the filter is built.
+const BITS_PER_KEY: usize = 10;
+/// The number of bit positions set for each key, which minimizes the false positive rate (~1%) for `BITS_PER_KEY`.
+const NUM_HASHES: u64 = 7;
+/// The minimum number of bits in the filter.
+const MIN_NUM_BITS: usize = 1 << 12;
+
+/// A Bloom filter over the keys of a map, which is consulted before looking up a key in the map.
+///
+/// The filter may report that an absent key is present, but never that a present key is absent,
+/// provided that every key is inserted into the filter before it is inserted into the map.
+/// As keys cannot be removed from a Bloom filter, removals are only counted, and once there are
+/// too many of them, the filter is rebuilt from the keys in the map.
+pub struct MembershipFilter<K: Hash> {
+    /// The state of the filter, which is shared between clones.
+    state: Arc<RwLock<FilterState>>,
+    /// The type of the keys.
+    _key: PhantomData<fn(&K)>,
+}
+
+/// The state of a membership filter.
+#[derive(Default)]
+struct FilterState {
+    /// The bits of the filter, or `None` if the filter has not been built yet.
+    bits: Option<Vec<u64>>,
+    /// The hasher of the keys.
+    hasher: RandomState,
+    /// The number of keys inserted since the filter was built.
+    num_keys: usize,
+    /// The number of keys removed since the filter was built.
+    num_removals: usize,
+}
+
+impl<K: Hash> Clone for MembershipFilter<K> {
+    fn clone(&self) -> Self {
+        Self { state: self.state.clone(), _key: PhantomData }
+    }
+}
+
+impl<K: Hash> Default for MembershipFilter<K> {
+    /// Initializes a filter that has not been built, and therefore reports every key as present.
+    fn default() -> Self {
+        Self { state: Default::default(), _key: PhantomData }
+    }
+}
+
+impl<K: Hash> MembershipFilter<K> {
+    /// Returns `true` if the filter has not been built, or if it has become too full or too stale to be useful.
+    pub fn needs_rebuild(&self) -> bool {
+        let state = self.state.read();
+        match &state.bits {
+            Some(bits) => {
+                state.num_removals > state.num_keys / 4 || state.num_keys > 2 * bits.len() * 64 / BITS_PER_KEY
+            }
+            None => true,
+        }
+    }
+
+    /// Inserts the given key into the filter.
+    /// Note: This must be called before the key is inserted into the map.
+    pub fn insert(&self, key: &K) {
+        let mut state = self.state.write();
+        state.num_keys += 1;
+        let hashes = hash_key(&state.hasher, key);
+        if let Some(bits) = &mut state.bits {
+            set_bits(bits, hashes);
+        }
+    }
+
+    /// Records the removal of a key from the map, which is only forgotten by the filter once it is rebuilt.
+    pub fn mark_removed(&self) {
+        self.state.write().num_removals += 1;
+    }
+
+    /// Discards the filter, so that it is rebuilt from the keys in the map on the next membership check.
+    /// Note: This must be called after keys are inserted into the map without being inserted into the filter.
+    pub fn reset(&self) {
+        *self.state.write() = Default::default();
+    }
+
+    /// Returns `false` if the given key is definitely not in the map, and `true` if it may be.
+    /// If the filter has not been built, every key may be in the map.
+    pub fn may_contain(&self, key: &K) -> bool {
+        let state = self.state.read();
+        match &state.bits {
+            Some(bits) => {
+                let num_bits = bits.len() as u64 * 64;
+                let is_set = |i: u64| bits[(i / 64) as usize] & (1 << (i % 64)) != 0;
+                bit_indices(hash_key(&state.hasher, key), num_bits).all(is_set)
+            }
+            None => true,
+        }
+    }
+
+    /// Rebuilds the filter from the keys returned by `keys`, and returns `true` if the filter was rebuilt.
+    ///
+    /// Keys which are pending in an atomic batch are not returned by `keys`, so the filter is left
+    /// untouched if `is_atomic_in_progress` returns `true`. As it is called with the filter locked,
+    /// and keys are inserted into the filter after the atomic batch is started, no such key can be missed.
+    pub fn rebuild<Q: Borrow<K>, I: Iterator<Item = Q>>(
+        &self,
+        keys: impl FnOnce() -> I,
+        is_atomic_in_progress: impl FnOnce() -> bool,
+    ) -> bool {
+        let mut state = self.state.write();
+        if is_atomic_in_progress() {
+            return false;
+        }
+
+        // Hash the keys, to determine the size of the filter.
+        let hashes = keys().map(|key| hash_key(&state.hasher, key.borrow())).collect::<Vec<_>>();
+        let num_bits = (hashes.len() * BITS_PER_KEY).max(MIN_NUM_BITS).next_power_of_two();
+        let mut bits = vec![0u64; num_bits / 64];
+        hashes.iter().for_each(|hashes| set_bits(&mut bits, *hashes));
+
+        state.bits = Some(bits);
+        state.num_keys = hashes.len();
+        state.num_removals = 0;
+        true
+    }
+}
+
+/// Returns the two hashes of the given key, from which its bit indices are derived.
+fn hash_key<K: Hash>(hasher: &RandomState, key: &K) -> (u64, u64) {
+    let mut hasher = hasher.build_hasher();
+    key.hash(&mut hasher);
+    let h1 = hasher.finish();
+    hasher.write_u8(0);
+    // Ensure the second hash is odd, so that the bit indices are distinct.
+    let h2 = hasher.finish() | 1;
+    (h1, h2)
+}
+
+/// Returns the bit indices for the given hashes, using double hashing.
+fn bit_indices((h1, h2): (u64, u64), num_bits: u64) -> impl Iterator<Item = u64> {
+    (0..NUM_HASHES).map(move |i| h1.wrapping_add(i.wrapping_mul(h2)) % num_bits)
+}
+
+/// Sets the bits for the given hashes.
+fn set_bits(bits: &mut [u64], hashes: (u64, u64)) {
+    let num_bits = bits.len() as u64 * 64;
+    for i in bit_indices(hashes, num_bits) {
+        bits[(i / 64) as usize] |= 1 << (i % 64);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unbuilt_filter() {
+        let filter = MembershipFilter::<u64>::default();
+        assert!(filter.needs_rebuild());
+
+        // An unbuilt filter must report every key as present.
+        filter.insert(&1);
+        assert!(filter.may_contain(&1));
+        assert!(filter.may_contain(&2));
+    }
+
+    #[test]
+    fn test_no_false_negatives() {
+        let filter = MembershipFilter::<u64>::default();
+        assert!(filter.rebuild(|| (0..1000u64), || false));
+        assert!(!filter.needs_rebuild());
+
+        // Insert more keys after the filter is built.
+        (1000..2000).for_each(|key| filter.insert(&key));
+        assert!((0..2000).all(|key| filter.may_contain(&key)));
+
+        // Ensure the false positive rate is reasonable.
+        let false_positives = (2000..12000).filter(|key| filter.may_contain(key)).count();
+        assert!(false_positives < 1000, "Too many false positives: {false_positives}");
+    }
+
+    #[test]
+    fn test_rebuild_is_deferred_during_atomic_batch() {
+        let filter = MembershipFilter::<u64>::default();
+        assert!(!filter.rebuild(|| (0..10u64), || true));
+        assert!(filter.needs_rebuild());
+        assert!(filter.may_contain(&100));
+    }
+
+    #[test]
+    fn test_rebuild_after_removals() {
+        let filter = MembershipFilter::<u64>::default();
+        assert!(filter.rebuild(|| (0..100u64), || false));
+
+        // Remove most of the keys.
+        (0..90).for_each(|_| filter.mark_removed());
+        assert!(filter.needs_rebuild());
+
+        // The stale filter still reports the removed keys as present.
+        assert!((0..100).all(|key| filter.may_contain(&key)));
+
+        // Rebuild the filter from the remaining keys.
+        assert!(filter.rebuild(|| (90..100u64), || false));
+        assert!(!filter.needs_rebuild());
+        assert!((90..100).all(|key| filter.may_contain(&key)));
+        assert!((0..90).filter(|key| filter.may_contain(key)).count() < 10);
+    }
+
+    #[test]
+    fn test_rebuild_when_full() {
+        let filter = MembershipFilter::<u64>::default();
+        assert!(filter.rebuild(core::iter::empty::<u64>, || false));
+
+        // Fill the filter well beyond its capacity.
+        (0..(2 * MIN_NUM_BITS / BITS_PER_KEY + 1) as u64).for_each(|key| filter.insert(&key));
+        assert!(filter.needs_rebuild());
+    }
+}
@@ -73,8 +73,9 @@ impl<N: Network> BlockStorage<N> for BlockMemory<N> {
     fn open(dev: Option<u16>) -> Result<Self> {
         // Initialize the transition store.
         let transition_store = TransitionStore::<N, TransitionMemory<N>>::open(dev)?;
-        // Initialize the transaction store.
-        let transaction_store = TransactionStore::<N, TransactionMemory<N>>::open(transition_store)?;
+        // Initialize the transaction store, which must retain the transactions of the blocks.
+        let transaction_store =
+            TransactionStore::<N, TransactionMemory<N>>::open(transition_store)?.owned_by_block_store()?;
         // Return the block storage.
         Ok(Self {
             state_root_map: MemoryMap::default(),
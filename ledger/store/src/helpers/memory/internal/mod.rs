@@ -14,21 +14,62 @@
 
 #![allow(clippy::type_complexity)]
 
-use crate::helpers::{Map, MapRead};
+mod snapshot;
+pub use snapshot::*;
+
+use crate::helpers::{Entry, EntryGuard, Map, MapRead};
 use console::network::prelude::*;
 use indexmap::IndexMap;
 
-use core::{borrow::Borrow, hash::Hash};
-use parking_lot::{Mutex, RwLock};
+use core::{borrow::Borrow, hash::Hash, ops::Bound};
+use parking_lot::{Mutex, RwLock, RwLockUpgradableReadGuard, RwLockWriteGuard};
 use std::{
     borrow::Cow,
     collections::{btree_map, BTreeMap},
     sync::{
-        atomic::{AtomicBool, Ordering},
+        atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering},
         Arc,
     },
 };
 
+/// An iterator over a copy of a `MemoryMap`, which yields the entries of the map at the time the copy was taken.
+/// A write committed to the map while the iterator is live makes it stale, which is reported by `is_stale`
+/// and `try_next`. Note: `next` does not check for this, and keeps yielding the entries of the copy;
+/// callers that must not act on outdated entries should use `try_next`, or check `is_stale`.
+pub struct MemoryMapIter<I> {
+    /// The iterator over the copy of the map.
+    iter: I,
+    /// The version of the map.
+    version: Arc<AtomicU64>,
+    /// The version of the map when the copy was taken.
+    expected: u64,
+}
+
+impl<I: Iterator> MemoryMapIter<I> {
+    /// Returns `true` if a write was committed to the map since the copy was taken.
+    pub fn is_stale(&self) -> bool {
+        self.version.load(Ordering::SeqCst) != self.expected
+    }
+
+    /// Returns the next entry of the copy, or an error if the iterator is stale.
+    pub fn try_next(&mut self) -> Result<Option<I::Item>> {
+        ensure!(!self.is_stale(), "The memory map was modified while it was being iterated over");
+        Ok(self.iter.next())
+    }
+}
+
+impl<I: Iterator> Iterator for MemoryMapIter<I> {
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
 #[derive(Clone)]
 pub struct MemoryMap<
     K: Copy + Clone + PartialEq + Eq + Hash + Serialize + for<'de> Deserialize<'de> + Send + Sync,
@@ -37,10 +78,16 @@ pub struct MemoryMap<
     // The reason for using BTreeMap with binary keys is for the order of items to be the same as
     // the one in the RocksDB-backed DataMap; if not for that, it could be any map
     // with fast lookups and the keys could be typed (i.e. just `K` instead of `Vec<u8>`).
-    map: Arc<RwLock<BTreeMap<Vec<u8>, V>>>,
+    // The map is shared with its snapshots, and is copied on the first write after a snapshot is taken.
+    map: Arc<RwLock<Arc<BTreeMap<Vec<u8>, V>>>>,
     batch_in_progress: Arc<AtomicBool>,
     atomic_batch: Arc<Mutex<Vec<(K, Option<V>)>>>,
     checkpoint: Arc<Mutex<Vec<usize>>>,
+    // The number of writes committed to the map, which is used to detect whether the map has changed
+    // since an iterator over it was created. It is only updated under the write lock.
+    version: Arc<AtomicU64>,
+    // The number of entries removed from the map since it was last compacted.
+    num_removed: Arc<AtomicUsize>,
 }
 
 impl<
@@ -54,6 +101,8 @@ impl<
             batch_in_progress: Default::default(),
             atomic_batch: Default::default(),
             checkpoint: Default::default(),
+            version: Default::default(),
+            num_removed: Default::default(),
         }
     }
 }
@@ -70,14 +119,59 @@ impl<
         let map = iter.into_iter().map(|(k, v)| (bincode::serialize(&k).unwrap(), v)).collect();
         // Return the new map.
         Self {
-            map: Arc::new(RwLock::new(map)),
+            map: Arc::new(RwLock::new(Arc::new(map))),
             batch_in_progress: Default::default(),
             atomic_batch: Default::default(),
             checkpoint: Default::default(),
+            version: Default::default(),
+            num_removed: Default::default(),
         }
     }
 }
 
+impl<
+    K: Copy + Clone + PartialEq + Eq + Hash + Serialize + for<'de> Deserialize<'de> + Send + Sync,
+    V: Clone + PartialEq + Eq + Serialize + for<'de> Deserialize<'de> + Send + Sync,
+> MemoryMap<K, V>
+{
+    /// Returns a point-in-time view of the committed state of the map.
+    /// Writes to the map after the snapshot is taken, including pending atomic batches, are not visible in it.
+    /// The snapshot shares the entries of the map, so taking it does not copy them; instead,
+    /// the map copies its entries on the first write after the snapshot is taken.
+    pub fn snapshot(&self) -> MemoryMapSnapshot<K, V> {
+        MemoryMapSnapshot::new(self.map.read().clone())
+    }
+}
+
+#[cfg(not(feature = "verbose-debug"))]
+impl<
+    K: Copy + Clone + PartialEq + Eq + Hash + Serialize + for<'de> Deserialize<'de> + Send + Sync,
+    V: Clone + PartialEq + Eq + Serialize + for<'de> Deserialize<'de> + Send + Sync,
+> Debug for MemoryMap<K, V>
+{
+    /// Formats the map as its type and number of entries, without its contents.
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct(&format!("MemoryMap<{}, {}>", core::any::type_name::<K>(), core::any::type_name::<V>()))
+            .field("len", &self.map.read().len())
+            .finish()
+    }
+}
+
+#[cfg(feature = "verbose-debug")]
+impl<
+    K: Copy + Clone + Debug + PartialEq + Eq + Hash + Serialize + for<'de> Deserialize<'de> + Send + Sync,
+    V: Clone + Debug + PartialEq + Eq + Serialize + for<'de> Deserialize<'de> + Send + Sync,
+> Debug for MemoryMap<K, V>
+{
+    /// Formats the map with all of its entries.
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let map = self.map.read();
+        // Note: The 'unwrap' is safe here, because the keys are defined by us.
+        let entries = map.iter().map(|(k, v)| (bincode::deserialize::<K>(k).unwrap(), v));
+        f.debug_map().entries(entries).finish()
+    }
+}
+
 impl<
     'a,
     K: 'a + Copy + Clone + PartialEq + Eq + Hash + Serialize + for<'de> Deserialize<'de> + Send + Sync,
@@ -96,7 +190,10 @@ impl<
             }
             // Otherwise, insert the key-value pair directly into the map.
             false => {
-                self.map.write().insert(bincode::serialize(&key)?, value);
+                let raw_key = bincode::serialize(&key)?;
+                let mut locked_map = self.map.write();
+                self.version.fetch_add(1, Ordering::SeqCst);
+                Arc::make_mut(&mut *locked_map).insert(raw_key, value);
             }
         }
 
@@ -115,13 +212,36 @@ impl<
             }
             // Otherwise, remove the key-value pair directly from the map.
             false => {
-                self.map.write().remove(&bincode::serialize(&key)?);
+                let raw_key = bincode::serialize(&key)?;
+                let mut locked_map = self.map.write();
+                self.version.fetch_add(1, Ordering::SeqCst);
+                if Arc::make_mut(&mut *locked_map).remove(&raw_key).is_some() {
+                    self.num_removed.fetch_add(1, Ordering::SeqCst);
+                }
             }
         }
 
         Ok(())
     }
 
+    ///
+    /// Returns the entry for the given key, which holds a lock on the map until it is dropped.
+    /// This allows checking for the key and inserting it as a single atomic operation.
+    /// If an atomic batch is in progress, the entry reflects and updates the batch.
+    ///
+    fn entry(&'a self, key: K) -> Result<Entry<'a, K, V>> {
+        // Acquire an upgradable lock on the map, which excludes any writers until the entry is dropped.
+        let lock = self.map.upgradable_read();
+        // Serialize the key.
+        let raw_key = bincode::serialize(&key)?;
+        // Retrieve the current value, from the atomic batch first, if it exists.
+        let value = match self.get_pending(&key) {
+            Some(value) => value,
+            None => lock.get(&raw_key).cloned(),
+        };
+        Ok(Entry::new(key, value, Box::new(MemoryEntryGuard { map: self, lock: Some(lock), raw_key })))
+    }
+
     ///
     /// Begins an atomic operation. Any further calls to `insert` and `remove` will be queued
     /// without an actual write taking place until `finish_atomic` is called.
@@ -212,11 +332,20 @@ impl<
                 .map(|(key, value)| Ok((bincode::serialize(&key)?, value)))
                 .collect::<Result<Vec<_>>>()?;
 
+            // Increment the version of the map.
+            self.version.fetch_add(1, Ordering::SeqCst);
+
             // Perform all the queued operations.
+            let locked_map = Arc::make_mut(&mut *locked_map);
             for (key, value) in prepared_operations {
                 match value {
                     Some(value) => locked_map.insert(key, value),
-                    None => locked_map.remove(&key),
+                    None => {
+                        if locked_map.remove(&key).is_some() {
+                            self.num_removed.fetch_add(1, Ordering::SeqCst);
+                        }
+                        None
+                    }
                 };
             }
         }
@@ -228,6 +357,27 @@ impl<
 
         Ok(())
     }
+
+    ///
+    /// Rebuilds the map, as removals leave its nodes partially filled, and returns
+    /// the number of entries removed since the map was last compacted.
+    ///
+    fn compact(&self) -> Result<usize> {
+        // Ensure an atomic batch is not in progress.
+        ensure!(!self.is_atomic_in_progress(), "Cannot compact the map while an atomic batch is in progress");
+        // Acquire a write lock on the map.
+        let mut locked_map = self.map.write();
+        // Retrieve and reset the number of removed entries.
+        let num_removed = self.num_removed.swap(0, Ordering::SeqCst);
+        // If entries were removed, rebuild the map, as a map built from sorted entries is densely packed.
+        if num_removed > 0 {
+            let locked_map = Arc::make_mut(&mut *locked_map);
+            *locked_map = core::mem::take(locked_map).into_iter().collect();
+            self.checkpoint.lock().shrink_to_fit();
+            self.atomic_batch.lock().shrink_to_fit();
+        }
+        Ok(num_removed)
+    }
 }
 
 impl<
@@ -236,11 +386,12 @@ impl<
     V: 'a + Clone + PartialEq + Eq + Serialize + for<'de> Deserialize<'de> + Send + Sync,
 > MapRead<'a, K, V> for MemoryMap<K, V>
 {
-    type Iterator = core::iter::Map<btree_map::IntoIter<Vec<u8>, V>, fn((Vec<u8>, V)) -> (Cow<'a, K>, Cow<'a, V>)>;
-    type Keys = core::iter::Map<btree_map::IntoKeys<Vec<u8>, V>, fn(Vec<u8>) -> Cow<'a, K>>;
+    type Iterator =
+        MemoryMapIter<core::iter::Map<btree_map::IntoIter<Vec<u8>, V>, fn((Vec<u8>, V)) -> (Cow<'a, K>, Cow<'a, V>)>>;
+    type Keys = MemoryMapIter<core::iter::Map<btree_map::IntoKeys<Vec<u8>, V>, fn(Vec<u8>) -> Cow<'a, K>>>;
     type PendingIterator =
         core::iter::Map<indexmap::map::IntoIter<K, Option<V>>, fn((K, Option<V>)) -> (Cow<'a, K>, Option<Cow<'a, V>>)>;
-    type Values = core::iter::Map<btree_map::IntoValues<Vec<u8>, V>, fn(V) -> Cow<'a, V>>;
+    type Values = MemoryMapIter<core::iter::Map<btree_map::IntoValues<Vec<u8>, V>, fn(V) -> Cow<'a, V>>>;
 
     ///
     /// Returns `true` if the given key exists in the map.
@@ -288,6 +439,18 @@ impl<
         Ok(self.map.read().get(&bincode::serialize(key)?).cloned().map(Cow::Owned))
     }
 
+    ///
+    /// Returns the values for the given keys from the map, in the order of the given keys.
+    /// The map is read under a single lock, so a concurrent atomic batch is either fully visible or not at all.
+    ///
+    fn get_many_confirmed(&'a self, keys: &[K]) -> Result<Vec<Option<Cow<'a, V>>>> {
+        // Serialize the keys before acquiring the lock.
+        let raw_keys = keys.iter().map(bincode::serialize).collect::<Result<Vec<_>, _>>()?;
+        // Acquire a read lock on the map.
+        let locked_map = self.map.read();
+        Ok(raw_keys.iter().map(|raw_key| locked_map.get(raw_key).cloned().map(Cow::Owned)).collect())
+    }
+
     ///
     /// Returns the current value for the given key if it is scheduled
     /// to be inserted as part of an atomic batch.
@@ -322,23 +485,60 @@ impl<
     /// Returns an iterator visiting each key-value pair in the map.
     ///
     fn iter_confirmed(&'a self) -> Self::Iterator {
-        // Note: The 'unwrap' is safe here, because the keys are defined by us.
-        self.map.read().clone().into_iter().map(|(k, v)| (Cow::Owned(bincode::deserialize(&k).unwrap()), Cow::Owned(v)))
+        let map = self.map.read();
+        MemoryMapIter {
+            // Note: The 'unwrap' is safe here, because the keys are defined by us.
+            iter: (**map)
+                .clone()
+                .into_iter()
+                .map(|(k, v)| (Cow::Owned(bincode::deserialize(&k).unwrap()), Cow::Owned(v))),
+            version: self.version.clone(),
+            expected: self.version.load(Ordering::SeqCst),
+        }
+    }
+
+    ///
+    /// Returns an iterator visiting each key-value pair in the map, in the order of their serialized keys,
+    /// starting from the first key whose serialized form is not less than the given `raw_key`.
+    ///
+    fn iter_confirmed_from(&'a self, raw_key: &[u8]) -> Self::Iterator {
+        let map = self.map.read();
+        let range = map.range::<[u8], _>((Bound::Included(raw_key), Bound::Unbounded));
+        MemoryMapIter {
+            // Note: The 'unwrap' is safe here, because the keys are defined by us.
+            iter: range
+                .map(|(k, v)| (k.clone(), v.clone()))
+                .collect::<BTreeMap<_, _>>()
+                .into_iter()
+                .map(|(k, v)| (Cow::Owned(bincode::deserialize(&k).unwrap()), Cow::Owned(v))),
+            version: self.version.clone(),
+            expected: self.version.load(Ordering::SeqCst),
+        }
     }
 
     ///
     /// Returns an iterator over each key in the map.
     ///
     fn keys_confirmed(&'a self) -> Self::Keys {
-        // Note: The 'unwrap' is safe here, because the keys are defined by us.
-        self.map.read().clone().into_keys().map(|k| Cow::Owned(bincode::deserialize(&k).unwrap()))
+        let map = self.map.read();
+        MemoryMapIter {
+            // Note: The 'unwrap' is safe here, because the keys are defined by us.
+            iter: (**map).clone().into_keys().map(|k| Cow::Owned(bincode::deserialize(&k).unwrap())),
+            version: self.version.clone(),
+            expected: self.version.load(Ordering::SeqCst),
+        }
     }
 
     ///
     /// Returns an iterator over each value in the map.
     ///
     fn values_confirmed(&'a self) -> Self::Values {
-        self.map.read().clone().into_values().map(Cow::Owned)
+        let map = self.map.read();
+        MemoryMapIter {
+            iter: (**map).clone().into_values().map(Cow::Owned),
+            version: self.version.clone(),
+            expected: self.version.load(Ordering::SeqCst),
+        }
     }
 }
 
@@ -347,13 +547,84 @@ impl<
     V: Clone + PartialEq + Eq + Serialize + for<'de> Deserialize<'de> + Send + Sync,
 > Deref for MemoryMap<K, V>
 {
-    type Target = Arc<RwLock<BTreeMap<Vec<u8>, V>>>;
+    type Target = Arc<RwLock<Arc<BTreeMap<Vec<u8>, V>>>>;
 
     fn deref(&self) -> &Self::Target {
         &self.map
     }
 }
 
+/// The lock on a `MemoryMap` held by an entry, which is upgraded to a write lock for each write.
+struct MemoryEntryGuard<
+    'a,
+    K: Copy + Clone + PartialEq + Eq + Hash + Serialize + for<'de> Deserialize<'de> + Send + Sync,
+    V: Clone + PartialEq + Eq + Serialize + for<'de> Deserialize<'de> + Send + Sync,
+> {
+    /// The map.
+    map: &'a MemoryMap<K, V>,
+    /// The upgradable lock on the map; this is only `None` while it is being upgraded.
+    lock: Option<RwLockUpgradableReadGuard<'a, Arc<BTreeMap<Vec<u8>, V>>>>,
+    /// The serialized key of the entry.
+    raw_key: Vec<u8>,
+}
+
+impl<
+    'a,
+    K: Copy + Clone + PartialEq + Eq + Hash + Serialize + for<'de> Deserialize<'de> + Send + Sync,
+    V: Clone + PartialEq + Eq + Serialize + for<'de> Deserialize<'de> + Send + Sync,
+> MemoryEntryGuard<'a, K, V>
+{
+    /// Performs the given write on the map, by temporarily upgrading the lock to a write lock.
+    fn write<T>(&mut self, f: impl FnOnce(&mut BTreeMap<Vec<u8>, V>) -> T) -> T {
+        // Note: The 'expect' is safe here, as the lock is always restored below.
+        let mut lock = RwLockUpgradableReadGuard::upgrade(self.lock.take().expect("The entry lock is missing"));
+        // Increment the version of the map.
+        self.map.version.fetch_add(1, Ordering::SeqCst);
+        let output = f(Arc::make_mut(&mut *lock));
+        self.lock = Some(RwLockWriteGuard::downgrade_to_upgradable(lock));
+        output
+    }
+}
+
+impl<
+    'a,
+    K: Copy + Clone + PartialEq + Eq + Hash + Serialize + for<'de> Deserialize<'de> + Send + Sync,
+    V: Clone + PartialEq + Eq + Serialize + for<'de> Deserialize<'de> + Send + Sync,
+> EntryGuard<K, V> for MemoryEntryGuard<'a, K, V>
+{
+    /// Inserts the given key-value pair into the map.
+    fn insert(&mut self, key: K, value: V) -> Result<()> {
+        // Determine if an atomic batch is in progress.
+        match self.map.batch_in_progress.load(Ordering::SeqCst) {
+            // If a batch is in progress, add the key-value pair to the batch.
+            true => self.map.atomic_batch.lock().push((key, Some(value))),
+            // Otherwise, insert the key-value pair directly into the map.
+            false => {
+                let raw_key = self.raw_key.clone();
+                self.write(|map| map.insert(raw_key, value));
+            }
+        }
+        Ok(())
+    }
+
+    /// Removes the key-value pair for the given key from the map.
+    fn remove(&mut self, key: &K) -> Result<()> {
+        // Determine if an atomic batch is in progress.
+        match self.map.batch_in_progress.load(Ordering::SeqCst) {
+            // If a batch is in progress, add the key-None pair to the batch.
+            true => self.map.atomic_batch.lock().push((*key, None)),
+            // Otherwise, remove the key-value pair directly from the map.
+            false => {
+                let raw_key = self.raw_key.clone();
+                if self.write(|map| map.remove(&raw_key)).is_some() {
+                    self.map.num_removed.fetch_add(1, Ordering::SeqCst);
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -362,6 +633,13 @@ mod tests {
 
     type CurrentNetwork = Testnet3;
 
+    #[cfg(not(feature = "verbose-debug"))]
+    #[test]
+    fn test_debug() {
+        let map: MemoryMap<u32, u64> = (0..1000).map(|i| (i, i as u64)).collect();
+        assert_eq!(format!("{map:?}"), "MemoryMap<u32, u64> { len: 1000 }");
+    }
+
     #[test]
     fn test_contains_key() {
         // Initialize an address.
@@ -378,6 +656,57 @@ mod tests {
         assert!(map.contains_key_confirmed(&address).unwrap());
     }
 
+    #[test]
+    fn test_get_many_confirmed() {
+        // Initialize a map.
+        let map: MemoryMap<usize, String> = (0..10).map(|i| (i, i.to_string())).collect();
+
+        // Ensure the values align with the keys, including missing keys.
+        let values = map.get_many_confirmed(&[3, 42, 0, 3, 10]).unwrap();
+        assert_eq!(values, vec![
+            Some(Cow::Owned("3".to_string())),
+            None,
+            Some(Cow::Owned("0".to_string())),
+            Some(Cow::Owned("3".to_string())),
+            None
+        ]);
+        assert!(map.get_many_confirmed(&[]).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_get_many_confirmed_concurrent() {
+        const NUM_KEYS: usize = 64;
+        const NUM_ROUNDS: usize = 200;
+
+        // Initialize a map, where every key has the same value.
+        let map: MemoryMap<usize, usize> = (0..NUM_KEYS).map(|i| (i, 0)).collect();
+        let keys = (0..NUM_KEYS).collect::<Vec<_>>();
+
+        std::thread::scope(|scope| {
+            // Update every key to the next round in one atomic batch.
+            scope.spawn(|| {
+                for round in 1..=NUM_ROUNDS {
+                    map.start_atomic();
+                    for key in 0..NUM_KEYS {
+                        map.insert(key, round).unwrap();
+                    }
+                    map.finish_atomic().unwrap();
+                }
+            });
+            // Ensure every batch read observes all keys from the same round.
+            scope.spawn(|| {
+                for _ in 0..NUM_ROUNDS {
+                    let values = map.get_many_confirmed(&keys).unwrap();
+                    let first = values[0].clone().unwrap();
+                    assert!(values.iter().all(|value| value.as_ref() == Some(&first)));
+                }
+            });
+        });
+
+        // Ensure the final round is visible.
+        assert!(map.get_many_confirmed(&keys).unwrap().into_iter().all(|value| value == Some(Cow::Owned(NUM_ROUNDS))));
+    }
+
     #[test]
     fn test_insert_and_get_speculative() {
         // Initialize a map.
@@ -1107,4 +1436,218 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_entry() {
+        // Initialize a map.
+        let map: MemoryMap<usize, String> = Default::default();
+
+        // Insert into a vacant entry.
+        match map.entry(0).unwrap() {
+            Entry::Vacant(entry) => entry.insert("0".to_string()).unwrap(),
+            Entry::Occupied(_) => panic!("The entry should be vacant"),
+        }
+        assert_eq!(map.get_confirmed(&0).unwrap(), Some(Cow::Owned("0".to_string())));
+
+        // Update an occupied entry.
+        match map.entry(0).unwrap() {
+            Entry::Occupied(mut entry) => {
+                assert_eq!(entry.get(), "0");
+                assert_eq!(entry.insert("1".to_string()).unwrap(), "0");
+                assert_eq!(entry.get(), "1");
+            }
+            Entry::Vacant(_) => panic!("The entry should be occupied"),
+        }
+        assert_eq!(map.get_confirmed(&0).unwrap(), Some(Cow::Owned("1".to_string())));
+
+        // Ensure `or_insert` only inserts into a vacant entry.
+        assert_eq!(map.entry(0).unwrap().or_insert("2".to_string()).unwrap(), "1");
+        assert_eq!(map.entry(1).unwrap().or_insert("2".to_string()).unwrap(), "2");
+        assert_eq!(map.get_confirmed(&1).unwrap(), Some(Cow::Owned("2".to_string())));
+
+        // Remove an occupied entry.
+        match map.entry(1).unwrap() {
+            Entry::Occupied(entry) => assert_eq!(entry.remove().unwrap(), "2"),
+            Entry::Vacant(_) => panic!("The entry should be occupied"),
+        }
+        assert!(map.get_confirmed(&1).unwrap().is_none());
+
+        /* test entries within an atomic batch */
+
+        // Start an atomic write batch.
+        map.start_atomic();
+
+        // Ensure the entry reflects the batch.
+        map.insert(2, "3".to_string()).unwrap();
+        assert_eq!(map.entry(2).unwrap().or_insert("4".to_string()).unwrap(), "3");
+
+        // Ensure the writes through the entry are queued in the batch.
+        assert_eq!(map.entry(3).unwrap().or_insert("4".to_string()).unwrap(), "4");
+        assert!(map.get_confirmed(&3).unwrap().is_none());
+        assert_eq!(map.get_pending(&3), Some(Some("4".to_string())));
+
+        // Finish the current atomic write batch.
+        map.finish_atomic().unwrap();
+        assert_eq!(map.get_confirmed(&2).unwrap(), Some(Cow::Owned("3".to_string())));
+        assert_eq!(map.get_confirmed(&3).unwrap(), Some(Cow::Owned("4".to_string())));
+    }
+
+    #[test]
+    fn test_entry_concurrent() {
+        const NUM_THREADS: usize = 8;
+        const NUM_INCREMENTS: usize = 100;
+
+        // Initialize a map.
+        let map: MemoryMap<usize, usize> = Default::default();
+
+        // Increment the same counter from several threads, checking for and inserting it through an entry.
+        std::thread::scope(|scope| {
+            for _ in 0..NUM_THREADS {
+                scope.spawn(|| {
+                    for _ in 0..NUM_INCREMENTS {
+                        match map.entry(0).unwrap() {
+                            Entry::Occupied(mut entry) => {
+                                let value = *entry.get();
+                                entry.insert(value + 1).unwrap();
+                            }
+                            Entry::Vacant(entry) => entry.insert(1).unwrap(),
+                        }
+                    }
+                });
+            }
+        });
+
+        // Ensure no increment was lost.
+        assert_eq!(map.get_confirmed(&0).unwrap(), Some(Cow::Owned(NUM_THREADS * NUM_INCREMENTS)));
+    }
+
+    #[test]
+    fn test_entry_no_deadlock() {
+        // Initialize a map.
+        let map: MemoryMap<usize, usize> = Default::default();
+
+        // Ensure the map is writable once the entry is dropped, both directly and in an atomic batch.
+        map.entry(0).unwrap().or_insert(0).unwrap();
+        map.insert(0, 1).unwrap();
+        map.start_atomic();
+        map.entry(1).unwrap().or_insert(1).unwrap();
+        map.insert(2, 2).unwrap();
+        map.finish_atomic().unwrap();
+        map.start_atomic();
+        map.entry(3).unwrap().or_insert(3).unwrap();
+        map.abort_atomic();
+        assert_eq!(map.iter_confirmed().count(), 3);
+
+        // Ensure an entry in another thread waits for the entry to be dropped, instead of deadlocking.
+        let map = &map;
+        let (sender, receiver) = std::sync::mpsc::channel();
+        std::thread::scope(|scope| {
+            let entry = map.entry(4).unwrap();
+            scope.spawn(move || sender.send(map.entry(4).unwrap().or_insert(5).unwrap()).unwrap());
+            assert!(receiver.recv_timeout(std::time::Duration::from_millis(100)).is_err());
+            // Ensure the other thread proceeds once the entry is dropped, and observes its value.
+            entry.or_insert(4).unwrap();
+            assert_eq!(receiver.recv_timeout(std::time::Duration::from_secs(10)).unwrap(), 4);
+        });
+    }
+
+    #[test]
+    fn test_compact() {
+        // Initialize a map.
+        let map: MemoryMap<usize, usize> = Default::default();
+
+        // Insert 10,000 entries, and remove 9,000 of them.
+        for i in 0..10_000 {
+            map.insert(i, i).unwrap();
+        }
+        for i in 0..9_000 {
+            map.remove(&i).unwrap();
+        }
+        // Removing a missing key is not counted.
+        map.remove(&0).unwrap();
+
+        // Ensure the map cannot be compacted while an atomic batch is in progress.
+        map.start_atomic();
+        assert!(map.compact().is_err());
+        map.abort_atomic();
+
+        // Compact the map, and ensure the removed entries are reported once.
+        assert_eq!(map.compact().unwrap(), 9_000);
+        assert_eq!(map.compact().unwrap(), 0);
+
+        // Ensure the remaining entries are still readable.
+        assert_eq!(map.iter_confirmed().count(), 1_000);
+        for i in 0..10_000 {
+            let expected = if i < 9_000 { None } else { Some(Cow::Owned(i)) };
+            assert_eq!(map.get_confirmed(&i).unwrap(), expected);
+        }
+
+        // Ensure removals in an atomic batch are counted once the batch is finished.
+        map.start_atomic();
+        map.remove(&9_000).unwrap();
+        map.finish_atomic().unwrap();
+        assert_eq!(map.compact().unwrap(), 1);
+    }
+
+    #[test]
+    fn test_iter_after_modification() {
+        // Initialize a map.
+        let map: MemoryMap<usize, String> = [(0, "0".to_string()), (1, "1".to_string())].into_iter().collect();
+
+        // Start iterating over the map.
+        let mut iter = map.iter_confirmed();
+        assert_eq!(iter.next(), Some((Cow::Owned(0), Cow::Owned("0".to_string()))));
+
+        // Modify the map, and ensure the iterator keeps yielding the entries of its copy.
+        map.insert(2, "2".to_string()).unwrap();
+        assert!(iter.is_stale());
+        assert_eq!(iter.next(), Some((Cow::Owned(1), Cow::Owned("1".to_string()))));
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn test_iter_stale() {
+        // Initialize a map.
+        let map: MemoryMap<usize, String> = [(0, "0".to_string()), (1, "1".to_string())].into_iter().collect();
+
+        // Ensure an atomic batch which is in progress or aborted does not make a live iterator stale.
+        let mut keys = map.keys_confirmed();
+        map.start_atomic();
+        map.insert(2, "2".to_string()).unwrap();
+        assert!(!keys.is_stale());
+        map.abort_atomic();
+        map.start_atomic();
+        map.finish_atomic().unwrap();
+        assert!(!keys.is_stale());
+        assert!(keys.try_next().unwrap().is_some());
+        assert_eq!(keys.count(), 1);
+
+        // Ensure each kind of write to the map makes a live iterator stale.
+        type Write = fn(&MemoryMap<usize, String>);
+        let writes: [Write; 4] = [
+            |map| map.insert(3, "3".to_string()).unwrap(),
+            |map| map.remove(&3).unwrap(),
+            |map| {
+                map.start_atomic();
+                map.insert(3, "3".to_string()).unwrap();
+                map.finish_atomic().unwrap();
+            },
+            |map| {
+                map.entry(4).unwrap().or_insert("4".to_string()).unwrap();
+            },
+        ];
+        for write in writes {
+            let mut iter = map.iter_confirmed();
+            let values = map.values_confirmed();
+            let num_values = values.size_hint().0;
+            write(&map);
+            assert!(iter.is_stale() && values.is_stale());
+            assert!(iter.try_next().is_err());
+            // Ensure a stale iterator still yields the entries of its copy, in any build.
+            assert_eq!(values.count(), num_values);
+            // Ensure a new iterator is not stale.
+            assert!(!map.iter_confirmed().is_stale());
+            assert_eq!(map.iter_confirmed().count(), map.keys_confirmed().count());
+        }
+    }
 }
@@ -0,0 +1,163 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![allow(clippy::type_complexity)]
+
+use super::MemoryMap;
+use crate::helpers::MapRead;
+use console::network::prelude::*;
+
+use core::{borrow::Borrow, hash::Hash, marker::PhantomData, ops::Bound};
+use parking_lot::RwLock;
+use std::{
+    borrow::Cow,
+    collections::{btree_map, BTreeMap},
+    sync::Arc,
+};
+
+/// A point-in-time, read-only view of the committed state of a `MemoryMap`, which shares its entries with the map.
+#[derive(Clone)]
+pub struct MemoryMapSnapshot<
+    K: Copy + Clone + PartialEq + Eq + Hash + Serialize + for<'de> Deserialize<'de> + Send + Sync,
+    V: Clone + PartialEq + Eq + Serialize + for<'de> Deserialize<'de> + Send + Sync,
+> {
+    map: Arc<BTreeMap<Vec<u8>, V>>,
+    _phantom: PhantomData<K>,
+}
+
+impl<
+    K: Copy + Clone + PartialEq + Eq + Hash + Serialize + for<'de> Deserialize<'de> + Send + Sync,
+    V: Clone + PartialEq + Eq + Serialize + for<'de> Deserialize<'de> + Send + Sync,
+> MemoryMapSnapshot<K, V>
+{
+    /// Initializes a new snapshot from the given shared map.
+    pub(super) fn new(map: Arc<BTreeMap<Vec<u8>, V>>) -> Self {
+        Self { map, _phantom: PhantomData }
+    }
+}
+
+impl<
+    K: Copy + Clone + PartialEq + Eq + Hash + Serialize + for<'de> Deserialize<'de> + Send + Sync,
+    V: Clone + PartialEq + Eq + Serialize + for<'de> Deserialize<'de> + Send + Sync,
+> From<MemoryMapSnapshot<K, V>> for MemoryMap<K, V>
+{
+    /// Initializes a new `MemoryMap` from the given snapshot, which is independent of the snapshotted map.
+    /// Note: The new map shares the entries of the snapshot, and copies them on its first write.
+    fn from(snapshot: MemoryMapSnapshot<K, V>) -> Self {
+        Self { map: Arc::new(RwLock::new(snapshot.map)), ..Default::default() }
+    }
+}
+
+impl<
+    'a,
+    K: 'a + Copy + Clone + PartialEq + Eq + Hash + Serialize + for<'de> Deserialize<'de> + Send + Sync,
+    V: 'a + Clone + PartialEq + Eq + Serialize + for<'de> Deserialize<'de> + Send + Sync,
+> MapRead<'a, K, V> for MemoryMapSnapshot<K, V>
+{
+    type Iterator =
+        core::iter::Map<btree_map::Iter<'a, Vec<u8>, V>, fn((&'a Vec<u8>, &'a V)) -> (Cow<'a, K>, Cow<'a, V>)>;
+    type Keys = core::iter::Map<btree_map::Keys<'a, Vec<u8>, V>, fn(&'a Vec<u8>) -> Cow<'a, K>>;
+    type PendingIterator = core::iter::Empty<(Cow<'a, K>, Option<Cow<'a, V>>)>;
+    type Values = core::iter::Map<btree_map::Values<'a, Vec<u8>, V>, fn(&'a V) -> Cow<'a, V>>;
+
+    ///
+    /// Returns `true` if the given key exists in the map.
+    ///
+    fn contains_key_confirmed<Q>(&self, key: &Q) -> Result<bool>
+    where
+        K: Borrow<Q>,
+        Q: PartialEq + Eq + Hash + Serialize + ?Sized,
+    {
+        Ok(self.map.contains_key(&bincode::serialize(key)?))
+    }
+
+    ///
+    /// Returns `true` if the given key exists in the map.
+    /// As a snapshot has no atomic batch, this is the same as `contains_key_confirmed`.
+    ///
+    fn contains_key_speculative<Q>(&self, key: &Q) -> Result<bool>
+    where
+        K: Borrow<Q>,
+        Q: PartialEq + Eq + Hash + Serialize + ?Sized,
+    {
+        self.contains_key_confirmed(key)
+    }
+
+    ///
+    /// Returns the value for the given key from the map, if it exists.
+    ///
+    fn get_confirmed<Q>(&'a self, key: &Q) -> Result<Option<Cow<'a, V>>>
+    where
+        K: Borrow<Q>,
+        Q: PartialEq + Eq + Hash + Serialize + ?Sized,
+    {
+        Ok(self.map.get(&bincode::serialize(key)?).map(Cow::Borrowed))
+    }
+
+    ///
+    /// Returns `None`, as a snapshot has no atomic batch.
+    ///
+    fn get_pending<Q>(&self, _key: &Q) -> Option<Option<V>>
+    where
+        K: Borrow<Q>,
+        Q: PartialEq + Eq + Hash + Serialize + ?Sized,
+    {
+        None
+    }
+
+    ///
+    /// Returns an empty iterator, as a snapshot has no atomic batch.
+    ///
+    fn iter_pending(&'a self) -> Self::PendingIterator {
+        core::iter::empty()
+    }
+
+    ///
+    /// Returns an iterator visiting each key-value pair in the map.
+    ///
+    fn iter_confirmed(&'a self) -> Self::Iterator {
+        // Note: The 'unwrap' is safe here, because the keys are defined by us.
+        self.map.iter().map(|(k, v)| (Cow::Owned(bincode::deserialize(k).unwrap()), Cow::Borrowed(v)))
+    }
+
+    ///
+    /// Returns an iterator visiting each key-value pair in the map, in the order of their serialized keys,
+    /// starting from the first key whose serialized form is not less than the given `raw_key`.
+    ///
+    fn iter_confirmed_from(&'a self, raw_key: &[u8]) -> Self::Iterator {
+        // Find the number of keys that are less than the given key, to skip them without copying the map.
+        let num_skipped = self.map.range::<[u8], _>((Bound::Unbounded, Bound::Excluded(raw_key))).count();
+        let mut iter = self.map.iter();
+        if num_skipped > 0 {
+            iter.nth(num_skipped - 1);
+        }
+        // Note: The 'unwrap' is safe here, because the keys are defined by us.
+        iter.map(|(k, v)| (Cow::Owned(bincode::deserialize(k).unwrap()), Cow::Borrowed(v)))
+    }
+
+    ///
+    /// Returns an iterator over each key in the map.
+    ///
+    fn keys_confirmed(&'a self) -> Self::Keys {
+        // Note: The 'unwrap' is safe here, because the keys are defined by us.
+        self.map.keys().map(|k| Cow::Owned(bincode::deserialize(k).unwrap()))
+    }
+
+    ///
+    /// Returns an iterator over each value in the map.
+    ///
+    fn values_confirmed(&'a self) -> Self::Values {
+        self.map.values().map(Cow::Borrowed)
+    }
+}
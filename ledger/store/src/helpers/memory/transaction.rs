@@ -13,13 +13,19 @@
 // limitations under the License.
 
 use crate::{
-    helpers::memory::{MemoryMap, TransitionMemory},
+    cow_to_cloned,
+    helpers::{
+        memory::{MemoryMap, TransitionMemory},
+        MapRead,
+    },
     DeploymentStorage,
     DeploymentStore,
     ExecutionStorage,
     ExecutionStore,
     FeeStorage,
     FeeStore,
+    RejectionReason,
+    RetentionPolicy,
     TransactionStorage,
     TransactionType,
     TransitionStore,
@@ -28,25 +34,50 @@ use console::{
     prelude::*,
     program::{Identifier, ProgramID, ProgramOwner},
 };
+use ledger_block::Transaction;
 use synthesizer_program::Program;
 use synthesizer_snark::{Certificate, Proof, VerifyingKey};
 
+use parking_lot::RwLock;
+use std::{
+    collections::{hash_map::DefaultHasher, HashMap},
+    hash::Hasher,
+    sync::Arc,
+};
+
 /// An in-memory transaction storage.
 #[derive(Clone)]
 pub struct TransactionMemory<N: Network> {
     /// The mapping of `transaction ID` to `transaction type`.
     id_map: MemoryMap<N::TransactionID, TransactionType>,
+    /// The mapping of `transaction ID` to `size in bytes`.
+    size_map: MemoryMap<N::TransactionID, u64>,
+    /// The mapping of `transaction ID` to `(rejected transaction, rejection reason)`.
+    rejected_map: MemoryMap<N::TransactionID, (Transaction<N>, RejectionReason)>,
+    /// The mapping of `transaction ID` to `block height`, for the transactions subject to the retention policy.
+    height_map: MemoryMap<N::TransactionID, u32>,
+    /// The mapping of `(big-endian block height, transaction ID)` to `()`, which orders the transactions by height.
+    height_index_map: MemoryMap<([u8; 4], N::TransactionID), ()>,
+    /// The metadata map holding the retention policy.
+    retention_policy_map: MemoryMap<(), RetentionPolicy>,
     /// The deployment store.
     deployment_store: DeploymentStore<N, DeploymentMemory<N>>,
     /// The execution store.
     execution_store: ExecutionStore<N, ExecutionMemory<N>>,
     /// The fee store.
     fee_store: FeeStore<N, FeeMemory<N>>,
+    /// The lock held exclusively while an atomic batch is committed, and shared while the storage is snapshotted.
+    commit_lock: Arc<RwLock<()>>,
 }
 
 #[rustfmt::skip]
 impl<N: Network> TransactionStorage<N> for TransactionMemory<N> {
     type IDMap = MemoryMap<N::TransactionID, TransactionType>;
+    type SizeMap = MemoryMap<N::TransactionID, u64>;
+    type RejectedMap = MemoryMap<N::TransactionID, (Transaction<N>, RejectionReason)>;
+    type HeightMap = MemoryMap<N::TransactionID, u32>;
+    type HeightIndexMap = MemoryMap<([u8; 4], N::TransactionID), ()>;
+    type RetentionPolicyMap = MemoryMap<(), RetentionPolicy>;
     type DeploymentStorage = DeploymentMemory<N>;
     type ExecutionStorage = ExecutionMemory<N>;
     type FeeStorage = FeeMemory<N>;
@@ -61,7 +92,18 @@ impl<N: Network> TransactionStorage<N> for TransactionMemory<N> {
         // Initialize the execution store.
         let execution_store = ExecutionStore::<N, ExecutionMemory<N>>::open(fee_store.clone())?;
         // Return the transaction storage.
-        Ok(Self { id_map: MemoryMap::default(), deployment_store, execution_store, fee_store })
+        Ok(Self {
+            id_map: MemoryMap::default(),
+            size_map: MemoryMap::default(),
+            rejected_map: MemoryMap::default(),
+            height_map: MemoryMap::default(),
+            height_index_map: MemoryMap::default(),
+            retention_policy_map: MemoryMap::default(),
+            deployment_store,
+            execution_store,
+            fee_store,
+            commit_lock: Default::default(),
+        })
     }
 
     /// Returns the ID map.
@@ -69,6 +111,31 @@ impl<N: Network> TransactionStorage<N> for TransactionMemory<N> {
         &self.id_map
     }
 
+    /// Returns the size map.
+    fn size_map(&self) -> &Self::SizeMap {
+        &self.size_map
+    }
+
+    /// Returns the rejected map.
+    fn rejected_map(&self) -> &Self::RejectedMap {
+        &self.rejected_map
+    }
+
+    /// Returns the height map.
+    fn height_map(&self) -> &Self::HeightMap {
+        &self.height_map
+    }
+
+    /// Returns the height index map.
+    fn height_index_map(&self) -> &Self::HeightIndexMap {
+        &self.height_index_map
+    }
+
+    /// Returns the retention policy map.
+    fn retention_policy_map(&self) -> &Self::RetentionPolicyMap {
+        &self.retention_policy_map
+    }
+
     /// Returns the deployment store.
     fn deployment_store(&self) -> &DeploymentStore<N, Self::DeploymentStorage> {
         &self.deployment_store
@@ -83,6 +150,59 @@ impl<N: Network> TransactionStorage<N> for TransactionMemory<N> {
     fn fee_store(&self) -> &FeeStore<N, Self::FeeStorage> {
         &self.fee_store
     }
+
+    /// Finishes an atomic operation, performing all the queued writes.
+    /// The commit lock is held throughout, so that a snapshot reflects the batch either in full or not at all.
+    fn finish_atomic(&self) -> Result<()> {
+        let _commit_lock = self.commit_lock.write();
+        self.id_map().finish_atomic()?;
+        self.size_map().finish_atomic()?;
+        self.rejected_map().finish_atomic()?;
+        self.height_map().finish_atomic()?;
+        self.height_index_map().finish_atomic()?;
+        self.retention_policy_map().finish_atomic()?;
+        self.deployment_store().finish_atomic()?;
+        self.execution_store().finish_atomic()?;
+        self.fee_store().finish_atomic()
+    }
+}
+
+impl<N: Network> TransactionMemory<N> {
+    /// Initializes the transaction storage with the given `id_map` of transaction types, for testing.
+    /// The remaining maps and stores start empty, so the caller is responsible for any consistency they need
+    /// between the transaction types and the rest of the storage.
+    pub fn from_snapshot(
+        id_map: MemoryMap<N::TransactionID, TransactionType>,
+        transition_store: TransitionStore<N, TransitionMemory<N>>,
+    ) -> Result<Self> {
+        // Initialize the transaction storage.
+        let storage = <Self as TransactionStorage<N>>::open(transition_store)?;
+        // Return the transaction storage, with the given ID map.
+        Ok(Self { id_map, ..storage })
+    }
+
+    /// Returns a point-in-time copy of the committed state of the storage, which is independent of it.
+    /// The maps are snapshotted in a fixed order while the commit lock is shared, so no atomic batch is
+    /// committed in the meantime, and the copy reflects each batch either in full or not at all.
+    /// Note: The copy shares the entries of each map, which are only copied when either side is written to.
+    pub(crate) fn snapshot(&self) -> Self {
+        // Ensure no atomic batch is committed while the maps are snapshotted.
+        let _commit_lock = self.commit_lock.read();
+        // Note: The deployment and execution stores share the fee store, and so must share its copy.
+        let fee_store = self.fee_store.snapshot();
+        Self {
+            id_map: self.id_map.snapshot().into(),
+            size_map: self.size_map.snapshot().into(),
+            rejected_map: self.rejected_map.snapshot().into(),
+            height_map: self.height_map.snapshot().into(),
+            height_index_map: self.height_index_map.snapshot().into(),
+            retention_policy_map: self.retention_policy_map.snapshot().into(),
+            deployment_store: self.deployment_store.snapshot(fee_store.clone()),
+            execution_store: self.execution_store.snapshot(fee_store.clone()),
+            fee_store,
+            commit_lock: Default::default(),
+        }
+    }
 }
 
 /// An in-memory deployment storage.
@@ -103,6 +223,15 @@ pub struct DeploymentMemory<N: Network> {
     verifying_key_map: MemoryMap<(ProgramID<N>, Identifier<N>, u16), VerifyingKey<N>>,
     /// The certificate map.
     certificate_map: MemoryMap<(ProgramID<N>, Identifier<N>, u16), Certificate<N>>,
+    /// The program name map.
+    program_name_map: MemoryMap<([u8; 32], ProgramID<N>), ()>,
+    /// The edition index map.
+    edition_index_map: MemoryMap<(u16, ProgramID<N>), ()>,
+    /// The program string map.
+    program_string_map: MemoryMap<(ProgramID<N>, u16), String>,
+    /// The mapping of the hash of each distinct verifying key to a key in the verifying key map that stores it,
+    /// which allows bit-for-bit identical verifying keys to share a single copy.
+    verifying_key_index: Arc<RwLock<HashMap<u64, (ProgramID<N>, Identifier<N>, u16)>>>,
     /// The fee store.
     fee_store: FeeStore<N, FeeMemory<N>>,
 }
@@ -116,6 +245,9 @@ impl<N: Network> DeploymentStorage<N> for DeploymentMemory<N> {
     type ProgramMap = MemoryMap<(ProgramID<N>, u16), Program<N>>;
     type VerifyingKeyMap = MemoryMap<(ProgramID<N>, Identifier<N>, u16), VerifyingKey<N>>;
     type CertificateMap = MemoryMap<(ProgramID<N>, Identifier<N>, u16), Certificate<N>>;
+    type ProgramNameMap = MemoryMap<([u8; 32], ProgramID<N>), ()>;
+    type EditionIndexMap = MemoryMap<(u16, ProgramID<N>), ()>;
+    type ProgramStringMap = MemoryMap<(ProgramID<N>, u16), String>;
     type FeeStorage = FeeMemory<N>;
 
     /// Initializes the deployment storage.
@@ -128,6 +260,10 @@ impl<N: Network> DeploymentStorage<N> for DeploymentMemory<N> {
             program_map: MemoryMap::default(),
             verifying_key_map: MemoryMap::default(),
             certificate_map: MemoryMap::default(),
+            program_name_map: MemoryMap::default(),
+            edition_index_map: MemoryMap::default(),
+            program_string_map: MemoryMap::default(),
+            verifying_key_index: Default::default(),
             fee_store,
         })
     }
@@ -167,10 +303,88 @@ impl<N: Network> DeploymentStorage<N> for DeploymentMemory<N> {
         &self.certificate_map
     }
 
+    /// Returns the program name map.
+    fn program_name_map(&self) -> &Self::ProgramNameMap {
+        &self.program_name_map
+    }
+
+    /// Returns the edition index map.
+    fn edition_index_map(&self) -> &Self::EditionIndexMap {
+        &self.edition_index_map
+    }
+
+    /// Returns the program string map.
+    fn program_string_map(&self) -> &Self::ProgramStringMap {
+        &self.program_string_map
+    }
+
     /// Returns the fee store.
     fn fee_store(&self) -> &FeeStore<N, Self::FeeStorage> {
         &self.fee_store
     }
+
+    /// Returns a stored copy of the given verifying key, if a bit-for-bit identical verifying key is stored,
+    /// so that the verifying key map keeps a single copy of it. Otherwise, the given verifying key is indexed.
+    fn deduplicate_verifying_key(
+        &self,
+        key: &(ProgramID<N>, Identifier<N>, u16),
+        verifying_key: &VerifyingKey<N>,
+    ) -> Result<VerifyingKey<N>> {
+        // Hash the verifying key.
+        let mut hasher = DefaultHasher::new();
+        hasher.write(&verifying_key.to_bytes_le()?);
+        let hash = hasher.finish();
+
+        // Retrieve the stored copy of the verifying key, if it is still stored and is identical.
+        // Note: The stored copy is compared, as the indexed key may have been removed or overwritten.
+        let indexed_key = self.verifying_key_index.read().get(&hash).copied();
+        if let Some(indexed_key) = indexed_key {
+            if let Some(stored) = self.verifying_key_map.get_speculative(&indexed_key)? {
+                if *stored == *verifying_key {
+                    return Ok(cow_to_cloned!(stored));
+                }
+            }
+        }
+
+        // Otherwise, index the given verifying key.
+        self.verifying_key_index.write().insert(hash, *key);
+        Ok(verifying_key.clone())
+    }
+
+    /// Removes the given `(program ID, function name, edition)` from the index of deduplicated verifying keys.
+    /// Note: Another key which shares the same verifying key is indexed again when it is next deduplicated.
+    fn deindex_verifying_key(&self, key: &(ProgramID<N>, Identifier<N>, u16)) -> Result<()> {
+        self.verifying_key_index.write().retain(|_, indexed_key| indexed_key != key);
+        Ok(())
+    }
+}
+
+impl<N: Network> DeploymentMemory<N> {
+    /// Returns a point-in-time copy of the committed state of the storage, which uses the given copy of the fee store.
+    /// Note: The caller must ensure that no atomic batch is committed while the copy is taken.
+    pub(crate) fn snapshot(&self, fee_store: FeeStore<N, FeeMemory<N>>) -> Self {
+        Self {
+            id_map: self.id_map.snapshot().into(),
+            edition_map: self.edition_map.snapshot().into(),
+            reverse_id_map: self.reverse_id_map.snapshot().into(),
+            owner_map: self.owner_map.snapshot().into(),
+            program_map: self.program_map.snapshot().into(),
+            verifying_key_map: self.verifying_key_map.snapshot().into(),
+            certificate_map: self.certificate_map.snapshot().into(),
+            program_name_map: self.program_name_map.snapshot().into(),
+            edition_index_map: self.edition_index_map.snapshot().into(),
+            program_string_map: self.program_string_map.snapshot().into(),
+            // Note: The index is copied as is, since each indexed key is checked against the verifying key map.
+            verifying_key_index: Arc::new(RwLock::new(self.verifying_key_index.read().clone())),
+            fee_store,
+        }
+    }
+
+    /// Returns the number of entries in the index of deduplicated verifying keys.
+    #[cfg(test)]
+    pub(crate) fn num_indexed_verifying_keys(&self) -> usize {
+        self.verifying_key_index.read().len()
+    }
 }
 
 /// An in-memory execution storage.
@@ -178,7 +392,9 @@ impl<N: Network> DeploymentStorage<N> for DeploymentMemory<N> {
 #[allow(clippy::type_complexity)]
 pub struct ExecutionMemory<N: Network> {
     /// The ID map.
-    id_map: MemoryMap<N::TransactionID, (Vec<N::TransitionID>, bool)>,
+    id_map: MemoryMap<N::TransactionID, bool>,
+    /// The transition ID map.
+    transition_id_map: MemoryMap<(N::TransactionID, u8), N::TransitionID>,
     /// The reverse ID map.
     reverse_id_map: MemoryMap<N::TransitionID, N::TransactionID>,
     /// The inclusion map.
@@ -189,7 +405,8 @@ pub struct ExecutionMemory<N: Network> {
 
 #[rustfmt::skip]
 impl<N: Network> ExecutionStorage<N> for ExecutionMemory<N> {
-    type IDMap = MemoryMap<N::TransactionID, (Vec<N::TransitionID>, bool)>;
+    type IDMap = MemoryMap<N::TransactionID, bool>;
+    type TransitionIDMap = MemoryMap<(N::TransactionID, u8), N::TransitionID>;
     type ReverseIDMap = MemoryMap<N::TransitionID, N::TransactionID>;
     type InclusionMap = MemoryMap<N::TransactionID, (N::StateRoot, Option<Proof<N>>)>;
     type FeeStorage = FeeMemory<N>;
@@ -198,6 +415,7 @@ impl<N: Network> ExecutionStorage<N> for ExecutionMemory<N> {
     fn open(fee_store: FeeStore<N, Self::FeeStorage>) -> Result<Self> {
         Ok(Self {
             id_map: MemoryMap::default(),
+            transition_id_map: MemoryMap::default(),
             reverse_id_map: MemoryMap::default(),
             inclusion_map: MemoryMap::default(),
             fee_store
@@ -209,6 +427,11 @@ impl<N: Network> ExecutionStorage<N> for ExecutionMemory<N> {
         &self.id_map
     }
 
+    /// Returns the transition ID map.
+    fn transition_id_map(&self) -> &Self::TransitionIDMap {
+        &self.transition_id_map
+    }
+
     /// Returns the reverse ID map.
     fn reverse_id_map(&self) -> &Self::ReverseIDMap {
         &self.reverse_id_map
@@ -225,6 +448,20 @@ impl<N: Network> ExecutionStorage<N> for ExecutionMemory<N> {
     }
 }
 
+impl<N: Network> ExecutionMemory<N> {
+    /// Returns a point-in-time copy of the committed state of the storage, which uses the given copy of the fee store.
+    /// Note: The caller must ensure that no atomic batch is committed while the copy is taken.
+    pub(crate) fn snapshot(&self, fee_store: FeeStore<N, FeeMemory<N>>) -> Self {
+        Self {
+            id_map: self.id_map.snapshot().into(),
+            transition_id_map: self.transition_id_map.snapshot().into(),
+            reverse_id_map: self.reverse_id_map.snapshot().into(),
+            inclusion_map: self.inclusion_map.snapshot().into(),
+            fee_store,
+        }
+    }
+}
+
 /// An in-memory fee storage.
 #[derive(Clone)]
 #[allow(clippy::type_complexity)]
@@ -267,3 +504,15 @@ impl<N: Network> FeeStorage<N> for FeeMemory<N> {
         &self.transition_store
     }
 }
+
+impl<N: Network> FeeMemory<N> {
+    /// Returns a point-in-time copy of the committed state of the storage, which is independent of it.
+    /// Note: The caller must ensure that no atomic batch is committed while the copy is taken.
+    pub(crate) fn snapshot(&self) -> Self {
+        Self {
+            fee_map: self.fee_map.snapshot().into(),
+            reverse_fee_map: self.reverse_fee_map.snapshot().into(),
+            transition_store: self.transition_store.snapshot(),
+        }
+    }
+}
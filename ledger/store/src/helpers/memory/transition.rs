@@ -12,12 +12,26 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::{helpers::memory::MemoryMap, InputStorage, InputStore, OutputStorage, OutputStore, TransitionStorage};
+use crate::{
+    helpers::{memory::MemoryMap, MembershipFilter},
+    InputStorage,
+    InputStore,
+    OutputStorage,
+    OutputStore,
+    TransitionStorage,
+};
 use console::{
     prelude::*,
     program::{Ciphertext, Identifier, Plaintext, ProgramID, Record, Value},
     types::{Field, Group},
 };
+use ledger_block::Transition;
+
+use parking_lot::Mutex;
+use std::{
+    collections::{BTreeMap, HashMap},
+    sync::Arc,
+};
 
 /// An in-memory transition storage.
 #[derive(Clone)]
@@ -38,6 +52,10 @@ pub struct TransitionMemory<N: Network> {
     tcm_map: MemoryMap<N::TransitionID, Field<N>>,
     /// The reverse `tcm` map.
     reverse_tcm_map: MemoryMap<Field<N>, N::TransitionID>,
+    /// The membership filter of the serial numbers.
+    serial_number_filter: MembershipFilter<Field<N>>,
+    /// The membership filter of the record commitments.
+    commitment_filter: MembershipFilter<Field<N>>,
 }
 
 #[rustfmt::skip]
@@ -62,6 +80,8 @@ impl<N: Network> TransitionStorage<N> for TransitionMemory<N> {
             reverse_tpk_map: MemoryMap::default(),
             tcm_map: MemoryMap::default(),
             reverse_tcm_map: MemoryMap::default(),
+            serial_number_filter: MembershipFilter::default(),
+            commitment_filter: MembershipFilter::default(),
         })
     }
 
@@ -104,6 +124,307 @@ impl<N: Network> TransitionStorage<N> for TransitionMemory<N> {
     fn reverse_tcm_map(&self) -> &Self::ReverseTCMMap {
         &self.reverse_tcm_map
     }
+
+    /// Returns the membership filter of the serial numbers.
+    fn serial_number_filter(&self) -> &MembershipFilter<Field<N>> {
+        &self.serial_number_filter
+    }
+
+    /// Returns the membership filter of the record commitments.
+    fn commitment_filter(&self) -> &MembershipFilter<Field<N>> {
+        &self.commitment_filter
+    }
+}
+
+impl<N: Network> TransitionMemory<N> {
+    /// Returns a point-in-time copy of the committed state of the storage, which is independent of it.
+    /// Note: The caller must ensure that no atomic batch is committed while the copy is taken.
+    pub(crate) fn snapshot(&self) -> Self {
+        Self {
+            locator_map: self.locator_map.snapshot().into(),
+            input_store: self.input_store.snapshot(),
+            output_store: self.output_store.snapshot(),
+            finalize_map: self.finalize_map.snapshot().into(),
+            tpk_map: self.tpk_map.snapshot().into(),
+            reverse_tpk_map: self.reverse_tpk_map.snapshot().into(),
+            tcm_map: self.tcm_map.snapshot().into(),
+            reverse_tcm_map: self.reverse_tcm_map.snapshot().into(),
+            serial_number_filter: MembershipFilter::default(),
+            commitment_filter: MembershipFilter::default(),
+        }
+    }
+}
+
+/// The default number of transitions retained by a `TransitionMemoryLRU`.
+pub const DEFAULT_TRANSITION_LRU_CAPACITY: usize = 1 << 16;
+
+/// An in-memory transition storage, which retains at most `capacity` transitions.
+/// Once the capacity is exceeded, the least-recently-used transitions are evicted,
+/// and are expected to be persisted elsewhere; retrieving an evicted transition returns `None`.
+#[derive(Clone)]
+pub struct TransitionMemoryLRU<N: Network> {
+    /// The underlying transition storage.
+    memory: TransitionMemory<N>,
+    /// The order in which the transitions were last used.
+    order: Arc<Mutex<LruOrder<N::TransitionID>>>,
+    /// The updates to the order made in the atomic batch in progress, which are applied once it is finished.
+    pending: Arc<Mutex<Vec<LruUpdate<N::TransitionID>>>>,
+    /// The lengths of the pending updates at each atomic checkpoint.
+    checkpoints: Arc<Mutex<Vec<usize>>>,
+    /// The maximum number of transitions to retain.
+    capacity: usize,
+}
+
+impl<N: Network> TransitionMemoryLRU<N> {
+    /// Initializes the transition storage, retaining at most `capacity` transitions.
+    pub fn open_with_capacity(dev: Option<u16>, capacity: usize) -> Result<Self> {
+        // Ensure the capacity is nonzero.
+        ensure!(capacity > 0, "The capacity of the transition LRU must be nonzero");
+        Ok(Self {
+            memory: TransitionMemory::open(dev)?,
+            order: Default::default(),
+            pending: Default::default(),
+            checkpoints: Default::default(),
+            capacity,
+        })
+    }
+
+    /// Returns the maximum number of transitions retained.
+    pub const fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Returns the number of transitions currently retained.
+    pub fn len(&self) -> usize {
+        self.order.lock().len()
+    }
+
+    /// Returns `true` if no transitions are currently retained.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Applies the given update to the order, or defers it until the atomic batch in progress is finished.
+    fn update(&self, update: LruUpdate<N::TransitionID>) {
+        match self.is_atomic_in_progress() {
+            true => self.pending.lock().push(update),
+            false => self.order.lock().apply(update),
+        }
+    }
+
+    /// Evicts the least-recently-used transitions, until at most `capacity` transitions remain.
+    fn evict(&self) -> Result<()> {
+        loop {
+            // Retrieve the least-recently-used transition ID, if the capacity is exceeded.
+            let transition_id = {
+                let mut order = self.order.lock();
+                match order.len() > self.capacity {
+                    true => order.pop_oldest(),
+                    false => None,
+                }
+            };
+            // Remove the transition from the underlying storage.
+            match transition_id {
+                Some(transition_id) => self.memory.remove(&transition_id)?,
+                None => return Ok(()),
+            }
+        }
+    }
+}
+
+#[rustfmt::skip]
+impl<N: Network> TransitionStorage<N> for TransitionMemoryLRU<N> {
+    type LocatorMap = MemoryMap<N::TransitionID, (ProgramID<N>, Identifier<N>)>;
+    type InputStorage = InputMemory<N>;
+    type OutputStorage = OutputMemory<N>;
+    type FinalizeMap = MemoryMap<N::TransitionID, Option<Vec<Value<N>>>>;
+    type TPKMap = MemoryMap<N::TransitionID, Group<N>>;
+    type ReverseTPKMap = MemoryMap<Group<N>, N::TransitionID>;
+    type TCMMap = MemoryMap<N::TransitionID, Field<N>>;
+    type ReverseTCMMap = MemoryMap<Field<N>, N::TransitionID>;
+
+    /// Initializes the transition storage, retaining at most `DEFAULT_TRANSITION_LRU_CAPACITY` transitions.
+    fn open(dev: Option<u16>) -> Result<Self> {
+        Self::open_with_capacity(dev, DEFAULT_TRANSITION_LRU_CAPACITY)
+    }
+
+    /// Returns the transition program IDs and function names.
+    fn locator_map(&self) -> &Self::LocatorMap {
+        self.memory.locator_map()
+    }
+
+    /// Returns the transition input store.
+    fn input_store(&self) -> &InputStore<N, Self::InputStorage> {
+        self.memory.input_store()
+    }
+
+    /// Returns the transition output store.
+    fn output_store(&self) -> &OutputStore<N, Self::OutputStorage> {
+        self.memory.output_store()
+    }
+
+    /// Returns the transition finalize inputs.
+    fn finalize_map(&self) -> &Self::FinalizeMap {
+        self.memory.finalize_map()
+    }
+
+    /// Returns the transition public keys.
+    fn tpk_map(&self) -> &Self::TPKMap {
+        self.memory.tpk_map()
+    }
+
+    /// Returns the reverse `tpk` map.
+    fn reverse_tpk_map(&self) -> &Self::ReverseTPKMap {
+        self.memory.reverse_tpk_map()
+    }
+
+    /// Returns the transition commitments.
+    fn tcm_map(&self) -> &Self::TCMMap {
+        self.memory.tcm_map()
+    }
+
+    /// Returns the reverse `tcm` map.
+    fn reverse_tcm_map(&self) -> &Self::ReverseTCMMap {
+        self.memory.reverse_tcm_map()
+    }
+
+    /// Returns the membership filter of the serial numbers.
+    fn serial_number_filter(&self) -> &MembershipFilter<Field<N>> {
+        self.memory.serial_number_filter()
+    }
+
+    /// Returns the membership filter of the record commitments.
+    fn commitment_filter(&self) -> &MembershipFilter<Field<N>> {
+        self.memory.commitment_filter()
+    }
+
+    /// Checkpoints the atomic batch.
+    fn atomic_checkpoint(&self) {
+        self.memory.atomic_checkpoint();
+        self.checkpoints.lock().push(self.pending.lock().len());
+    }
+
+    /// Clears the latest atomic batch checkpoint.
+    fn clear_latest_checkpoint(&self) {
+        self.memory.clear_latest_checkpoint();
+        self.checkpoints.lock().pop();
+    }
+
+    /// Rewinds the atomic batch to the previous checkpoint, and drops the updates to the order made since.
+    fn atomic_rewind(&self) {
+        self.memory.atomic_rewind();
+        let checkpoint = self.checkpoints.lock().pop().unwrap_or(0);
+        self.pending.lock().truncate(checkpoint);
+    }
+
+    /// Aborts an atomic batch write operation, and drops the updates to the order made in it.
+    fn abort_atomic(&self) {
+        self.memory.abort_atomic();
+        self.pending.lock().clear();
+        self.checkpoints.lock().clear();
+    }
+
+    /// Finishes an atomic batch write operation, applies the updates to the order made in it,
+    /// and evicts the least-recently-used transitions.
+    fn finish_atomic(&self) -> Result<()> {
+        self.memory.finish_atomic()?;
+        self.checkpoints.lock().clear();
+        let pending = core::mem::take(&mut *self.pending.lock());
+        let mut order = self.order.lock();
+        pending.into_iter().for_each(|update| order.apply(update));
+        drop(order);
+        self.evict()
+    }
+
+    /// Stores the given `transition` into storage, and evicts the least-recently-used transitions.
+    /// If an atomic batch is in progress, the use and the eviction are deferred until the batch is finished.
+    fn insert(&self, transition: &Transition<N>) -> Result<()> {
+        self.memory.insert(transition)?;
+        self.update(LruUpdate::Touch(*transition.id()));
+        match self.is_atomic_in_progress() {
+            true => Ok(()),
+            false => self.evict(),
+        }
+    }
+
+    /// Removes the transition for the given `transition ID`.
+    /// If an atomic batch is in progress, the transition is untracked once the batch is finished.
+    fn remove(&self, transition_id: &N::TransitionID) -> Result<()> {
+        self.memory.remove(transition_id)?;
+        self.update(LruUpdate::Remove(*transition_id));
+        Ok(())
+    }
+
+    /// Returns the transition for the given `transition ID`, or `None` if it was evicted.
+    fn get(&self, transition_id: &N::TransitionID) -> Result<Option<Transition<N>>> {
+        let transition = self.memory.get(transition_id)?;
+        if transition.is_some() {
+            self.order.lock().touch(*transition_id);
+        }
+        Ok(transition)
+    }
+}
+
+/// An update to the order of an LRU.
+enum LruUpdate<K> {
+    /// Marks the key as the most-recently-used.
+    Touch(K),
+    /// Removes the key.
+    Remove(K),
+}
+
+/// The order in which the keys of an LRU were last used.
+struct LruOrder<K: Copy + Eq + core::hash::Hash> {
+    /// The mapping of `key` to its last use.
+    last_used: HashMap<K, u64>,
+    /// The mapping of `last use` to its key.
+    keys: BTreeMap<u64, K>,
+    /// The counter for the next use.
+    counter: u64,
+}
+
+impl<K: Copy + Eq + core::hash::Hash> Default for LruOrder<K> {
+    fn default() -> Self {
+        Self { last_used: Default::default(), keys: Default::default(), counter: 0 }
+    }
+}
+
+impl<K: Copy + Eq + core::hash::Hash> LruOrder<K> {
+    /// Returns the number of keys.
+    fn len(&self) -> usize {
+        self.last_used.len()
+    }
+
+    /// Applies the given update.
+    fn apply(&mut self, update: LruUpdate<K>) {
+        match update {
+            LruUpdate::Touch(key) => self.touch(key),
+            LruUpdate::Remove(key) => self.remove(&key),
+        }
+    }
+
+    /// Marks the given `key` as the most-recently-used.
+    fn touch(&mut self, key: K) {
+        if let Some(last_used) = self.last_used.insert(key, self.counter) {
+            self.keys.remove(&last_used);
+        }
+        self.keys.insert(self.counter, key);
+        self.counter += 1;
+    }
+
+    /// Removes the given `key`.
+    fn remove(&mut self, key: &K) {
+        if let Some(last_used) = self.last_used.remove(key) {
+            self.keys.remove(&last_used);
+        }
+    }
+
+    /// Removes and returns the least-recently-used key.
+    fn pop_oldest(&mut self) -> Option<K> {
+        let (_, key) = self.keys.pop_first()?;
+        self.last_used.remove(&key);
+        Some(key)
+    }
 }
 
 /// An in-memory transition input storage.
@@ -201,6 +522,24 @@ impl<N: Network> InputStorage<N> for InputMemory<N> {
     }
 }
 
+impl<N: Network> InputMemory<N> {
+    /// Returns a point-in-time copy of the committed state of the storage, which is independent of it.
+    /// Note: The caller must ensure that no atomic batch is committed while the copy is taken.
+    pub(crate) fn snapshot(&self) -> Self {
+        Self {
+            id_map: self.id_map.snapshot().into(),
+            reverse_id_map: self.reverse_id_map.snapshot().into(),
+            constant: self.constant.snapshot().into(),
+            public: self.public.snapshot().into(),
+            private: self.private.snapshot().into(),
+            record: self.record.snapshot().into(),
+            record_tag: self.record_tag.snapshot().into(),
+            external_record: self.external_record.snapshot().into(),
+            dev: self.dev,
+        }
+    }
+}
+
 /// An in-memory transition output storage.
 #[derive(Clone)]
 #[allow(clippy::type_complexity)]
@@ -217,6 +556,8 @@ pub struct OutputMemory<N: Network> {
     private: MemoryMap<Field<N>, Option<Ciphertext<N>>>,
     /// The mapping of `commitment` to `(checksum, (optional) record ciphertext)`.
     record: MemoryMap<Field<N>, (Field<N>, Option<Record<N, Ciphertext<N>>>)>,
+    /// The mapping of `commitment` to `record ciphertext`, for the records that have not been purged.
+    record_ciphertext: MemoryMap<Field<N>, Record<N, Ciphertext<N>>>,
     /// The mapping of `record nonce` to `commitment`.
     record_nonce: MemoryMap<Group<N>, Field<N>>,
     /// The mapping of `external hash` to `()`. Note: This is **not** the record commitment.
@@ -233,6 +574,7 @@ impl<N: Network> OutputStorage<N> for OutputMemory<N> {
     type PublicMap = MemoryMap<Field<N>, Option<Plaintext<N>>>;
     type PrivateMap = MemoryMap<Field<N>, Option<Ciphertext<N>>>;
     type RecordMap = MemoryMap<Field<N>, (Field<N>, Option<Record<N, Ciphertext<N>>>)>;
+    type RecordCiphertextMap = MemoryMap<Field<N>, Record<N, Ciphertext<N>>>;
     type RecordNonceMap = MemoryMap<Group<N>, Field<N>>;
     type ExternalRecordMap = MemoryMap<Field<N>, ()>;
 
@@ -245,6 +587,7 @@ impl<N: Network> OutputStorage<N> for OutputMemory<N> {
             public: Default::default(),
             private: Default::default(),
             record: Default::default(),
+            record_ciphertext: Default::default(),
             record_nonce: Default::default(),
             external_record: Default::default(),
             dev,
@@ -281,6 +624,11 @@ impl<N: Network> OutputStorage<N> for OutputMemory<N> {
         &self.record
     }
 
+    /// Returns the record ciphertext map.
+    fn record_ciphertext_map(&self) -> &Self::RecordCiphertextMap {
+        &self.record_ciphertext
+    }
+
     /// Returns the record nonce map.
     fn record_nonce_map(&self) -> &Self::RecordNonceMap {
         &self.record_nonce
@@ -296,3 +644,22 @@ impl<N: Network> OutputStorage<N> for OutputMemory<N> {
         self.dev
     }
 }
+
+impl<N: Network> OutputMemory<N> {
+    /// Returns a point-in-time copy of the committed state of the storage, which is independent of it.
+    /// Note: The caller must ensure that no atomic batch is committed while the copy is taken.
+    pub(crate) fn snapshot(&self) -> Self {
+        Self {
+            id_map: self.id_map.snapshot().into(),
+            reverse_id_map: self.reverse_id_map.snapshot().into(),
+            constant: self.constant.snapshot().into(),
+            public: self.public.snapshot().into(),
+            private: self.private.snapshot().into(),
+            record: self.record.snapshot().into(),
+            record_ciphertext: self.record_ciphertext.snapshot().into(),
+            record_nonce: self.record_nonce.snapshot().into(),
+            external_record: self.external_record.snapshot().into(),
+            dev: self.dev,
+        }
+    }
+}
@@ -12,6 +12,12 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod entry;
+pub use entry::*;
+
+mod filter;
+pub use filter::*;
+
 pub mod memory;
 #[cfg(feature = "rocks")]
 pub mod rocksdb;
@@ -38,6 +44,17 @@ pub trait Map<
     ///
     fn remove(&self, key: &K) -> Result<()>;
 
+    ///
+    /// Returns the entry for the given key, which holds a lock on the map until it is dropped.
+    /// This allows checking for the key and inserting it as a single atomic operation.
+    /// If an atomic batch is in progress, the entry reflects and updates the batch.
+    ///
+    /// Note: The lock is not reentrant. While the entry is alive, the same thread must not call `insert`,
+    /// `remove`, `entry`, or the atomic batch methods of the map: they wait for the entry to be dropped,
+    /// and so deadlock. Other threads calling them simply wait until the entry is dropped.
+    ///
+    fn entry(&'a self, key: K) -> Result<Entry<'a, K, V>>;
+
     ///
     /// Begins an atomic operation. Any further calls to `insert` and `remove` will be queued
     /// without an actual write taking place until `finish_atomic` is called.
@@ -77,6 +94,34 @@ pub trait Map<
     /// Finishes an atomic operation, performing all the queued writes.
     ///
     fn finish_atomic(&self) -> Result<()>;
+
+    ///
+    /// Reclaims the space left behind by removed entries, and returns an estimate of the number of
+    /// removed entries whose space was reclaimed. By default, this is a no-op that returns `0`.
+    ///
+    fn compact(&self) -> Result<usize> {
+        Ok(0)
+    }
+
+    ///
+    /// Inserts the given serialized key-value pair into the map, as produced by `MapRead::raw_iter`.
+    /// The key and value are checked to deserialize into the types of this map, and to serialize back
+    /// into the same bytes, so that bytes from another network or value schema are never written.
+    ///
+    fn insert_raw(&self, key_bytes: &[u8], value_bytes: &[u8]) -> Result<()>
+    where
+        K: for<'de> Deserialize<'de>,
+        V: for<'de> Deserialize<'de>,
+    {
+        // Deserialize the key and value.
+        let key: K = bincode::deserialize(key_bytes)?;
+        let value: V = bincode::deserialize(value_bytes)?;
+        // Ensure the key and value serialize back into the given bytes.
+        ensure!(bincode::serialize(&key)? == key_bytes, "The key bytes do not match the key type of the map");
+        ensure!(bincode::serialize(&value)? == value_bytes, "The value bytes do not match the value type of the map");
+        // Insert the key-value pair.
+        self.insert(key, value)
+    }
 }
 
 /// A trait representing map-like storage operations with read-only capabilities.
@@ -116,6 +161,29 @@ pub trait MapRead<
         K: Borrow<Q>,
         Q: PartialEq + Eq + Hash + Serialize + ?Sized;
 
+    ///
+    /// Returns the values for the given keys from the map, in the order of the given keys.
+    /// A key which does not exist yields `None`, so the values always align with the keys.
+    ///
+    fn get_many_confirmed(&'a self, keys: &[K]) -> Result<Vec<Option<Cow<'a, V>>>> {
+        keys.iter().map(|key| self.get_confirmed(key)).collect()
+    }
+
+    ///
+    /// Returns the serialized value for the given key from the map, if it exists.
+    /// A map which stores its values serialized returns them without deserializing them.
+    ///
+    fn get_raw_confirmed<Q>(&'a self, key: &Q) -> Result<Option<Vec<u8>>>
+    where
+        K: Borrow<Q>,
+        Q: PartialEq + Eq + Hash + Serialize + ?Sized,
+    {
+        match self.get_confirmed(key)? {
+            Some(value) => Ok(Some(bincode::serialize(&*value)?)),
+            None => Ok(None),
+        }
+    }
+
     ///
     /// Returns the current value for the given key if it is scheduled
     /// to be inserted as part of an atomic batch.
@@ -162,6 +230,12 @@ pub trait MapRead<
     ///
     fn iter_confirmed(&'a self) -> Self::Iterator;
 
+    ///
+    /// Returns an iterator visiting each key-value pair in the map, in the order of their serialized keys,
+    /// starting from the first key whose serialized form is not less than the given `raw_key`.
+    ///
+    fn iter_confirmed_from(&'a self, raw_key: &[u8]) -> Self::Iterator;
+
     ///
     /// Returns an iterator over each key in the map.
     ///
@@ -171,6 +245,44 @@ pub trait MapRead<
     /// Returns an iterator over each value in the map.
     ///
     fn values_confirmed(&'a self) -> Self::Values;
+
+    ///
+    /// Returns an iterator over each serialized key-value pair in the map, which can be written
+    /// into a map of the same types with `Map::insert_raw`, without knowing the types.
+    ///
+    #[allow(clippy::type_complexity)]
+    fn raw_iter(&'a self) -> core::iter::Map<Self::Iterator, fn((Cow<'a, K>, Cow<'a, V>)) -> (Vec<u8>, Vec<u8>)> {
+        // Note: The 'unwrap' is safe here, because the keys and values are defined by us.
+        self.iter_confirmed().map(|(k, v)| (bincode::serialize(&*k).unwrap(), bincode::serialize(&*v).unwrap()))
+    }
+}
+
+/// The maximum number of entries that are copied in a single atomic batch by `backup_map`.
+const BACKUP_BATCH_SIZE: usize = 4096;
+
+/// Copies each entry of the `source` map into the `target` map, in atomic batches of up to `BACKUP_BATCH_SIZE` entries.
+/// The maps may have different backends, as the entries are copied in their serialized form.
+pub(crate) fn backup_map<'a, K, V>(source: &'a impl MapRead<'a, K, V>, target: &impl Map<'a, K, V>) -> Result<()>
+where
+    K: 'a + Copy + Clone + PartialEq + Eq + Hash + Serialize + for<'de> Deserialize<'de> + Send + Sync,
+    V: 'a + Clone + PartialEq + Eq + Serialize + for<'de> Deserialize<'de> + Send + Sync,
+{
+    // Ensure an atomic batch is not in progress on the target map.
+    ensure!(!target.is_atomic_in_progress(), "Cannot back up into a map while an atomic batch is in progress");
+
+    let mut entries = source.raw_iter().peekable();
+    while entries.peek().is_some() {
+        // Copy the next batch of entries.
+        target.start_atomic();
+        for (key_bytes, value_bytes) in entries.by_ref().take(BACKUP_BATCH_SIZE) {
+            if let Err(error) = target.insert_raw(&key_bytes, &value_bytes) {
+                target.abort_atomic();
+                return Err(error);
+            }
+        }
+        target.finish_atomic()?;
+    }
+    Ok(())
 }
 
 /// This macro executes the given block of operations as a new atomic write batch IFF there is no
@@ -245,7 +357,8 @@ macro_rules! atomic_finalize {
             // If this is a failed real run, abort the atomic batch.
             (FinalizeMode::RealRun, Err(error_msg)) => {
                 $self.abort_atomic();
-                Err(anyhow!("Failed to finalize transactions: {error_msg}"))
+                let message = format!("Failed to finalize transactions: {error_msg}");
+                Err(anyhow!($crate::StorageError::AtomicAborted(message)))
             }
             // If this is a successful dry run, abort the atomic batch.
             (FinalizeMode::DryRun, Ok(result)) => {
@@ -255,7 +368,8 @@ macro_rules! atomic_finalize {
             // If this is a failed dry run, abort the atomic batch.
             (FinalizeMode::DryRun, Err(error_msg)) => {
                 $self.abort_atomic();
-                Err(anyhow!("Failed to finalize transactions: {error_msg}"))
+                let message = format!("Failed to finalize transactions: {error_msg}");
+                Err(anyhow!($crate::StorageError::AtomicAborted(message)))
             }
         }
     }};
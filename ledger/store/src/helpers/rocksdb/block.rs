@@ -79,8 +79,9 @@ impl<N: Network> BlockStorage<N> for BlockDB<N> {
     fn open(dev: Option<u16>) -> Result<Self> {
         // Initialize the transition store.
         let transition_store = TransitionStore::<N, TransitionDB<N>>::open(dev)?;
-        // Initialize the transaction store.
-        let transaction_store = TransactionStore::<N, TransactionDB<N>>::open(transition_store)?;
+        // Initialize the transaction store, which must retain the transactions of the blocks.
+        let transaction_store =
+            TransactionStore::<N, TransactionDB<N>>::open(transition_store)?.owned_by_block_store()?;
         // Return the block storage.
         Ok(Self {
             state_root_map: internal::RocksDB::open_map(N::ID, dev, MapID::Block(BlockMap::StateRoot))?,
@@ -27,6 +27,7 @@ pub enum MapID {
     TransitionInput(TransitionInputMap),
     TransitionOutput(TransitionOutputMap),
     Program(ProgramMap),
+    Metadata(MetadataMap),
     #[cfg(test)]
     Test(TestMap),
 }
@@ -44,6 +45,7 @@ impl From<MapID> for u16 {
             MapID::TransitionInput(id) => id as u16,
             MapID::TransitionOutput(id) => id as u16,
             MapID::Program(id) => id as u16,
+            MapID::Metadata(id) => id as u16,
             #[cfg(test)]
             MapID::Test(id) => id as u16,
         }
@@ -93,6 +95,9 @@ pub enum DeploymentMap {
     Program = DataID::DeploymentProgramMap as u16,
     VerifyingKey = DataID::DeploymentVerifyingKeyMap as u16,
     Certificate = DataID::DeploymentCertificateMap as u16,
+    ProgramName = DataID::DeploymentProgramNameMap as u16,
+    EditionIndex = DataID::DeploymentEditionIndexMap as u16,
+    ProgramString = DataID::DeploymentProgramStringMap as u16,
 }
 
 /// The RocksDB map prefix for execution-related entries.
@@ -104,6 +109,7 @@ pub enum ExecutionMap {
     ID = DataID::ExecutionIDMap as u16,
     ReverseID = DataID::ExecutionReverseIDMap as u16,
     Inclusion = DataID::ExecutionInclusionMap as u16,
+    TransitionID = DataID::ExecutionTransitionIDMap as u16,
 }
 
 /// The RocksDB map prefix for fee-related entries.
@@ -146,6 +152,7 @@ pub enum TransitionOutputMap {
     Record = DataID::OutputRecordMap as u16,
     RecordNonce = DataID::OutputRecordNonceMap as u16,
     ExternalRecord = DataID::OutputExternalRecordMap as u16,
+    RecordCiphertext = DataID::OutputRecordCiphertextMap as u16,
 }
 
 /// The RocksDB map prefix for transaction-related entries.
@@ -155,6 +162,11 @@ pub enum TransitionOutputMap {
 #[repr(u16)]
 pub enum TransactionMap {
     ID = DataID::TransactionIDMap as u16,
+    Size = DataID::TransactionSizeMap as u16,
+    Rejected = DataID::TransactionRejectedMap as u16,
+    Height = DataID::TransactionHeightMap as u16,
+    HeightIndex = DataID::TransactionHeightIndexMap as u16,
+    RetentionPolicy = DataID::TransactionRetentionPolicyMap as u16,
 }
 
 /// The RocksDB map prefix for transition-related entries.
@@ -184,6 +196,15 @@ pub enum ProgramMap {
     Value = DataID::ValueMap as u16,
 }
 
+/// The RocksDB map prefix for database metadata entries.
+// Note: the order of these variants can be changed at any point in time,
+// as long as the corresponding DataID values remain the same.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[repr(u16)]
+pub enum MetadataMap {
+    SchemaVersion = DataID::SchemaVersionMap as u16,
+}
+
 /// The RocksDB map prefix for test-related entries.
 // Note: the order of these variants can be changed at any point in time.
 #[cfg(test)]
@@ -268,6 +289,27 @@ enum DataID {
     KeyValueIDMap,
     KeyMap,
     ValueMap,
+    // Transaction
+    TransactionSizeMap,
+    // Output
+    OutputRecordCiphertextMap,
+    // Transaction
+    TransactionRejectedMap,
+    // Deployment
+    DeploymentProgramNameMap,
+    // Transaction
+    TransactionHeightMap,
+    TransactionRetentionPolicyMap,
+    // Execution
+    ExecutionTransitionIDMap,
+    // Deployment
+    DeploymentEditionIndexMap,
+    // Transaction
+    TransactionHeightIndexMap,
+    // Metadata
+    SchemaVersionMap,
+    // Deployment
+    DeploymentProgramStringMap,
 
     // Testing
     #[cfg(test)]
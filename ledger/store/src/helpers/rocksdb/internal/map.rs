@@ -15,10 +15,14 @@
 #![allow(clippy::type_complexity)]
 
 use super::*;
-use crate::helpers::{Map, MapRead};
+use crate::{
+    helpers::{Entry, EntryGuard, Map, MapRead},
+    StorageError,
+};
 
 use core::{fmt, fmt::Debug, hash::Hash, mem};
 use indexmap::IndexMap;
+use parking_lot::MutexGuard;
 use std::{borrow::Cow, sync::atomic::Ordering};
 
 #[derive(Clone)]
@@ -51,10 +55,12 @@ impl<
             }
             // Otherwise, insert the key-value pair directly into the map.
             false => {
+                // Acquire the lock on the atomic batch, so as not to interleave with an entry of the map.
+                let _lock = self.atomic_batch.lock();
                 // Prepare the prefixed key and serialized value.
                 let raw_key = self.create_prefixed_key(&key)?;
                 let raw_value = bincode::serialize(&value)?;
-                self.database.put(raw_key, raw_value)?;
+                self.database.put(raw_key, raw_value).map_err(StorageError::from)?;
             }
         }
 
@@ -73,15 +79,37 @@ impl<
             }
             // Otherwise, remove the key-value pair directly from the map.
             false => {
+                // Acquire the lock on the atomic batch, so as not to interleave with an entry of the map.
+                let _lock = self.atomic_batch.lock();
                 // Prepare the prefixed key.
                 let raw_key = self.create_prefixed_key(key)?;
-                self.database.delete(raw_key)?;
+                self.database.delete(raw_key).map_err(StorageError::from)?;
             }
         }
 
         Ok(())
     }
 
+    ///
+    /// Returns the entry for the given key, which holds a lock on the map until it is dropped.
+    /// This allows checking for the key and inserting it as a single atomic operation.
+    /// If an atomic batch is in progress, the entry reflects and updates the batch.
+    ///
+    fn entry(&'a self, key: K) -> Result<Entry<'a, K, V>> {
+        // Acquire the lock on the atomic batch, which excludes any writers until the entry is dropped.
+        let atomic_batch = self.atomic_batch.lock();
+        // Retrieve the current value, from the atomic batch first, if it exists.
+        let pending = match self.is_atomic_in_progress() {
+            true => atomic_batch.iter().rev().find(|(k, _)| *k == key).map(|(_, value)| value.clone()),
+            false => None,
+        };
+        let value = match pending {
+            Some(value) => value,
+            None => self.get_confirmed(&key)?.map(|value| value.into_owned()),
+        };
+        Ok(Entry::new(key, value, Box::new(DataEntryGuard { map: self, atomic_batch })))
+    }
+
     ///
     /// Begins an atomic operation. Any further calls to `insert` and `remove` will be queued
     /// without an actual write taking place until `finish_atomic` is called.
@@ -209,7 +237,7 @@ impl<
             // Empty the collection of pending operations.
             let batch = mem::take(&mut *self.database.atomic_batch.lock());
             // Execute all the operations atomically.
-            self.database.rocksdb.write(batch)?;
+            self.database.rocksdb.write(batch).map_err(StorageError::from)?;
             // Ensure that the database atomic batch is empty.
             assert!(self.database.atomic_batch.lock().is_empty());
         }
@@ -280,6 +308,33 @@ impl<
         }
     }
 
+    ///
+    /// Returns the serialized value for the given key from the map, if it exists.
+    ///
+    fn get_raw_confirmed<Q>(&'a self, key: &Q) -> Result<Option<Vec<u8>>>
+    where
+        K: Borrow<Q>,
+        Q: PartialEq + Eq + Hash + Serialize + ?Sized,
+    {
+        Ok(self.get_raw(key)?.map(|bytes| bytes.to_vec()))
+    }
+
+    ///
+    /// Returns the values for the given keys from the map, in the order of the given keys.
+    /// The keys are read in a single `multi_get`, which is served from one consistent view of the database.
+    ///
+    fn get_many_confirmed(&'a self, keys: &[K]) -> Result<Vec<Option<Cow<'a, V>>>> {
+        let raw_keys = keys.iter().map(|key| self.create_prefixed_key(key)).collect::<Result<Vec<_>>>()?;
+        self.database
+            .multi_get(raw_keys)
+            .into_iter()
+            .map(|value| match value? {
+                Some(bytes) => Ok(Some(Cow::Owned(bincode::deserialize(&bytes)?))),
+                None => Ok(None),
+            })
+            .collect()
+    }
+
     ///
     /// Returns the current value for the given key if it is scheduled
     /// to be inserted as part of an atomic batch.
@@ -317,6 +372,17 @@ impl<
         Iter::new(self.database.prefix_iterator(&self.context))
     }
 
+    ///
+    /// Returns an iterator visiting each key-value pair in the map, in the order of their serialized keys,
+    /// starting from the first key whose serialized form is not less than the given `raw_key`.
+    ///
+    fn iter_confirmed_from(&'a self, raw_key: &[u8]) -> Self::Iterator {
+        let mut prefixed_raw_key = self.context.clone();
+        prefixed_raw_key.extend_from_slice(raw_key);
+        // Note: The iterator stops at the end of this map, as the prefix extractor covers the map prefix.
+        Iter::new(self.database.prefix_iterator(&prefixed_raw_key))
+    }
+
     ///
     /// Returns an iterator over each key in the map.
     ///
@@ -344,25 +410,137 @@ impl<K: Serialize + DeserializeOwned, V: Serialize + DeserializeOwned> DataMap<K
         Ok(raw_key)
     }
 
+    /// Returns the entries whose stored value differs from the current encoding of the value.
+    /// These are the values stored with an older, but still decodable, encoding of `V`,
+    /// which are migrated by inserting them again.
+    pub(crate) fn stale_values(&self) -> Result<Vec<(K, V)>> {
+        let mut entries = Vec::new();
+        for entry in self.database.prefix_iterator(&self.context) {
+            let (raw_key, raw_value) = entry?;
+            let value: V = bincode::deserialize(&raw_value)?;
+            if *raw_value != *bincode::serialize(&value)? {
+                entries.push((bincode::deserialize(&raw_key[PREFIX_LEN..])?, value));
+            }
+        }
+        Ok(entries)
+    }
+
+    /// Returns the entries whose stored value is not an encoding of `V`, decoded as the legacy type `L`.
+    /// This finds the values stored with an older encoding that can no longer be read as `V`.
+    pub(crate) fn legacy_values<L: DeserializeOwned>(&self) -> Result<Vec<(K, L)>> {
+        let mut entries = Vec::new();
+        for entry in self.database.prefix_iterator(&self.context) {
+            let (raw_key, raw_value) = entry?;
+            // Skip the values which decode as `V`, and re-encode to the same bytes.
+            if let Ok(value) = bincode::deserialize::<V>(&raw_value) {
+                if *raw_value == *bincode::serialize(&value)? {
+                    continue;
+                }
+            }
+            entries.push((bincode::deserialize(&raw_key[PREFIX_LEN..])?, bincode::deserialize(&raw_value)?));
+        }
+        Ok(entries)
+    }
+
     fn get_raw<Q>(&self, key: &Q) -> Result<Option<rocksdb::DBPinnableSlice>>
     where
         K: Borrow<Q>,
         Q: Serialize + ?Sized,
     {
         let raw_key = self.create_prefixed_key(key)?;
-        match self.database.get_pinned(&raw_key)? {
+        match self.database.get_pinned(&raw_key).map_err(StorageError::from)? {
             Some(data) => Ok(Some(data)),
             None => Ok(None),
         }
     }
 }
 
+impl DataMap<u16, u16> {
+    /// Runs the given `migration` of the map with the given `map_id`, unless this schema version map
+    /// already holds a version of at least `version` for it, and returns the number of migrated entries.
+    /// The migration must make its writes in atomic batches; they are then committed together with
+    /// the new version, so the migration runs once per database, and runs again if it was interrupted.
+    pub(crate) fn migrate_once<T: Into<u16>>(
+        &self,
+        map_id: T,
+        version: u16,
+        migration: impl FnOnce() -> Result<usize>,
+    ) -> Result<usize> {
+        let map_id = map_id.into();
+        // Skip the migration if the map is already at the given version.
+        if let Some(current) = self.get_confirmed(&map_id)? {
+            if *current >= version {
+                return Ok(0);
+            }
+        }
+
+        // Hold the writes of the migration in the database-wide batch until the new version is stored.
+        self.start_atomic();
+        let result = migration().and_then(|num_migrated| {
+            self.insert(map_id, version)?;
+            Ok(num_migrated)
+        });
+        match result {
+            Ok(num_migrated) => self.finish_atomic().map(|_| num_migrated),
+            Err(error) => {
+                self.abort_atomic();
+                Err(error)
+            }
+        }
+    }
+}
+
 impl<K: Serialize + DeserializeOwned, V: Serialize + DeserializeOwned> fmt::Debug for DataMap<K, V> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("DataMap").field("context", &self.context).finish()
     }
 }
 
+/// The lock on a `DataMap` held by an entry.
+struct DataEntryGuard<'a, K: Serialize + DeserializeOwned, V: Serialize + DeserializeOwned> {
+    /// The map.
+    map: &'a DataMap<K, V>,
+    /// The lock on the atomic batch of the map.
+    atomic_batch: MutexGuard<'a, Vec<(K, Option<V>)>>,
+}
+
+impl<'a, K: Copy + Serialize + DeserializeOwned, V: Serialize + DeserializeOwned> EntryGuard<K, V>
+    for DataEntryGuard<'a, K, V>
+{
+    /// Inserts the given key-value pair into the map.
+    fn insert(&mut self, key: K, value: V) -> Result<()> {
+        // Determine if an atomic batch is in progress.
+        match self.map.batch_in_progress.load(Ordering::SeqCst) {
+            // If a batch is in progress, add the key-value pair to the batch.
+            true => self.atomic_batch.push((key, Some(value))),
+            // Otherwise, insert the key-value pair directly into the map.
+            false => {
+                // Prepare the prefixed key and serialized value.
+                let raw_key = self.map.create_prefixed_key(&key)?;
+                let raw_value = bincode::serialize(&value)?;
+                self.map.database.put(raw_key, raw_value).map_err(StorageError::from)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Removes the key-value pair for the given key from the map.
+    fn remove(&mut self, key: &K) -> Result<()> {
+        // Determine if an atomic batch is in progress.
+        match self.map.batch_in_progress.load(Ordering::SeqCst) {
+            // If a batch is in progress, add the key to the batch.
+            true => self.atomic_batch.push((*key, None)),
+            // Otherwise, remove the key-value pair directly from the map.
+            false => {
+                // Prepare the prefixed key.
+                let raw_key = self.map.create_prefixed_key(key)?;
+                self.map.database.delete(raw_key).map_err(StorageError::from)?;
+            }
+        }
+        Ok(())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -371,6 +549,7 @@ mod tests {
         atomic_finalize,
         helpers::rocksdb::{internal::tests::temp_dir, MapID, TestMap},
         FinalizeMode,
+        TransactionType,
     };
     use console::{
         account::{Address, FromStr},
@@ -559,6 +738,28 @@ mod tests {
         assert!(map.contains_key_confirmed(&address).unwrap());
     }
 
+    #[test]
+    #[serial]
+    fn test_get_many_confirmed() {
+        // Initialize a map.
+        let map: DataMap<usize, String> =
+            RocksDB::open_map_testing(temp_dir(), None, MapID::Test(TestMap::Test)).expect("Failed to open data map");
+        for i in 0..10 {
+            map.insert(i, i.to_string()).unwrap();
+        }
+
+        // Ensure the values align with the keys, including missing keys.
+        let values = map.get_many_confirmed(&[3, 42, 0, 3, 10]).unwrap();
+        assert_eq!(values, vec![
+            Some(Cow::Owned("3".to_string())),
+            None,
+            Some(Cow::Owned("0".to_string())),
+            Some(Cow::Owned("3".to_string())),
+            None
+        ]);
+        assert!(map.get_many_confirmed(&[]).unwrap().is_empty());
+    }
+
     #[test]
     #[serial]
     #[traced_test]
@@ -612,6 +813,97 @@ mod tests {
         assert_eq!(map.get_speculative(&0).unwrap(), Some(Cow::Owned("9".to_string())));
     }
 
+    #[test]
+    #[serial]
+    #[traced_test]
+    fn test_entry() {
+        // Initialize a map.
+        let map: DataMap<usize, String> =
+            RocksDB::open_map_testing(temp_dir(), None, MapID::Test(TestMap::Test)).expect("Failed to open data map");
+
+        // Insert into a vacant entry.
+        match map.entry(0).unwrap() {
+            Entry::Vacant(entry) => entry.insert("0".to_string()).unwrap(),
+            Entry::Occupied(_) => panic!("The entry should be vacant"),
+        }
+        assert_eq!(map.get_confirmed(&0).unwrap(), Some(Cow::Owned("0".to_string())));
+
+        // Update an occupied entry.
+        match map.entry(0).unwrap() {
+            Entry::Occupied(mut entry) => {
+                assert_eq!(entry.get(), "0");
+                assert_eq!(entry.insert("1".to_string()).unwrap(), "0");
+                assert_eq!(entry.get(), "1");
+            }
+            Entry::Vacant(_) => panic!("The entry should be occupied"),
+        }
+        assert_eq!(map.get_confirmed(&0).unwrap(), Some(Cow::Owned("1".to_string())));
+
+        // Ensure `or_insert` only inserts into a vacant entry.
+        assert_eq!(map.entry(0).unwrap().or_insert("2".to_string()).unwrap(), "1");
+        assert_eq!(map.entry(1).unwrap().or_insert("2".to_string()).unwrap(), "2");
+        assert_eq!(map.get_confirmed(&1).unwrap(), Some(Cow::Owned("2".to_string())));
+
+        // Remove an occupied entry.
+        match map.entry(1).unwrap() {
+            Entry::Occupied(entry) => assert_eq!(entry.remove().unwrap(), "2"),
+            Entry::Vacant(_) => panic!("The entry should be occupied"),
+        }
+        assert!(map.get_confirmed(&1).unwrap().is_none());
+
+        /* test entries within an atomic batch */
+
+        // Start an atomic write batch.
+        map.start_atomic();
+
+        // Ensure the entry reflects the batch.
+        map.insert(2, "3".to_string()).unwrap();
+        assert_eq!(map.entry(2).unwrap().or_insert("4".to_string()).unwrap(), "3");
+
+        // Ensure the writes through the entry are queued in the batch.
+        assert_eq!(map.entry(3).unwrap().or_insert("4".to_string()).unwrap(), "4");
+        assert!(map.get_confirmed(&3).unwrap().is_none());
+        assert_eq!(map.get_pending(&3), Some(Some("4".to_string())));
+
+        // Finish the current atomic write batch.
+        map.finish_atomic().unwrap();
+        assert_eq!(map.get_confirmed(&2).unwrap(), Some(Cow::Owned("3".to_string())));
+        assert_eq!(map.get_confirmed(&3).unwrap(), Some(Cow::Owned("4".to_string())));
+    }
+
+    #[test]
+    #[serial]
+    #[traced_test]
+    fn test_entry_no_deadlock() {
+        // Initialize a map.
+        let map: DataMap<usize, usize> =
+            RocksDB::open_map_testing(temp_dir(), None, MapID::Test(TestMap::Test)).expect("Failed to open data map");
+
+        // Ensure the map is writable once the entry is dropped, both directly and in an atomic batch.
+        map.entry(0).unwrap().or_insert(0).unwrap();
+        map.insert(0, 1).unwrap();
+        map.start_atomic();
+        map.entry(1).unwrap().or_insert(1).unwrap();
+        map.insert(2, 2).unwrap();
+        map.finish_atomic().unwrap();
+        map.start_atomic();
+        map.entry(3).unwrap().or_insert(3).unwrap();
+        map.abort_atomic();
+        assert_eq!(map.iter_confirmed().count(), 3);
+
+        // Ensure an entry in another thread waits for the entry to be dropped, instead of deadlocking.
+        let map = &map;
+        let (sender, receiver) = std::sync::mpsc::channel();
+        std::thread::scope(|scope| {
+            let entry = map.entry(4).unwrap();
+            scope.spawn(move || sender.send(map.entry(4).unwrap().or_insert(5).unwrap()).unwrap());
+            assert!(receiver.recv_timeout(std::time::Duration::from_millis(100)).is_err());
+            // Ensure the other thread proceeds once the entry is dropped, and observes its value.
+            entry.or_insert(4).unwrap();
+            assert_eq!(receiver.recv_timeout(std::time::Duration::from_secs(10)).unwrap(), 4);
+        });
+    }
+
     #[test]
     #[serial]
     #[traced_test]
@@ -1486,4 +1778,106 @@ mod tests {
         assert_eq!(test_storage.extra_maps.own_map2.iter_confirmed().count(), 1);
         assert_eq!(test_storage.extra_maps.extra_maps.own_map.iter_confirmed().count(), 0);
     }
+
+    #[test]
+    #[serial]
+    fn test_stale_values() {
+        // Initialize a database.
+        let database = RocksDB::open_testing(temp_dir(), None).expect("Failed to open a test database");
+
+        // Store transaction types in the legacy encoding, a 4-byte variant index.
+        let legacy_map: DataMap<usize, u32> = open_map_testing_from_db(database.clone(), MapID::Test(TestMap::Test));
+        legacy_map.insert(0, 0).unwrap();
+        legacy_map.insert(1, 1).unwrap();
+        legacy_map.insert(2, 2).unwrap();
+
+        // Open the same map with the current value type.
+        let map: DataMap<usize, TransactionType> = open_map_testing_from_db(database, MapID::Test(TestMap::Test));
+        // Ensure the legacy values are readable.
+        assert_eq!(map.get_confirmed(&1).unwrap().unwrap().into_owned(), TransactionType::Execute);
+
+        // Ensure every legacy value is stale.
+        let expected = vec![(0, TransactionType::Deploy), (1, TransactionType::Execute), (2, TransactionType::Fee)];
+        let stale = map.stale_values().unwrap();
+        assert_eq!(stale, expected);
+
+        // Rewrite the values, and ensure they are now stored as a single byte.
+        for (key, value) in stale {
+            map.insert(key, value).unwrap();
+        }
+        for (key, expected) in expected {
+            assert_eq!(map.get_raw(&key).unwrap().unwrap().as_ref(), &[expected as u8]);
+            assert_eq!(map.get_confirmed(&key).unwrap().unwrap().into_owned(), expected);
+        }
+        assert!(map.stale_values().unwrap().is_empty());
+    }
+
+    #[test]
+    #[serial]
+    fn test_migrate_once() {
+        // Initialize a database.
+        let database = RocksDB::open_testing(temp_dir(), None).expect("Failed to open a test database");
+
+        let schema_version_map: DataMap<u16, u16> =
+            open_map_testing_from_db(database.clone(), MapID::Test(TestMap::Test2));
+        let map: DataMap<usize, String> = open_map_testing_from_db(database, MapID::Test(TestMap::Test));
+        let map_id = MapID::Test(TestMap::Test);
+        let raw_map_id = u16::from(map_id);
+
+        // Returns a migration that inserts the given entry in an atomic batch.
+        let migration = |key: usize| {
+            let map = &map;
+            move || {
+                atomic_batch_scope!(map, {
+                    map.insert(key, key.to_string())?;
+                    Ok(1)
+                })
+            }
+        };
+
+        // Ensure a failed migration stores neither its writes nor the version.
+        let result = schema_version_map.migrate_once(map_id, 1, || Err(anyhow!("Migration failed")));
+        assert!(result.is_err());
+        assert!(!schema_version_map.contains_key_confirmed(&raw_map_id).unwrap());
+
+        // Ensure the migration runs once, and commits its writes together with the version.
+        assert_eq!(schema_version_map.migrate_once(map_id, 1, migration(0)).unwrap(), 1);
+        assert_eq!(map.get_confirmed(&0).unwrap().unwrap().into_owned(), "0");
+        assert_eq!(schema_version_map.get_confirmed(&raw_map_id).unwrap().unwrap().into_owned(), 1);
+        assert_eq!(schema_version_map.migrate_once(map_id, 1, migration(1)).unwrap(), 0);
+        assert!(!map.contains_key_confirmed(&1).unwrap());
+
+        // Ensure a newer version runs the migration again.
+        assert_eq!(schema_version_map.migrate_once(map_id, 2, migration(2)).unwrap(), 1);
+        assert_eq!(map.get_confirmed(&2).unwrap().unwrap().into_owned(), "2");
+        assert_eq!(schema_version_map.get_confirmed(&raw_map_id).unwrap().unwrap().into_owned(), 2);
+        assert!(!schema_version_map.is_atomic_in_progress());
+        assert!(!map.is_atomic_in_progress());
+    }
+
+    #[test]
+    #[serial]
+    fn test_legacy_values() {
+        // Initialize a database.
+        let database = RocksDB::open_testing(temp_dir(), None).expect("Failed to open a test database");
+
+        // Store values in a legacy encoding, which is not readable as the current value type.
+        let legacy_map: DataMap<usize, (Vec<u32>, bool)> =
+            open_map_testing_from_db(database.clone(), MapID::Test(TestMap::Test));
+        legacy_map.insert(0, (vec![], false)).unwrap();
+        legacy_map.insert(1, (vec![7, 8], true)).unwrap();
+
+        // Open the same map with the current value type, and store a value in the current encoding.
+        let map: DataMap<usize, bool> = open_map_testing_from_db(database, MapID::Test(TestMap::Test));
+        map.insert(2, true).unwrap();
+
+        // Ensure only the legacy values are returned, including the one whose first byte decodes as the current type.
+        let legacy = map.legacy_values::<(Vec<u32>, bool)>().unwrap();
+        assert_eq!(legacy, vec![(0, (vec![], false)), (1, (vec![7, 8], true))]);
+
+        // Rewrite the legacy values, and ensure none remain.
+        map.insert(0, false).unwrap();
+        map.insert(1, true).unwrap();
+        assert!(map.legacy_values::<(Vec<u32>, bool)>().unwrap().is_empty());
+    }
 }
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use crate::{
+    atomic_batch_scope,
     helpers::rocksdb::{
         self,
         DataMap,
@@ -21,6 +22,7 @@ use crate::{
         ExecutionMap,
         FeeMap,
         MapID,
+        MetadataMap,
         TransactionMap,
         TransitionDB,
     },
@@ -30,6 +32,8 @@ use crate::{
     ExecutionStore,
     FeeStorage,
     FeeStore,
+    RejectionReason,
+    RetentionPolicy,
     TransactionStorage,
     TransactionType,
     TransitionStore,
@@ -38,14 +42,36 @@ use console::{
     prelude::*,
     program::{Identifier, ProgramID, ProgramOwner},
 };
+use ledger_block::Transaction;
 use synthesizer_program::Program;
 use synthesizer_snark::{Certificate, Proof, VerifyingKey};
 
+/// The schema version of the transaction ID map, which stores each transaction type as a single byte.
+const TRANSACTION_ID_MAP_VERSION: u16 = 1;
+/// The schema version of the execution ID map, which stores the transition IDs in the transition ID map.
+const EXECUTION_ID_MAP_VERSION: u16 = 1;
+/// The schema version of the deployment edition index map, which indexes every stored edition.
+const DEPLOYMENT_EDITION_INDEX_VERSION: u16 = 1;
+/// The schema version of the deployment program name map, which indexes every stored program.
+const DEPLOYMENT_PROGRAM_NAME_VERSION: u16 = 1;
+/// The schema version of the deployment program string map, which stores the string of every stored program.
+const DEPLOYMENT_PROGRAM_STRING_VERSION: u16 = 1;
+
 /// A database transaction storage.
 #[derive(Clone)]
 pub struct TransactionDB<N: Network> {
     /// The mapping of `transaction ID` to `transaction type`.
     id_map: DataMap<N::TransactionID, TransactionType>,
+    /// The mapping of `transaction ID` to `size in bytes`.
+    size_map: DataMap<N::TransactionID, u64>,
+    /// The mapping of `transaction ID` to `(rejected transaction, rejection reason)`.
+    rejected_map: DataMap<N::TransactionID, (Transaction<N>, RejectionReason)>,
+    /// The mapping of `transaction ID` to `block height`, for the transactions subject to the retention policy.
+    height_map: DataMap<N::TransactionID, u32>,
+    /// The mapping of `(big-endian block height, transaction ID)` to `()`, which orders the transactions by height.
+    height_index_map: DataMap<([u8; 4], N::TransactionID), ()>,
+    /// The metadata map holding the retention policy.
+    retention_policy_map: DataMap<(), RetentionPolicy>,
     /// The deployment store.
     deployment_store: DeploymentStore<N, DeploymentDB<N>>,
     /// The execution store.
@@ -57,6 +83,11 @@ pub struct TransactionDB<N: Network> {
 #[rustfmt::skip]
 impl<N: Network> TransactionStorage<N> for TransactionDB<N> {
     type IDMap = DataMap<N::TransactionID, TransactionType>;
+    type SizeMap = DataMap<N::TransactionID, u64>;
+    type RejectedMap = DataMap<N::TransactionID, (Transaction<N>, RejectionReason)>;
+    type HeightMap = DataMap<N::TransactionID, u32>;
+    type HeightIndexMap = DataMap<([u8; 4], N::TransactionID), ()>;
+    type RetentionPolicyMap = DataMap<(), RetentionPolicy>;
     type DeploymentStorage = DeploymentDB<N>;
     type ExecutionStorage = ExecutionDB<N>;
     type FeeStorage = FeeDB<N>;
@@ -71,7 +102,25 @@ impl<N: Network> TransactionStorage<N> for TransactionDB<N> {
         // Initialize the execution store.
         let execution_store = ExecutionStore::<N, ExecutionDB<N>>::open(fee_store.clone())?;
         // Return the transaction storage.
-        Ok(Self { id_map: rocksdb::RocksDB::open_map(N::ID, execution_store.dev(), MapID::Transaction(TransactionMap::ID))?, deployment_store, execution_store, fee_store })
+        let dev = execution_store.dev();
+        let storage = Self {
+            id_map: rocksdb::RocksDB::open_map(N::ID, dev, MapID::Transaction(TransactionMap::ID))?,
+            size_map: rocksdb::RocksDB::open_map(N::ID, dev, MapID::Transaction(TransactionMap::Size))?,
+            rejected_map: rocksdb::RocksDB::open_map(N::ID, dev, MapID::Transaction(TransactionMap::Rejected))?,
+            height_map: rocksdb::RocksDB::open_map(N::ID, dev, MapID::Transaction(TransactionMap::Height))?,
+            height_index_map: rocksdb::RocksDB::open_map(N::ID, dev, MapID::Transaction(TransactionMap::HeightIndex))?,
+            retention_policy_map: rocksdb::RocksDB::open_map(N::ID, dev, MapID::Transaction(TransactionMap::RetentionPolicy))?,
+            deployment_store,
+            execution_store,
+            fee_store,
+        };
+        // Rewrite any transaction types stored in the legacy encoding, once per database.
+        let schema_version_map: DataMap<u16, u16> =
+            rocksdb::RocksDB::open_map(N::ID, dev, MapID::Metadata(MetadataMap::SchemaVersion))?;
+        schema_version_map.migrate_once(MapID::Transaction(TransactionMap::ID), TRANSACTION_ID_MAP_VERSION, || {
+            storage.reencode_id_map()
+        })?;
+        Ok(storage)
     }
 
     /// Returns the ID map.
@@ -79,6 +128,31 @@ impl<N: Network> TransactionStorage<N> for TransactionDB<N> {
         &self.id_map
     }
 
+    /// Returns the size map.
+    fn size_map(&self) -> &Self::SizeMap {
+        &self.size_map
+    }
+
+    /// Returns the rejected map.
+    fn rejected_map(&self) -> &Self::RejectedMap {
+        &self.rejected_map
+    }
+
+    /// Returns the height map.
+    fn height_map(&self) -> &Self::HeightMap {
+        &self.height_map
+    }
+
+    /// Returns the height index map.
+    fn height_index_map(&self) -> &Self::HeightIndexMap {
+        &self.height_index_map
+    }
+
+    /// Returns the retention policy map.
+    fn retention_policy_map(&self) -> &Self::RetentionPolicyMap {
+        &self.retention_policy_map
+    }
+
     /// Returns the deployment store.
     fn deployment_store(&self) -> &DeploymentStore<N, Self::DeploymentStorage> {
         &self.deployment_store
@@ -95,6 +169,26 @@ impl<N: Network> TransactionStorage<N> for TransactionDB<N> {
     }
 }
 
+impl<N: Network> TransactionDB<N> {
+    /// Rewrites the ID map entries stored in the legacy encoding of the transaction type,
+    /// and returns the number of rewritten entries.
+    fn reencode_id_map(&self) -> Result<usize> {
+        // Retrieve the entries stored in the legacy encoding.
+        let stale = self.id_map.stale_values()?;
+        if stale.is_empty() {
+            return Ok(0);
+        }
+
+        atomic_batch_scope!(self, {
+            for (transaction_id, transaction_type) in &stale {
+                // Overwrite the legacy value with the current encoding.
+                self.id_map.insert(*transaction_id, *transaction_type)?;
+            }
+            Ok(stale.len())
+        })
+    }
+}
+
 /// A database deployment storage.
 #[derive(Clone)]
 #[allow(clippy::type_complexity)]
@@ -113,6 +207,12 @@ pub struct DeploymentDB<N: Network> {
     verifying_key_map: DataMap<(ProgramID<N>, Identifier<N>, u16), VerifyingKey<N>>,
     /// The certificate map.
     certificate_map: DataMap<(ProgramID<N>, Identifier<N>, u16), Certificate<N>>,
+    /// The program name map.
+    program_name_map: DataMap<([u8; 32], ProgramID<N>), ()>,
+    /// The edition index map.
+    edition_index_map: DataMap<(u16, ProgramID<N>), ()>,
+    /// The program string map.
+    program_string_map: DataMap<(ProgramID<N>, u16), String>,
     /// The fee store.
     fee_store: FeeStore<N, FeeDB<N>>,
 }
@@ -126,13 +226,16 @@ impl<N: Network> DeploymentStorage<N> for DeploymentDB<N> {
     type ProgramMap = DataMap<(ProgramID<N>, u16), Program<N>>;
     type VerifyingKeyMap = DataMap<(ProgramID<N>, Identifier<N>, u16), VerifyingKey<N>>;
     type CertificateMap = DataMap<(ProgramID<N>, Identifier<N>, u16), Certificate<N>>;
+    type ProgramNameMap = DataMap<([u8; 32], ProgramID<N>), ()>;
+    type EditionIndexMap = DataMap<(u16, ProgramID<N>), ()>;
+    type ProgramStringMap = DataMap<(ProgramID<N>, u16), String>;
     type FeeStorage = FeeDB<N>;
 
     /// Initializes the deployment storage.
     fn open(fee_store: FeeStore<N, Self::FeeStorage>) -> Result<Self> {
         // Retrieve the optional development ID.
         let dev = fee_store.dev();
-        Ok(Self {
+        let storage = Self {
             id_map: rocksdb::RocksDB::open_map(N::ID, dev, MapID::Deployment(DeploymentMap::ID))?,
             edition_map: rocksdb::RocksDB::open_map(N::ID, dev, MapID::Deployment(DeploymentMap::Edition))?,
             reverse_id_map: rocksdb::RocksDB::open_map(N::ID, dev, MapID::Deployment(DeploymentMap::ReverseID))?,
@@ -140,8 +243,32 @@ impl<N: Network> DeploymentStorage<N> for DeploymentDB<N> {
             program_map: rocksdb::RocksDB::open_map(N::ID, dev, MapID::Deployment(DeploymentMap::Program))?,
             verifying_key_map: rocksdb::RocksDB::open_map(N::ID, dev, MapID::Deployment(DeploymentMap::VerifyingKey))?,
             certificate_map: rocksdb::RocksDB::open_map(N::ID, dev, MapID::Deployment(DeploymentMap::Certificate))?,
+            program_name_map: rocksdb::RocksDB::open_map(N::ID, dev, MapID::Deployment(DeploymentMap::ProgramName))?,
+            edition_index_map: rocksdb::RocksDB::open_map(N::ID, dev, MapID::Deployment(DeploymentMap::EditionIndex))?,
+            program_string_map: rocksdb::RocksDB::open_map(N::ID, dev, MapID::Deployment(DeploymentMap::ProgramString))?,
             fee_store,
-        })
+        };
+        // Index the editions of any deployments stored before the edition index existed, once per database.
+        let schema_version_map: DataMap<u16, u16> =
+            rocksdb::RocksDB::open_map(N::ID, dev, MapID::Metadata(MetadataMap::SchemaVersion))?;
+        schema_version_map.migrate_once(
+            MapID::Deployment(DeploymentMap::EditionIndex),
+            DEPLOYMENT_EDITION_INDEX_VERSION,
+            || storage.backfill_edition_index(),
+        )?;
+        // Index the names of any programs stored before the program name map existed, once per database.
+        schema_version_map.migrate_once(
+            MapID::Deployment(DeploymentMap::ProgramName),
+            DEPLOYMENT_PROGRAM_NAME_VERSION,
+            || storage.backfill_program_names(),
+        )?;
+        // Store the strings of any programs stored before the program string map existed, once per database.
+        schema_version_map.migrate_once(
+            MapID::Deployment(DeploymentMap::ProgramString),
+            DEPLOYMENT_PROGRAM_STRING_VERSION,
+            || storage.backfill_program_strings(),
+        )?;
+        Ok(storage)
     }
 
     /// Returns the ID map.
@@ -179,6 +306,21 @@ impl<N: Network> DeploymentStorage<N> for DeploymentDB<N> {
         &self.certificate_map
     }
 
+    /// Returns the program name map.
+    fn program_name_map(&self) -> &Self::ProgramNameMap {
+        &self.program_name_map
+    }
+
+    /// Returns the edition index map.
+    fn edition_index_map(&self) -> &Self::EditionIndexMap {
+        &self.edition_index_map
+    }
+
+    /// Returns the program string map.
+    fn program_string_map(&self) -> &Self::ProgramStringMap {
+        &self.program_string_map
+    }
+
     /// Returns the fee store.
     fn fee_store(&self) -> &FeeStore<N, Self::FeeStorage> {
         &self.fee_store
@@ -190,7 +332,9 @@ impl<N: Network> DeploymentStorage<N> for DeploymentDB<N> {
 #[allow(clippy::type_complexity)]
 pub struct ExecutionDB<N: Network> {
     /// The ID map.
-    id_map: DataMap<N::TransactionID, (Vec<N::TransitionID>, bool)>,
+    id_map: DataMap<N::TransactionID, bool>,
+    /// The transition ID map.
+    transition_id_map: DataMap<(N::TransactionID, u8), N::TransitionID>,
     /// The reverse ID map.
     reverse_id_map: DataMap<N::TransitionID, N::TransactionID>,
     /// The inclusion map.
@@ -201,7 +345,8 @@ pub struct ExecutionDB<N: Network> {
 
 #[rustfmt::skip]
 impl<N: Network> ExecutionStorage<N> for ExecutionDB<N> {
-    type IDMap = DataMap<N::TransactionID, (Vec<N::TransitionID>, bool)>;
+    type IDMap = DataMap<N::TransactionID, bool>;
+    type TransitionIDMap = DataMap<(N::TransactionID, u8), N::TransitionID>;
     type ReverseIDMap = DataMap<N::TransitionID, N::TransactionID>;
     type InclusionMap = DataMap<N::TransactionID, (N::StateRoot, Option<Proof<N>>)>;
     type FeeStorage = FeeDB<N>;
@@ -210,12 +355,20 @@ impl<N: Network> ExecutionStorage<N> for ExecutionDB<N> {
     fn open(fee_store: FeeStore<N, Self::FeeStorage>) -> Result<Self> {
         // Retrieve the optional development ID.
         let dev = fee_store.dev();
-        Ok(Self {
+        let storage = Self {
             id_map: rocksdb::RocksDB::open_map(N::ID, dev, MapID::Execution(ExecutionMap::ID))?,
+            transition_id_map: rocksdb::RocksDB::open_map(N::ID, dev, MapID::Execution(ExecutionMap::TransitionID))?,
             reverse_id_map: rocksdb::RocksDB::open_map(N::ID, dev, MapID::Execution(ExecutionMap::ReverseID))?,
             inclusion_map: rocksdb::RocksDB::open_map(N::ID, dev, MapID::Execution(ExecutionMap::Inclusion))?,
             fee_store,
-        })
+        };
+        // Migrate the executions stored in the legacy encoding of the ID map, once per database.
+        let schema_version_map: DataMap<u16, u16> =
+            rocksdb::RocksDB::open_map(N::ID, dev, MapID::Metadata(MetadataMap::SchemaVersion))?;
+        schema_version_map.migrate_once(MapID::Execution(ExecutionMap::ID), EXECUTION_ID_MAP_VERSION, || {
+            storage.migrate_legacy_id_map()
+        })?;
+        Ok(storage)
     }
 
     /// Returns the ID map.
@@ -223,6 +376,11 @@ impl<N: Network> ExecutionStorage<N> for ExecutionDB<N> {
         &self.id_map
     }
 
+    /// Returns the transition ID map.
+    fn transition_id_map(&self) -> &Self::TransitionIDMap {
+        &self.transition_id_map
+    }
+
     /// Returns the reverse ID map.
     fn reverse_id_map(&self) -> &Self::ReverseIDMap {
         &self.reverse_id_map
@@ -239,6 +397,31 @@ impl<N: Network> ExecutionStorage<N> for ExecutionDB<N> {
     }
 }
 
+impl<N: Network> ExecutionDB<N> {
+    /// Migrates the ID map entries stored in the legacy `(transition IDs, has fee)` encoding,
+    /// by moving the transition IDs into the transition ID map and keeping only the fee boolean.
+    /// Returns the number of migrated executions.
+    fn migrate_legacy_id_map(&self) -> Result<usize> {
+        // Retrieve the executions stored in the legacy encoding.
+        let legacy = self.id_map.legacy_values::<(Vec<N::TransitionID>, bool)>()?;
+        if legacy.is_empty() {
+            return Ok(0);
+        }
+
+        atomic_batch_scope!(self, {
+            for (transaction_id, (transition_ids, has_fee)) in &legacy {
+                // Store the transition IDs, keyed by their index in the execution.
+                for (index, transition_id) in transition_ids.iter().enumerate() {
+                    self.transition_id_map.insert((*transaction_id, u8::try_from(index)?), *transition_id)?;
+                }
+                // Overwrite the legacy value with the fee boolean.
+                self.id_map.insert(*transaction_id, *has_fee)?;
+            }
+            Ok(legacy.len())
+        })
+    }
+}
+
 /// A database for fee storage.
 #[derive(Clone)]
 #[allow(clippy::type_complexity)]
@@ -13,7 +13,10 @@
 // limitations under the License.
 
 use crate::{
-    helpers::rocksdb::{self, DataMap, Database, MapID, TransitionInputMap, TransitionMap, TransitionOutputMap},
+    helpers::{
+        rocksdb::{self, DataMap, Database, MapID, TransitionInputMap, TransitionMap, TransitionOutputMap},
+        MembershipFilter,
+    },
     InputStorage,
     InputStore,
     OutputStorage,
@@ -45,6 +48,10 @@ pub struct TransitionDB<N: Network> {
     tcm_map: DataMap<N::TransitionID, Field<N>>,
     /// The reverse `tcm` map.
     reverse_tcm_map: DataMap<Field<N>, N::TransitionID>,
+    /// The membership filter of the serial numbers.
+    serial_number_filter: MembershipFilter<Field<N>>,
+    /// The membership filter of the record commitments.
+    commitment_filter: MembershipFilter<Field<N>>,
 }
 
 #[rustfmt::skip]
@@ -69,6 +76,8 @@ impl<N: Network> TransitionStorage<N> for TransitionDB<N> {
             reverse_tpk_map: rocksdb::RocksDB::open_map(N::ID, dev, MapID::Transition(TransitionMap::ReverseTPK))?,
             tcm_map: rocksdb::RocksDB::open_map(N::ID, dev, MapID::Transition(TransitionMap::TCM))?,
             reverse_tcm_map: rocksdb::RocksDB::open_map(N::ID, dev,  MapID::Transition(TransitionMap::ReverseTCM))?,
+            serial_number_filter: MembershipFilter::default(),
+            commitment_filter: MembershipFilter::default(),
         })
     }
 
@@ -111,6 +120,16 @@ impl<N: Network> TransitionStorage<N> for TransitionDB<N> {
     fn reverse_tcm_map(&self) -> &Self::ReverseTCMMap {
         &self.reverse_tcm_map
     }
+
+    /// Returns the membership filter of the serial numbers.
+    fn serial_number_filter(&self) -> &MembershipFilter<Field<N>> {
+        &self.serial_number_filter
+    }
+
+    /// Returns the membership filter of the record commitments.
+    fn commitment_filter(&self) -> &MembershipFilter<Field<N>> {
+        &self.commitment_filter
+    }
 }
 
 /// An database transition input storage.
@@ -224,6 +243,8 @@ pub struct OutputDB<N: Network> {
     private: DataMap<Field<N>, Option<Ciphertext<N>>>,
     /// The mapping of `commitment` to `(checksum, (optional) record ciphertext)`.
     record: DataMap<Field<N>, (Field<N>, Option<Record<N, Ciphertext<N>>>)>,
+    /// The mapping of `commitment` to `record ciphertext`, for the records that have not been purged.
+    record_ciphertext: DataMap<Field<N>, Record<N, Ciphertext<N>>>,
     /// The mapping of `record nonce` to `commitment`.
     record_nonce: DataMap<Group<N>, Field<N>>,
     /// The mapping of `external commitment` to `()`. Note: This is **not** the record commitment.
@@ -240,6 +261,7 @@ impl<N: Network> OutputStorage<N> for OutputDB<N> {
     type PublicMap = DataMap<Field<N>, Option<Plaintext<N>>>;
     type PrivateMap = DataMap<Field<N>, Option<Ciphertext<N>>>;
     type RecordMap = DataMap<Field<N>, (Field<N>, Option<Record<N, Ciphertext<N>>>)>;
+    type RecordCiphertextMap = DataMap<Field<N>, Record<N, Ciphertext<N>>>;
     type RecordNonceMap = DataMap<Group<N>, Field<N>>;
     type ExternalRecordMap = DataMap<Field<N>, ()>;
 
@@ -252,6 +274,7 @@ impl<N: Network> OutputStorage<N> for OutputDB<N> {
             public: rocksdb::RocksDB::open_map(N::ID, dev, MapID::TransitionOutput(TransitionOutputMap::Public))?,
             private: rocksdb::RocksDB::open_map(N::ID, dev, MapID::TransitionOutput(TransitionOutputMap::Private))?,
             record: rocksdb::RocksDB::open_map(N::ID, dev, MapID::TransitionOutput(TransitionOutputMap::Record))?,
+            record_ciphertext: rocksdb::RocksDB::open_map(N::ID, dev, MapID::TransitionOutput(TransitionOutputMap::RecordCiphertext))?,
             record_nonce: rocksdb::RocksDB::open_map(N::ID, dev, MapID::TransitionOutput(TransitionOutputMap::RecordNonce))?,
             external_record: rocksdb::RocksDB::open_map(N::ID, dev, MapID::TransitionOutput(TransitionOutputMap::ExternalRecord))?,
             dev,
@@ -288,6 +311,11 @@ impl<N: Network> OutputStorage<N> for OutputDB<N> {
         &self.record
     }
 
+    /// Returns the record ciphertext map.
+    fn record_ciphertext_map(&self) -> &Self::RecordCiphertextMap {
+        &self.record_ciphertext
+    }
+
     /// Returns the record nonce map.
     fn record_nonce_map(&self) -> &Self::RecordNonceMap {
         &self.record_nonce
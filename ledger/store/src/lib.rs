@@ -23,6 +23,9 @@ pub use block::*;
 mod consensus;
 pub use consensus::*;
 
+mod error;
+pub use error::*;
+
 mod program;
 pub use program::*;
 
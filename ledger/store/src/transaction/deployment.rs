@@ -16,21 +16,72 @@ use crate::{
     atomic_batch_scope,
     cow_to_cloned,
     cow_to_copied,
-    helpers::{Map, MapRead},
+    helpers::{
+        backup_map,
+        memory::{DeploymentMemory, FeeMemory},
+        Map,
+        MapRead,
+    },
     FeeStorage,
     FeeStore,
+    IntegrityViolation,
+    StorageError,
+    TransactionType,
 };
 use console::{
     network::prelude::*,
     program::{Identifier, ProgramID, ProgramOwner},
 };
-use ledger_block::{Deployment, Fee, Transaction};
-use synthesizer_program::Program;
+use ledger_block::{Deployment, DeploymentSizeReport, Fee, Transaction};
+use synthesizer_program::{Program, ProgramDiff};
 use synthesizer_snark::{Certificate, VerifyingKey};
 
 use anyhow::Result;
 use core::marker::PhantomData;
-use std::borrow::Cow;
+use indexmap::{IndexMap, IndexSet};
+use parking_lot::RwLock;
+use std::{borrow::Cow, sync::Arc};
+
+/// The outcome of re-checking the stored certificates of a program with `DeploymentStore::verify_certificates`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CertificateReport<N: Network> {
+    /// The program ID.
+    program_id: ProgramID<N>,
+    /// Whether the certificate of each function is valid, in the order of the program functions.
+    functions: IndexMap<Identifier<N>, bool>,
+}
+
+impl<N: Network> CertificateReport<N> {
+    /// Returns the program ID.
+    pub const fn program_id(&self) -> &ProgramID<N> {
+        &self.program_id
+    }
+
+    /// Returns whether the certificate of each function is valid, in the order of the program functions.
+    pub const fn functions(&self) -> &IndexMap<Identifier<N>, bool> {
+        &self.functions
+    }
+
+    /// Returns `true` if the certificate of every function is valid.
+    pub fn is_valid(&self) -> bool {
+        self.functions.values().all(|is_valid| *is_valid)
+    }
+
+    /// Returns an iterator over the functions whose certificate is invalid or missing.
+    pub fn invalid_functions(&self) -> impl '_ + Iterator<Item = &Identifier<N>> {
+        self.functions.iter().filter(|(_, is_valid)| !**is_valid).map(|(function_name, _)| function_name)
+    }
+}
+
+/// Returns the name of the given program ID, padded with zeros, as used in the program name map.
+fn to_program_name<N: Network>(program_id: &ProgramID<N>) -> [u8; 32] {
+    let mut program_name = [0u8; 32];
+    // Note: A program name is a lowercase identifier, which is at most 31 bytes long.
+    for (byte, name_byte) in program_name.iter_mut().zip(program_id.name().to_string().bytes()) {
+        *byte = name_byte;
+    }
+    program_name
+}
 
 /// A trait for deployment storage.
 pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
@@ -48,6 +99,12 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
     type VerifyingKeyMap: for<'a> Map<'a, (ProgramID<N>, Identifier<N>, u16), VerifyingKey<N>>;
     /// The mapping of `(program ID, function name, edition)` to `certificate`.
     type CertificateMap: for<'a> Map<'a, (ProgramID<N>, Identifier<N>, u16), Certificate<N>>;
+    /// The mapping of `(program name, program ID)` to `()`, ordered by program name.
+    type ProgramNameMap: for<'a> Map<'a, ([u8; 32], ProgramID<N>), ()>;
+    /// The mapping of `(edition, program ID)` to `()`, grouped by edition.
+    type EditionIndexMap: for<'a> Map<'a, (u16, ProgramID<N>), ()>;
+    /// The mapping of `(program ID, edition)` to the program, as its canonical string.
+    type ProgramStringMap: for<'a> Map<'a, (ProgramID<N>, u16), String>;
     /// The fee storage.
     type FeeStorage: FeeStorage<N>;
 
@@ -68,6 +125,12 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
     fn verifying_key_map(&self) -> &Self::VerifyingKeyMap;
     /// Returns the certificate map.
     fn certificate_map(&self) -> &Self::CertificateMap;
+    /// Returns the program name map.
+    fn program_name_map(&self) -> &Self::ProgramNameMap;
+    /// Returns the edition index map.
+    fn edition_index_map(&self) -> &Self::EditionIndexMap;
+    /// Returns the program string map.
+    fn program_string_map(&self) -> &Self::ProgramStringMap;
     /// Returns the fee storage.
     fn fee_store(&self) -> &FeeStore<N, Self::FeeStorage>;
 
@@ -85,6 +148,9 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
         self.program_map().start_atomic();
         self.verifying_key_map().start_atomic();
         self.certificate_map().start_atomic();
+        self.program_name_map().start_atomic();
+        self.edition_index_map().start_atomic();
+        self.program_string_map().start_atomic();
         self.fee_store().start_atomic();
     }
 
@@ -97,6 +163,9 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
             || self.program_map().is_atomic_in_progress()
             || self.verifying_key_map().is_atomic_in_progress()
             || self.certificate_map().is_atomic_in_progress()
+            || self.program_name_map().is_atomic_in_progress()
+            || self.edition_index_map().is_atomic_in_progress()
+            || self.program_string_map().is_atomic_in_progress()
             || self.fee_store().is_atomic_in_progress()
     }
 
@@ -109,6 +178,9 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
         self.program_map().atomic_checkpoint();
         self.verifying_key_map().atomic_checkpoint();
         self.certificate_map().atomic_checkpoint();
+        self.program_name_map().atomic_checkpoint();
+        self.edition_index_map().atomic_checkpoint();
+        self.program_string_map().atomic_checkpoint();
         self.fee_store().atomic_checkpoint();
     }
 
@@ -121,6 +193,9 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
         self.program_map().clear_latest_checkpoint();
         self.verifying_key_map().clear_latest_checkpoint();
         self.certificate_map().clear_latest_checkpoint();
+        self.program_name_map().clear_latest_checkpoint();
+        self.edition_index_map().clear_latest_checkpoint();
+        self.program_string_map().clear_latest_checkpoint();
         self.fee_store().clear_latest_checkpoint();
     }
 
@@ -133,6 +208,9 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
         self.program_map().atomic_rewind();
         self.verifying_key_map().atomic_rewind();
         self.certificate_map().atomic_rewind();
+        self.program_name_map().atomic_rewind();
+        self.edition_index_map().atomic_rewind();
+        self.program_string_map().atomic_rewind();
         self.fee_store().atomic_rewind();
     }
 
@@ -145,6 +223,9 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
         self.program_map().abort_atomic();
         self.verifying_key_map().abort_atomic();
         self.certificate_map().abort_atomic();
+        self.program_name_map().abort_atomic();
+        self.edition_index_map().abort_atomic();
+        self.program_string_map().abort_atomic();
         self.fee_store().abort_atomic();
     }
 
@@ -157,16 +238,128 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
         self.program_map().finish_atomic()?;
         self.verifying_key_map().finish_atomic()?;
         self.certificate_map().finish_atomic()?;
+        self.program_name_map().finish_atomic()?;
+        self.edition_index_map().finish_atomic()?;
+        self.program_string_map().finish_atomic()?;
         self.fee_store().finish_atomic()
     }
 
+    /// Reclaims the space left behind by removed entries, and returns an estimate of the number of
+    /// removed entries whose space was reclaimed.
+    fn compact(&self) -> Result<usize> {
+        let mut num_removed = self.id_map().compact()?;
+        num_removed += self.edition_map().compact()?;
+        num_removed += self.reverse_id_map().compact()?;
+        num_removed += self.owner_map().compact()?;
+        num_removed += self.program_map().compact()?;
+        num_removed += self.verifying_key_map().compact()?;
+        num_removed += self.certificate_map().compact()?;
+        num_removed += self.program_name_map().compact()?;
+        num_removed += self.edition_index_map().compact()?;
+        num_removed += self.program_string_map().compact()?;
+        num_removed += self.fee_store().compact()?;
+        Ok(num_removed)
+    }
+
+    /// Copies each map of the storage into the `other` storage, which may have a different backend.
+    /// Note: The fee store is shared with the transaction storage, which copies it.
+    fn backup_to<S: DeploymentStorage<N>>(&self, other: &S) -> Result<()> {
+        backup_map(self.id_map(), other.id_map())?;
+        backup_map(self.edition_map(), other.edition_map())?;
+        backup_map(self.reverse_id_map(), other.reverse_id_map())?;
+        backup_map(self.owner_map(), other.owner_map())?;
+        backup_map(self.program_map(), other.program_map())?;
+        backup_map(self.verifying_key_map(), other.verifying_key_map())?;
+        backup_map(self.certificate_map(), other.certificate_map())?;
+        backup_map(self.program_name_map(), other.program_name_map())?;
+        backup_map(self.edition_index_map(), other.edition_index_map())?;
+        backup_map(self.program_string_map(), other.program_string_map())?;
+        Ok(())
+    }
+
+    /// Adds each stored edition of a program that is missing from the edition index map, as a single atomic batch,
+    /// and returns the number of added entries. This indexes the deployments stored before the index existed.
+    /// Note: The edition map only holds the latest edition of each program, so every stored edition is read
+    /// from the keys of the reverse ID map instead.
+    /// Note: This scans every stored edition, so persistent storage should run it once, when it is first opened.
+    fn backfill_edition_index(&self) -> Result<usize> {
+        // Collect the stored editions that are missing from the edition index map.
+        let mut missing = Vec::new();
+        for key in self.reverse_id_map().keys_confirmed() {
+            let (program_id, edition) = cow_to_copied!(key);
+            if !self.edition_index_map().contains_key_confirmed(&(edition, program_id))? {
+                missing.push((edition, program_id));
+            }
+        }
+        // Add the missing editions.
+        if !missing.is_empty() {
+            atomic_batch_scope!(self, {
+                for key in &missing {
+                    self.edition_index_map().insert(*key, ())?;
+                }
+                Ok(())
+            })?;
+        }
+        Ok(missing.len())
+    }
+
+    /// Adds each stored program that is missing from the program name map, as a single atomic batch,
+    /// and returns the number of added entries. This indexes the programs stored before the index existed.
+    /// Note: This scans every stored program, so persistent storage should run it once, when it is first opened.
+    fn backfill_program_names(&self) -> Result<usize> {
+        // Collect the stored programs that are missing from the program name map.
+        let mut missing = Vec::new();
+        for key in self.edition_map().keys_confirmed() {
+            let program_id = cow_to_copied!(key);
+            let key = (to_program_name(&program_id), program_id);
+            if !self.program_name_map().contains_key_confirmed(&key)? {
+                missing.push(key);
+            }
+        }
+        // Add the missing programs.
+        if !missing.is_empty() {
+            atomic_batch_scope!(self, {
+                for key in &missing {
+                    self.program_name_map().insert(*key, ())?;
+                }
+                Ok(())
+            })?;
+        }
+        Ok(missing.len())
+    }
+
+    /// Adds the string of each stored program that is missing from the program string map, as a single atomic batch,
+    /// and returns the number of added entries. This stores the strings of the programs stored before the map existed.
+    /// Note: This decodes every stored program, so persistent storage should run it once, when it is first opened.
+    fn backfill_program_strings(&self) -> Result<usize> {
+        // Collect the strings of the stored programs that are missing from the program string map.
+        let mut missing = Vec::new();
+        for (key, program) in self.program_map().iter_confirmed() {
+            let key = cow_to_copied!(key);
+            if !self.program_string_map().contains_key_confirmed(&key)? {
+                missing.push((key, program.to_string()));
+            }
+        }
+        // Add the missing strings.
+        if !missing.is_empty() {
+            atomic_batch_scope!(self, {
+                for (key, program_string) in &missing {
+                    self.program_string_map().insert(*key, program_string.clone())?;
+                }
+                Ok(())
+            })?;
+        }
+        Ok(missing.len())
+    }
+
     /// Stores the given `deployment transaction` pair into storage.
     fn insert(&self, transaction: &Transaction<N>) -> Result<()> {
         // Ensure the transaction is a deployment.
         let (transaction_id, owner, deployment, fee) = match transaction {
             Transaction::Deploy(transaction_id, owner, deployment, fee) => (transaction_id, owner, deployment, fee),
-            Transaction::Execute(..) => bail!("Attempted to insert an execute transaction into deployment storage."),
-            Transaction::Fee(..) => bail!("Attempted to insert fee transaction into deployment storage."),
+            Transaction::Execute(..) | Transaction::Fee(..) => {
+                bail!(StorageError::type_mismatch(transaction.id(), TransactionType::Deploy, transaction.into()))
+            }
         };
 
         // Ensure the deployment is ordered.
@@ -186,6 +379,8 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
             self.id_map().insert(*transaction_id, program_id)?;
             // Store the edition.
             self.edition_map().insert(program_id, edition)?;
+            // Store the program ID in the edition index.
+            self.edition_index_map().insert((edition, program_id), ())?;
 
             // Store the reverse program ID.
             self.reverse_id_map().insert((program_id, edition), *transaction_id)?;
@@ -193,11 +388,16 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
             self.owner_map().insert((program_id, edition), *owner)?;
             // Store the program.
             self.program_map().insert((program_id, edition), program.clone())?;
+            // Store the program string.
+            self.program_string_map().insert((program_id, edition), program.to_string())?;
+            // Store the program name.
+            self.program_name_map().insert((to_program_name(&program_id), program_id), ())?;
 
             // Store the verifying keys and certificates.
             for (function_name, (verifying_key, certificate)) in deployment.verifying_keys() {
-                // Store the verifying key.
-                self.verifying_key_map().insert((program_id, *function_name, edition), verifying_key.clone())?;
+                // Store the verifying key, or a stored copy of an identical verifying key.
+                let key = (program_id, *function_name, edition);
+                self.verifying_key_map().insert(key, self.deduplicate_verifying_key(&key, verifying_key)?)?;
                 // Store the certificate.
                 self.certificate_map().insert((program_id, *function_name, edition), certificate.clone())?;
             }
@@ -209,29 +409,89 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
         })
     }
 
+    /// Stores the given `deployment transaction` pair into storage. If `check_certificates` is `true`, the certificate
+    /// of each function is first checked against its verifying key with `verify`, and nothing is written if a check
+    /// fails. Trusted paths, such as syncing from a known-good source, may set `check_certificates` to `false`.
+    fn insert_checked<F>(&self, transaction: &Transaction<N>, check_certificates: bool, verify: F) -> Result<()>
+    where
+        F: Fn(&ProgramID<N>, &Identifier<N>, &VerifyingKey<N>, &Certificate<N>) -> bool,
+    {
+        // Check the certificate of each function, before the transaction is stored.
+        if let (true, Transaction::Deploy(_, _, deployment, _)) = (check_certificates, transaction) {
+            let program_id = deployment.program_id();
+            for (function_name, (verifying_key, certificate)) in deployment.verifying_keys() {
+                if !verify(program_id, function_name, verifying_key, certificate) {
+                    bail!(StorageError::invalid_certificate(program_id, function_name));
+                }
+            }
+        }
+        // Store the transaction.
+        self.insert(transaction)
+    }
+
+    /// Returns the copy of the given verifying key to store at the given `(program ID, function name, edition)`.
+    /// By default, this is a clone of the given verifying key. Storages that deduplicate verifying keys
+    /// instead return a stored copy of a bit-for-bit identical verifying key, if there is one.
+    fn deduplicate_verifying_key(
+        &self,
+        _key: &(ProgramID<N>, Identifier<N>, u16),
+        verifying_key: &VerifyingKey<N>,
+    ) -> Result<VerifyingKey<N>> {
+        Ok(verifying_key.clone())
+    }
+
+    /// Removes the given `(program ID, function name, edition)` from the index of deduplicated verifying keys,
+    /// before its verifying key is removed. By default, there is no index, and this does nothing.
+    fn deindex_verifying_key(&self, _key: &(ProgramID<N>, Identifier<N>, u16)) -> Result<()> {
+        Ok(())
+    }
+
     /// Removes the deployment transaction for the given `transaction ID`.
     fn remove(&self, transaction_id: &N::TransactionID) -> Result<()> {
         // Retrieve the program ID.
         let program_id = match self.get_program_id(transaction_id)? {
             Some(edition) => edition,
-            None => bail!("Failed to get the program ID for transaction '{transaction_id}'"),
+            None => bail!(StorageError::NotFound(format!(
+                "Failed to get the program ID for transaction '{transaction_id}'"
+            ))),
         };
         // Retrieve the edition.
-        let edition = match self.get_edition(&program_id)? {
-            Some(edition) => edition,
-            None => bail!("Failed to locate the edition for program '{program_id}'"),
+        // Note: The edition is read speculatively, as several editions of a program may be removed in one atomic batch.
+        let edition = match self.edition_map().get_speculative(&program_id)? {
+            Some(edition) => cow_to_copied!(edition),
+            None => bail!(StorageError::Corrupted(format!("Failed to locate the edition for program '{program_id}'"))),
         };
+        // Ensure the transaction deployed the latest edition, as the editions are removed from the latest one.
+        match self.reverse_id_map().get_speculative(&(program_id, edition))? {
+            Some(id) if cow_to_copied!(id) == *transaction_id => (),
+            _ => bail!("Transaction '{transaction_id}' did not deploy the latest edition of program '{program_id}'"),
+        }
         // Retrieve the program.
         let program = match self.program_map().get_confirmed(&(program_id, edition))? {
             Some(program) => cow_to_cloned!(program),
-            None => bail!("Failed to locate program '{program_id}' for transaction '{transaction_id}'"),
+            None => bail!(StorageError::Corrupted(format!(
+                "Failed to locate program '{program_id}' for transaction '{transaction_id}'"
+            ))),
+        };
+        // Determine if the previous edition of the program is still stored.
+        let has_previous_edition = match edition.checked_sub(1) {
+            Some(previous) => self.reverse_id_map().contains_key_speculative(&(program_id, previous))?,
+            None => false,
         };
 
         atomic_batch_scope!(self, {
             // Remove the program ID.
             self.id_map().remove(transaction_id)?;
-            // Remove the edition.
-            self.edition_map().remove(&program_id)?;
+            // Restore the previous edition if it is still stored, or otherwise remove the edition and program name.
+            match has_previous_edition {
+                true => self.edition_map().insert(program_id, edition - 1)?,
+                false => {
+                    self.edition_map().remove(&program_id)?;
+                    self.program_name_map().remove(&(to_program_name(&program_id), program_id))?;
+                }
+            }
+            // Remove the program ID from the edition index.
+            self.edition_index_map().remove(&(edition, program_id))?;
 
             // Remove the reverse program ID.
             self.reverse_id_map().remove(&(program_id, edition))?;
@@ -239,10 +499,13 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
             self.owner_map().remove(&(program_id, edition))?;
             // Remove the program.
             self.program_map().remove(&(program_id, edition))?;
+            // Remove the program string.
+            self.program_string_map().remove(&(program_id, edition))?;
 
             // Remove the verifying keys and certificates.
             for function_name in program.functions().keys() {
-                // Remove the verifying key.
+                // Remove the verifying key, and its entry in the index of deduplicated verifying keys.
+                self.deindex_verifying_key(&(program_id, *function_name, edition))?;
                 self.verifying_key_map().remove(&(program_id, *function_name, edition))?;
                 // Remove the certificate.
                 self.certificate_map().remove(&(program_id, *function_name, edition))?;
@@ -272,7 +535,9 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
         // Retrieve the transaction ID.
         match self.reverse_id_map().get_confirmed(&(*program_id, edition))? {
             Some(transaction_id) => Ok(Some(cow_to_copied!(transaction_id))),
-            None => bail!("Failed to find the transaction ID for program '{program_id}' (edition {edition})"),
+            None => bail!(StorageError::Corrupted(format!(
+                "Failed to find the transaction ID for program '{program_id}' (edition {edition})"
+            ))),
         }
     }
 
@@ -325,35 +590,171 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
         // Retrieve the program.
         match self.program_map().get_confirmed(&(*program_id, edition))? {
             Some(program) => Ok(Some(cow_to_cloned!(program))),
-            None => bail!("Failed to get program '{program_id}' (edition {edition})"),
+            None => bail!(StorageError::Corrupted(format!("Failed to get program '{program_id}' (edition {edition})"))),
+        }
+    }
+
+    /// Returns the program for the given `program ID` and `edition`.
+    fn get_program_for_edition(&self, program_id: &ProgramID<N>, edition: u16) -> Result<Option<Program<N>>> {
+        // Check if the program ID is for 'credits.aleo'.
+        // This case is handled separately, as it is a default program of the VM.
+        if program_id == &ProgramID::from_str("credits.aleo")? {
+            return match edition == N::EDITION {
+                true => Ok(Some(Program::credits()?)),
+                false => Ok(None),
+            };
+        }
+
+        // Retrieve the program.
+        match self.program_map().get_confirmed(&(*program_id, edition))? {
+            Some(program) => Ok(Some(cow_to_cloned!(program))),
+            None => Ok(None),
+        }
+    }
+
+    /// Returns the program for the given `program ID`, in its byte representation.
+    /// The stored bytes are returned as is, without decoding the program.
+    fn get_program_bytes(&self, program_id: &ProgramID<N>) -> Result<Option<Vec<u8>>> {
+        // Check if the program ID is for 'credits.aleo'.
+        // This case is handled separately, as it is a default program of the VM.
+        if program_id == &ProgramID::from_str("credits.aleo")? {
+            return Ok(Some(Program::<N>::credits()?.to_bytes_le()?));
+        }
+
+        // Retrieve the edition.
+        let edition = match self.get_edition(program_id)? {
+            Some(edition) => edition,
+            None => return Ok(None),
+        };
+        // Retrieve the serialized program.
+        match self.program_map().get_raw_confirmed(&(*program_id, edition))? {
+            // Note: A program is serialized as its length-prefixed byte representation.
+            Some(bytes) => Ok(Some(bincode::deserialize::<Vec<u8>>(&bytes)?)),
+            None => bail!(StorageError::Corrupted(format!("Failed to get program '{program_id}' (edition {edition})"))),
+        }
+    }
+
+    /// Returns the program for the given `program ID`, as a string.
+    /// The stored canonical string is returned as is, without decoding the program.
+    fn get_program_string(&self, program_id: &ProgramID<N>) -> Result<Option<String>> {
+        // Check if the program ID is for 'credits.aleo'.
+        // This case is handled separately, as it is a default program of the VM.
+        if program_id == &ProgramID::from_str("credits.aleo")? {
+            return Ok(Some(Program::<N>::credits()?.to_string()));
+        }
+
+        // Retrieve the edition.
+        let edition = match self.get_edition(program_id)? {
+            Some(edition) => edition,
+            None => return Ok(None),
+        };
+        // Retrieve the program string.
+        match self.program_string_map().get_confirmed(&(*program_id, edition))? {
+            Some(program_string) => Ok(Some(cow_to_cloned!(program_string))),
+            None => bail!(StorageError::Corrupted(format!("Failed to get program '{program_id}' (edition {edition})"))),
         }
     }
 
     /// Returns the verifying key for the given `program ID` and `function name`.
-    fn get_verifying_key(
-        &self,
-        program_id: &ProgramID<N>,
-        function_name: &Identifier<N>,
-    ) -> Result<Option<VerifyingKey<N>>> {
+    /// If the verifying key does not exist, a `StorageError::MissingVerifyingKey` error is returned.
+    fn get_verifying_key(&self, program_id: &ProgramID<N>, function_name: &Identifier<N>) -> Result<VerifyingKey<N>> {
         // Check if the program ID is for 'credits.aleo'.
         // This case is handled separately, as it is a default program of the VM.
         // TODO (howardwu): After we update 'fee' rules and 'Ratify' in genesis, we can remove this.
         if program_id == &ProgramID::from_str("credits.aleo")? {
             // Load the verifying key.
             let verifying_key = N::get_credits_verifying_key(function_name.to_string())?;
-            return Ok(Some(VerifyingKey::new(verifying_key.clone())));
+            return Ok(VerifyingKey::new(verifying_key.clone()));
         }
 
+        // Initialize the error for a missing verifying key.
+        let missing = || StorageError::missing_verifying_key(program_id, function_name);
+
         // Retrieve the edition.
         let edition = match self.get_edition(program_id)? {
             Some(edition) => edition,
-            None => return Ok(None),
+            None => return Err(missing().into()),
         };
         // Retrieve the verifying key.
         match self.verifying_key_map().get_confirmed(&(*program_id, *function_name, edition))? {
-            Some(verifying_key) => Ok(Some(cow_to_cloned!(verifying_key))),
-            None => bail!("Failed to get the verifying key for '{program_id}/{function_name}' (edition {edition})"),
+            Some(verifying_key) => Ok(cow_to_cloned!(verifying_key)),
+            None => Err(missing().into()),
+        }
+    }
+
+    /// Returns the violations of the verifying keys, which are each function of the latest edition of a program
+    /// without a verifying key, and each verifying key whose edition of the program is not in storage.
+    fn check_verifying_keys(&self) -> Result<Vec<IntegrityViolation<N>>> {
+        let mut violations = Vec::new();
+        // Retrieve the `(program ID, function name, edition)` of each verifying key.
+        let verifying_key_ids =
+            self.verifying_key_map().keys_confirmed().map(|key| cow_to_copied!(key)).collect::<IndexSet<_>>();
+        // Ensure each function of the latest edition of each program has a verifying key.
+        for (program_id, edition) in self.edition_map().iter_confirmed() {
+            let (program_id, edition) = (cow_to_copied!(program_id), cow_to_copied!(edition));
+            let program = match self.program_map().get_confirmed(&(program_id, edition))? {
+                Some(program) => cow_to_cloned!(program),
+                None => {
+                    violations.push(IntegrityViolation::MissingProgram { program_id, edition });
+                    continue;
+                }
+            };
+            for function_name in program.functions().keys().copied() {
+                if !verifying_key_ids.contains(&(program_id, function_name, edition)) {
+                    violations.push(IntegrityViolation::MissingVerifyingKey { program_id, function_name, edition });
+                }
+            }
+        }
+        // Ensure each verifying key belongs to an edition of a program in storage.
+        for (program_id, function_name, edition) in verifying_key_ids {
+            if !self.program_map().contains_key_confirmed(&(program_id, edition))? {
+                violations.push(IntegrityViolation::OrphanedVerifyingKey { program_id, function_name, edition });
+            }
         }
+        Ok(violations)
+    }
+
+    /// Returns the verifying keys for the given `(program ID, function name)` pairs, in the order they are given.
+    /// The editions and verifying keys are each fetched in one batched read, and a missing verifying key is returned as `None`.
+    fn get_verifying_keys(&self, keys: &[(ProgramID<N>, Identifier<N>)]) -> Result<Vec<Option<VerifyingKey<N>>>> {
+        let credits_id = ProgramID::from_str("credits.aleo")?;
+
+        // Retrieve the edition of each program.
+        // Note: 'credits.aleo' is a default program of the VM, and is handled separately.
+        let program_ids =
+            keys.iter().map(|(program_id, _)| *program_id).filter(|id| id != &credits_id).collect::<IndexSet<_>>();
+        let program_ids = program_ids.into_iter().collect::<Vec<_>>();
+        let editions = self.edition_map().get_many_confirmed(&program_ids)?;
+        let editions = program_ids
+            .iter()
+            .zip(editions)
+            .filter_map(|(program_id, edition)| Some((*program_id, cow_to_copied!(edition?))))
+            .collect::<IndexMap<_, _>>();
+
+        // Retrieve the verifying keys of the deployed programs.
+        let vk_keys = keys
+            .iter()
+            .filter_map(|(program_id, function_name)| Some((*program_id, *function_name, *editions.get(program_id)?)))
+            .collect::<Vec<_>>();
+        let mut verifying_keys = self.verifying_key_map().get_many_confirmed(&vk_keys)?.into_iter();
+
+        // Return the verifying keys in the order they were requested.
+        keys.iter()
+            .map(|(program_id, function_name)| {
+                if program_id == &credits_id {
+                    let verifying_key = N::get_credits_verifying_key(function_name.to_string())?;
+                    return Ok(Some(VerifyingKey::new(verifying_key.clone())));
+                }
+                // Note: The verifying keys were fetched in the order of `keys`, skipping undeployed programs.
+                match editions.contains_key(program_id) {
+                    true => match verifying_keys.next() {
+                        Some(Some(verifying_key)) => Ok(Some(cow_to_cloned!(verifying_key))),
+                        _ => Ok(None),
+                    },
+                    false => Ok(None),
+                }
+            })
+            .collect()
     }
 
     /// Returns the certificate for the given `program ID` and `function name`.
@@ -377,10 +778,50 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
         // Retrieve the certificate.
         match self.certificate_map().get_confirmed(&(*program_id, *function_name, edition))? {
             Some(certificate) => Ok(Some(cow_to_cloned!(certificate))),
-            None => bail!("Failed to get the certificate for '{program_id}/{function_name}' (edition {edition})"),
+            None => bail!(StorageError::NotFound(format!(
+                "Failed to get the certificate for '{program_id}/{function_name}' (edition {edition})"
+            ))),
         }
     }
 
+    /// Returns the `(function name, verifying key, certificate)` of each function of the given `program ID`,
+    /// in the order of their serialized function names. The verifying keys are read in a single range scan
+    /// over the keys that start with the program ID, and the certificates are read in one batched read.
+    fn get_all_functions(
+        &self,
+        program_id: &ProgramID<N>,
+    ) -> Result<Vec<(Identifier<N>, VerifyingKey<N>, Certificate<N>)>> {
+        // Retrieve the edition.
+        let edition = match self.get_edition(program_id)? {
+            Some(edition) => edition,
+            None => bail!(StorageError::NotFound(format!("Failed to get the edition for program '{program_id}'"))),
+        };
+        // Seek to the first verifying key of the program.
+        let raw_key = bincode::serialize(program_id)?;
+        // Stop at the first key that belongs to another program, and skip the keys of other editions.
+        let (keys, verifying_keys): (Vec<_>, Vec<_>) = self
+            .verifying_key_map()
+            .iter_confirmed_from(&raw_key)
+            .map(|(key, verifying_key)| (cow_to_copied!(key), verifying_key))
+            .take_while(|((id, _, _), _)| id == program_id)
+            .filter(|((_, _, key_edition), _)| *key_edition == edition)
+            .map(|(key, verifying_key)| (key, cow_to_cloned!(verifying_key)))
+            .unzip();
+        // Retrieve the certificates.
+        let certificates = self.certificate_map().get_many_confirmed(&keys)?;
+        // Return the functions.
+        keys.into_iter()
+            .zip(verifying_keys)
+            .zip(certificates)
+            .map(|(((_, function_name, _), verifying_key), certificate)| match certificate {
+                Some(certificate) => Ok((function_name, verifying_key, cow_to_cloned!(certificate))),
+                None => bail!(StorageError::Corrupted(format!(
+                    "Failed to get the certificate for '{program_id}/{function_name}' (edition {edition})"
+                ))),
+            })
+            .collect()
+    }
+
     /// Returns the deployment for the given `transaction ID`.
     fn get_deployment(&self, transaction_id: &N::TransactionID) -> Result<Option<Deployment<N>>> {
         // Retrieve the program ID.
@@ -391,12 +832,14 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
         // Retrieve the edition.
         let edition = match self.get_edition(&program_id)? {
             Some(edition) => edition,
-            None => bail!("Failed to get the edition for program '{program_id}'"),
+            None => bail!(StorageError::NotFound(format!("Failed to get the edition for program '{program_id}'"))),
         };
         // Retrieve the program.
         let program = match self.program_map().get_confirmed(&(program_id, edition))? {
             Some(program) => cow_to_cloned!(program),
-            None => bail!("Failed to get the deployed program '{program_id}' (edition {edition})"),
+            None => bail!(StorageError::Corrupted(format!(
+                "Failed to get the deployed program '{program_id}' (edition {edition})"
+            ))),
         };
 
         // Initialize a vector for the verifying keys and certificates.
@@ -407,12 +850,16 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
             // Retrieve the verifying key.
             let verifying_key = match self.verifying_key_map().get_confirmed(&(program_id, *function_name, edition))? {
                 Some(verifying_key) => cow_to_cloned!(verifying_key),
-                None => bail!("Failed to get the verifying key for '{program_id}/{function_name}' (edition {edition})"),
+                None => bail!(StorageError::Corrupted(format!(
+                    "Failed to get the verifying key for '{program_id}/{function_name}' (edition {edition})"
+                ))),
             };
             // Retrieve the certificate.
             let certificate = match self.certificate_map().get_confirmed(&(program_id, *function_name, edition))? {
                 Some(certificate) => cow_to_cloned!(certificate),
-                None => bail!("Failed to get the certificate for '{program_id}/{function_name}' (edition {edition})"),
+                None => bail!(StorageError::Corrupted(format!(
+                    "Failed to get the certificate for '{program_id}/{function_name}' (edition {edition})"
+                ))),
             };
             // Add the verifying key and certificate to the deployment.
             verifying_keys.push((*function_name, (verifying_key, certificate)));
@@ -422,6 +869,57 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
         Ok(Some(Deployment::new(edition, program, verifying_keys)?))
     }
 
+    /// Returns the size in bytes of each component of the deployment for the given `transaction ID`.
+    /// This is computed from the stored components, without reassembling the deployment.
+    fn get_size_breakdown(&self, transaction_id: &N::TransactionID) -> Result<Option<DeploymentSizeReport<N>>> {
+        // Retrieve the program ID.
+        let program_id = match self.get_program_id(transaction_id)? {
+            Some(program_id) => program_id,
+            None => return Ok(None),
+        };
+        // Retrieve the edition.
+        let edition = match self.get_edition(&program_id)? {
+            Some(edition) => edition,
+            None => bail!(StorageError::NotFound(format!("Failed to get the edition for program '{program_id}'"))),
+        };
+        // Retrieve the program.
+        let program = match self.program_map().get_confirmed(&(program_id, edition))? {
+            Some(program) => program,
+            None => bail!(StorageError::Corrupted(format!(
+                "Failed to get the deployed program '{program_id}' (edition {edition})"
+            ))),
+        };
+
+        // Retrieve the verifying keys and certificates.
+        let mut components = Vec::with_capacity(program.functions().len());
+        for function_name in program.functions().keys() {
+            // Retrieve the verifying key.
+            let verifying_key = match self.verifying_key_map().get_confirmed(&(program_id, *function_name, edition))? {
+                Some(verifying_key) => verifying_key,
+                None => bail!(StorageError::Corrupted(format!(
+                    "Failed to get the verifying key for '{program_id}/{function_name}' (edition {edition})"
+                ))),
+            };
+            // Retrieve the certificate.
+            let certificate = match self.certificate_map().get_confirmed(&(program_id, *function_name, edition))? {
+                Some(certificate) => certificate,
+                None => bail!(StorageError::Corrupted(format!(
+                    "Failed to get the certificate for '{program_id}/{function_name}' (edition {edition})"
+                ))),
+            };
+            components.push((function_name, verifying_key, certificate));
+        }
+
+        // Compute the size report.
+        let report = DeploymentSizeReport::new(
+            &program,
+            components.iter().map(|(function_name, verifying_key, certificate)| {
+                (*function_name, verifying_key.as_ref(), certificate.as_ref())
+            }),
+        )?;
+        Ok(Some(report))
+    }
+
     /// Returns the fee for the given `transaction ID`.
     fn get_fee(&self, transaction_id: &N::TransactionID) -> Result<Option<Fee<N>>> {
         self.fee_store().get_fee(transaction_id)
@@ -446,7 +944,9 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
         // Retrieve the owner.
         match self.owner_map().get_confirmed(&(*program_id, edition))? {
             Some(owner) => Ok(Some(cow_to_copied!(owner))),
-            None => bail!("Failed to find the Owner for program '{program_id}' (edition {edition})"),
+            None => bail!(StorageError::Corrupted(format!(
+                "Failed to find the Owner for program '{program_id}' (edition {edition})"
+            ))),
         }
     }
 
@@ -460,13 +960,15 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
         // Retrieve the fee.
         let fee = match self.get_fee(transaction_id)? {
             Some(fee) => fee,
-            None => bail!("Failed to get the fee for transaction '{transaction_id}'"),
+            None => bail!(StorageError::Corrupted(format!("Failed to get the fee for transaction '{transaction_id}'"))),
         };
 
         // Retrieve the owner.
         let owner = match self.get_owner(deployment.program_id())? {
             Some(owner) => owner,
-            None => bail!("Failed to get the owner for transaction '{transaction_id}'"),
+            None => {
+                bail!(StorageError::Corrupted(format!("Failed to get the owner for transaction '{transaction_id}'")))
+            }
         };
 
         // Construct the deployment transaction.
@@ -474,16 +976,23 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
         // Ensure the transaction ID matches.
         match *transaction_id == deployment_transaction.id() {
             true => Ok(Some(deployment_transaction)),
-            false => bail!("The deployment transaction ID does not match '{transaction_id}'"),
+            false => bail!(StorageError::Corrupted(format!(
+                "The deployment transaction ID does not match '{transaction_id}'"
+            ))),
         }
     }
 }
 
+/// The maximum number of programs held in the program cache of a deployment store.
+const MAX_CACHED_PROGRAMS: usize = 64;
+
 /// The deployment store.
 #[derive(Clone)]
 pub struct DeploymentStore<N: Network, D: DeploymentStorage<N>> {
     /// The deployment storage.
     storage: D,
+    /// The cache of recently retrieved programs, keyed by `(program ID, edition)`.
+    program_cache: Arc<RwLock<IndexMap<(ProgramID<N>, u16), Program<N>>>>,
     /// PhantomData.
     _phantom: PhantomData<N>,
 }
@@ -494,22 +1003,53 @@ impl<N: Network, D: DeploymentStorage<N>> DeploymentStore<N, D> {
         // Initialize the deployment storage.
         let storage = D::open(fee_store)?;
         // Return the deployment store.
-        Ok(Self { storage, _phantom: PhantomData })
+        Ok(Self::from(storage))
     }
 
     /// Initializes a deployment store from storage.
     pub fn from(storage: D) -> Self {
-        Self { storage, _phantom: PhantomData }
+        Self { storage, program_cache: Default::default(), _phantom: PhantomData }
     }
 
     /// Stores the given `deployment transaction` into storage.
     pub fn insert(&self, transaction: &Transaction<N>) -> Result<()> {
-        self.storage.insert(transaction)
+        self.storage.insert(transaction)?;
+        // Evict the cached editions of the deployed program.
+        if let Some(deployment) = transaction.deployment() {
+            self.evict_program(deployment.program_id());
+        }
+        Ok(())
+    }
+
+    /// Stores the given `deployment transaction` into storage. If `check_certificates` is `true`, the certificate
+    /// of each function is first checked against its verifying key with `verify`, and a `StorageError::InvalidCertificate` error
+    /// naming the first failing function is returned, without writing to storage.
+    pub fn insert_checked<F>(&self, transaction: &Transaction<N>, check_certificates: bool, verify: F) -> Result<()>
+    where
+        F: Fn(&ProgramID<N>, &Identifier<N>, &VerifyingKey<N>, &Certificate<N>) -> bool,
+    {
+        self.storage.insert_checked(transaction, check_certificates, verify)?;
+        // Evict the cached editions of the deployed program.
+        if let Some(deployment) = transaction.deployment() {
+            self.evict_program(deployment.program_id());
+        }
+        Ok(())
     }
 
     /// Removes the transaction for the given `transaction ID`.
     pub fn remove(&self, transaction_id: &N::TransactionID) -> Result<()> {
-        self.storage.remove(transaction_id)
+        let program_id = self.storage.get_program_id(transaction_id)?;
+        self.storage.remove(transaction_id)?;
+        // Evict the cached editions of the removed program.
+        if let Some(program_id) = program_id {
+            self.evict_program(&program_id);
+        }
+        Ok(())
+    }
+
+    /// Removes every cached edition of the given program from the program cache.
+    fn evict_program(&self, program_id: &ProgramID<N>) {
+        self.program_cache.write().retain(|(cached_program_id, _), _| cached_program_id != program_id);
     }
 
     /// Starts an atomic batch write operation.
@@ -544,7 +1084,21 @@ impl<N: Network, D: DeploymentStorage<N>> DeploymentStore<N, D> {
 
     /// Finishes an atomic batch write operation.
     pub fn finish_atomic(&self) -> Result<()> {
-        self.storage.finish_atomic()
+        self.storage.finish_atomic()?;
+        // Note: The program cache is cleared, as a program may have been cached by a concurrent read
+        // after it was evicted by an insertion or removal in the batch, but before the batch was written.
+        self.program_cache.write().clear();
+        Ok(())
+    }
+
+    /// Reclaims the space left behind by removed entries, and returns an estimate of the number of removed entries.
+    pub fn compact(&self) -> Result<usize> {
+        self.storage.compact()
+    }
+
+    /// Copies each map of the store into the `other` store, which may have a different backend.
+    pub(crate) fn backup_to<S: DeploymentStorage<N>>(&self, other: &DeploymentStore<N, S>) -> Result<()> {
+        self.storage.backup_to(&other.storage)
     }
 
     /// Returns the optional development ID.
@@ -553,6 +1107,14 @@ impl<N: Network, D: DeploymentStorage<N>> DeploymentStore<N, D> {
     }
 }
 
+impl<N: Network> DeploymentStore<N, DeploymentMemory<N>> {
+    /// Returns a point-in-time copy of the committed state of the store, which uses the given copy of the fee store.
+    /// Note: The caller must ensure that no atomic batch is committed while the copy is taken.
+    pub(crate) fn snapshot(&self, fee_store: FeeStore<N, FeeMemory<N>>) -> Self {
+        Self::from(self.storage.snapshot(fee_store))
+    }
+}
+
 impl<N: Network, D: DeploymentStorage<N>> DeploymentStore<N, D> {
     /// Returns the transaction for the given `transaction ID`.
     pub fn get_transaction(&self, transaction_id: &N::TransactionID) -> Result<Option<Transaction<N>>> {
@@ -564,6 +1126,11 @@ impl<N: Network, D: DeploymentStorage<N>> DeploymentStore<N, D> {
         self.storage.get_deployment(transaction_id)
     }
 
+    /// Returns the size in bytes of each component of the deployment for the given `transaction ID`.
+    pub fn get_size_breakdown(&self, transaction_id: &N::TransactionID) -> Result<Option<DeploymentSizeReport<N>>> {
+        self.storage.get_size_breakdown(transaction_id)
+    }
+
     /// Returns the edition for the given `program ID`.
     pub fn get_edition(&self, program_id: &ProgramID<N>) -> Result<Option<u16>> {
         self.storage.get_edition(program_id)
@@ -575,19 +1142,77 @@ impl<N: Network, D: DeploymentStorage<N>> DeploymentStore<N, D> {
     }
 
     /// Returns the program for the given `program ID`.
+    /// The program is cached, so that repeated retrievals of the same edition do not decode it again.
     pub fn get_program(&self, program_id: &ProgramID<N>) -> Result<Option<Program<N>>> {
-        self.storage.get_program(program_id)
+        // Retrieve the edition.
+        // Note: A program without a stored edition, such as 'credits.aleo', is retrieved from storage as is.
+        let edition = match self.storage.get_edition(program_id)? {
+            Some(edition) => edition,
+            None => return self.storage.get_program(program_id),
+        };
+        // Return the program from the cache, if it exists.
+        if let Some(program) = self.program_cache.read().get(&(*program_id, edition)) {
+            return Ok(Some(program.clone()));
+        }
+        // Retrieve the program.
+        let program = match self.storage.get_program_for_edition(program_id, edition)? {
+            Some(program) => program,
+            None => bail!(StorageError::Corrupted(format!("Failed to get program '{program_id}' (edition {edition})"))),
+        };
+        // Cache the program, evicting the oldest program if the cache is full.
+        let mut program_cache = self.program_cache.write();
+        if program_cache.len() >= MAX_CACHED_PROGRAMS {
+            program_cache.shift_remove_index(0);
+        }
+        program_cache.insert((*program_id, edition), program.clone());
+        Ok(Some(program))
+    }
+
+    /// Returns the program for the given `program ID`, in its byte representation, without decoding it.
+    pub fn get_program_bytes(&self, program_id: &ProgramID<N>) -> Result<Option<Vec<u8>>> {
+        self.storage.get_program_bytes(program_id)
+    }
+
+    /// Returns the program for the given `program ID`, as a string, without decoding it.
+    pub fn get_program_string(&self, program_id: &ProgramID<N>) -> Result<Option<String>> {
+        self.storage.get_program_string(program_id)
+    }
+
+    /// Returns the program for the given `program ID` and `edition`.
+    pub fn get_program_for_edition(&self, program_id: &ProgramID<N>, edition: u16) -> Result<Option<Program<N>>> {
+        self.storage.get_program_for_edition(program_id, edition)
     }
 
     /// Returns the verifying key for the given `(program ID, function name)`.
+    /// If the verifying key does not exist, a `StorageError::MissingVerifyingKey` error is returned.
     pub fn get_verifying_key(
         &self,
         program_id: &ProgramID<N>,
         function_name: &Identifier<N>,
-    ) -> Result<Option<VerifyingKey<N>>> {
+    ) -> Result<VerifyingKey<N>> {
         self.storage.get_verifying_key(program_id, function_name)
     }
 
+    /// Returns the verifying keys for the given `(program ID, function name)` pairs, in the order they are given.
+    /// Each distinct pair is fetched once, in one batched read per map.
+    /// A missing verifying key is returned as `None`, so that the caller can report every missing key.
+    pub fn get_verifying_keys_batch(
+        &self,
+        keys: &[(ProgramID<N>, Identifier<N>)],
+    ) -> Result<Vec<Option<VerifyingKey<N>>>> {
+        // Deduplicate the requested pairs.
+        let unique_keys = keys.iter().copied().collect::<IndexSet<_>>();
+        // Fetch the verifying key for each distinct pair, in the order of `unique_keys`.
+        let verifying_keys = self.storage.get_verifying_keys(&unique_keys.iter().copied().collect::<Vec<_>>())?;
+        // Return the verifying keys in the order they were requested.
+        keys.iter()
+            .map(|key| match unique_keys.get_index_of(key) {
+                Some(index) => Ok(verifying_keys[index].clone()),
+                None => bail!("Failed to locate the verifying key for '{}/{}'", key.0, key.1),
+            })
+            .collect()
+    }
+
     /// Returns the certificate for the given `(program ID, function name)`.
     pub fn get_certificate(
         &self,
@@ -597,12 +1222,81 @@ impl<N: Network, D: DeploymentStorage<N>> DeploymentStore<N, D> {
         self.storage.get_certificate(program_id, function_name)
     }
 
+    /// Returns the `(function name, verifying key, certificate)` of each function of the given `program ID`,
+    /// in the order of their serialized function names.
+    pub fn get_all_functions(
+        &self,
+        program_id: &ProgramID<N>,
+    ) -> Result<Vec<(Identifier<N>, VerifyingKey<N>, Certificate<N>)>> {
+        self.storage.get_all_functions(program_id)
+    }
+
+    /// Returns the violations of the verifying keys, which are each function of the latest edition of a program
+    /// without a verifying key, and each verifying key whose edition of the program is not in storage.
+    pub fn check_verifying_keys(&self) -> Result<Vec<IntegrityViolation<N>>> {
+        self.storage.check_verifying_keys()
+    }
+
     /// Returns the fee for the given `transaction ID`.
     pub fn get_fee(&self, transaction_id: &N::TransactionID) -> Result<Option<Fee<N>>> {
         self.storage.get_fee(transaction_id)
     }
 }
 
+impl<N: Network, D: DeploymentStorage<N>> DeploymentStore<N, D> {
+    /// Re-checks the stored certificate of each function in the given program against its stored verifying key,
+    /// where `verify` returns `true` if the certificate is valid for the program function and verifying key.
+    /// Every function is checked, and a function with a missing verifying key or certificate is reported as invalid.
+    pub fn verify_certificates<F>(&self, program_id: &ProgramID<N>, verify: F) -> Result<CertificateReport<N>>
+    where
+        F: Fn(&ProgramID<N>, &Identifier<N>, &VerifyingKey<N>, &Certificate<N>) -> bool,
+    {
+        // Retrieve the program.
+        let Some(program) = self.get_program(program_id)? else {
+            bail!(StorageError::NotFound(format!(
+                "Failed to verify the certificates: program '{program_id}' is not deployed"
+            )))
+        };
+        // Check the certificate of each function.
+        let mut functions = IndexMap::with_capacity(program.functions().len());
+        for function_name in program.functions().keys() {
+            // Retrieve the verifying key.
+            let verifying_key = match self.storage.get_verifying_key(program_id, function_name) {
+                Ok(verifying_key) => Some(verifying_key),
+                Err(error) => match error.downcast_ref::<StorageError>() {
+                    Some(StorageError::MissingVerifyingKey { .. }) => None,
+                    _ => return Err(error),
+                },
+            };
+            // Retrieve the certificate.
+            let certificate = self.storage.get_certificate(program_id, function_name)?;
+            // Check the certificate.
+            let is_valid = match (verifying_key, certificate) {
+                (Some(verifying_key), Some(certificate)) => {
+                    verify(program_id, function_name, &verifying_key, &certificate)
+                }
+                _ => false,
+            };
+            functions.insert(*function_name, is_valid);
+        }
+        Ok(CertificateReport { program_id: *program_id, functions })
+    }
+
+    /// Returns the changes to the given program from edition `from` to edition `to`.
+    pub fn diff_editions(&self, program_id: &ProgramID<N>, from: u16, to: u16) -> Result<ProgramDiff<N>> {
+        // Retrieve the program at each edition.
+        let Some(old) = self.get_program_for_edition(program_id, from)? else {
+            bail!(StorageError::NotFound(format!(
+                "Failed to diff program '{program_id}': edition {from} is not stored"
+            )))
+        };
+        let Some(new) = self.get_program_for_edition(program_id, to)? else {
+            bail!(StorageError::NotFound(format!("Failed to diff program '{program_id}': edition {to} is not stored")))
+        };
+        Ok(old.diff(&new))
+    }
+}
+
 impl<N: Network, D: DeploymentStorage<N>> DeploymentStore<N, D> {
     /// Returns the transaction ID that deployed the given `program ID`.
     pub fn find_transaction_id_from_program_id(&self, program_id: &ProgramID<N>) -> Result<Option<N::TransactionID>> {
@@ -623,6 +1317,16 @@ impl<N: Network, D: DeploymentStorage<N>> DeploymentStore<N, D> {
     pub fn contains_program_id(&self, program_id: &ProgramID<N>) -> Result<bool> {
         self.storage.edition_map().contains_key_confirmed(program_id)
     }
+
+    /// Returns the number of distinct programs, across all editions.
+    pub fn num_programs(&self) -> usize {
+        self.storage.edition_map().keys_confirmed().count()
+    }
+
+    /// Returns the number of verifying keys, for all deployments.
+    pub fn num_verifying_keys(&self) -> usize {
+        self.storage.verifying_key_map().keys_confirmed().count()
+    }
 }
 
 impl<N: Network, D: DeploymentStorage<N>> DeploymentStore<N, D> {
@@ -639,6 +1343,39 @@ impl<N: Network, D: DeploymentStorage<N>> DeploymentStore<N, D> {
         })
     }
 
+    /// Returns an iterator over the program IDs that start with the given prefix, ignoring case, for all deployments.
+    pub fn program_ids_with_prefix(&self, prefix: &str) -> impl '_ + Iterator<Item = Cow<'_, ProgramID<N>>> {
+        // Match the prefix against the lowercase program IDs.
+        let prefix = prefix.to_ascii_lowercase();
+        // Retrieve the part of the prefix that falls within the program name.
+        let name_prefix = prefix.split('.').next().unwrap_or_default().as_bytes().to_vec();
+        // Seek to the first program name that is not less than the name prefix,
+        // and stop at the first program name that no longer starts with it.
+        self.storage
+            .program_name_map()
+            .iter_confirmed_from(&name_prefix)
+            .map(|(key, _)| cow_to_copied!(key))
+            .take_while(move |(program_name, _)| program_name.starts_with(&name_prefix))
+            .map(|(_, program_id)| program_id)
+            .filter(move |program_id| program_id.to_string().to_ascii_lowercase().starts_with(&prefix))
+            .map(Cow::Owned)
+    }
+
+    /// Returns an iterator over the IDs of the programs that have a deployment of the given edition.
+    /// Each stored edition of a program is indexed, so a program may be returned for several editions.
+    pub fn program_ids_by_edition(&self, edition: u16) -> Result<impl '_ + Iterator<Item = Cow<'_, ProgramID<N>>>> {
+        // Seek to the first program ID of the edition.
+        let raw_key = bincode::serialize(&edition)?;
+        // Stop at the first key that belongs to another edition.
+        Ok(self
+            .storage
+            .edition_index_map()
+            .iter_confirmed_from(&raw_key)
+            .map(|(key, _)| cow_to_copied!(key))
+            .take_while(move |(key_edition, _)| *key_edition == edition)
+            .map(|(_, program_id)| Cow::Owned(program_id)))
+    }
+
     /// Returns an iterator over the programs, for all deployments.
     pub fn programs(&self) -> impl '_ + Iterator<Item = Cow<'_, Program<N>>> {
         self.storage.program_map().values_confirmed().map(|program| match program {
@@ -667,6 +1404,8 @@ mod tests {
     use super::*;
     use crate::{helpers::memory::DeploymentMemory, TransitionStore};
 
+    type CurrentNetwork = console::network::Testnet3;
+
     #[test]
     fn test_insert_get_remove() {
         let rng = &mut TestRng::default();
@@ -752,4 +1491,662 @@ mod tests {
             assert_eq!(None, candidate);
         }
     }
+
+    #[test]
+    fn test_program_ids_with_prefix() {
+        let rng = &mut TestRng::default();
+
+        // Sample the deployment transaction.
+        let transaction = ledger_test_helpers::sample_deployment_transaction(true, rng);
+        let program_id = match transaction {
+            Transaction::Deploy(_, _, ref deployment, _) => *deployment.program_id(),
+            _ => panic!("Incorrect transaction type"),
+        };
+
+        // Initialize a new transition store.
+        let transition_store = TransitionStore::open(None).unwrap();
+        // Initialize a new fee store.
+        let fee_store = FeeStore::open(transition_store).unwrap();
+        // Initialize a new deployment store.
+        let deployment_store = DeploymentStore::<_, DeploymentMemory<_>>::open(fee_store).unwrap();
+
+        // Returns the program IDs that start with the given prefix, as strings.
+        let find = |prefix: &str| {
+            let program_ids = deployment_store.program_ids_with_prefix(prefix);
+            program_ids.map(|program_id| program_id.to_string()).collect::<Vec<_>>()
+        };
+
+        // Ensure the deployed program is found once inserted, and no longer found once removed.
+        let program_name = program_id.name().to_string();
+        assert!(find(&program_name).is_empty());
+        deployment_store.insert(&transaction).unwrap();
+        assert_eq!(find(&program_name), [program_id.to_string()]);
+        deployment_store.remove(&transaction.id()).unwrap();
+        assert!(find(&program_name).is_empty());
+
+        // Add several program names directly to the program name map.
+        for program_id in ["tok.aleo", "token.aleo", "token_v2.aleo", "tokens.aleo", "swap.aleo"] {
+            let program_id = ProgramID::<CurrentNetwork>::from_str(program_id).unwrap();
+            deployment_store.storage.program_name_map().insert((to_program_name(&program_id), program_id), ()).unwrap();
+        }
+
+        // Ensure a prefix may match zero programs.
+        assert!(find("bridge").is_empty());
+        assert!(find("tokenz").is_empty());
+        assert!(find("token.aleox").is_empty());
+        // Ensure a prefix may match one program.
+        assert_eq!(find("sw"), ["swap.aleo"]);
+        assert_eq!(find("token."), ["token.aleo"]);
+        assert_eq!(find("tokens.aleo"), ["tokens.aleo"]);
+        // Ensure a prefix may match many programs, in order.
+        assert_eq!(find("tok"), ["tok.aleo", "token.aleo", "token_v2.aleo", "tokens.aleo"]);
+        assert_eq!(find("token"), ["token.aleo", "token_v2.aleo", "tokens.aleo"]);
+        assert_eq!(find("").len(), 5);
+        // Ensure the prefix is matched regardless of case.
+        assert_eq!(find("SWAP.Aleo"), ["swap.aleo"]);
+        assert_eq!(find("Token_"), ["token_v2.aleo"]);
+    }
+
+    #[test]
+    fn test_program_ids_by_edition() {
+        let rng = &mut TestRng::default();
+
+        // Sample the deployment transaction.
+        let transaction = ledger_test_helpers::sample_deployment_transaction(true, rng);
+        let (program_id, edition) = match transaction {
+            Transaction::Deploy(_, _, ref deployment, _) => (*deployment.program_id(), deployment.edition()),
+            _ => panic!("Incorrect transaction type"),
+        };
+
+        // Initialize a new transition store.
+        let transition_store = TransitionStore::open(None).unwrap();
+        // Initialize a new fee store.
+        let fee_store = FeeStore::open(transition_store).unwrap();
+        // Initialize a new deployment store.
+        let deployment_store = DeploymentStore::<_, DeploymentMemory<_>>::open(fee_store).unwrap();
+
+        // Returns the program IDs of the given edition, as strings.
+        let find = |edition: u16| {
+            let program_ids = deployment_store.program_ids_by_edition(edition).unwrap();
+            program_ids.map(|program_id| program_id.to_string()).collect::<Vec<_>>()
+        };
+
+        // Ensure the deployed program is indexed once inserted, and no longer indexed once removed.
+        assert!(find(edition).is_empty());
+        deployment_store.insert(&transaction).unwrap();
+        assert_eq!(find(edition), [program_id.to_string()]);
+        assert!(find(edition + 1).is_empty());
+        deployment_store.remove(&transaction.id()).unwrap();
+        assert!(find(edition).is_empty());
+
+        // Add several editions directly to the edition index map.
+        // Note: Editions 1 and 256 share a byte in their serialized form, and must not be confused.
+        for (edition, program_id) in [(1, "token.aleo"), (256, "swap.aleo"), (1, "swap.aleo"), (2, "bridge.aleo")] {
+            let program_id = ProgramID::<CurrentNetwork>::from_str(program_id).unwrap();
+            deployment_store.storage.edition_index_map().insert((edition, program_id), ()).unwrap();
+        }
+
+        // Ensure each edition returns only its own programs.
+        let mut programs_1 = find(1);
+        programs_1.sort();
+        assert_eq!(programs_1, ["swap.aleo", "token.aleo"]);
+        assert_eq!(find(2), ["bridge.aleo"]);
+        assert_eq!(find(256), ["swap.aleo"]);
+        assert!(find(3).is_empty());
+        assert!(find(u16::MAX).is_empty());
+    }
+
+    #[test]
+    fn test_backfill_edition_index() {
+        let rng = &mut TestRng::default();
+
+        // Sample the deployment transaction.
+        let transaction = ledger_test_helpers::sample_deployment_transaction(true, rng);
+        let (program_id, edition) = match transaction {
+            Transaction::Deploy(_, _, ref deployment, _) => (*deployment.program_id(), deployment.edition()),
+            _ => panic!("Incorrect transaction type"),
+        };
+
+        // Initialize a new transition store.
+        let transition_store = TransitionStore::open(None).unwrap();
+        // Initialize a new fee store.
+        let fee_store = FeeStore::open(transition_store).unwrap();
+        // Initialize a new deployment store.
+        let deployment_store = DeploymentStore::<_, DeploymentMemory<_>>::open(fee_store).unwrap();
+
+        // Insert the deployment, and remove it from the edition index map, as if it was stored before the index.
+        deployment_store.insert(&transaction).unwrap();
+        deployment_store.storage.edition_index_map().remove(&(edition, program_id)).unwrap();
+        assert_eq!(deployment_store.program_ids_by_edition(edition).unwrap().count(), 0);
+
+        // Ensure the backfill restores the entry, and adds nothing when run again.
+        assert_eq!(deployment_store.storage.backfill_edition_index().unwrap(), 1);
+        let program_ids = deployment_store.program_ids_by_edition(edition).unwrap().map(|id| *id).collect::<Vec<_>>();
+        assert_eq!(program_ids, [program_id]);
+        assert_eq!(deployment_store.storage.backfill_edition_index().unwrap(), 0);
+    }
+
+    #[test]
+    fn test_backfill_program_names() {
+        let rng = &mut TestRng::default();
+
+        // Sample the deployment transaction.
+        let transaction = ledger_test_helpers::sample_deployment_transaction(true, rng);
+        let program_id = match transaction {
+            Transaction::Deploy(_, _, ref deployment, _) => *deployment.program_id(),
+            _ => panic!("Incorrect transaction type"),
+        };
+        let program_name = program_id.name().to_string();
+
+        // Initialize a new transition store.
+        let transition_store = TransitionStore::open(None).unwrap();
+        // Initialize a new fee store.
+        let fee_store = FeeStore::open(transition_store).unwrap();
+        // Initialize a new deployment store.
+        let deployment_store = DeploymentStore::<_, DeploymentMemory<_>>::open(fee_store).unwrap();
+
+        // Insert the deployment, and remove it from the program name map, as if it was stored before the index.
+        deployment_store.insert(&transaction).unwrap();
+        deployment_store.storage.program_name_map().remove(&(to_program_name(&program_id), program_id)).unwrap();
+        assert_eq!(deployment_store.program_ids_with_prefix(&program_name).count(), 0);
+
+        // Ensure the backfill restores the entry, and adds nothing when run again.
+        assert_eq!(deployment_store.storage.backfill_program_names().unwrap(), 1);
+        let program_ids = deployment_store.program_ids_with_prefix(&program_name).map(|id| *id).collect::<Vec<_>>();
+        assert_eq!(program_ids, [program_id]);
+        assert_eq!(deployment_store.storage.backfill_program_names().unwrap(), 0);
+    }
+
+    #[test]
+    fn test_backfill_program_strings() {
+        let rng = &mut TestRng::default();
+
+        // Sample the deployment transaction.
+        let transaction = ledger_test_helpers::sample_deployment_transaction(true, rng);
+        let program = transaction.deployment().unwrap().program().clone();
+        let program_id = *program.id();
+        let edition = CurrentNetwork::EDITION;
+
+        // Initialize a new transition store.
+        let transition_store = TransitionStore::open(None).unwrap();
+        // Initialize a new fee store.
+        let fee_store = FeeStore::open(transition_store).unwrap();
+        // Initialize a new deployment store.
+        let deployment_store = DeploymentStore::<_, DeploymentMemory<_>>::open(fee_store).unwrap();
+
+        // Insert the deployment, and remove it from the program string map, as if it was stored before the map.
+        deployment_store.insert(&transaction).unwrap();
+        deployment_store.storage.program_string_map().remove(&(program_id, edition)).unwrap();
+        assert!(deployment_store.get_program_string(&program_id).is_err());
+
+        // Ensure the backfill restores the entry, and adds nothing when run again.
+        assert_eq!(deployment_store.storage.backfill_program_strings().unwrap(), 1);
+        assert_eq!(deployment_store.get_program_string(&program_id).unwrap(), Some(program.to_string()));
+        assert_eq!(deployment_store.storage.backfill_program_strings().unwrap(), 0);
+    }
+
+    #[test]
+    fn test_get_verifying_key() {
+        let rng = &mut TestRng::default();
+
+        // Sample the deployment transaction.
+        let transaction = ledger_test_helpers::sample_deployment_transaction(true, rng);
+        let deployment = match transaction {
+            Transaction::Deploy(_, _, ref deployment, _) => deployment.clone(),
+            _ => panic!("Incorrect transaction type"),
+        };
+        let program_id = *deployment.program_id();
+
+        // Initialize a new transition store.
+        let transition_store = TransitionStore::open(None).unwrap();
+        // Initialize a new fee store.
+        let fee_store = FeeStore::open(transition_store).unwrap();
+        // Initialize a new deployment store.
+        let deployment_store = DeploymentStore::<_, DeploymentMemory<_>>::open(fee_store).unwrap();
+
+        for (function_name, _) in deployment.verifying_keys() {
+            // Ensure the verifying key is reported as missing.
+            let error = deployment_store.get_verifying_key(&program_id, function_name).unwrap_err();
+            let expected = StorageError::missing_verifying_key(program_id, function_name);
+            assert_eq!(Some(&expected), error.downcast_ref::<StorageError>());
+        }
+
+        // Insert the deployment transaction.
+        deployment_store.insert(&transaction).unwrap();
+
+        for (function_name, (verifying_key, _)) in deployment.verifying_keys() {
+            // Retrieve the verifying key.
+            let candidate = deployment_store.get_verifying_key(&program_id, function_name).unwrap();
+            assert_eq!(*verifying_key, candidate);
+        }
+
+        // Ensure a function that was not deployed is reported as missing.
+        let function_name = Identifier::from_str("missing_function").unwrap();
+        let error = deployment_store.get_verifying_key(&program_id, &function_name).unwrap_err();
+        let expected = StorageError::missing_verifying_key(program_id, function_name);
+        assert_eq!(Some(&expected), error.downcast_ref::<StorageError>());
+    }
+
+    #[test]
+    fn test_check_verifying_keys() {
+        let rng = &mut TestRng::default();
+
+        // Sample the deployment transaction.
+        let transaction = ledger_test_helpers::sample_deployment_transaction(true, rng);
+        let deployment = match transaction {
+            Transaction::Deploy(_, _, ref deployment, _) => deployment.clone(),
+            _ => panic!("Incorrect transaction type"),
+        };
+        let program_id = *deployment.program_id();
+        let edition = deployment.edition();
+
+        // Initialize a new transition store.
+        let transition_store = TransitionStore::open(None).unwrap();
+        // Initialize a new fee store.
+        let fee_store = FeeStore::open(transition_store).unwrap();
+        // Initialize a new deployment store.
+        let deployment_store = DeploymentStore::<_, DeploymentMemory<_>>::open(fee_store).unwrap();
+        assert!(deployment_store.check_verifying_keys().unwrap().is_empty());
+
+        // Insert the deployment transaction.
+        deployment_store.insert(&transaction).unwrap();
+        assert!(deployment_store.check_verifying_keys().unwrap().is_empty());
+
+        // Remove the verifying key of a function.
+        let (function_name, (verifying_key, _)) = &deployment.verifying_keys()[0];
+        let verifying_key_map = deployment_store.storage.verifying_key_map();
+        verifying_key_map.remove(&(program_id, *function_name, edition)).unwrap();
+        let missing = IntegrityViolation::MissingVerifyingKey { program_id, function_name: *function_name, edition };
+        assert_eq!(deployment_store.check_verifying_keys().unwrap(), vec![missing.clone()]);
+
+        // Add a verifying key for an edition which is not in storage.
+        verifying_key_map.insert((program_id, *function_name, edition + 1), verifying_key.clone()).unwrap();
+        let (function_name, edition_1) = (*function_name, edition + 1);
+        let orphaned = IntegrityViolation::OrphanedVerifyingKey { program_id, function_name, edition: edition_1 };
+        assert_eq!(deployment_store.check_verifying_keys().unwrap(), vec![missing, orphaned.clone()]);
+
+        // Remove the program, which leaves the remaining verifying keys of its edition without a program.
+        deployment_store.storage.program_map().remove(&(program_id, edition)).unwrap();
+        let violations = deployment_store.check_verifying_keys().unwrap();
+        assert_eq!(violations.len(), deployment.verifying_keys().len() + 1);
+        assert_eq!(violations[0], IntegrityViolation::MissingProgram { program_id, edition });
+        assert!(violations.contains(&orphaned));
+        for (function_name, _) in &deployment.verifying_keys()[1..] {
+            let function_name = *function_name;
+            let orphaned = IntegrityViolation::OrphanedVerifyingKey { program_id, function_name, edition };
+            assert!(violations.contains(&orphaned));
+        }
+    }
+
+    #[test]
+    fn test_get_verifying_keys_batch() {
+        let rng = &mut TestRng::default();
+
+        // Sample the deployment transaction.
+        let transaction = ledger_test_helpers::sample_deployment_transaction(true, rng);
+        let deployment = match transaction {
+            Transaction::Deploy(_, _, ref deployment, _) => deployment.clone(),
+            _ => panic!("Incorrect transaction type"),
+        };
+        let program_id = *deployment.program_id();
+
+        // Initialize a new transition store.
+        let transition_store = TransitionStore::open(None).unwrap();
+        // Initialize a new fee store.
+        let fee_store = FeeStore::open(transition_store).unwrap();
+        // Initialize a new deployment store.
+        let deployment_store = DeploymentStore::<_, DeploymentMemory<_>>::open(fee_store).unwrap();
+
+        // Insert the deployment transaction.
+        deployment_store.insert(&transaction).unwrap();
+
+        // Prepare the requested keys, with duplicates and missing entries.
+        let (function_name, (verifying_key, _)) = deployment.verifying_keys().iter().next().unwrap();
+        let missing_function = Identifier::from_str("missing_function").unwrap();
+        let missing_program = ProgramID::from_str("missing_program.aleo").unwrap();
+        let keys = [
+            (program_id, *function_name),
+            (program_id, missing_function),
+            (program_id, *function_name),
+            (missing_program, *function_name),
+            (program_id, missing_function),
+        ];
+
+        // Ensure the verifying keys are returned in the order requested.
+        let candidates = deployment_store.get_verifying_keys_batch(&keys).unwrap();
+        let expected = [Some(verifying_key.clone()), None, Some(verifying_key.clone()), None, None];
+        assert_eq!(candidates, expected);
+
+        // Ensure an empty request returns no verifying keys.
+        assert!(deployment_store.get_verifying_keys_batch(&[]).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_insert_checked() {
+        let rng = &mut TestRng::default();
+
+        // Sample the deployment transaction.
+        let transaction = ledger_test_helpers::sample_deployment_transaction(true, rng);
+        let (transaction_id, owner, deployment, fee) = match transaction {
+            Transaction::Deploy(transaction_id, owner, ref deployment, ref fee) => {
+                (transaction_id, owner, deployment.clone(), fee.clone())
+            }
+            _ => panic!("Incorrect transaction type"),
+        };
+        let program_id = *deployment.program_id();
+
+        // Check each pair against the pairs of the sampled deployment.
+        let verify = |_: &ProgramID<CurrentNetwork>,
+                      function_name: &Identifier<CurrentNetwork>,
+                      verifying_key: &VerifyingKey<CurrentNetwork>,
+                      certificate: &Certificate<CurrentNetwork>| {
+            deployment.verifying_keys().iter().any(|(name, (expected_key, expected_certificate))| {
+                name == function_name && expected_key == verifying_key && expected_certificate == certificate
+            })
+        };
+
+        // Craft a deployment whose certificate is paired with the verifying key of another function.
+        let (function_name, (_, certificate)) = deployment.verifying_keys()[0].clone();
+        let other_key = CurrentNetwork::get_credits_verifying_key("transfer_public".to_string()).unwrap();
+        let verifying_keys = vec![(function_name, (VerifyingKey::new(other_key.clone()), certificate))];
+        let malformed = Deployment::new(deployment.edition(), deployment.program().clone(), verifying_keys).unwrap();
+        let malformed = Transaction::Deploy(transaction_id, owner, Box::new(malformed), fee);
+
+        // Initialize a new transition store.
+        let transition_store = TransitionStore::open(None).unwrap();
+        // Initialize a new fee store.
+        let fee_store = FeeStore::open(transition_store).unwrap();
+        // Initialize a new deployment store.
+        let deployment_store = DeploymentStore::<_, DeploymentMemory<_>>::open(fee_store).unwrap();
+
+        // Ensure the malformed deployment is rejected, naming the function, and that nothing is written.
+        let error = deployment_store.insert_checked(&malformed, true, verify).unwrap_err();
+        let expected = StorageError::invalid_certificate(program_id, function_name);
+        assert_eq!(Some(&expected), error.downcast_ref::<StorageError>());
+        assert!(!deployment_store.contains_program_id(&program_id).unwrap());
+        assert_eq!(deployment_store.get_program_id(&transaction_id).unwrap(), None);
+        assert_eq!(deployment_store.num_verifying_keys(), 0);
+
+        // Ensure the malformed deployment is stored if the check is skipped.
+        deployment_store.insert_checked(&malformed, false, verify).unwrap();
+        assert!(deployment_store.contains_program_id(&program_id).unwrap());
+        deployment_store.remove(&transaction_id).unwrap();
+        assert!(!deployment_store.contains_program_id(&program_id).unwrap());
+
+        // Ensure the well-formed deployment is stored.
+        deployment_store.insert_checked(&transaction, true, verify).unwrap();
+        assert_eq!(deployment_store.get_deployment(&transaction_id).unwrap(), Some(deployment.clone()));
+    }
+
+    #[test]
+    fn test_get_all_functions() {
+        let rng = &mut TestRng::default();
+
+        // Sample the deployment transaction.
+        let transaction = ledger_test_helpers::sample_deployment_transaction(true, rng);
+        let deployment = match transaction {
+            Transaction::Deploy(_, _, ref deployment, _) => deployment.clone(),
+            _ => panic!("Incorrect transaction type"),
+        };
+        let program_id = *deployment.program_id();
+
+        // Initialize a new transition store.
+        let transition_store = TransitionStore::open(None).unwrap();
+        // Initialize a new fee store.
+        let fee_store = FeeStore::open(transition_store).unwrap();
+        // Initialize a new deployment store.
+        let deployment_store = DeploymentStore::<_, DeploymentMemory<_>>::open(fee_store).unwrap();
+        assert!(deployment_store.get_all_functions(&program_id).is_err());
+
+        // Insert the deployment transaction.
+        deployment_store.insert(&transaction).unwrap();
+
+        // Store a verifying key for another edition, and for another program.
+        let (function_name, (verifying_key, _)) = deployment.verifying_keys().iter().next().unwrap();
+        let other_program = ProgramID::from_str("zzz_other.aleo").unwrap();
+        let (edition, verifying_key_map) = (deployment.edition(), deployment_store.storage.verifying_key_map());
+        verifying_key_map.insert((program_id, *function_name, edition + 1), verifying_key.clone()).unwrap();
+        verifying_key_map.insert((other_program, *function_name, edition), verifying_key.clone()).unwrap();
+
+        // Ensure the functions match the deployment, in any order.
+        let mut functions = deployment_store.get_all_functions(&program_id).unwrap();
+        functions.sort_by_key(|(function_name, _, _)| function_name.to_string());
+        let mut expected = deployment
+            .verifying_keys()
+            .iter()
+            .map(|(function_name, (verifying_key, certificate))| {
+                (*function_name, verifying_key.clone(), certificate.clone())
+            })
+            .collect::<Vec<_>>();
+        expected.sort_by_key(|(function_name, _, _)| function_name.to_string());
+        assert_eq!(functions, expected);
+
+        // Ensure a program that was not deployed fails.
+        assert!(deployment_store.get_all_functions(&other_program).is_err());
+    }
+
+    #[test]
+    fn test_get_size_breakdown() {
+        let rng = &mut TestRng::default();
+
+        // Sample the deployment transaction.
+        let transaction = ledger_test_helpers::sample_deployment_transaction(true, rng);
+        let deployment = match transaction {
+            Transaction::Deploy(_, _, ref deployment, _) => deployment.clone(),
+            _ => panic!("Incorrect transaction type"),
+        };
+
+        // Initialize a new transition store.
+        let transition_store = TransitionStore::open(None).unwrap();
+        // Initialize a new fee store.
+        let fee_store = FeeStore::open(transition_store).unwrap();
+        // Initialize a new deployment store.
+        let deployment_store = DeploymentStore::<_, DeploymentMemory<_>>::open(fee_store).unwrap();
+        assert_eq!(deployment_store.get_size_breakdown(&transaction.id()).unwrap(), None);
+
+        // Insert the deployment transaction.
+        deployment_store.insert(&transaction).unwrap();
+
+        // Ensure the size report matches the deployment, and its total matches the size of the deployment.
+        let report = deployment_store.get_size_breakdown(&transaction.id()).unwrap().unwrap();
+        assert_eq!(report, deployment.size_breakdown().unwrap());
+        assert_eq!(report.total(), deployment.size_in_bytes().unwrap());
+    }
+
+    #[test]
+    fn test_diff_editions() {
+        let rng = &mut TestRng::default();
+
+        // Sample the deployment transaction.
+        let transaction = ledger_test_helpers::sample_deployment_transaction(true, rng);
+        let program_id = match transaction {
+            Transaction::Deploy(_, _, ref deployment, _) => *deployment.program_id(),
+            _ => panic!("Incorrect transaction type"),
+        };
+        let edition = CurrentNetwork::EDITION;
+
+        // Initialize a new transition store.
+        let transition_store = TransitionStore::open(None).unwrap();
+        // Initialize a new fee store.
+        let fee_store = FeeStore::open(transition_store).unwrap();
+        // Initialize a new deployment store.
+        let deployment_store = DeploymentStore::<_, DeploymentMemory<_>>::open(fee_store).unwrap();
+        assert!(deployment_store.diff_editions(&program_id, edition, edition).is_err());
+
+        // Insert the deployment transaction.
+        deployment_store.insert(&transaction).unwrap();
+
+        // Ensure the stored edition matches the latest program, and is unchanged with respect to itself.
+        let program = deployment_store.get_program_for_edition(&program_id, edition).unwrap();
+        assert_eq!(program, deployment_store.get_program(&program_id).unwrap());
+        assert!(deployment_store.diff_editions(&program_id, edition, edition).unwrap().is_empty());
+
+        // Ensure an edition which is not stored cannot be diffed.
+        assert_eq!(deployment_store.get_program_for_edition(&program_id, edition + 1).unwrap(), None);
+        assert!(deployment_store.diff_editions(&program_id, edition, edition + 1).is_err());
+        assert!(deployment_store.diff_editions(&program_id, edition + 1, edition).is_err());
+    }
+
+    #[test]
+    fn test_get_program_string_and_bytes() {
+        let rng = &mut TestRng::default();
+
+        // Sample the deployment transaction.
+        let transaction = ledger_test_helpers::sample_deployment_transaction(true, rng);
+        let program_id = *transaction.deployment().unwrap().program_id();
+
+        // Initialize a new transition store.
+        let transition_store = TransitionStore::open(None).unwrap();
+        // Initialize a new fee store.
+        let fee_store = FeeStore::open(transition_store).unwrap();
+        // Initialize a new deployment store.
+        let deployment_store = DeploymentStore::<_, DeploymentMemory<_>>::open(fee_store).unwrap();
+        assert_eq!(deployment_store.get_program_string(&program_id).unwrap(), None);
+        assert_eq!(deployment_store.get_program_bytes(&program_id).unwrap(), None);
+
+        // Insert the deployment transaction.
+        deployment_store.insert(&transaction).unwrap();
+
+        // Ensure the string and bytes match the retrieved program.
+        let program = deployment_store.get_program(&program_id).unwrap().unwrap();
+        assert_eq!(deployment_store.get_program_string(&program_id).unwrap(), Some(program.to_string()));
+        assert_eq!(deployment_store.get_program_bytes(&program_id).unwrap(), Some(program.to_bytes_le().unwrap()));
+        let bytes = deployment_store.get_program_bytes(&program_id).unwrap().unwrap();
+        assert_eq!(Program::from_bytes_le(&bytes).unwrap(), program);
+
+        // Ensure 'credits.aleo' is also served.
+        let credits_id = ProgramID::from_str("credits.aleo").unwrap();
+        let credits = Program::<CurrentNetwork>::credits().unwrap();
+        assert_eq!(deployment_store.get_program_string(&credits_id).unwrap(), Some(credits.to_string()));
+        assert_eq!(deployment_store.get_program_bytes(&credits_id).unwrap(), Some(credits.to_bytes_le().unwrap()));
+    }
+
+    #[test]
+    fn test_program_cache() {
+        let rng = &mut TestRng::default();
+
+        // Sample the deployment transaction.
+        let transaction = ledger_test_helpers::sample_deployment_transaction(true, rng);
+        let transaction_id = transaction.id();
+        let program = transaction.deployment().unwrap().program().clone();
+        let program_id = *program.id();
+        let edition = CurrentNetwork::EDITION;
+
+        // Initialize a new transition store.
+        let transition_store = TransitionStore::open(None).unwrap();
+        // Initialize a new fee store.
+        let fee_store = FeeStore::open(transition_store).unwrap();
+        // Initialize a new deployment store.
+        let deployment_store = DeploymentStore::<_, DeploymentMemory<_>>::open(fee_store).unwrap();
+
+        // Insert the deployment transaction, and ensure the program is cached once it is retrieved.
+        deployment_store.insert(&transaction).unwrap();
+        assert!(deployment_store.program_cache.read().is_empty());
+        assert_eq!(deployment_store.get_program(&program_id).unwrap(), Some(program.clone()));
+        assert_eq!(deployment_store.program_cache.read().get(&(program_id, edition)), Some(&program));
+
+        // Store a new edition of the program.
+        let new_program = Program::<CurrentNetwork>::from_str(&format!(
+            "program {program_id};\n\nfunction new_edition:\n    input r0 as u8.private;\n    output r0 as u8.private;"
+        ))
+        .unwrap();
+        deployment_store.storage.program_map().insert((program_id, edition + 1), new_program.clone()).unwrap();
+        let new_program_string = new_program.to_string();
+        deployment_store.storage.program_string_map().insert((program_id, edition + 1), new_program_string).unwrap();
+        deployment_store.storage.edition_map().insert(program_id, edition + 1).unwrap();
+
+        // Ensure the new edition is retrieved instead of the cached edition.
+        assert_eq!(deployment_store.get_program(&program_id).unwrap(), Some(new_program.clone()));
+        assert_eq!(deployment_store.get_program_string(&program_id).unwrap(), Some(new_program.to_string()));
+        assert_eq!(deployment_store.program_cache.read().get(&(program_id, edition + 1)), Some(&new_program));
+
+        // Restore the original edition, and ensure the removal evicts the cached editions.
+        deployment_store.storage.edition_map().insert(program_id, edition).unwrap();
+        deployment_store.storage.program_map().remove(&(program_id, edition + 1)).unwrap();
+        deployment_store.storage.program_string_map().remove(&(program_id, edition + 1)).unwrap();
+        deployment_store.remove(&transaction_id).unwrap();
+        assert!(deployment_store.program_cache.read().is_empty());
+        assert_eq!(deployment_store.get_program(&program_id).unwrap(), None);
+    }
+
+    #[test]
+    fn test_deduplicate_verifying_keys() {
+        let rng = &mut TestRng::default();
+
+        // Sample a verifying key.
+        let transaction = ledger_test_helpers::sample_deployment_transaction(true, rng);
+        let (function_name, (verifying_key, _)) = match transaction {
+            Transaction::Deploy(_, _, ref deployment, _) => deployment.verifying_keys()[0].clone(),
+            _ => panic!("Incorrect transaction type"),
+        };
+        let verifying_key_bytes = verifying_key.to_bytes_le().unwrap();
+
+        // Initialize a new transition store.
+        let transition_store = TransitionStore::open(None).unwrap();
+        // Initialize a new fee store.
+        let fee_store = FeeStore::open(transition_store).unwrap();
+        // Initialize a new deployment storage.
+        let deployment_storage = DeploymentMemory::open(fee_store).unwrap();
+
+        // Store a separately-allocated copy of the same verifying key for 100 programs.
+        const NUM_PROGRAMS: usize = 100;
+        for i in 0..NUM_PROGRAMS {
+            let program_id = ProgramID::<CurrentNetwork>::from_str(&format!("program_{i}.aleo")).unwrap();
+            let key = (program_id, function_name, 0);
+            let verifying_key = VerifyingKey::read_le(&verifying_key_bytes[..]).unwrap();
+            let verifying_key = deployment_storage.deduplicate_verifying_key(&key, &verifying_key).unwrap();
+            deployment_storage.verifying_key_map().insert(key, verifying_key).unwrap();
+        }
+
+        // Ensure the programs share a single copy of the verifying key.
+        let mut copies = deployment_storage
+            .verifying_key_map()
+            .values_confirmed()
+            .map(|verifying_key| &**verifying_key as *const _ as usize)
+            .collect::<Vec<_>>();
+        assert_eq!(copies.len(), NUM_PROGRAMS);
+        copies.sort_unstable();
+        copies.dedup();
+        assert_eq!(copies.len(), 1);
+        // Ensure this saves `NUM_PROGRAMS - 1` copies of the verifying key.
+        let stored_bytes = copies.len() * verifying_key_bytes.len();
+        let savings = NUM_PROGRAMS * verifying_key_bytes.len() - stored_bytes;
+        assert_eq!(savings, (NUM_PROGRAMS - 1) * verifying_key_bytes.len());
+        assert_eq!(deployment_storage.num_indexed_verifying_keys(), 1);
+
+        // Ensure the snapshot keeps the index, and still shares the copy of the verifying key.
+        let fee_store = deployment_storage.fee_store().clone();
+        let snapshot = deployment_storage.snapshot(fee_store);
+        assert_eq!(snapshot.num_indexed_verifying_keys(), 1);
+        let key = (ProgramID::<CurrentNetwork>::from_str("program_snapshot.aleo").unwrap(), function_name, 0);
+        let verifying_key = VerifyingKey::read_le(&verifying_key_bytes[..]).unwrap();
+        let verifying_key = snapshot.deduplicate_verifying_key(&key, &verifying_key).unwrap();
+        assert_eq!(&*verifying_key as *const _ as usize, copies[0]);
+    }
+
+    #[test]
+    fn test_remove_deindexes_verifying_keys() {
+        let rng = &mut TestRng::default();
+
+        // Sample the deployment transaction.
+        let transaction = ledger_test_helpers::sample_deployment_transaction(true, rng);
+        let num_functions = match transaction {
+            Transaction::Deploy(_, _, ref deployment, _) => deployment.verifying_keys().len(),
+            _ => panic!("Incorrect transaction type"),
+        };
+
+        // Initialize a new transition store.
+        let transition_store = TransitionStore::open(None).unwrap();
+        // Initialize a new fee store.
+        let fee_store = FeeStore::open(transition_store).unwrap();
+        // Initialize a new deployment store.
+        let deployment_store = DeploymentStore::<_, DeploymentMemory<_>>::open(fee_store).unwrap();
+
+        // Ensure the verifying keys are indexed once inserted, and no longer indexed once removed.
+        deployment_store.insert(&transaction).unwrap();
+        assert!(deployment_store.storage.num_indexed_verifying_keys() > 0);
+        assert!(deployment_store.storage.num_indexed_verifying_keys() <= num_functions);
+        deployment_store.remove(&transaction.id()).unwrap();
+        assert_eq!(deployment_store.storage.num_indexed_verifying_keys(), 0);
+    }
 }
@@ -0,0 +1,136 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use parking_lot::Mutex;
+use std::sync::{
+    mpsc::{sync_channel, Receiver, SyncSender, TrySendError},
+    Arc,
+};
+
+/// The number of events buffered for each subscriber of a transaction store.
+/// Once a subscriber has this many undelivered events, further events are dropped for that subscriber.
+pub const TRANSACTION_EVENT_CAPACITY: usize = 1024;
+
+/// A change to a transaction store, which is delivered to the subscribers once it is committed.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum TransactionEvent<N: Network> {
+    /// The transaction was inserted.
+    Inserted { id: N::TransactionID, transaction_type: TransactionType },
+    /// The transaction was inserted as rejected, so it is not in the transaction ID map.
+    Rejected { id: N::TransactionID },
+    /// The transaction was removed.
+    Removed { id: N::TransactionID },
+}
+
+impl<N: Network> TransactionEvent<N> {
+    /// Returns the event for the insertion of the given `transaction`.
+    pub(super) fn inserted(transaction: &Transaction<N>) -> Self {
+        Self::Inserted { id: transaction.id(), transaction_type: transaction.into() }
+    }
+
+    /// Returns the transaction ID.
+    pub const fn id(&self) -> &N::TransactionID {
+        match self {
+            Self::Inserted { id, .. } => id,
+            Self::Rejected { id } => id,
+            Self::Removed { id } => id,
+        }
+    }
+}
+
+/// The subscribers of a transaction store, and the events of the atomic batch in progress.
+/// The events of a batch are held back until it is finished, and mirror its checkpoints and rewinds.
+pub(super) struct Subscribers<N: Network> {
+    /// The senders to each subscriber.
+    senders: Arc<Mutex<Vec<SyncSender<TransactionEvent<N>>>>>,
+    /// The events of the atomic batch in progress, in the order they were written.
+    pending: Arc<Mutex<Vec<TransactionEvent<N>>>>,
+    /// The number of pending events at each checkpoint of the atomic batch.
+    checkpoints: Arc<Mutex<Vec<usize>>>,
+}
+
+impl<N: Network> Clone for Subscribers<N> {
+    /// Returns a handle to the same subscribers.
+    fn clone(&self) -> Self {
+        Self { senders: self.senders.clone(), pending: self.pending.clone(), checkpoints: self.checkpoints.clone() }
+    }
+}
+
+impl<N: Network> Default for Subscribers<N> {
+    /// Returns an empty set of subscribers.
+    fn default() -> Self {
+        Self { senders: Default::default(), pending: Default::default(), checkpoints: Default::default() }
+    }
+}
+
+impl<N: Network> Subscribers<N> {
+    /// Returns the receiver of a new subscriber.
+    pub(super) fn subscribe(&self) -> Receiver<TransactionEvent<N>> {
+        let (sender, receiver) = sync_channel(TRANSACTION_EVENT_CAPACITY);
+        self.senders.lock().push(sender);
+        receiver
+    }
+
+    /// Delivers the given events if they are committed, and otherwise holds them back until the batch is finished.
+    pub(super) fn emit(&self, events: impl IntoIterator<Item = TransactionEvent<N>>, is_atomic_in_progress: bool) {
+        match is_atomic_in_progress {
+            true => self.pending.lock().extend(events),
+            false => self.deliver(events),
+        }
+    }
+
+    /// Checkpoints the pending events.
+    pub(super) fn checkpoint(&self) {
+        self.checkpoints.lock().push(self.pending.lock().len());
+    }
+
+    /// Clears the latest checkpoint of the pending events.
+    pub(super) fn clear_latest_checkpoint(&self) {
+        let _ = self.checkpoints.lock().pop();
+    }
+
+    /// Discards the pending events after the latest checkpoint.
+    pub(super) fn rewind(&self) {
+        let checkpoint = self.checkpoints.lock().pop().unwrap_or(0);
+        self.pending.lock().truncate(checkpoint);
+    }
+
+    /// Discards all of the pending events.
+    pub(super) fn abort(&self) {
+        self.checkpoints.lock().clear();
+        self.pending.lock().clear();
+    }
+
+    /// Delivers all of the pending events, in the order they were written.
+    pub(super) fn commit(&self) {
+        self.checkpoints.lock().clear();
+        let events = std::mem::take(&mut *self.pending.lock());
+        self.deliver(events);
+    }
+
+    /// Sends the given events to each subscriber, without blocking.
+    /// If a subscriber has fallen `TRANSACTION_EVENT_CAPACITY` events behind, the events are dropped for it,
+    /// and if a subscriber has dropped its receiver, it is removed.
+    fn deliver(&self, events: impl IntoIterator<Item = TransactionEvent<N>>) {
+        let mut senders = self.senders.lock();
+        if senders.is_empty() {
+            return;
+        }
+        for event in events {
+            senders.retain(|sender| !matches!(sender.try_send(event), Err(TrySendError::Disconnected(_))));
+        }
+    }
+}
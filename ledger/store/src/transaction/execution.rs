@@ -16,13 +16,20 @@ use crate::{
     atomic_batch_scope,
     cow_to_cloned,
     cow_to_copied,
-    helpers::{Map, MapRead},
+    helpers::{
+        backup_map,
+        memory::{ExecutionMemory, FeeMemory},
+        Map,
+        MapRead,
+    },
     FeeStorage,
     FeeStore,
+    StorageError,
+    TransactionType,
     TransitionStore,
 };
 use console::network::prelude::*;
-use ledger_block::{Execution, Transaction, Transition};
+use ledger_block::{Execution, Transaction};
 use synthesizer_snark::Proof;
 
 use anyhow::Result;
@@ -31,8 +38,11 @@ use std::borrow::Cow;
 
 /// A trait for execution storage.
 pub trait ExecutionStorage<N: Network>: Clone + Send + Sync {
-    /// The mapping of `transaction ID` to `([transition ID], has_fee)`.
-    type IDMap: for<'a> Map<'a, N::TransactionID, (Vec<N::TransitionID>, bool)>;
+    /// The mapping of `transaction ID` to `has_fee`.
+    type IDMap: for<'a> Map<'a, N::TransactionID, bool>;
+    /// The mapping of `(transaction ID, index)` to `transition ID`.
+    /// Note: The index is a `u8`, so that the serialized keys of a transaction are ordered by index.
+    type TransitionIDMap: for<'a> Map<'a, (N::TransactionID, u8), N::TransitionID>;
     /// The mapping of `transition ID` to `transaction ID`.
     type ReverseIDMap: for<'a> Map<'a, N::TransitionID, N::TransactionID>;
     /// The mapping of `transaction ID` to `(global state root, (optional) proof)`.
@@ -45,6 +55,8 @@ pub trait ExecutionStorage<N: Network>: Clone + Send + Sync {
 
     /// Returns the ID map.
     fn id_map(&self) -> &Self::IDMap;
+    /// Returns the transition ID map.
+    fn transition_id_map(&self) -> &Self::TransitionIDMap;
     /// Returns the reverse ID map.
     fn reverse_id_map(&self) -> &Self::ReverseIDMap;
     /// Returns the inclusion map.
@@ -64,6 +76,7 @@ pub trait ExecutionStorage<N: Network>: Clone + Send + Sync {
     /// Starts an atomic batch write operation.
     fn start_atomic(&self) {
         self.id_map().start_atomic();
+        self.transition_id_map().start_atomic();
         self.reverse_id_map().start_atomic();
         self.inclusion_map().start_atomic();
         self.fee_store().start_atomic();
@@ -72,6 +85,7 @@ pub trait ExecutionStorage<N: Network>: Clone + Send + Sync {
     /// Checks if an atomic batch is in progress.
     fn is_atomic_in_progress(&self) -> bool {
         self.id_map().is_atomic_in_progress()
+            || self.transition_id_map().is_atomic_in_progress()
             || self.reverse_id_map().is_atomic_in_progress()
             || self.inclusion_map().is_atomic_in_progress()
             || self.fee_store().is_atomic_in_progress()
@@ -80,6 +94,7 @@ pub trait ExecutionStorage<N: Network>: Clone + Send + Sync {
     /// Checkpoints the atomic batch.
     fn atomic_checkpoint(&self) {
         self.id_map().atomic_checkpoint();
+        self.transition_id_map().atomic_checkpoint();
         self.reverse_id_map().atomic_checkpoint();
         self.inclusion_map().atomic_checkpoint();
         self.fee_store().atomic_checkpoint();
@@ -88,6 +103,7 @@ pub trait ExecutionStorage<N: Network>: Clone + Send + Sync {
     /// Clears the latest atomic batch checkpoint.
     fn clear_latest_checkpoint(&self) {
         self.id_map().clear_latest_checkpoint();
+        self.transition_id_map().clear_latest_checkpoint();
         self.reverse_id_map().clear_latest_checkpoint();
         self.inclusion_map().clear_latest_checkpoint();
         self.fee_store().clear_latest_checkpoint();
@@ -96,6 +112,7 @@ pub trait ExecutionStorage<N: Network>: Clone + Send + Sync {
     /// Rewinds the atomic batch to the previous checkpoint.
     fn atomic_rewind(&self) {
         self.id_map().atomic_rewind();
+        self.transition_id_map().atomic_rewind();
         self.reverse_id_map().atomic_rewind();
         self.inclusion_map().atomic_rewind();
         self.fee_store().atomic_rewind();
@@ -104,6 +121,7 @@ pub trait ExecutionStorage<N: Network>: Clone + Send + Sync {
     /// Aborts an atomic batch write operation.
     fn abort_atomic(&self) {
         self.id_map().abort_atomic();
+        self.transition_id_map().abort_atomic();
         self.reverse_id_map().abort_atomic();
         self.inclusion_map().abort_atomic();
         self.fee_store().abort_atomic();
@@ -112,35 +130,58 @@ pub trait ExecutionStorage<N: Network>: Clone + Send + Sync {
     /// Finishes an atomic batch write operation.
     fn finish_atomic(&self) -> Result<()> {
         self.id_map().finish_atomic()?;
+        self.transition_id_map().finish_atomic()?;
         self.reverse_id_map().finish_atomic()?;
         self.inclusion_map().finish_atomic()?;
         self.fee_store().finish_atomic()
     }
 
+    /// Reclaims the space left behind by removed entries, and returns an estimate of the number of
+    /// removed entries whose space was reclaimed.
+    fn compact(&self) -> Result<usize> {
+        let mut num_removed = self.id_map().compact()?;
+        num_removed += self.transition_id_map().compact()?;
+        num_removed += self.reverse_id_map().compact()?;
+        num_removed += self.inclusion_map().compact()?;
+        num_removed += self.fee_store().compact()?;
+        Ok(num_removed)
+    }
+
+    /// Copies each map of the storage into the `other` storage, which may have a different backend.
+    /// Note: The fee store is shared with the transaction storage, which copies it.
+    fn backup_to<S: ExecutionStorage<N>>(&self, other: &S) -> Result<()> {
+        backup_map(self.id_map(), other.id_map())?;
+        backup_map(self.transition_id_map(), other.transition_id_map())?;
+        backup_map(self.reverse_id_map(), other.reverse_id_map())?;
+        backup_map(self.inclusion_map(), other.inclusion_map())?;
+        Ok(())
+    }
+
     /// Stores the given `execution transaction` pair into storage.
     fn insert(&self, transaction: &Transaction<N>) -> Result<()> {
         // Ensure the transaction is a execution.
         let (transaction_id, execution, fee) = match transaction {
-            Transaction::Deploy(..) => bail!("Attempted to insert a deploy transaction into execution storage."),
             Transaction::Execute(transaction_id, execution, fee) => (transaction_id, execution, fee),
-            Transaction::Fee(..) => bail!("Attempted to insert a fee transaction into execution storage."),
+            Transaction::Deploy(..) | Transaction::Fee(..) => {
+                bail!(StorageError::type_mismatch(transaction.id(), TransactionType::Execute, transaction.into()))
+            }
         };
 
         // Retrieve the transitions.
         let transitions = execution.transitions();
-        // Retrieve the transition IDs.
-        let transition_ids = execution.transitions().map(Transition::id).copied().collect();
         // Retrieve the global state root.
         let global_state_root = execution.global_state_root();
         // Retrieve the proof.
         let proof = execution.proof().cloned();
 
         atomic_batch_scope!(self, {
-            // Store the transition IDs.
-            self.id_map().insert(*transaction_id, (transition_ids, fee.is_some()))?;
+            // Store the fee boolean.
+            self.id_map().insert(*transaction_id, fee.is_some())?;
 
             // Store the execution.
-            for transition in transitions {
+            for (index, transition) in transitions.enumerate() {
+                // Store the transition ID at its index.
+                self.transition_id_map().insert((*transaction_id, u8::try_from(index)?), *transition.id())?;
                 // Store the transition ID.
                 self.reverse_id_map().insert(*transition.id(), *transaction_id)?;
                 // Store the transition.
@@ -162,18 +203,24 @@ pub trait ExecutionStorage<N: Network>: Clone + Send + Sync {
 
     /// Removes the execution transaction for the given `transaction ID`.
     fn remove(&self, transaction_id: &N::TransactionID) -> Result<()> {
-        // Retrieve the transition IDs and fee boolean.
-        let (transition_ids, has_fee) = match self.id_map().get_confirmed(transaction_id)? {
-            Some(ids) => cow_to_cloned!(ids),
-            None => bail!("Failed to get the transition IDs for the transaction '{transaction_id}'"),
+        // Retrieve the fee boolean.
+        let has_fee = match self.id_map().get_confirmed(transaction_id)? {
+            Some(has_fee) => cow_to_copied!(has_fee),
+            None => bail!(StorageError::NotFound(format!(
+                "Failed to get the transition IDs for the transaction '{transaction_id}'"
+            ))),
         };
+        // Retrieve the transition IDs.
+        let transition_ids = transition_ids_for_execution(self, *transaction_id)?.collect::<Vec<_>>();
 
         atomic_batch_scope!(self, {
-            // Remove the transition IDs.
+            // Remove the fee boolean.
             self.id_map().remove(transaction_id)?;
 
             // Remove the execution.
-            for transition_id in transition_ids {
+            for (index, transition_id) in transition_ids.into_iter().enumerate() {
+                // Remove the transition ID at its index.
+                self.transition_id_map().remove(&(*transaction_id, u8::try_from(index)?))?;
                 // Remove the transition ID.
                 self.reverse_id_map().remove(&transition_id)?;
                 // Remove the transition.
@@ -211,57 +258,74 @@ pub trait ExecutionStorage<N: Network>: Clone + Send + Sync {
 
     /// Returns the execution for the given `transaction ID`.
     fn get_execution(&self, transaction_id: &N::TransactionID) -> Result<Option<Execution<N>>> {
+        // Ensure the execution exists.
+        if !self.id_map().contains_key_confirmed(transaction_id)? {
+            return Ok(None);
+        }
         // Retrieve the transition IDs.
-        let (transition_ids, _) = match self.id_map().get_confirmed(transaction_id)? {
-            Some(ids) => cow_to_cloned!(ids),
-            None => return Ok(None),
-        };
+        let transition_ids = transition_ids_for_execution(self, *transaction_id)?.collect::<Vec<_>>();
 
         // Retrieve the global state root and proof.
         let (global_state_root, proof) = match self.inclusion_map().get_confirmed(transaction_id)? {
             Some(inclusion) => cow_to_cloned!(inclusion),
-            None => bail!("Failed to get the proof for the transaction '{transaction_id}'"),
+            None => bail!(StorageError::Corrupted(format!(
+                "Failed to get the proof for the transaction '{transaction_id}'"
+            ))),
         };
 
-        // Initialize a vector for the transitions.
-        let mut transitions = Vec::new();
-
         // Retrieve the transitions.
-        for transition_id in &transition_ids {
-            match self.transition_store().get_transition(transition_id)? {
-                Some(transition) => transitions.push(transition),
-                None => bail!("Failed to get transition '{transition_id}' for transaction '{transaction_id}'"),
-            };
-        }
+        let transitions = transition_ids
+            .iter()
+            .zip(self.transition_store().get_transitions(&transition_ids)?)
+            .map(|(transition_id, transition)| match transition {
+                Some(transition) => Ok(transition),
+                None => bail!(StorageError::Corrupted(format!(
+                    "Failed to get transition '{transition_id}' for transaction '{transaction_id}'"
+                ))),
+            })
+            .collect::<Result<Vec<_>>>()?;
 
         // Return the execution.
         Ok(Some(Execution::from(transitions.into_iter(), global_state_root, proof)?))
     }
 
+    /// Returns the global state root for the given `transaction ID`.
+    fn get_global_state_root(&self, transaction_id: &N::TransactionID) -> Result<Option<N::StateRoot>> {
+        match self.inclusion_map().get_confirmed(transaction_id)? {
+            Some(inclusion) => Ok(Some(inclusion.0)),
+            None => Ok(None),
+        }
+    }
+
     /// Returns the transaction for the given `transaction ID`.
     fn get_transaction(&self, transaction_id: &N::TransactionID) -> Result<Option<Transaction<N>>> {
-        // Retrieve the transition IDs and fee boolean.
-        let (transition_ids, has_fee) = match self.id_map().get_confirmed(transaction_id)? {
-            Some(ids) => cow_to_cloned!(ids),
+        // Retrieve the fee boolean.
+        let has_fee = match self.id_map().get_confirmed(transaction_id)? {
+            Some(has_fee) => cow_to_copied!(has_fee),
             None => return Ok(None),
         };
+        // Retrieve the transition IDs.
+        let transition_ids = transition_ids_for_execution(self, *transaction_id)?.collect::<Vec<_>>();
 
         // Retrieve the global state root and proof.
         let (global_state_root, proof) = match self.inclusion_map().get_confirmed(transaction_id)? {
             Some(inclusion) => cow_to_cloned!(inclusion),
-            None => bail!("Failed to get the proof for the transaction '{transaction_id}'"),
+            None => bail!(StorageError::Corrupted(format!(
+                "Failed to get the proof for the transaction '{transaction_id}'"
+            ))),
         };
 
-        // Initialize a vector for the transitions.
-        let mut transitions = Vec::new();
-
         // Retrieve the transitions.
-        for transition_id in &transition_ids {
-            match self.transition_store().get_transition(transition_id)? {
-                Some(transition) => transitions.push(transition),
-                None => bail!("Failed to get transition '{transition_id}' for transaction '{transaction_id}'"),
-            };
-        }
+        let transitions = transition_ids
+            .iter()
+            .zip(self.transition_store().get_transitions(&transition_ids)?)
+            .map(|(transition_id, transition)| match transition {
+                Some(transition) => Ok(transition),
+                None => bail!(StorageError::Corrupted(format!(
+                    "Failed to get transition '{transition_id}' for transaction '{transaction_id}'"
+                ))),
+            })
+            .collect::<Result<Vec<_>>>()?;
 
         // Construct the execution.
         let execution = Execution::from(transitions.into_iter(), global_state_root, proof)?;
@@ -272,7 +336,9 @@ pub trait ExecutionStorage<N: Network>: Clone + Send + Sync {
             true => match self.fee_store().get_fee(transaction_id)? {
                 // Construct the transaction.
                 Some(fee) => Transaction::from_execution(execution, Some(fee))?,
-                None => bail!("Failed to get the fee for transaction '{transaction_id}'"),
+                None => {
+                    bail!(StorageError::Corrupted(format!("Failed to get the fee for transaction '{transaction_id}'")))
+                }
             },
             false => Transaction::from_execution(execution, None)?,
         };
@@ -280,9 +346,55 @@ pub trait ExecutionStorage<N: Network>: Clone + Send + Sync {
         // Ensure the transaction ID matches.
         match *transaction_id == transaction.id() {
             true => Ok(Some(transaction)),
-            false => bail!("Mismatching transaction ID for transaction '{transaction_id}'"),
+            false => {
+                bail!(StorageError::Corrupted(format!("Mismatching transaction ID for transaction '{transaction_id}'")))
+            }
         }
     }
+
+    /// Returns the sum of the fee amounts (in microcredits) for the given execution `transaction IDs`.
+    /// Executions without a fee contribute zero to the total.
+    fn fee_total_for_transaction_ids(&self, transaction_ids: &[N::TransactionID]) -> Result<u128> {
+        // Initialize the total.
+        let mut total = 0u128;
+        // Accumulate the fee amount for each transaction.
+        for transaction_id in transaction_ids {
+            // Retrieve the fee boolean.
+            let has_fee = match self.id_map().get_confirmed(transaction_id)? {
+                Some(has_fee) => cow_to_copied!(has_fee),
+                None => bail!(StorageError::NotFound(format!(
+                    "Failed to find the execution transaction '{transaction_id}'"
+                ))),
+            };
+            // Add the fee amount to the total.
+            if has_fee {
+                match self.fee_store().get_fee(transaction_id)? {
+                    Some(fee) => total += *fee.amount()? as u128,
+                    None => bail!(StorageError::Corrupted(format!(
+                        "Failed to get the fee for transaction '{transaction_id}'"
+                    ))),
+                }
+            }
+        }
+        Ok(total)
+    }
+}
+
+/// Returns an iterator over the transition IDs for the given execution `transaction ID`, in the order of the execution.
+/// The transition IDs are retrieved by a range scan over the keys that start with the transaction ID.
+fn transition_ids_for_execution<N: Network, E: ExecutionStorage<N>>(
+    storage: &E,
+    transaction_id: N::TransactionID,
+) -> Result<impl '_ + Iterator<Item = N::TransitionID>> {
+    // Seek to the first transition ID of the transaction.
+    let raw_key = bincode::serialize(&(transaction_id, 0u8))?;
+    // Stop at the first key that belongs to another transaction.
+    Ok(storage
+        .transition_id_map()
+        .iter_confirmed_from(&raw_key)
+        .map(|(key, transition_id)| (cow_to_copied!(key), cow_to_copied!(transition_id)))
+        .take_while(move |((id, _), _)| *id == transaction_id)
+        .map(|(_, transition_id)| transition_id))
 }
 
 /// The execution store.
@@ -313,6 +425,29 @@ impl<N: Network, E: ExecutionStorage<N>> ExecutionStore<N, E> {
         self.storage.insert(transaction)
     }
 
+    /// Stores the given `execution transaction` into storage, if `is_valid_root` accepts every global state root
+    /// in the execution and its fee. The roots are checked before the atomic batch is started.
+    pub fn insert_with_root_check(
+        &self,
+        transaction: &Transaction<N>,
+        is_valid_root: impl Fn(&N::StateRoot) -> Result<bool>,
+    ) -> Result<()> {
+        // Retrieve the global state roots of the execution and the fee.
+        let global_state_roots = match transaction {
+            Transaction::Execute(_, execution, fee) => {
+                [Some(execution.global_state_root()), fee.as_ref().map(|fee| fee.global_state_root())]
+            }
+            _ => bail!(StorageError::type_mismatch(transaction.id(), TransactionType::Execute, transaction.into())),
+        };
+        // Ensure each global state root is valid.
+        for global_state_root in global_state_roots.into_iter().flatten() {
+            if !is_valid_root(&global_state_root)? {
+                bail!("Transaction '{}' has an unknown global state root '{global_state_root}'", transaction.id())
+            }
+        }
+        self.storage.insert(transaction)
+    }
+
     /// Removes the transaction for the given `transaction ID`.
     pub fn remove(&self, transaction_id: &N::TransactionID) -> Result<()> {
         self.storage.remove(transaction_id)
@@ -353,12 +488,30 @@ impl<N: Network, E: ExecutionStorage<N>> ExecutionStore<N, E> {
         self.storage.finish_atomic()
     }
 
+    /// Reclaims the space left behind by removed entries, and returns an estimate of the number of removed entries.
+    pub fn compact(&self) -> Result<usize> {
+        self.storage.compact()
+    }
+
+    /// Copies each map of the store into the `other` store, which may have a different backend.
+    pub(crate) fn backup_to<S: ExecutionStorage<N>>(&self, other: &ExecutionStore<N, S>) -> Result<()> {
+        self.storage.backup_to(&other.storage)
+    }
+
     /// Returns the optional development ID.
     pub fn dev(&self) -> Option<u16> {
         self.storage.dev()
     }
 }
 
+impl<N: Network> ExecutionStore<N, ExecutionMemory<N>> {
+    /// Returns a point-in-time copy of the committed state of the store, which uses the given copy of the fee store.
+    /// Note: The caller must ensure that no atomic batch is committed while the copy is taken.
+    pub(crate) fn snapshot(&self, fee_store: FeeStore<N, FeeMemory<N>>) -> Self {
+        Self::from(self.storage.snapshot(fee_store))
+    }
+}
+
 impl<N: Network, E: ExecutionStorage<N>> ExecutionStore<N, E> {
     /// Returns the transaction for the given `transaction ID`.
     pub fn get_transaction(&self, transaction_id: &N::TransactionID) -> Result<Option<Transaction<N>>> {
@@ -369,6 +522,30 @@ impl<N: Network, E: ExecutionStorage<N>> ExecutionStore<N, E> {
     pub fn get_execution(&self, transaction_id: &N::TransactionID) -> Result<Option<Execution<N>>> {
         self.storage.get_execution(transaction_id)
     }
+
+    /// Returns `true` if the given execution `transaction ID` exists.
+    pub fn contains_transaction_id(&self, transaction_id: &N::TransactionID) -> Result<bool> {
+        self.storage.id_map().contains_key_confirmed(transaction_id)
+    }
+
+    /// Returns an iterator over the transition IDs for the given execution `transaction ID`, in the order of the execution.
+    /// The transition IDs are streamed from a range scan, so the execution is not loaded as a whole.
+    pub fn get_transition_ids_for_execution(
+        &self,
+        transaction_id: &N::TransactionID,
+    ) -> Result<impl '_ + Iterator<Item = N::TransitionID>> {
+        transition_ids_for_execution(&self.storage, *transaction_id)
+    }
+
+    /// Returns the global state root for the given `transaction ID`.
+    pub fn get_global_state_root(&self, transaction_id: &N::TransactionID) -> Result<Option<N::StateRoot>> {
+        self.storage.get_global_state_root(transaction_id)
+    }
+
+    /// Returns the sum of the fee amounts (in microcredits) for the given execution `transaction IDs`.
+    pub fn fee_total_for_transaction_ids(&self, transaction_ids: &[N::TransactionID]) -> Result<u128> {
+        self.storage.fee_total_for_transaction_ids(transaction_ids)
+    }
 }
 
 impl<N: Network, E: ExecutionStorage<N>> ExecutionStore<N, E> {
@@ -392,6 +569,8 @@ impl<N: Network, E: ExecutionStorage<N>> ExecutionStore<N, E> {
 mod tests {
     use super::*;
     use crate::{helpers::memory::ExecutionMemory, TransitionStore};
+    use indexmap::IndexMap;
+    use synthesizer_snark::VerifyingKey;
 
     type CurrentNetwork = console::network::Testnet3;
 
@@ -481,6 +660,122 @@ mod tests {
         insert_get_remove(transaction).unwrap();
     }
 
+    #[test]
+    fn test_get_transition_ids_for_execution() {
+        let rng = &mut TestRng::default();
+
+        // Sample the execution transactions.
+        let transaction_0 = ledger_test_helpers::sample_execution_transaction_with_fee(true, rng);
+        let transaction_1 = ledger_test_helpers::sample_execution_transaction_with_fee(false, rng);
+
+        // Initialize a new transition store.
+        let transition_store = TransitionStore::open(None).unwrap();
+        // Initialize a new fee store.
+        let fee_store = FeeStore::open(transition_store).unwrap();
+        // Initialize a new execution store.
+        let execution_store = ExecutionStore::<_, ExecutionMemory<_>>::open(fee_store).unwrap();
+
+        // Ensure a missing transaction ID has no transition IDs.
+        assert_eq!(execution_store.get_transition_ids_for_execution(&transaction_0.id()).unwrap().count(), 0);
+
+        // Insert the execution transactions.
+        execution_store.insert(&transaction_0).unwrap();
+        execution_store.insert(&transaction_1).unwrap();
+
+        // Ensure the transition IDs of each execution are streamed in order, without those of the other execution.
+        for transaction in [&transaction_0, &transaction_1] {
+            let expected = match transaction {
+                Transaction::Execute(_, execution, _) => execution.transitions().map(|t| *t.id()).collect::<Vec<_>>(),
+                _ => panic!("Incorrect transaction type"),
+            };
+            let candidate =
+                execution_store.get_transition_ids_for_execution(&transaction.id()).unwrap().collect::<Vec<_>>();
+            assert_eq!(expected, candidate);
+        }
+
+        // Remove an execution, and ensure its transition IDs are removed.
+        execution_store.remove(&transaction_0.id()).unwrap();
+        assert_eq!(execution_store.get_transition_ids_for_execution(&transaction_0.id()).unwrap().count(), 0);
+    }
+
+    #[test]
+    fn test_fee_total_for_transaction_ids() {
+        let rng = &mut TestRng::default();
+
+        // Sample the execution transactions.
+        let transaction_0 = ledger_test_helpers::sample_execution_transaction_with_fee(true, rng);
+        let transaction_1 = ledger_test_helpers::sample_execution_transaction_with_fee(false, rng);
+
+        // Initialize a new transition store.
+        let transition_store = TransitionStore::open(None).unwrap();
+        // Initialize a new fee store.
+        let fee_store = FeeStore::open(transition_store).unwrap();
+        // Initialize a new execution store.
+        let execution_store = ExecutionStore::<_, ExecutionMemory<_>>::open(fee_store).unwrap();
+
+        // Ensure a missing transaction ID is an error.
+        assert!(execution_store.fee_total_for_transaction_ids(&[transaction_0.id()]).is_err());
+
+        // Insert the execution transactions.
+        execution_store.insert(&transaction_0).unwrap();
+        execution_store.insert(&transaction_1).unwrap();
+
+        // Compute the expected total.
+        let expected = [&transaction_0, &transaction_1]
+            .iter()
+            .map(|transaction| *transaction.fee_amount().unwrap() as u128)
+            .sum::<u128>();
+
+        // Ensure the total matches.
+        let candidate =
+            execution_store.fee_total_for_transaction_ids(&[transaction_0.id(), transaction_1.id()]).unwrap();
+        assert_eq!(expected, candidate);
+
+        // Ensure an empty list sums to zero.
+        assert_eq!(0, execution_store.fee_total_for_transaction_ids(&[]).unwrap());
+    }
+
+    #[test]
+    fn test_insert_with_root_check() {
+        let rng = &mut TestRng::default();
+
+        // Sample the execution transaction.
+        let transaction = ledger_test_helpers::sample_execution_transaction_with_fee(true, rng);
+        let transaction_id = transaction.id();
+        let (global_state_root, fee_global_state_root) = match transaction {
+            Transaction::Execute(_, ref execution, Some(ref fee)) => {
+                (execution.global_state_root(), fee.global_state_root())
+            }
+            _ => panic!("Incorrect transaction type"),
+        };
+
+        // Initialize a new transition store.
+        let transition_store = TransitionStore::open(None).unwrap();
+        // Initialize a new fee store.
+        let fee_store = FeeStore::open(transition_store).unwrap();
+        // Initialize a new execution store.
+        let execution_store = ExecutionStore::<_, ExecutionMemory<_>>::open(fee_store).unwrap();
+
+        // Ensure an unknown global state root is rejected.
+        let result = execution_store.insert_with_root_check(&transaction, |_| Ok(false));
+        assert!(result.is_err());
+        assert!(!execution_store.is_atomic_in_progress());
+        assert_eq!(None, execution_store.get_transaction(&transaction_id).unwrap());
+
+        // Ensure an error from the callback is propagated.
+        let result = execution_store.insert_with_root_check(&transaction, |_| bail!("Failed to look up the root"));
+        assert!(result.is_err());
+        assert!(!execution_store.is_atomic_in_progress());
+        assert_eq!(None, execution_store.get_transaction(&transaction_id).unwrap());
+        assert_eq!(None, execution_store.get_global_state_root(&transaction_id).unwrap());
+
+        // Ensure a known global state root is accepted.
+        let is_valid_root = |root: &_| Ok(*root == global_state_root || *root == fee_global_state_root);
+        execution_store.insert_with_root_check(&transaction, is_valid_root).unwrap();
+        assert_eq!(Some(transaction), execution_store.get_transaction(&transaction_id).unwrap());
+        assert_eq!(Some(global_state_root), execution_store.get_global_state_root(&transaction_id).unwrap());
+    }
+
     #[test]
     fn test_find_transaction_id() {
         let rng = &mut TestRng::default();
@@ -493,4 +788,58 @@ mod tests {
         let transaction = ledger_test_helpers::sample_execution_transaction_with_fee(false, rng);
         find_transaction_id(transaction).unwrap();
     }
+    #[test]
+    fn test_reverify_stored_execution() {
+        let rng = &mut TestRng::default();
+
+        // Sample the execution transaction.
+        let transaction = ledger_test_helpers::sample_execution_transaction_with_fee(true, rng);
+        let transaction_id = transaction.id();
+
+        // Initialize a new transition store.
+        let transition_store = TransitionStore::open(None).unwrap();
+        // Initialize a new fee store.
+        let fee_store = FeeStore::open(transition_store).unwrap();
+        // Initialize a new execution store.
+        let execution_store = ExecutionStore::<_, ExecutionMemory<_>>::open(fee_store).unwrap();
+        // Insert the execution transaction.
+        execution_store.insert(&transaction).unwrap();
+
+        // Retrieve the execution proof.
+        let proof = match execution_store.storage.inclusion_map().get_confirmed(&transaction_id).unwrap() {
+            Some(inclusion) => cow_to_cloned!(inclusion).1.unwrap(),
+            None => panic!("Missing the execution proof"),
+        };
+
+        // Reconstruct the public inputs of each transition, grouped by verifying key.
+        let transition_store = execution_store.storage.transition_store();
+        let mut verifier_inputs = IndexMap::new();
+        for transition_id in execution_store.get_transition_ids_for_execution(&transaction_id).unwrap() {
+            // Note: The sample execution is a call to 'credits.aleo', which makes no external calls.
+            let program_id = transition_store.get_program_id(&transition_id).unwrap().unwrap();
+            let function_name = transition_store.get_function_name(&transition_id).unwrap().unwrap();
+            assert_eq!(program_id.to_string(), "credits.aleo");
+            let inputs = transition_store.get_transition_public_inputs(&transition_id, &[]).unwrap().unwrap();
+            verifier_inputs
+                .entry(function_name)
+                .or_insert_with(|| {
+                    let verifying_key = CurrentNetwork::get_credits_verifying_key(function_name.to_string()).unwrap();
+                    (VerifyingKey::new(verifying_key.clone()), vec![])
+                })
+                .1
+                .push(inputs);
+        }
+
+        // Ensure the execution proof verifies against the reconstructed public inputs.
+        let verifier_inputs = verifier_inputs.into_values().collect::<Vec<_>>();
+        assert!(VerifyingKey::verify_batch("execution", verifier_inputs.clone(), &proof));
+
+        // Ensure the execution proof does not verify against tampered public inputs.
+        let mut tampered_inputs = verifier_inputs;
+        tampered_inputs[0].1[0][1] += *console::types::Field::<CurrentNetwork>::one();
+        assert!(!VerifyingKey::verify_batch("execution", tampered_inputs, &proof));
+
+        // Ensure a missing transition has no public inputs.
+        assert_eq!(transition_store.get_transition_public_inputs(&Uniform::rand(rng), &[]).unwrap(), None);
+    }
 }
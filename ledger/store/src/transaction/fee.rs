@@ -16,7 +16,8 @@ use crate::{
     atomic_batch_scope,
     cow_to_cloned,
     cow_to_copied,
-    helpers::{Map, MapRead},
+    helpers::{backup_map, memory::FeeMemory, Map, MapRead},
+    StorageError,
     TransitionStorage,
     TransitionStore,
 };
@@ -101,6 +102,23 @@ pub trait FeeStorage<N: Network>: Clone + Send + Sync {
         self.transition_store().finish_atomic()
     }
 
+    /// Reclaims the space left behind by removed entries, and returns an estimate of the number of
+    /// removed entries whose space was reclaimed.
+    fn compact(&self) -> Result<usize> {
+        let mut num_removed = self.fee_map().compact()?;
+        num_removed += self.reverse_fee_map().compact()?;
+        num_removed += self.transition_store().compact()?;
+        Ok(num_removed)
+    }
+
+    /// Copies each map of the storage into the `other` storage, which may have a different backend.
+    fn backup_to<S: FeeStorage<N>>(&self, other: &S) -> Result<()> {
+        backup_map(self.fee_map(), other.fee_map())?;
+        backup_map(self.reverse_fee_map(), other.reverse_fee_map())?;
+        self.transition_store().backup_to(other.transition_store())?;
+        Ok(())
+    }
+
     /// Stores the given `(transaction ID, fee)` pair into storage.
     fn insert(&self, transaction_id: N::TransactionID, fee: &Fee<N>) -> Result<()> {
         atomic_batch_scope!(self, {
@@ -121,7 +139,9 @@ pub trait FeeStorage<N: Network>: Clone + Send + Sync {
         // Retrieve the fee transition ID.
         let (transition_id, _, _) = match self.fee_map().get_confirmed(transaction_id)? {
             Some(fee_id) => cow_to_cloned!(fee_id),
-            None => bail!("Failed to locate the fee transition ID for transaction '{transaction_id}'"),
+            None => bail!(StorageError::NotFound(format!(
+                "Failed to locate the fee transition ID for transaction '{transaction_id}'"
+            ))),
         };
 
         atomic_batch_scope!(self, {
@@ -157,7 +177,9 @@ pub trait FeeStorage<N: Network>: Clone + Send + Sync {
         // Retrieve the fee transition.
         match self.transition_store().get_transition(&fee_transition_id)? {
             Some(transition) => Ok(Some(Fee::from_unchecked(transition, global_state_root, proof))),
-            None => bail!("Failed to locate the fee transition for transaction '{transaction_id}'"),
+            None => bail!(StorageError::Corrupted(format!(
+                "Failed to locate the fee transition for transaction '{transaction_id}'"
+            ))),
         }
     }
 }
@@ -235,17 +257,40 @@ impl<N: Network, F: FeeStorage<N>> FeeStore<N, F> {
         self.storage.finish_atomic()
     }
 
+    /// Reclaims the space left behind by removed entries, and returns an estimate of the number of removed entries.
+    pub fn compact(&self) -> Result<usize> {
+        self.storage.compact()
+    }
+
+    /// Copies each map of the store into the `other` store, which may have a different backend.
+    pub(crate) fn backup_to<S: FeeStorage<N>>(&self, other: &FeeStore<N, S>) -> Result<()> {
+        self.storage.backup_to(&other.storage)
+    }
+
     /// Returns the optional development ID.
     pub fn dev(&self) -> Option<u16> {
         self.storage.dev()
     }
 }
 
+impl<N: Network> FeeStore<N, FeeMemory<N>> {
+    /// Returns a point-in-time copy of the committed state of the store, which is independent of it.
+    /// Note: The caller must ensure that no atomic batch is committed while the copy is taken.
+    pub(crate) fn snapshot(&self) -> Self {
+        Self::from(self.storage.snapshot())
+    }
+}
+
 impl<N: Network, F: FeeStorage<N>> FeeStore<N, F> {
     /// Returns the fee for the given `transaction ID`.
     pub fn get_fee(&self, transaction_id: &N::TransactionID) -> Result<Option<Fee<N>>> {
         self.storage.get_fee(transaction_id)
     }
+
+    /// Returns the fee transition ID for the given `transaction ID`, without retrieving the fee transition.
+    pub fn get_fee_transition_id(&self, transaction_id: &N::TransactionID) -> Result<Option<N::TransitionID>> {
+        Ok(self.storage.fee_map().get_confirmed(transaction_id)?.map(|fee| fee.0))
+    }
 }
 
 impl<N: Network, F: FeeStorage<N>> FeeStore<N, F> {
@@ -0,0 +1,202 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// The depth of the checks performed by `TransactionStore::check_integrity`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum IntegrityDepth {
+    /// Checks that the transaction ID map agrees with the deployment, execution, and fee stores.
+    Shallow,
+    /// Additionally checks that every transition of a transaction is in the transition store,
+    /// and that every function of the latest edition of each program has a verifying key.
+    Deep,
+}
+
+/// An inconsistency between the maps of a transaction store, as found by `TransactionStore::check_integrity`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum IntegrityViolation<N: Network> {
+    /// The transaction ID map has a deployment, execution, or fee which is missing from its store.
+    DanglingTransaction { transaction_id: N::TransactionID, transaction_type: TransactionType },
+    /// The deployment or execution store has a transaction which is missing from the transaction ID map.
+    UntrackedTransaction { transaction_id: N::TransactionID, transaction_type: TransactionType },
+    /// A transition of the transaction is missing from the transition store.
+    MissingTransition { transaction_id: N::TransactionID, transition_id: N::TransitionID },
+    /// The latest edition of the program is missing from the deployment store.
+    MissingProgram { program_id: ProgramID<N>, edition: u16 },
+    /// A function of the latest edition of the program has no verifying key.
+    MissingVerifyingKey { program_id: ProgramID<N>, function_name: Identifier<N>, edition: u16 },
+    /// The verifying key belongs to an edition of a program which is missing from the deployment store.
+    OrphanedVerifyingKey { program_id: ProgramID<N>, function_name: Identifier<N>, edition: u16 },
+}
+
+impl<N: Network> Display for IntegrityViolation<N> {
+    /// Prints the violation.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Self::DanglingTransaction { transaction_id, transaction_type } => {
+                write!(f, "Transaction '{transaction_id}' ({transaction_type:?}) is missing from its store")
+            }
+            Self::UntrackedTransaction { transaction_id, transaction_type } => {
+                write!(f, "Transaction '{transaction_id}' ({transaction_type:?}) is missing from the ID map")
+            }
+            Self::MissingTransition { transaction_id, transition_id } => {
+                write!(f, "Transition '{transition_id}' of transaction '{transaction_id}' is missing")
+            }
+            Self::MissingProgram { program_id, edition } => {
+                write!(f, "Program '{program_id}' (edition {edition}) is missing")
+            }
+            Self::MissingVerifyingKey { program_id, function_name, edition } => {
+                write!(f, "Missing the verifying key for '{program_id}/{function_name}' (edition {edition})")
+            }
+            Self::OrphanedVerifyingKey { program_id, function_name, edition } => {
+                write!(f, "The verifying key for '{program_id}/{function_name}' (edition {edition}) has no program")
+            }
+        }
+    }
+}
+
+/// The violations found by `TransactionStore::check_integrity`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct IntegrityReport<N: Network> {
+    /// The depth of the checks.
+    depth: IntegrityDepth,
+    /// The violations, in the order they were found.
+    violations: Vec<IntegrityViolation<N>>,
+}
+
+impl<N: Network> IntegrityReport<N> {
+    /// Returns the depth of the checks.
+    pub const fn depth(&self) -> IntegrityDepth {
+        self.depth
+    }
+
+    /// Returns the violations, in the order they were found.
+    pub fn violations(&self) -> &[IntegrityViolation<N>] {
+        &self.violations
+    }
+
+    /// Returns `true` if no violations were found.
+    pub fn is_ok(&self) -> bool {
+        self.violations.is_empty()
+    }
+}
+
+impl<N: Network, T: TransactionStorage<N>> TransactionStore<N, T> {
+    /// Checks that the maps of the store agree with one another, and returns every violation that is found.
+    /// `IntegrityDepth::Shallow` compares the transaction ID map with the deployment, execution, and fee stores,
+    /// and `IntegrityDepth::Deep` additionally reads the transitions and verifying keys that they refer to.
+    /// Note: The maps are read in turn, so the store should not be written to during the check.
+    pub fn check_integrity(&self, depth: IntegrityDepth) -> Result<IntegrityReport<N>> {
+        let mut violations = Vec::new();
+
+        // Retrieve the transaction IDs from the deployment and execution stores.
+        let mut deployment_ids = self.deployment_transaction_ids().map(|id| *id).collect::<IndexSet<_>>();
+        let mut execution_ids = self.execution_transaction_ids().map(|id| *id).collect::<IndexSet<_>>();
+
+        for (transaction_type, transaction_id) in self.iter_with_type() {
+            let transaction_id = cow_to_copied!(transaction_id);
+            // Ensure each deployment and execution in the ID map is in its respective store.
+            let is_stored = match transaction_type {
+                TransactionType::Deploy => deployment_ids.shift_remove(&transaction_id),
+                TransactionType::Execute => execution_ids.shift_remove(&transaction_id),
+                TransactionType::Fee => self.storage.fee_store().get_fee_transition_id(&transaction_id)?.is_some(),
+            };
+            if !is_stored {
+                violations.push(IntegrityViolation::DanglingTransaction { transaction_id, transaction_type });
+                continue;
+            }
+
+            // Ensure each transition of the transaction, including the fee, is in the transition store.
+            if depth == IntegrityDepth::Deep {
+                let mut transition_ids = match transaction_type {
+                    TransactionType::Execute => self
+                        .storage
+                        .execution_store()
+                        .get_transition_ids_for_execution(&transaction_id)?
+                        .collect::<Vec<_>>(),
+                    TransactionType::Deploy | TransactionType::Fee => Vec::new(),
+                };
+                transition_ids.extend(self.storage.fee_store().get_fee_transition_id(&transaction_id)?);
+                for transition_id in transition_ids {
+                    if !self.transition_store().contains_transition_id(&transition_id)? {
+                        violations.push(IntegrityViolation::MissingTransition { transaction_id, transition_id });
+                    }
+                }
+            }
+        }
+
+        // Ensure the stores do not have any transaction IDs which are missing from the ID map.
+        for (transaction_ids, transaction_type) in
+            [(deployment_ids, TransactionType::Deploy), (execution_ids, TransactionType::Execute)]
+        {
+            for transaction_id in transaction_ids {
+                violations.push(IntegrityViolation::UntrackedTransaction { transaction_id, transaction_type });
+            }
+        }
+
+        // Ensure the verifying keys agree with the programs.
+        if depth == IntegrityDepth::Deep {
+            violations.extend(self.storage.deployment_store().check_verifying_keys()?);
+        }
+
+        Ok(IntegrityReport { depth, violations })
+    }
+
+    /// Removes the dangling transactions in the given `report` from the transaction ID map,
+    /// along with their size, block height, and height index entry, as a single atomic batch,
+    /// and returns their transaction IDs.
+    /// A transaction is only removed if it is still missing from its store.
+    /// The other violations are not repaired, as the missing data can only be restored from the blocks.
+    pub fn repair(&self, report: &IntegrityReport<N>) -> Result<Vec<N::TransactionID>> {
+        let removed = atomic_batch_scope!(self.storage, {
+            let mut removed = Vec::new();
+            for violation in report.violations() {
+                let IntegrityViolation::DanglingTransaction { transaction_id, transaction_type } = violation else {
+                    continue;
+                };
+                // Ensure the transaction is still recorded with the same type.
+                match self.storage.id_map().get_confirmed(transaction_id)? {
+                    Some(candidate) if cow_to_copied!(candidate) == *transaction_type => (),
+                    _ => continue,
+                }
+                // Ensure the transaction is still missing from its store.
+                let is_stored = match transaction_type {
+                    TransactionType::Deploy => {
+                        self.storage.deployment_store().get_program_id(transaction_id)?.is_some()
+                    }
+                    TransactionType::Execute => {
+                        self.storage.execution_store().contains_transaction_id(transaction_id)?
+                    }
+                    TransactionType::Fee => self.storage.fee_store().get_fee_transition_id(transaction_id)?.is_some(),
+                };
+                if !is_stored {
+                    // Retrieve the block height, so its entry in the height index can be removed too.
+                    let height =
+                        self.storage.height_map().get_confirmed(transaction_id)?.map(|height| cow_to_copied!(height));
+                    self.storage.id_map().remove(transaction_id)?;
+                    self.storage.size_map().remove(transaction_id)?;
+                    if let Some(height) = height {
+                        self.storage.height_map().remove(transaction_id)?;
+                        self.storage.height_index_map().remove(&(height.to_be_bytes(), *transaction_id))?;
+                    }
+                    removed.push(*transaction_id);
+                }
+            }
+            Ok(removed)
+        })?;
+        self.emit(removed.iter().map(|id| TransactionEvent::Removed { id: *id }));
+        Ok(removed)
+    }
+}
@@ -15,16 +15,27 @@
 mod deployment;
 pub use deployment::*;
 
+mod events;
+pub use events::*;
+
 mod execution;
 pub use execution::*;
 
 mod fee;
 pub use fee::*;
 
+mod integrity;
+pub use integrity::*;
+
+mod snapshot;
+pub use snapshot::*;
+
 use crate::{
     atomic_batch_scope,
+    cow_to_cloned,
     cow_to_copied,
-    helpers::{Map, MapRead},
+    helpers::{backup_map, Map, MapRead},
+    StorageError,
     TransitionStorage,
     TransitionStore,
 };
@@ -36,24 +47,300 @@ use ledger_block::{Deployment, Execution, Transaction};
 use synthesizer_program::Program;
 use synthesizer_snark::{Certificate, VerifyingKey};
 
-use anyhow::Result;
+use anyhow::{Context, Result};
+use core::marker::PhantomData;
+use indexmap::IndexSet;
 use serde::{Deserialize, Serialize};
-use std::borrow::Cow;
-
-#[derive(Copy, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+use std::{borrow::Cow, collections::HashSet, sync::mpsc::Receiver};
+
+/// The type of a transaction, as stored in the `transaction ID` map.
+///
+/// The type is serialized as its explicit `u8` discriminant, both in bytes and with serde.
+/// The discriminants are frozen: a new variant must be given a new, unused discriminant.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[repr(u8)]
 pub enum TransactionType {
     /// A transaction that is a deployment.
-    Deploy,
+    Deploy = 0,
     /// A transaction that is an execution.
-    Execute,
+    Execute = 1,
     /// A transaction that is a fee.
-    Fee,
+    Fee = 2,
+}
+
+impl TryFrom<u8> for TransactionType {
+    type Error = Error;
+
+    /// Returns the transaction type for the given discriminant.
+    fn try_from(discriminant: u8) -> Result<Self> {
+        match discriminant {
+            0 => Ok(Self::Deploy),
+            1 => Ok(Self::Execute),
+            2 => Ok(Self::Fee),
+            _ => bail!(StorageError::Corrupted(format!("Invalid transaction type '{discriminant}'"))),
+        }
+    }
+}
+
+impl<N: Network> From<&Transaction<N>> for TransactionType {
+    /// Returns the type of the given transaction.
+    fn from(transaction: &Transaction<N>) -> Self {
+        match transaction {
+            Transaction::Deploy(..) => Self::Deploy,
+            Transaction::Execute(..) => Self::Execute,
+            Transaction::Fee(..) => Self::Fee,
+        }
+    }
+}
+
+impl ToBytes for TransactionType {
+    /// Writes the transaction type to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        (*self as u8).write_le(&mut writer)
+    }
+}
+
+impl FromBytes for TransactionType {
+    /// Reads the transaction type from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        Self::try_from(u8::read_le(&mut reader)?).map_err(|e| error(e.to_string()))
+    }
+}
+
+impl Serialize for TransactionType {
+    /// Serializes the transaction type as its discriminant.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u8(*self as u8)
+    }
+}
+
+impl<'de> Deserialize<'de> for TransactionType {
+    /// Deserializes the transaction type from its discriminant.
+    ///
+    /// Note: Types stored before the discriminants were made explicit are encoded as a 4-byte
+    /// little-endian variant index, whose first byte is the same discriminant.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Self::try_from(u8::deserialize(deserializer)?).map_err(de::Error::custom)
+    }
+}
+
+/// The reason a transaction was rejected, as stored in the `rejected` map.
+///
+/// The reason is serialized by its variant index, so new variants must be appended after `Other`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum RejectionReason {
+    /// The transaction failed to finalize.
+    FinalizeFailed,
+    /// The transaction spends a record that was already spent.
+    DoubleSpend,
+    /// The transaction deploys a program that already exists.
+    DuplicateProgram,
+    /// The transaction fee is insufficient.
+    InsufficientFee,
+    /// The transaction was rejected for the given reason.
+    Other(String),
+}
+
+impl Display for RejectionReason {
+    /// Prints the rejection reason.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Self::FinalizeFailed => write!(f, "the transaction failed to finalize"),
+            Self::DoubleSpend => write!(f, "the transaction spends a record that was already spent"),
+            Self::DuplicateProgram => write!(f, "the transaction deploys a program that already exists"),
+            Self::InsufficientFee => write!(f, "the transaction fee is insufficient"),
+            Self::Other(reason) => write!(f, "{reason}"),
+        }
+    }
+}
+
+/// The policy for retaining the transactions in a transaction store, as stored in the retention policy map.
+/// Deployments are always retained, regardless of the policy.
+///
+/// The policy is serialized by its variant index, so new variants must be appended after `KeepAfterHeight`.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum RetentionPolicy {
+    /// Retains all transactions.
+    #[default]
+    KeepAll,
+    /// Retains the transactions in the given number of latest blocks, including the current block.
+    KeepLastBlocks(u32),
+    /// Retains the transactions in the blocks after the given block height.
+    KeepAfterHeight(u32),
+}
+
+impl RetentionPolicy {
+    /// Returns `true` if a transaction in the block at the given `height` is retained,
+    /// when the current block height is `current_height`.
+    pub fn is_retained(&self, height: u32, current_height: u32) -> bool {
+        match self {
+            Self::KeepAll => true,
+            Self::KeepLastBlocks(num_blocks) => current_height.saturating_sub(height) < *num_blocks,
+            Self::KeepAfterHeight(min_height) => height > *min_height,
+        }
+    }
+}
+
+/// The outcome of rolling back the transactions of orphaned blocks with `TransactionStore::rollback`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RollbackReport<N: Network> {
+    /// The transactions that were removed.
+    removed: Vec<N::TransactionID>,
+    /// The transactions that were retained, as they are also in the new branch.
+    retained: Vec<N::TransactionID>,
+}
+
+impl<N: Network> RollbackReport<N> {
+    /// Returns the transactions that were removed, in the order they were given.
+    pub fn removed(&self) -> &[N::TransactionID] {
+        &self.removed
+    }
+
+    /// Returns the transactions that were retained, in the order they were given.
+    pub fn retained(&self) -> &[N::TransactionID] {
+        &self.retained
+    }
+}
+
+/// The statistics of a transaction store, as computed by `TransactionStore::statistics`.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Serialize)]
+pub struct StoreStatistics {
+    /// The number of transactions.
+    num_transactions: u64,
+    /// The number of deployment transactions.
+    num_deployments: u64,
+    /// The number of execution transactions.
+    num_executions: u64,
+    /// The number of distinct programs.
+    num_programs: u64,
+    /// The number of verifying keys.
+    num_verifying_keys: u64,
+    /// The total size in bytes of the transactions.
+    num_bytes: u64,
+    /// The lowest block height of a transaction, if any transaction was inserted with a height.
+    min_height: Option<u32>,
+    /// The highest block height of a transaction, if any transaction was inserted with a height.
+    max_height: Option<u32>,
+}
+
+impl StoreStatistics {
+    /// Returns the number of transactions.
+    pub const fn num_transactions(&self) -> u64 {
+        self.num_transactions
+    }
+
+    /// Returns the number of deployment transactions.
+    pub const fn num_deployments(&self) -> u64 {
+        self.num_deployments
+    }
+
+    /// Returns the number of execution transactions.
+    pub const fn num_executions(&self) -> u64 {
+        self.num_executions
+    }
+
+    /// Returns the number of distinct programs.
+    pub const fn num_programs(&self) -> u64 {
+        self.num_programs
+    }
+
+    /// Returns the number of verifying keys.
+    pub const fn num_verifying_keys(&self) -> u64 {
+        self.num_verifying_keys
+    }
+
+    /// Returns the total size in bytes of the transactions.
+    pub const fn num_bytes(&self) -> u64 {
+        self.num_bytes
+    }
+
+    /// Returns the lowest block height of a transaction, if any transaction was inserted with a height.
+    pub const fn min_height(&self) -> Option<u32> {
+        self.min_height
+    }
+
+    /// Returns the highest block height of a transaction, if any transaction was inserted with a height.
+    pub const fn max_height(&self) -> Option<u32> {
+        self.max_height
+    }
+}
+
+/// The size limits enforced by a transaction store, before a transaction is inserted with `TransactionStore::insert`
+/// or `TransactionStore::insert_checked`. These limits are a local policy for transactions that are received
+/// outside of a block, and are not consensus rules: the transactions of a block are never checked against them.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct SizeLimits {
+    /// The maximum size in bytes of a transaction.
+    pub max_transaction_size: u64,
+    /// The maximum size in bytes of the program in a deployment.
+    pub max_program_size: u64,
+    /// The maximum size in bytes of the verifying key of each function in a deployment.
+    pub max_verifying_key_size: u64,
+}
+
+impl SizeLimits {
+    /// The default maximum size in bytes of a transaction.
+    /// Note: The consensus rules do not bound the size of a transaction, so this is a local default.
+    pub const MAX_TRANSACTION_SIZE: u64 = 16 * 1024 * 1024;
+    /// The default maximum size in bytes of the program in a deployment.
+    pub const MAX_PROGRAM_SIZE: u64 = 1024 * 1024;
+    /// The default maximum size in bytes of the verifying key of each function in a deployment.
+    pub const MAX_VERIFYING_KEY_SIZE: u64 = 256 * 1024;
+
+    /// Ensures the given `transaction` is within the size limits, and otherwise returns a `TooLarge` storage error.
+    /// The size of the transaction is counted without allocating its byte representation.
+    pub fn check<N: Network>(&self, transaction: &Transaction<N>) -> Result<()> {
+        // Ensures the given size is within the given limit.
+        let ensure_within = |size: u64, limit: u64| match size <= limit {
+            true => Ok(()),
+            false => Err(Error::new(StorageError::TooLarge { size, limit })),
+        };
+
+        ensure_within(transaction.size_in_bytes()?, self.max_transaction_size)
+            .with_context(|| format!("Transaction '{}' is too large", transaction.id()))?;
+
+        if let Some(deployment) = transaction.deployment() {
+            let program_id = deployment.program_id();
+            let program_size = deployment.program_size_in_bytes()?;
+            ensure_within(program_size, self.max_program_size)
+                .with_context(|| format!("The program '{program_id}' is too large"))?;
+            for (function_name, (verifying_key, _)) in deployment.verifying_keys() {
+                ensure_within(u64::try_from(verifying_key.size_in_bytes())?, self.max_verifying_key_size)
+                    .with_context(|| format!("The verifying key for '{program_id}/{function_name}' is too large"))?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Default for SizeLimits {
+    /// Returns the default size limits.
+    fn default() -> Self {
+        Self {
+            max_transaction_size: Self::MAX_TRANSACTION_SIZE,
+            max_program_size: Self::MAX_PROGRAM_SIZE,
+            max_verifying_key_size: Self::MAX_VERIFYING_KEY_SIZE,
+        }
+    }
 }
 
+/// The maximum number of transactions that are pruned in a single atomic batch.
+const RETENTION_BATCH_SIZE: usize = 1024;
+
 /// A trait for transaction storage.
 pub trait TransactionStorage<N: Network>: Clone + Send + Sync {
     /// The mapping of `transaction ID` to `transaction type`.
     type IDMap: for<'a> Map<'a, N::TransactionID, TransactionType>;
+    /// The mapping of `transaction ID` to `size in bytes`.
+    type SizeMap: for<'a> Map<'a, N::TransactionID, u64>;
+    /// The mapping of `transaction ID` to `(rejected transaction, rejection reason)`.
+    type RejectedMap: for<'a> Map<'a, N::TransactionID, (Transaction<N>, RejectionReason)>;
+    /// The mapping of `transaction ID` to `block height`, for the transactions subject to the retention policy.
+    type HeightMap: for<'a> Map<'a, N::TransactionID, u32>;
+    /// The mapping of `(big-endian block height, transaction ID)` to `()`, which orders the transactions by height.
+    type HeightIndexMap: for<'a> Map<'a, ([u8; 4], N::TransactionID), ()>;
+    /// The metadata map holding the retention policy.
+    type RetentionPolicyMap: for<'a> Map<'a, (), RetentionPolicy>;
     /// The deployment storage.
     type DeploymentStorage: DeploymentStorage<N, FeeStorage = Self::FeeStorage>;
     /// The execution storage.
@@ -68,6 +355,16 @@ pub trait TransactionStorage<N: Network>: Clone + Send + Sync {
 
     /// Returns the ID map.
     fn id_map(&self) -> &Self::IDMap;
+    /// Returns the size map.
+    fn size_map(&self) -> &Self::SizeMap;
+    /// Returns the rejected map.
+    fn rejected_map(&self) -> &Self::RejectedMap;
+    /// Returns the height map.
+    fn height_map(&self) -> &Self::HeightMap;
+    /// Returns the height index map.
+    fn height_index_map(&self) -> &Self::HeightIndexMap;
+    /// Returns the retention policy map.
+    fn retention_policy_map(&self) -> &Self::RetentionPolicyMap;
     /// Returns the deployment store.
     fn deployment_store(&self) -> &DeploymentStore<N, Self::DeploymentStorage>;
     /// Returns the execution store.
@@ -89,6 +386,11 @@ pub trait TransactionStorage<N: Network>: Clone + Send + Sync {
     /// Starts an atomic batch write operation.
     fn start_atomic(&self) {
         self.id_map().start_atomic();
+        self.size_map().start_atomic();
+        self.rejected_map().start_atomic();
+        self.height_map().start_atomic();
+        self.height_index_map().start_atomic();
+        self.retention_policy_map().start_atomic();
         self.deployment_store().start_atomic();
         self.execution_store().start_atomic();
         self.fee_store().start_atomic();
@@ -97,6 +399,11 @@ pub trait TransactionStorage<N: Network>: Clone + Send + Sync {
     /// Checks if an atomic batch is in progress.
     fn is_atomic_in_progress(&self) -> bool {
         self.id_map().is_atomic_in_progress()
+            || self.size_map().is_atomic_in_progress()
+            || self.rejected_map().is_atomic_in_progress()
+            || self.height_map().is_atomic_in_progress()
+            || self.height_index_map().is_atomic_in_progress()
+            || self.retention_policy_map().is_atomic_in_progress()
             || self.deployment_store().is_atomic_in_progress()
             || self.execution_store().is_atomic_in_progress()
             || self.fee_store().is_atomic_in_progress()
@@ -105,6 +412,11 @@ pub trait TransactionStorage<N: Network>: Clone + Send + Sync {
     /// Checkpoints the atomic batch.
     fn atomic_checkpoint(&self) {
         self.id_map().atomic_checkpoint();
+        self.size_map().atomic_checkpoint();
+        self.rejected_map().atomic_checkpoint();
+        self.height_map().atomic_checkpoint();
+        self.height_index_map().atomic_checkpoint();
+        self.retention_policy_map().atomic_checkpoint();
         self.deployment_store().atomic_checkpoint();
         self.execution_store().atomic_checkpoint();
         self.fee_store().atomic_checkpoint();
@@ -113,6 +425,11 @@ pub trait TransactionStorage<N: Network>: Clone + Send + Sync {
     /// Clears the latest atomic batch checkpoint.
     fn clear_latest_checkpoint(&self) {
         self.id_map().clear_latest_checkpoint();
+        self.size_map().clear_latest_checkpoint();
+        self.rejected_map().clear_latest_checkpoint();
+        self.height_map().clear_latest_checkpoint();
+        self.height_index_map().clear_latest_checkpoint();
+        self.retention_policy_map().clear_latest_checkpoint();
         self.deployment_store().clear_latest_checkpoint();
         self.execution_store().clear_latest_checkpoint();
         self.fee_store().clear_latest_checkpoint();
@@ -121,6 +438,11 @@ pub trait TransactionStorage<N: Network>: Clone + Send + Sync {
     /// Rewinds the atomic batch to the previous checkpoint.
     fn atomic_rewind(&self) {
         self.id_map().atomic_rewind();
+        self.size_map().atomic_rewind();
+        self.rejected_map().atomic_rewind();
+        self.height_map().atomic_rewind();
+        self.height_index_map().atomic_rewind();
+        self.retention_policy_map().atomic_rewind();
         self.deployment_store().atomic_rewind();
         self.execution_store().atomic_rewind();
         self.fee_store().atomic_rewind();
@@ -129,6 +451,11 @@ pub trait TransactionStorage<N: Network>: Clone + Send + Sync {
     /// Aborts an atomic batch write operation.
     fn abort_atomic(&self) {
         self.id_map().abort_atomic();
+        self.size_map().abort_atomic();
+        self.rejected_map().abort_atomic();
+        self.height_map().abort_atomic();
+        self.height_index_map().abort_atomic();
+        self.retention_policy_map().abort_atomic();
         self.deployment_store().abort_atomic();
         self.execution_store().abort_atomic();
         self.fee_store().abort_atomic();
@@ -137,14 +464,55 @@ pub trait TransactionStorage<N: Network>: Clone + Send + Sync {
     /// Finishes an atomic batch write operation.
     fn finish_atomic(&self) -> Result<()> {
         self.id_map().finish_atomic()?;
+        self.size_map().finish_atomic()?;
+        self.rejected_map().finish_atomic()?;
+        self.height_map().finish_atomic()?;
+        self.height_index_map().finish_atomic()?;
+        self.retention_policy_map().finish_atomic()?;
         self.deployment_store().finish_atomic()?;
         self.execution_store().finish_atomic()?;
         self.fee_store().finish_atomic()
     }
 
+    /// Reclaims the space left behind by removed entries, and returns an estimate of the number of
+    /// removed entries whose space was reclaimed. This is refused while an atomic batch is in progress.
+    fn compact(&self) -> Result<usize> {
+        ensure!(!self.is_atomic_in_progress(), "Cannot compact the storage while an atomic batch is in progress");
+        let mut num_removed = self.id_map().compact()?;
+        num_removed += self.size_map().compact()?;
+        num_removed += self.rejected_map().compact()?;
+        num_removed += self.height_map().compact()?;
+        num_removed += self.height_index_map().compact()?;
+        num_removed += self.retention_policy_map().compact()?;
+        num_removed += self.deployment_store().compact()?;
+        num_removed += self.execution_store().compact()?;
+        num_removed += self.fee_store().compact()?;
+        Ok(num_removed)
+    }
+
+    /// Copies each map of the storage into the `other` storage, which may have a different backend.
+    fn backup_to<S: TransactionStorage<N>>(&self, other: &S) -> Result<()> {
+        backup_map(self.id_map(), other.id_map())?;
+        backup_map(self.size_map(), other.size_map())?;
+        backup_map(self.rejected_map(), other.rejected_map())?;
+        backup_map(self.height_map(), other.height_map())?;
+        backup_map(self.height_index_map(), other.height_index_map())?;
+        backup_map(self.retention_policy_map(), other.retention_policy_map())?;
+        self.deployment_store().backup_to(other.deployment_store())?;
+        self.execution_store().backup_to(other.execution_store())?;
+        self.fee_store().backup_to(other.fee_store())?;
+        Ok(())
+    }
+
     /// Stores the given `transaction` into storage.
     fn insert(&self, transaction: &Transaction<N>) -> Result<()> {
+        // Compute the size of the transaction.
+        let size_in_bytes = transaction.size_in_bytes()?;
+
         atomic_batch_scope!(self, {
+            // Store the transaction size.
+            self.size_map().insert(transaction.id(), size_in_bytes)?;
+
             match transaction {
                 Transaction::Deploy(..) => {
                     // Store the transaction type.
@@ -169,17 +537,64 @@ pub trait TransactionStorage<N: Network>: Clone + Send + Sync {
         })
     }
 
+    /// Stores the given `transaction`, from the block at the given `height`, into storage.
+    /// Unless it is a deployment, the transaction is then subject to the retention policy.
+    fn insert_at_height(&self, transaction: &Transaction<N>, height: u32) -> Result<()> {
+        atomic_batch_scope!(self, {
+            // Store the transaction.
+            self.insert(transaction)?;
+            // Store the block height, as deployments are exempt from the retention policy.
+            if !matches!(transaction, Transaction::Deploy(..)) {
+                self.height_map().insert(transaction.id(), height)?;
+                self.height_index_map().insert((height.to_be_bytes(), transaction.id()), ())?;
+            }
+            Ok(())
+        })
+    }
+
+    /// Stores the given rejected `transaction` and the `reason` it was rejected into storage.
+    /// Note: The rejected transaction is not stored in the ID map, so it is not returned by `get_transaction`.
+    fn insert_rejected(&self, transaction: &Transaction<N>, reason: RejectionReason) -> Result<()> {
+        // Ensure the transaction was not already accepted.
+        if self.id_map().contains_key_confirmed(&transaction.id())? {
+            bail!("Transaction '{}' was already stored, and cannot be rejected", transaction.id());
+        }
+
+        atomic_batch_scope!(self, {
+            // Store the rejected transaction and the rejection reason.
+            self.rejected_map().insert(transaction.id(), (transaction.clone(), reason))?;
+            Ok(())
+        })
+    }
+
     /// Removes the transaction for the given `transaction ID`.
     fn remove(&self, transaction_id: &N::TransactionID) -> Result<()> {
         // Retrieve the transaction type.
         let transaction_type = match self.id_map().get_confirmed(transaction_id)? {
             Some(transaction_type) => cow_to_copied!(transaction_type),
-            None => bail!("Failed to get the type for transaction '{transaction_id}'"),
+            // If the transaction was rejected, remove the rejected transaction.
+            None if self.rejected_map().contains_key_confirmed(transaction_id)? => {
+                return atomic_batch_scope!(self, {
+                    // Remove the rejected transaction and the rejection reason.
+                    self.rejected_map().remove(transaction_id)?;
+                    Ok(())
+                });
+            }
+            None => bail!(StorageError::NotFound(format!("Failed to get the type for transaction '{transaction_id}'"))),
         };
+        // Retrieve the block height, if the transaction is subject to the retention policy.
+        let height = self.height_map().get_confirmed(transaction_id)?.map(|height| cow_to_copied!(height));
 
         atomic_batch_scope!(self, {
             // Remove the transaction type.
             self.id_map().remove(transaction_id)?;
+            // Remove the transaction size.
+            self.size_map().remove(transaction_id)?;
+            // Remove the block height.
+            if let Some(height) = height {
+                self.height_map().remove(transaction_id)?;
+                self.height_index_map().remove(&(height.to_be_bytes(), *transaction_id))?;
+            }
             // Remove the transaction.
             match transaction_type {
                 // Remove the deployment transaction.
@@ -193,6 +608,76 @@ pub trait TransactionStorage<N: Network>: Clone + Send + Sync {
         })
     }
 
+    /// Stores the given retention `policy`, which is enforced by `enforce_retention`.
+    fn set_retention_policy(&self, policy: RetentionPolicy) -> Result<()> {
+        atomic_batch_scope!(self, {
+            // Store the retention policy.
+            self.retention_policy_map().insert((), policy)?;
+            Ok(())
+        })
+    }
+
+    /// Returns the retention policy, which is `RetentionPolicy::KeepAll` if none was stored.
+    fn get_retention_policy(&self) -> Result<RetentionPolicy> {
+        match self.retention_policy_map().get_confirmed(&())? {
+            Some(policy) => Ok(cow_to_copied!(policy)),
+            None => Ok(RetentionPolicy::KeepAll),
+        }
+    }
+
+    /// Removes the transactions that are no longer retained by the retention policy at the given `current_height`,
+    /// and returns their transaction IDs. The transactions are removed in atomic batches of `RETENTION_BATCH_SIZE`.
+    fn enforce_retention(&self, current_height: u32) -> Result<Vec<N::TransactionID>> {
+        // Retrieve the retention policy.
+        let policy = self.get_retention_policy()?;
+        if policy == RetentionPolicy::KeepAll {
+            return Ok(vec![]);
+        }
+
+        // Collect the transaction IDs that are no longer retained. The height index is ordered by height,
+        // so the expired transactions are the ones before the first retained height.
+        let expired = self
+            .height_index_map()
+            .keys_confirmed()
+            .map(|key| cow_to_copied!(key))
+            .take_while(|(height, _)| !policy.is_retained(u32::from_be_bytes(*height), current_height))
+            .map(|(_, transaction_id)| transaction_id)
+            .collect::<Vec<_>>();
+
+        // Remove the expired transactions.
+        for transaction_ids in expired.chunks(RETENTION_BATCH_SIZE) {
+            atomic_batch_scope!(self, {
+                for transaction_id in transaction_ids {
+                    self.remove(transaction_id)?;
+                }
+                Ok(())
+            })?;
+        }
+        Ok(expired)
+    }
+
+    /// Removes the transactions in `remove` that are not in `keep`, as a single atomic batch.
+    /// The transactions in `remove` are given in the order they were stored, and are removed in reverse order,
+    /// so that the edition of a program is restored when a later deployment of it is removed.
+    fn rollback(&self, remove: &[N::TransactionID], keep: &HashSet<N::TransactionID>) -> Result<RollbackReport<N>> {
+        // Ensure each transaction is removed or retained only once.
+        let mut seen = HashSet::with_capacity(remove.len());
+        let transaction_ids = remove.iter().copied().filter(|id| seen.insert(*id)).collect::<Vec<_>>();
+        // Split the transactions into those to remove and those to retain.
+        let (retained, removed): (Vec<_>, Vec<_>) =
+            transaction_ids.into_iter().partition(|transaction_id| keep.contains(transaction_id));
+
+        atomic_batch_scope!(self, {
+            // Remove the transactions, from the latest one.
+            for transaction_id in removed.iter().rev() {
+                self.remove(transaction_id)?;
+            }
+            Ok(())
+        })?;
+
+        Ok(RollbackReport { removed, retained })
+    }
+
     /// Returns the transaction ID that contains the given `transition ID`.
     fn find_transaction_id_from_transition_id(
         &self,
@@ -206,7 +691,35 @@ pub trait TransactionStorage<N: Network>: Clone + Send + Sync {
         self.deployment_store().find_transaction_id_from_program_id(program_id)
     }
 
+    /// Returns the size in bytes of the transaction for the given `transaction ID`.
+    fn get_transaction_size(&self, transaction_id: &N::TransactionID) -> Result<Option<u64>> {
+        match self.size_map().get_confirmed(transaction_id)? {
+            Some(size_in_bytes) => Ok(Some(cow_to_copied!(size_in_bytes))),
+            None => Ok(None),
+        }
+    }
+
+    /// Returns the rejection reason for the given `transaction ID`, if the transaction was rejected.
+    fn get_rejection_reason(&self, transaction_id: &N::TransactionID) -> Result<Option<RejectionReason>> {
+        match self.rejected_map().get_confirmed(transaction_id)? {
+            Some(rejected) => Ok(Some(cow_to_cloned!(rejected).1)),
+            None => Ok(None),
+        }
+    }
+
+    /// Returns the transaction for the given `transaction ID`, whether it was accepted or rejected.
+    fn get_any_transaction(&self, transaction_id: &N::TransactionID) -> Result<Option<Transaction<N>>> {
+        match self.get_transaction(transaction_id)? {
+            Some(transaction) => Ok(Some(transaction)),
+            None => match self.rejected_map().get_confirmed(transaction_id)? {
+                Some(rejected) => Ok(Some(cow_to_cloned!(rejected).0)),
+                None => Ok(None),
+            },
+        }
+    }
+
     /// Returns the transaction for the given `transaction ID`.
+    /// Note: Rejected transactions are not returned; use `get_any_transaction` to include them.
     fn get_transaction(&self, transaction_id: &N::TransactionID) -> Result<Option<Transaction<N>>> {
         // Retrieve the transaction type.
         let transaction_type = match self.id_map().get_confirmed(transaction_id)? {
@@ -222,7 +735,7 @@ pub trait TransactionStorage<N: Network>: Clone + Send + Sync {
             // Return the fee transaction.
             TransactionType::Fee => match self.fee_store().get_fee(transaction_id)? {
                 Some(fee) => Ok(Some(Transaction::Fee(*transaction_id, fee))),
-                None => bail!("Failed to get fee for transaction '{transaction_id}'"),
+                None => bail!(StorageError::Corrupted(format!("Failed to get fee for transaction '{transaction_id}'"))),
             },
         }
     }
@@ -231,10 +744,16 @@ pub trait TransactionStorage<N: Network>: Clone + Send + Sync {
 /// The transaction store.
 #[derive(Clone)]
 pub struct TransactionStore<N: Network, T: TransactionStorage<N>> {
-    /// The map of `transaction ID` to `transaction type`.
-    transaction_ids: T::IDMap,
     /// The transaction storage.
     storage: T,
+    /// The size limits enforced before a transaction is inserted.
+    limits: SizeLimits,
+    /// The subscribers to the committed insertions and removals.
+    subscribers: Subscribers<N>,
+    /// Whether the store is owned by a block store, and so must retain all of its transactions.
+    is_block_owned: bool,
+    /// PhantomData.
+    _phantom: PhantomData<N>,
 }
 
 impl<N: Network, T: TransactionStorage<N>> TransactionStore<N, T> {
@@ -243,22 +762,153 @@ impl<N: Network, T: TransactionStorage<N>> TransactionStore<N, T> {
         // Initialize the transaction storage.
         let storage = T::open(transition_store)?;
         // Return the transaction store.
-        Ok(Self { transaction_ids: storage.id_map().clone(), storage })
+        Ok(Self::from(storage))
+    }
+
+    /// Initializes a transaction store that enforces the given retention `policy`.
+    /// The policy is persisted, so that it is kept when the store is reopened with `open`.
+    pub fn open_with_policy(
+        transition_store: TransitionStore<N, T::TransitionStorage>,
+        policy: RetentionPolicy,
+    ) -> Result<Self> {
+        // Initialize the transaction store.
+        let store = Self::open(transition_store)?;
+        // Store the retention policy.
+        store.storage.set_retention_policy(policy)?;
+        Ok(store)
     }
 
     /// Initializes a transaction store from storage.
     pub fn from(storage: T) -> Self {
-        Self { transaction_ids: storage.id_map().clone(), storage }
+        Self {
+            storage,
+            limits: SizeLimits::default(),
+            subscribers: Default::default(),
+            is_block_owned: false,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Marks the transaction store as owned by a block store. The blocks reference each of their transactions,
+    /// and the double-spend checks rely on their serial numbers and commitments, so the store then refuses
+    /// any retention policy other than `RetentionPolicy::KeepAll`, including one that is already stored.
+    pub fn owned_by_block_store(mut self) -> Result<Self> {
+        Self::ensure_retains_all(self.storage.get_retention_policy()?)?;
+        self.is_block_owned = true;
+        Ok(self)
+    }
+
+    /// Sets the size limits enforced before a transaction is inserted with `insert` or `insert_checked`.
+    pub fn with_size_limits(mut self, limits: SizeLimits) -> Self {
+        self.limits = limits;
+        self
+    }
+
+    /// Returns the size limits enforced before a transaction is inserted with `insert` or `insert_checked`.
+    pub const fn size_limits(&self) -> &SizeLimits {
+        &self.limits
+    }
+
+    /// Returns a receiver of the insertions, rejections, and removals of transactions, which are sent once committed,
+    /// in the order they were written. The events of an atomic batch are sent when it is finished,
+    /// and are never sent if it is aborted or rewound.
+    ///
+    /// Writers never wait on a subscriber: once a subscriber has `TRANSACTION_EVENT_CAPACITY` undelivered events,
+    /// further events are dropped for it until it catches up. A subscriber is removed when its receiver is dropped.
+    /// Note: The subscribers are not carried over to a snapshot of the store.
+    pub fn subscribe(&self) -> Receiver<TransactionEvent<N>> {
+        self.subscribers.subscribe()
     }
 
     /// Stores the given `transaction` into storage.
+    /// If the transaction exceeds the size limits, a `StorageError::TooLarge` error is returned and nothing is stored.
+    /// Note: The transactions of a block are stored with `insert_at_height`, which does not check the size limits.
     pub fn insert(&self, transaction: &Transaction<N>) -> Result<()> {
-        self.storage.insert(transaction)
+        self.limits.check(transaction)?;
+        self.storage.insert(transaction)?;
+        self.emit([TransactionEvent::inserted(transaction)]);
+        Ok(())
+    }
+
+    /// Stores the given `transaction`, from the block at the given `height`, into storage.
+    /// The size limits are not checked, as the block is already valid under the consensus rules.
+    /// A store owned by a block store never prunes, so it does not record the block height.
+    pub fn insert_at_height(&self, transaction: &Transaction<N>, height: u32) -> Result<()> {
+        match self.is_block_owned {
+            true => self.storage.insert(transaction)?,
+            false => self.storage.insert_at_height(transaction, height)?,
+        }
+        self.emit([TransactionEvent::inserted(transaction)]);
+        Ok(())
+    }
+
+    /// Stores the given rejected `transaction` and the `reason` it was rejected into storage.
+    /// The size limits are not checked, as the rejected transaction is recorded by a valid block.
+    pub fn insert_rejected(&self, transaction: &Transaction<N>, reason: RejectionReason) -> Result<()> {
+        self.storage.insert_rejected(transaction, reason)?;
+        self.emit([TransactionEvent::Rejected { id: transaction.id() }]);
+        Ok(())
+    }
+
+    /// Stores the given `transaction` into storage, if it passes the structural checks and the size limits.
+    /// Note: This does not verify the proofs of the transaction.
+    pub fn insert_checked(&self, transaction: &Transaction<N>) -> Result<()> {
+        transaction.check_structure()?;
+        self.limits.check(transaction)?;
+        self.storage.insert(transaction)?;
+        self.emit([TransactionEvent::inserted(transaction)]);
+        Ok(())
     }
 
     /// Removes the transaction for the given `transaction ID`.
     pub fn remove(&self, transaction_id: &N::TransactionID) -> Result<()> {
-        self.storage.remove(transaction_id)
+        self.storage.remove(transaction_id)?;
+        self.emit([TransactionEvent::Removed { id: *transaction_id }]);
+        Ok(())
+    }
+
+    /// Sends the given events to the subscribers, or holds them back until the atomic batch in progress is finished.
+    fn emit(&self, events: impl IntoIterator<Item = TransactionEvent<N>>) {
+        self.subscribers.emit(events, self.is_atomic_in_progress());
+    }
+
+    /// Stores the given retention `policy`.
+    /// A store owned by a block store refuses any policy other than `RetentionPolicy::KeepAll`.
+    pub fn set_retention_policy(&self, policy: RetentionPolicy) -> Result<()> {
+        if self.is_block_owned {
+            Self::ensure_retains_all(policy)?;
+        }
+        self.storage.set_retention_policy(policy)
+    }
+
+    /// Ensures the given retention `policy` retains all transactions.
+    fn ensure_retains_all(policy: RetentionPolicy) -> Result<()> {
+        match policy {
+            RetentionPolicy::KeepAll => Ok(()),
+            _ => bail!(StorageError::Unsupported(format!(
+                "The retention policy '{policy:?}' cannot be used by a transaction store owned by a block store"
+            ))),
+        }
+    }
+
+    /// Removes the transactions that are no longer retained by the retention policy at the given `current_height`,
+    /// and returns their transaction IDs. Deployments are never removed.
+    /// A store owned by a block store refuses to remove its transactions.
+    pub fn enforce_retention(&self, current_height: u32) -> Result<Vec<N::TransactionID>> {
+        if self.is_block_owned {
+            Self::ensure_retains_all(self.storage.get_retention_policy()?)?;
+        }
+        let removed = self.storage.enforce_retention(current_height)?;
+        self.emit(removed.iter().map(|id| TransactionEvent::Removed { id: *id }));
+        Ok(removed)
+    }
+
+    /// Removes the transactions of orphaned blocks in `remove` that are not in `keep`, as a single atomic batch.
+    /// The transactions in `remove` are given in the order they were stored, and are removed in reverse order.
+    pub fn rollback(&self, remove: &[N::TransactionID], keep: &HashSet<N::TransactionID>) -> Result<RollbackReport<N>> {
+        let report = self.storage.rollback(remove, keep)?;
+        self.emit(report.removed().iter().map(|id| TransactionEvent::Removed { id: *id }));
+        Ok(report)
     }
 
     /// Returns the deployment store.
@@ -284,26 +934,51 @@ impl<N: Network, T: TransactionStorage<N>> TransactionStore<N, T> {
     /// Checkpoints the atomic batch.
     pub fn atomic_checkpoint(&self) {
         self.storage.atomic_checkpoint();
+        self.subscribers.checkpoint();
     }
 
     /// Clears the latest atomic batch checkpoint.
     pub fn clear_latest_checkpoint(&self) {
         self.storage.clear_latest_checkpoint();
+        self.subscribers.clear_latest_checkpoint();
     }
 
     /// Rewinds the atomic batch to the previous checkpoint.
     pub fn atomic_rewind(&self) {
         self.storage.atomic_rewind();
+        self.subscribers.rewind();
     }
 
     /// Aborts an atomic batch write operation.
     pub fn abort_atomic(&self) {
         self.storage.abort_atomic();
+        self.subscribers.abort();
     }
 
-    /// Finishes an atomic batch write operation.
+    /// Finishes an atomic batch write operation, and then sends its events to the subscribers.
     pub fn finish_atomic(&self) -> Result<()> {
-        self.storage.finish_atomic()
+        match self.storage.finish_atomic() {
+            Ok(()) => {
+                self.subscribers.commit();
+                Ok(())
+            }
+            Err(error) => {
+                self.subscribers.abort();
+                Err(error)
+            }
+        }
+    }
+
+    /// Reclaims the space left behind by removed entries, and returns an estimate of the number of removed entries.
+    /// This is refused while an atomic batch is in progress.
+    pub fn compact(&self) -> Result<usize> {
+        self.storage.compact()
+    }
+
+    /// Copies each map of the store into the `other` store, which may have a different backend, in large atomic batches.
+    /// Each entry is checked to deserialize into the types of the `other` store before it is written.
+    pub fn backup_to<S: TransactionStorage<N>>(&self, other: &TransactionStore<N, S>) -> Result<()> {
+        self.storage.backup_to(&other.storage)
     }
 
     /// Returns the optional development ID.
@@ -318,48 +993,64 @@ impl<N: Network, T: TransactionStorage<N>> TransactionStore<N, T> {
         self.storage.get_transaction(transaction_id)
     }
 
+    /// Returns the transaction for the given `transaction ID`, whether it was accepted or rejected.
+    pub fn get_any_transaction(&self, transaction_id: &N::TransactionID) -> Result<Option<Transaction<N>>> {
+        self.storage.get_any_transaction(transaction_id)
+    }
+
+    /// Returns the rejection reason for the given `transaction ID`, if the transaction was rejected.
+    pub fn get_rejection_reason(&self, transaction_id: &N::TransactionID) -> Result<Option<RejectionReason>> {
+        self.storage.get_rejection_reason(transaction_id)
+    }
+
+    /// Returns the retention policy.
+    pub fn retention_policy(&self) -> Result<RetentionPolicy> {
+        self.storage.get_retention_policy()
+    }
+
+    /// Returns the size in bytes of the transaction for the given `transaction ID`.
+    pub fn get_transaction_size(&self, transaction_id: &N::TransactionID) -> Result<Option<u64>> {
+        self.storage.get_transaction_size(transaction_id)
+    }
+
     /// Returns the deployment for the given `transaction ID`.
     pub fn get_deployment(&self, transaction_id: &N::TransactionID) -> Result<Option<Deployment<N>>> {
         // Retrieve the transaction type.
-        let transaction_type = match self.transaction_ids.get_confirmed(transaction_id)? {
+        let transaction_type = match self.storage.id_map().get_confirmed(transaction_id)? {
             Some(transaction_type) => cow_to_copied!(transaction_type),
-            None => bail!("Failed to get the type for transaction '{transaction_id}'"),
+            None => bail!(StorageError::NotFound(format!("Failed to get the type for transaction '{transaction_id}'"))),
         };
         // Retrieve the deployment.
         match transaction_type {
             // Return the deployment.
             TransactionType::Deploy => self.storage.deployment_store().get_deployment(transaction_id),
             // Throw an error.
-            TransactionType::Execute => bail!("Tried to get a deployment for execution transaction '{transaction_id}'"),
-            // Throw an error.
-            TransactionType::Fee => bail!("Tried to get a deployment for fee transaction '{transaction_id}'"),
+            found => bail!(StorageError::type_mismatch(transaction_id, TransactionType::Deploy, found)),
         }
     }
 
     /// Returns the execution for the given `transaction ID`.
     pub fn get_execution(&self, transaction_id: &N::TransactionID) -> Result<Option<Execution<N>>> {
         // Retrieve the transaction type.
-        let transaction_type = match self.transaction_ids.get_confirmed(transaction_id)? {
+        let transaction_type = match self.storage.id_map().get_confirmed(transaction_id)? {
             Some(transaction_type) => cow_to_copied!(transaction_type),
-            None => bail!("Failed to get the type for transaction '{transaction_id}'"),
+            None => bail!(StorageError::NotFound(format!("Failed to get the type for transaction '{transaction_id}'"))),
         };
         // Retrieve the execution.
         match transaction_type {
-            // Throw an error.
-            TransactionType::Deploy => bail!("Tried to get an execution for deployment transaction '{transaction_id}'"),
             // Return the execution.
             TransactionType::Execute => self.storage.execution_store().get_execution(transaction_id),
             // Throw an error.
-            TransactionType::Fee => bail!("Tried to get an execution for fee transaction '{transaction_id}'"),
+            found => bail!(StorageError::type_mismatch(transaction_id, TransactionType::Execute, found)),
         }
     }
 
     /// Returns the edition for the given `transaction ID`.
     pub fn get_edition(&self, transaction_id: &N::TransactionID) -> Result<Option<u16>> {
         // Retrieve the transaction type.
-        let transaction_type = match self.transaction_ids.get_confirmed(transaction_id)? {
+        let transaction_type = match self.storage.id_map().get_confirmed(transaction_id)? {
             Some(transaction_type) => cow_to_copied!(transaction_type),
-            None => bail!("Failed to get the type for transaction '{transaction_id}'"),
+            None => bail!(StorageError::NotFound(format!("Failed to get the type for transaction '{transaction_id}'"))),
         };
         // Retrieve the edition.
         match transaction_type {
@@ -369,7 +1060,9 @@ impl<N: Network, T: TransactionStorage<N>> TransactionStore<N, T> {
                 // Return the edition.
                 match program_id {
                     Some(program_id) => self.storage.deployment_store().get_edition(&program_id),
-                    None => bail!("Failed to get the program ID for deployment transaction '{transaction_id}'"),
+                    None => bail!(StorageError::Corrupted(format!(
+                        "Failed to get the program ID for deployment transaction '{transaction_id}'"
+                    ))),
                 }
             }
             // Return 'None'.
@@ -389,15 +1082,45 @@ impl<N: Network, T: TransactionStorage<N>> TransactionStore<N, T> {
         self.storage.deployment_store().get_program(program_id)
     }
 
+    /// Returns the program with the given `name`, such as `token.aleo`.
+    /// If the name is malformed, a `StorageError::MalformedProgramName` error is returned,
+    /// and if the program is not deployed, a `StorageError::NotFound` error is returned.
+    pub fn get_program_from_str(&self, name: &str) -> Result<Program<N>> {
+        let program_id = Self::parse_program_name(name)?;
+        match self.get_program(&program_id)? {
+            Some(program) => Ok(program),
+            None => bail!(StorageError::NotFound(format!("Program '{program_id}' is not deployed"))),
+        }
+    }
+
+    /// Returns the program deployed by the given `transaction ID`.
+    /// If the transaction is not a deployment, `None` is returned.
+    pub fn get_program_by_transaction_id(&self, transaction_id: &N::TransactionID) -> Result<Option<Program<N>>> {
+        match self.get_program_id(transaction_id)? {
+            Some(program_id) => self.get_program(&program_id),
+            None => Ok(None),
+        }
+    }
+
     /// Returns the verifying key for the given `(program ID, function name)`.
+    /// If the verifying key does not exist, a `StorageError::MissingVerifyingKey` error is returned.
     pub fn get_verifying_key(
         &self,
         program_id: &ProgramID<N>,
         function_name: &Identifier<N>,
-    ) -> Result<Option<VerifyingKey<N>>> {
+    ) -> Result<VerifyingKey<N>> {
         self.storage.deployment_store().get_verifying_key(program_id, function_name)
     }
 
+    /// Returns the verifying keys for the given `(program ID, function name)` pairs, in the order they are given.
+    /// A missing verifying key is returned as `None`.
+    pub fn get_verifying_keys_batch(
+        &self,
+        keys: &[(ProgramID<N>, Identifier<N>)],
+    ) -> Result<Vec<Option<VerifyingKey<N>>>> {
+        self.storage.deployment_store().get_verifying_keys_batch(keys)
+    }
+
     /// Returns the certificate for the given `(program ID, function name)`.
     pub fn get_certificate(
         &self,
@@ -408,12 +1131,52 @@ impl<N: Network, T: TransactionStorage<N>> TransactionStore<N, T> {
     }
 }
 
+impl<N: Network, T: TransactionStorage<N>> TransactionStore<N, T> {
+    /// Re-checks the stored certificate of each function in the given program against its stored verifying key,
+    /// where `verify` returns `true` if the certificate is valid for the program function and verifying key.
+    pub fn verify_certificates<F>(&self, program_id: &ProgramID<N>, verify: F) -> Result<CertificateReport<N>>
+    where
+        F: Fn(&ProgramID<N>, &Identifier<N>, &VerifyingKey<N>, &Certificate<N>) -> bool,
+    {
+        self.storage.deployment_store().verify_certificates(program_id, verify)
+    }
+
+    /// Re-checks the stored certificates of every deployed program, as in `verify_certificates`.
+    pub fn verify_all_certificates<F>(&self, verify: F) -> Result<Vec<CertificateReport<N>>>
+    where
+        F: Fn(&ProgramID<N>, &Identifier<N>, &VerifyingKey<N>, &Certificate<N>) -> bool,
+    {
+        // Note: A program that was deployed in multiple editions is listed once per edition.
+        let program_ids = self.program_ids().map(|program_id| *program_id).collect::<IndexSet<_>>();
+        program_ids.iter().map(|program_id| self.verify_certificates(program_id, &verify)).collect()
+    }
+}
+
 impl<N: Network, T: TransactionStorage<N>> TransactionStore<N, T> {
     /// Returns the transaction ID that contains the given `program ID`.
     pub fn find_transaction_id_from_program_id(&self, program_id: &ProgramID<N>) -> Result<Option<N::TransactionID>> {
         self.storage.deployment_store().find_transaction_id_from_program_id(program_id)
     }
 
+    /// Returns the ID of the transaction that deployed the program with the given `name`, such as `token.aleo`.
+    /// If the name is malformed, a `StorageError::MalformedProgramName` error is returned,
+    /// and if the program is not deployed, a `StorageError::NotFound` error is returned.
+    pub fn find_deployment_id_from_str(&self, name: &str) -> Result<N::TransactionID> {
+        let program_id = Self::parse_program_name(name)?;
+        match self.find_transaction_id_from_program_id(&program_id)? {
+            Some(transaction_id) => Ok(transaction_id),
+            None => bail!(StorageError::NotFound(format!("Program '{program_id}' is not deployed"))),
+        }
+    }
+
+    /// Parses the given program name with `ProgramID::try_from_lowercase`,
+    /// and otherwise returns a `StorageError::MalformedProgramName` error.
+    fn parse_program_name(name: &str) -> Result<ProgramID<N>> {
+        ProgramID::try_from_lowercase(name).map_err(|error| {
+            StorageError::MalformedProgramName { name: name.to_string(), reason: error.to_string() }.into()
+        })
+    }
+
     /// Returns the transaction ID that contains the given `transition ID`.
     pub fn find_transaction_id_from_transition_id(
         &self,
@@ -426,19 +1189,109 @@ impl<N: Network, T: TransactionStorage<N>> TransactionStore<N, T> {
 impl<N: Network, T: TransactionStorage<N>> TransactionStore<N, T> {
     /// Returns `true` if the given transaction ID exists.
     pub fn contains_transaction_id(&self, transaction_id: &N::TransactionID) -> Result<bool> {
-        self.transaction_ids.contains_key_confirmed(transaction_id)
+        self.storage.id_map().contains_key_confirmed(transaction_id)
+    }
+
+    /// Returns `true` if the given transaction ID was rejected.
+    pub fn contains_rejected(&self, transaction_id: &N::TransactionID) -> Result<bool> {
+        self.storage.rejected_map().contains_key_confirmed(transaction_id)
     }
 
     /// Returns `true` if the given program ID exists.
     pub fn contains_program_id(&self, program_id: &ProgramID<N>) -> Result<bool> {
         self.storage.deployment_store().contains_program_id(program_id)
     }
+
+    /// Returns the number of deployment and execution transactions, as `(num_deployments, num_executions)`.
+    /// Both counts are read from a single snapshot of the transaction types, so that a concurrent
+    /// insertion or removal is reflected in either both counts or neither.
+    pub fn type_counts(&self) -> Result<(u64, u64)> {
+        let (mut num_deployments, mut num_executions) = (0, 0);
+        // Count both transaction types in a single pass, as the map is iterated over a snapshot of it.
+        for transaction_type in self.storage.id_map().values_confirmed() {
+            match *transaction_type {
+                TransactionType::Deploy => num_deployments += 1,
+                TransactionType::Execute => num_executions += 1,
+                TransactionType::Fee => (),
+            }
+        }
+        Ok((num_deployments, num_executions))
+    }
+
+    /// Returns the statistics of the store. Each map is read in a single pass over a point-in-time view of it,
+    /// so that writers are not blocked while the statistics are computed. For statistics which are consistent
+    /// across the maps while the store is being written to, compute them on a `TransactionStore::snapshot`.
+    pub fn statistics(&self) -> StoreStatistics {
+        let mut statistics = StoreStatistics::default();
+        // Count the transactions by type.
+        for transaction_type in self.storage.id_map().values_confirmed() {
+            statistics.num_transactions += 1;
+            match *transaction_type {
+                TransactionType::Deploy => statistics.num_deployments += 1,
+                TransactionType::Execute => statistics.num_executions += 1,
+                TransactionType::Fee => (),
+            }
+        }
+        // Count the programs and verifying keys.
+        statistics.num_programs = self.storage.deployment_store().num_programs() as u64;
+        statistics.num_verifying_keys = self.storage.deployment_store().num_verifying_keys() as u64;
+        // Sum the sizes of the transactions.
+        statistics.num_bytes = self.storage.size_map().values_confirmed().map(|size| *size).sum();
+        // Find the range of block heights.
+        for height in self.storage.height_map().values_confirmed() {
+            statistics.min_height = Some(statistics.min_height.map_or(*height, |min| min.min(*height)));
+            statistics.max_height = Some(statistics.max_height.map_or(*height, |max| max.max(*height)));
+        }
+        statistics
+    }
+
+    /// Ensures the transaction ID map agrees with the deployment and execution stores, i.e. that every
+    /// deployment and execution in the ID map is in its respective store, and that no store has an
+    /// entry which is missing from the ID map. This is intended for debug assertions and tests.
+    pub fn assert_consistent(&self) -> Result<()> {
+        // Retrieve the transaction IDs from the deployment and execution stores.
+        let mut deployment_ids = self.deployment_transaction_ids().map(|id| *id).collect::<HashSet<_>>();
+        let mut execution_ids = self.execution_transaction_ids().map(|id| *id).collect::<HashSet<_>>();
+        // Ensure each deployment and execution in the ID map is in its respective store.
+        for (transaction_id, transaction_type) in self.storage.id_map().iter_confirmed() {
+            match *transaction_type {
+                TransactionType::Deploy => ensure!(
+                    deployment_ids.remove(&*transaction_id),
+                    "Deployment transaction '{transaction_id}' is missing from the deployment store"
+                ),
+                TransactionType::Execute => ensure!(
+                    execution_ids.remove(&*transaction_id),
+                    "Execution transaction '{transaction_id}' is missing from the execution store"
+                ),
+                TransactionType::Fee => (),
+            }
+        }
+        // Ensure the stores do not have any transaction IDs which are missing from the ID map.
+        if let Some(transaction_id) = deployment_ids.iter().chain(execution_ids.iter()).next() {
+            bail!("Transaction '{transaction_id}' is missing from the transaction ID map")
+        }
+        Ok(())
+    }
 }
 
 impl<N: Network, T: TransactionStorage<N>> TransactionStore<N, T> {
     /// Returns an iterator over the transaction IDs, for all transactions.
     pub fn transaction_ids(&self) -> impl '_ + Iterator<Item = Cow<'_, N::TransactionID>> {
-        self.transaction_ids.keys_confirmed()
+        self.storage.id_map().keys_confirmed()
+    }
+
+    /// Returns an iterator over the `(transaction type, transaction ID)`, for all transactions.
+    /// This reads both from a single scan of the transaction ID map.
+    pub fn iter_with_type(&self) -> impl '_ + Iterator<Item = (TransactionType, Cow<'_, N::TransactionID>)> {
+        self.storage
+            .id_map()
+            .iter_confirmed()
+            .map(|(transaction_id, transaction_type)| (cow_to_copied!(transaction_type), transaction_id))
+    }
+
+    /// Returns an iterator over the `(transaction ID, size in bytes)`, for all transactions.
+    pub fn transaction_sizes(&self) -> impl '_ + Iterator<Item = (Cow<'_, N::TransactionID>, Cow<'_, u64>)> {
+        self.storage.size_map().iter_confirmed()
     }
 
     /// Returns an iterator over the deployment transaction IDs, for all deployments.
@@ -456,6 +1309,16 @@ impl<N: Network, T: TransactionStorage<N>> TransactionStore<N, T> {
         self.storage.deployment_store().program_ids()
     }
 
+    /// Returns an iterator over the program IDs that start with the given prefix, ignoring case, for all deployments.
+    pub fn program_ids_with_prefix(&self, prefix: &str) -> impl '_ + Iterator<Item = Cow<'_, ProgramID<N>>> {
+        self.storage.deployment_store().program_ids_with_prefix(prefix)
+    }
+
+    /// Returns an iterator over the IDs of the programs that have a deployment of the given edition.
+    pub fn program_ids_by_edition(&self, edition: u16) -> Result<impl '_ + Iterator<Item = Cow<'_, ProgramID<N>>>> {
+        self.storage.deployment_store().program_ids_by_edition(edition)
+    }
+
     /// Returns an iterator over the programs, for all deployments.
     pub fn programs(&self) -> impl '_ + Iterator<Item = Cow<'_, Program<N>>> {
         self.storage.deployment_store().programs()
@@ -479,7 +1342,35 @@ impl<N: Network, T: TransactionStorage<N>> TransactionStore<N, T> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::helpers::memory::{TransactionMemory, TransitionMemory};
+    use crate::helpers::memory::{MemoryMap, TransactionMemory, TransitionMemory};
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_transaction_type_encoding() {
+        // Ensure the discriminants of the existing variants never shift.
+        for (transaction_type, discriminant) in
+            [(TransactionType::Deploy, 0u8), (TransactionType::Execute, 1u8), (TransactionType::Fee, 2u8)]
+        {
+            // Check the bytes.
+            let bytes = transaction_type.to_bytes_le().unwrap();
+            assert_eq!(bytes, vec![discriminant]);
+            assert_eq!(TransactionType::from_bytes_le(&bytes).unwrap(), transaction_type);
+            // Check the serde encoding.
+            let encoded = bincode::serialize(&transaction_type).unwrap();
+            assert_eq!(encoded, vec![discriminant]);
+            assert_eq!(bincode::deserialize::<TransactionType>(&encoded).unwrap(), transaction_type);
+            // Ensure the legacy encoding, a 4-byte variant index, still decodes.
+            let legacy = bincode::serialize(&u32::from(discriminant)).unwrap();
+            assert_eq!(bincode::deserialize::<TransactionType>(&legacy).unwrap(), transaction_type);
+        }
+
+        // Ensure an unknown discriminant is rejected.
+        assert!(TransactionType::try_from(3u8).is_err());
+        assert!(TransactionType::from_bytes_le(&[3u8]).is_err());
+        assert!(bincode::deserialize::<TransactionType>(&[3u8]).is_err());
+    }
 
     #[test]
     fn test_insert_get_remove() {
@@ -521,6 +1412,647 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_insert_checked() {
+        let rng = &mut TestRng::default();
+
+        // Initialize a new transition store.
+        let transition_store = TransitionStore::<_, TransitionMemory<_>>::open(None).unwrap();
+        // Initialize a new transaction store.
+        let transaction_store = TransactionStore::<_, TransactionMemory<_>>::open(transition_store).unwrap();
+
+        // Sample an execution transaction.
+        let transaction = ledger_test_helpers::sample_execution_transaction_with_fee(true, rng);
+        let Transaction::Execute(id, execution, _) = transaction.clone() else { unreachable!() };
+
+        // Ensure an execution without a fee is not stored.
+        let malformed = Transaction::Execute(id, execution, None);
+        assert!(transaction_store.insert_checked(&malformed).is_err());
+        assert!(!transaction_store.contains_transaction_id(&id).unwrap());
+
+        // Ensure the well-formed transaction is stored.
+        transaction_store.insert_checked(&transaction).unwrap();
+        assert_eq!(transaction_store.get_transaction(&id).unwrap(), Some(transaction));
+    }
+
+    #[test]
+    fn test_size_limits() {
+        let rng = &mut TestRng::default();
+
+        // Sample a deployment transaction, and compute the size of its components.
+        let transaction = ledger_test_helpers::sample_deployment_transaction(true, rng);
+        let transaction_id = transaction.id();
+        let size = transaction.size_in_bytes().unwrap();
+        let report = transaction.deployment().unwrap().size_breakdown().unwrap();
+        let program_size = report.program();
+        let verifying_key_size = report.verifying_keys().iter().map(|(_, size)| *size).max().unwrap();
+
+        // Returns a new transaction store which enforces the given size limits.
+        let open = |limits: SizeLimits| {
+            let transition_store = TransitionStore::<_, TransitionMemory<_>>::open(None).unwrap();
+            TransactionStore::<_, TransactionMemory<_>>::open(transition_store).unwrap().with_size_limits(limits)
+        };
+        let limits = SizeLimits {
+            max_transaction_size: size,
+            max_program_size: program_size,
+            max_verifying_key_size: verifying_key_size,
+        };
+
+        // Ensure the transaction is rejected if it exceeds any limit by one byte, and nothing is stored.
+        for (limits, size) in [
+            (SizeLimits { max_transaction_size: size - 1, ..limits }, size),
+            (SizeLimits { max_program_size: program_size - 1, ..limits }, program_size),
+            (SizeLimits { max_verifying_key_size: verifying_key_size - 1, ..limits }, verifying_key_size),
+        ] {
+            let transaction_store = open(limits);
+            let error = transaction_store.insert(&transaction).unwrap_err();
+            assert_eq!(error.downcast_ref::<StorageError>(), Some(&StorageError::TooLarge { size, limit: size - 1 }));
+            let error = transaction_store.insert_checked(&transaction).unwrap_err();
+            assert_eq!(error.downcast_ref::<StorageError>(), Some(&StorageError::TooLarge { size, limit: size - 1 }));
+            assert!(!transaction_store.contains_transaction_id(&transaction_id).unwrap());
+            assert_eq!(transaction_store.statistics().num_transactions(), 0);
+
+            // Ensure the transaction of a block is stored regardless of the limits.
+            transaction_store.insert_rejected(&transaction, RejectionReason::DoubleSpend).unwrap();
+            transaction_store.remove(&transaction_id).unwrap();
+            transaction_store.insert_at_height(&transaction, 1).unwrap();
+            assert!(transaction_store.contains_transaction_id(&transaction_id).unwrap());
+        }
+
+        // Ensure the transaction is stored if it is exactly at the limits.
+        let transaction_store = open(limits);
+        assert_eq!(transaction_store.size_limits(), &limits);
+        transaction_store.insert(&transaction).unwrap();
+        assert_eq!(transaction_store.get_transaction(&transaction_id).unwrap(), Some(transaction.clone()));
+
+        // Ensure the limits are kept in a snapshot.
+        let snapshot = transaction_store.snapshot();
+        assert_eq!(snapshot.size_limits(), &limits);
+
+        // Ensure the default limits accept the transaction.
+        let transition_store = TransitionStore::<_, TransitionMemory<_>>::open(None).unwrap();
+        let transaction_store = TransactionStore::<_, TransactionMemory<_>>::open(transition_store).unwrap();
+        assert_eq!(transaction_store.size_limits(), &SizeLimits::default());
+        transaction_store.insert(&transaction).unwrap();
+    }
+
+    #[test]
+    fn test_transaction_size() {
+        let rng = &mut TestRng::default();
+
+        // Initialize a new transition store.
+        let transition_store = TransitionStore::<_, TransitionMemory<_>>::open(None).unwrap();
+        // Initialize a new transaction store.
+        let transaction_store = TransactionStore::<_, TransactionMemory<_>>::open(transition_store).unwrap();
+
+        // Sample the transactions.
+        let transactions = [
+            ledger_test_helpers::sample_deployment_transaction(true, rng),
+            ledger_test_helpers::sample_execution_transaction_with_fee(true, rng),
+        ];
+
+        for transaction in &transactions {
+            let transaction_id = transaction.id();
+
+            // Ensure the transaction size does not exist.
+            assert_eq!(transaction_store.get_transaction_size(&transaction_id).unwrap(), None);
+
+            // Insert the transaction.
+            transaction_store.insert(transaction).unwrap();
+
+            // Ensure the stored size matches the byte representation.
+            let expected = transaction.to_bytes_le().unwrap().len() as u64;
+            assert_eq!(transaction_store.get_transaction_size(&transaction_id).unwrap(), Some(expected));
+        }
+
+        // Ensure the sizes are iterated for all transactions.
+        assert_eq!(transaction_store.transaction_sizes().count(), transactions.len());
+
+        for transaction in &transactions {
+            // Remove the transaction.
+            transaction_store.remove(&transaction.id()).unwrap();
+            // Ensure the transaction size does not exist.
+            assert_eq!(transaction_store.get_transaction_size(&transaction.id()).unwrap(), None);
+        }
+        assert_eq!(transaction_store.transaction_sizes().count(), 0);
+    }
+
+    #[test]
+    fn test_insert_rejected() {
+        let rng = &mut TestRng::default();
+
+        // Initialize a new transition store.
+        let transition_store = TransitionStore::<_, TransitionMemory<_>>::open(None).unwrap();
+        // Initialize a new transaction store.
+        let transaction_store = TransactionStore::<_, TransactionMemory<_>>::open(transition_store).unwrap();
+
+        // Sample an execution transaction.
+        let transaction = ledger_test_helpers::sample_execution_transaction_with_fee(true, rng);
+        let transaction_id = transaction.id();
+        let reason = RejectionReason::Other("Failed to finalize".to_string());
+
+        // Insert the transaction as rejected.
+        transaction_store.insert_rejected(&transaction, reason.clone()).unwrap();
+        assert!(transaction_store.contains_rejected(&transaction_id).unwrap());
+        assert_eq!(transaction_store.get_rejection_reason(&transaction_id).unwrap(), Some(reason));
+
+        // Ensure the type map stays clean, and the transaction is only returned by `get_any_transaction`.
+        assert!(!transaction_store.contains_transaction_id(&transaction_id).unwrap());
+        assert_eq!(transaction_store.transaction_ids().count(), 0);
+        assert_eq!(transaction_store.get_transaction(&transaction_id).unwrap(), None);
+        assert_eq!(transaction_store.get_any_transaction(&transaction_id).unwrap(), Some(transaction.clone()));
+
+        // Remove the rejected transaction.
+        transaction_store.remove(&transaction_id).unwrap();
+        assert!(!transaction_store.contains_rejected(&transaction_id).unwrap());
+        assert_eq!(transaction_store.get_rejection_reason(&transaction_id).unwrap(), None);
+        assert_eq!(transaction_store.get_any_transaction(&transaction_id).unwrap(), None);
+
+        // Ensure an accepted transaction cannot be rejected.
+        transaction_store.insert(&transaction).unwrap();
+        assert!(transaction_store.insert_rejected(&transaction, RejectionReason::FinalizeFailed).is_err());
+        assert!(!transaction_store.contains_rejected(&transaction_id).unwrap());
+        assert_eq!(transaction_store.get_any_transaction(&transaction_id).unwrap(), Some(transaction));
+    }
+
+    #[test]
+    fn test_from_snapshot() {
+        let rng = &mut TestRng::default();
+
+        // Sample the transaction types.
+        let transaction_types = [TransactionType::Deploy, TransactionType::Execute, TransactionType::Fee]
+            .into_iter()
+            .map(|transaction_type| (Uniform::rand(rng), transaction_type))
+            .collect::<Vec<(<CurrentNetwork as Network>::TransactionID, _)>>();
+
+        // Initialize a new transition store.
+        let transition_store = TransitionStore::<_, TransitionMemory<_>>::open(None).unwrap();
+        // Initialize a new transaction store, from the transaction types.
+        let id_map = transaction_types.iter().copied().collect::<MemoryMap<_, _>>();
+        let storage = TransactionMemory::from_snapshot(id_map, transition_store).unwrap();
+        let transaction_store = TransactionStore::<_, TransactionMemory<_>>::from(storage);
+
+        // Ensure the transaction types are visible through the store, without going through `insert`.
+        assert_eq!(transaction_store.type_counts().unwrap(), (1, 1));
+        for (transaction_id, transaction_type) in &transaction_types {
+            assert!(transaction_store.contains_transaction_id(transaction_id).unwrap());
+            assert!(transaction_store.iter_with_type().any(|(candidate_type, candidate_id)| {
+                candidate_type == *transaction_type && *candidate_id == *transaction_id
+            }));
+        }
+        assert_eq!(transaction_store.transaction_ids().count(), transaction_types.len());
+    }
+
+    #[test]
+    fn test_type_counts() {
+        let rng = &mut TestRng::default();
+
+        // Initialize a new transition store.
+        let transition_store = TransitionStore::<_, TransitionMemory<_>>::open(None).unwrap();
+        // Initialize a new transaction store.
+        let transaction_store = TransactionStore::<_, TransactionMemory<_>>::open(transition_store).unwrap();
+        assert_eq!(transaction_store.type_counts().unwrap(), (0, 0));
+
+        // Insert the transactions.
+        let deployment = ledger_test_helpers::sample_deployment_transaction(true, rng);
+        let execution = ledger_test_helpers::sample_execution_transaction_with_fee(true, rng);
+        transaction_store.insert(&deployment).unwrap();
+        assert_eq!(transaction_store.type_counts().unwrap(), (1, 0));
+        transaction_store.insert(&execution).unwrap();
+        assert_eq!(transaction_store.type_counts().unwrap(), (1, 1));
+        transaction_store.insert(&ledger_test_helpers::sample_execution_transaction_with_fee(false, rng)).unwrap();
+        assert_eq!(transaction_store.type_counts().unwrap(), (1, 2));
+
+        // Ensure fee transactions are not counted.
+        transaction_store.insert(&ledger_test_helpers::sample_fee_private_transaction(rng)).unwrap();
+        assert_eq!(transaction_store.type_counts().unwrap(), (1, 2));
+
+        // Ensure the counts match the deployment and execution transaction IDs.
+        let num_deployments = transaction_store.deployment_transaction_ids().count() as u64;
+        let num_executions = transaction_store.execution_transaction_ids().count() as u64;
+        assert_eq!(transaction_store.type_counts().unwrap(), (num_deployments, num_executions));
+
+        // Ensure each transaction ID is yielded with its type.
+        let mut num_fees = 0;
+        for (transaction_type, transaction_id) in transaction_store.iter_with_type() {
+            let transaction = transaction_store.get_transaction(&transaction_id).unwrap().unwrap();
+            match transaction_type {
+                TransactionType::Deploy => assert!(matches!(transaction, Transaction::Deploy(..))),
+                TransactionType::Execute => assert!(matches!(transaction, Transaction::Execute(..))),
+                TransactionType::Fee => {
+                    assert!(matches!(transaction, Transaction::Fee(..)));
+                    num_fees += 1;
+                }
+            }
+        }
+        assert_eq!(num_fees, 1);
+        assert_eq!(transaction_store.iter_with_type().count(), transaction_store.transaction_ids().count());
+
+        // Remove the transactions.
+        transaction_store.remove(&deployment.id()).unwrap();
+        assert_eq!(transaction_store.type_counts().unwrap(), (0, 2));
+        transaction_store.remove(&execution.id()).unwrap();
+        assert_eq!(transaction_store.type_counts().unwrap(), (0, 1));
+    }
+
+    #[test]
+    fn test_snapshot() {
+        let rng = &mut TestRng::default();
+
+        // Initialize a new transition store.
+        let transition_store = TransitionStore::<_, TransitionMemory<_>>::open(None).unwrap();
+        // Initialize a new transaction store.
+        let transaction_store = TransactionStore::<_, TransactionMemory<_>>::open(transition_store).unwrap();
+
+        // Insert a deployment, and take a snapshot.
+        let deployment = ledger_test_helpers::sample_deployment_transaction(true, rng);
+        transaction_store.insert(&deployment).unwrap();
+        let snapshot = transaction_store.snapshot();
+        assert_eq!(snapshot.get_transaction(&deployment.id()).unwrap(), Some(deployment.clone()));
+
+        // Insert an execution in an atomic batch, and ensure a snapshot taken while the batch is in progress
+        // does not reflect the batch.
+        let execution = ledger_test_helpers::sample_execution_transaction_with_fee(true, rng);
+        transaction_store.start_atomic();
+        transaction_store.insert(&execution).unwrap();
+        let batch_snapshot = transaction_store.snapshot();
+        assert!(!batch_snapshot.contains_transaction_id(&execution.id()).unwrap());
+        for transition in execution.transitions() {
+            assert!(!batch_snapshot.contains_transition_id(transition.id()).unwrap());
+        }
+
+        // Ensure a snapshot taken once the batch is committed reflects the whole batch.
+        transaction_store.finish_atomic().unwrap();
+        let batch_snapshot = transaction_store.snapshot();
+        assert_eq!(batch_snapshot.get_transaction(&execution.id()).unwrap(), Some(execution.clone()));
+        for transition in execution.transitions() {
+            assert!(batch_snapshot.contains_transition_id(transition.id()).unwrap());
+        }
+
+        // Ensure the earlier snapshot does not reflect the batch.
+        assert!(!snapshot.contains_transaction_id(&execution.id()).unwrap());
+        assert_eq!(snapshot.transaction_ids().count(), 1);
+        for transition in execution.transitions() {
+            assert!(!snapshot.contains_transition_id(transition.id()).unwrap());
+        }
+
+        // Ensure writes to the store do not affect the snapshot.
+        transaction_store.remove(&deployment.id()).unwrap();
+        assert_eq!(snapshot.get_transaction(&deployment.id()).unwrap(), Some(deployment));
+
+        // Ensure snapshots taken while batches are being committed reflect each batch either in full or not at all.
+        const NUM_ROUNDS: usize = 20;
+        let store = transaction_store.clone();
+        let transaction = execution.clone();
+        let handle = std::thread::spawn(move || {
+            for _ in 0..NUM_ROUNDS {
+                store.remove(&transaction.id()).unwrap();
+                store.insert(&transaction).unwrap();
+            }
+        });
+        for _ in 0..NUM_ROUNDS {
+            let snapshot = transaction_store.snapshot();
+            let is_stored = snapshot.contains_transaction_id(&execution.id()).unwrap();
+            for transition in execution.transitions() {
+                assert_eq!(snapshot.contains_transition_id(transition.id()).unwrap(), is_stored);
+            }
+        }
+        handle.join().unwrap();
+    }
+
+    #[test]
+    fn test_statistics() {
+        let rng = &mut TestRng::default();
+
+        // Initialize a new transition store.
+        let transition_store = TransitionStore::<_, TransitionMemory<_>>::open(None).unwrap();
+        // Initialize a new transaction store.
+        let transaction_store = TransactionStore::<_, TransactionMemory<_>>::open(transition_store).unwrap();
+        assert_eq!(transaction_store.statistics(), StoreStatistics::default());
+
+        // Insert a deployment, two executions, and a fee transaction at increasing heights.
+        let deployment = ledger_test_helpers::sample_deployment_transaction(true, rng);
+        let execution_0 = ledger_test_helpers::sample_execution_transaction_with_fee(true, rng);
+        let execution_1 = ledger_test_helpers::sample_execution_transaction_with_fee(false, rng);
+        let fee = ledger_test_helpers::sample_fee_private_transaction(rng);
+        let transactions = [&deployment, &execution_0, &execution_1, &fee];
+        for (height, transaction) in (3..).zip(transactions) {
+            transaction_store.insert_at_height(transaction, height).unwrap();
+        }
+
+        // Ensure the statistics match the population.
+        let statistics = transaction_store.statistics();
+        assert_eq!(statistics.num_transactions(), 4);
+        assert_eq!(statistics.num_deployments(), 1);
+        assert_eq!(statistics.num_executions(), 2);
+        assert_eq!(statistics.num_programs(), 1);
+        let num_verifying_keys = deployment.deployment().unwrap().verifying_keys().len() as u64;
+        assert_eq!(statistics.num_verifying_keys(), num_verifying_keys);
+        let num_bytes = transactions.iter().map(|transaction| transaction.size_in_bytes().unwrap()).sum::<u64>();
+        assert_eq!(statistics.num_bytes(), num_bytes);
+        // Ensure the heights exclude the deployment, as it is exempt from the retention policy.
+        assert_eq!(statistics.min_height(), Some(4));
+        assert_eq!(statistics.max_height(), Some(6));
+
+        // Ensure the statistics of a snapshot match.
+        assert_eq!(transaction_store.snapshot().statistics(), statistics);
+
+        // Remove an execution, and ensure the statistics are updated.
+        transaction_store.remove(&execution_1.id()).unwrap();
+        let statistics = transaction_store.statistics();
+        assert_eq!(statistics.num_transactions(), 3);
+        assert_eq!(statistics.num_executions(), 1);
+        assert_eq!(statistics.num_bytes(), num_bytes - execution_1.size_in_bytes().unwrap());
+        assert_eq!((statistics.min_height(), statistics.max_height()), (Some(4), Some(6)));
+    }
+
+    #[test]
+    fn test_assert_consistent() {
+        let rng = &mut TestRng::default();
+
+        // Initialize a new transition store.
+        let transition_store = TransitionStore::<_, TransitionMemory<_>>::open(None).unwrap();
+        // Initialize a new transaction store.
+        let transaction_store = TransactionStore::<_, TransactionMemory<_>>::open(transition_store).unwrap();
+        transaction_store.assert_consistent().unwrap();
+
+        // Insert the transactions.
+        let deployment = ledger_test_helpers::sample_deployment_transaction(true, rng);
+        let execution = ledger_test_helpers::sample_execution_transaction_with_fee(true, rng);
+        let fee = ledger_test_helpers::sample_fee_private_transaction(rng);
+        for transaction in [&deployment, &execution, &fee] {
+            transaction_store.insert(transaction).unwrap();
+        }
+        transaction_store.assert_consistent().unwrap();
+
+        // Desynchronize the store by removing the deployment from the ID map directly.
+        let id_map = transaction_store.storage.id_map();
+        id_map.remove(&deployment.id()).unwrap();
+        assert!(transaction_store.assert_consistent().is_err());
+        // Restore the deployment, and ensure the store is consistent again.
+        id_map.insert(deployment.id(), TransactionType::Deploy).unwrap();
+        transaction_store.assert_consistent().unwrap();
+
+        // Desynchronize the store by recording the execution as a deployment.
+        id_map.insert(execution.id(), TransactionType::Deploy).unwrap();
+        assert!(transaction_store.assert_consistent().is_err());
+        id_map.insert(execution.id(), TransactionType::Execute).unwrap();
+        transaction_store.assert_consistent().unwrap();
+
+        // Desynchronize the store by adding an execution which is not in the execution store.
+        id_map.insert(Uniform::rand(rng), TransactionType::Execute).unwrap();
+        assert!(transaction_store.assert_consistent().is_err());
+
+        // Ensure a clone of the store observes the same (inconsistent) state.
+        assert!(transaction_store.clone().assert_consistent().is_err());
+    }
+
+    #[test]
+    fn test_retention_policy() {
+        let rng = &mut TestRng::default();
+
+        // Ensure the policies retain the expected block heights.
+        assert!(RetentionPolicy::KeepAll.is_retained(0, u32::MAX));
+        assert!(RetentionPolicy::KeepLastBlocks(2).is_retained(9, 10));
+        assert!(!RetentionPolicy::KeepLastBlocks(2).is_retained(8, 10));
+        assert!(RetentionPolicy::KeepAfterHeight(5).is_retained(6, 10));
+        assert!(!RetentionPolicy::KeepAfterHeight(5).is_retained(5, 10));
+
+        // Initialize a new transition store.
+        let transition_store = TransitionStore::<_, TransitionMemory<_>>::open(None).unwrap();
+        // Initialize a new transaction store, which retains the last 2 blocks.
+        let policy = RetentionPolicy::KeepLastBlocks(2);
+        let transaction_store =
+            TransactionStore::<_, TransactionMemory<_>>::open_with_policy(transition_store, policy).unwrap();
+        assert_eq!(transaction_store.retention_policy().unwrap(), policy);
+
+        // Ensure the policy is kept when the store is reopened from its storage.
+        let reopened = TransactionStore::<_, TransactionMemory<_>>::from(transaction_store.storage.clone());
+        assert_eq!(reopened.retention_policy().unwrap(), policy);
+
+        // Insert a deployment, an execution, and a fee transaction at increasing heights.
+        let deployment = ledger_test_helpers::sample_deployment_transaction(true, rng);
+        let execution = ledger_test_helpers::sample_execution_transaction_with_fee(true, rng);
+        let fee = ledger_test_helpers::sample_fee_private_transaction(rng);
+        transaction_store.insert_at_height(&deployment, 0).unwrap();
+        transaction_store.insert_at_height(&execution, 1).unwrap();
+        transaction_store.insert_at_height(&fee, 2).unwrap();
+
+        // Ensure nothing is pruned while the transactions are within the window.
+        for height in 0..=2 {
+            assert!(transaction_store.enforce_retention(height).unwrap().is_empty());
+        }
+        assert_eq!(transaction_store.transaction_ids().count(), 3);
+
+        // Advance past the window of the execution, and ensure only the execution is pruned.
+        assert_eq!(transaction_store.enforce_retention(3).unwrap(), vec![execution.id()]);
+        assert!(!transaction_store.contains_transaction_id(&execution.id()).unwrap());
+        assert_eq!(transaction_store.get_transaction(&fee.id()).unwrap(), Some(fee.clone()));
+        assert_eq!(transaction_store.get_transaction(&deployment.id()).unwrap(), Some(deployment.clone()));
+
+        // Advance far past the window, and ensure the deployment survives.
+        assert_eq!(transaction_store.enforce_retention(100).unwrap(), vec![fee.id()]);
+        assert!(!transaction_store.contains_transaction_id(&fee.id()).unwrap());
+        assert_eq!(transaction_store.get_transaction(&deployment.id()).unwrap(), Some(deployment));
+        assert_eq!(transaction_store.transaction_ids().count(), 1);
+
+        // Ensure a store with a stored policy cannot be owned by a block store.
+        let owned = TransactionStore::<_, TransactionMemory<_>>::from(transaction_store.storage.clone());
+        let error = owned.owned_by_block_store().unwrap_err();
+        assert!(matches!(error.downcast_ref::<StorageError>(), Some(StorageError::Unsupported(_))));
+
+        // Ensure a store owned by a block store refuses any policy other than `KeepAll`.
+        let transition_store = TransitionStore::<_, TransitionMemory<_>>::open(None).unwrap();
+        let owned = TransactionStore::<_, TransactionMemory<_>>::open(transition_store).unwrap();
+        let owned = owned.owned_by_block_store().unwrap();
+        let error = owned.set_retention_policy(policy).unwrap_err();
+        assert!(matches!(error.downcast_ref::<StorageError>(), Some(StorageError::Unsupported(_))));
+        assert_eq!(owned.retention_policy().unwrap(), RetentionPolicy::KeepAll);
+        owned.set_retention_policy(RetentionPolicy::KeepAll).unwrap();
+        assert!(owned.enforce_retention(100).unwrap().is_empty());
+
+        // Ensure a store owned by a block store does not record the block heights.
+        let execution = ledger_test_helpers::sample_execution_transaction_with_fee(true, rng);
+        owned.insert_at_height(&execution, 1).unwrap();
+        assert_eq!(owned.get_transaction(&execution.id()).unwrap(), Some(execution));
+        assert_eq!(owned.storage.height_map().keys_confirmed().count(), 0);
+        assert_eq!(owned.storage.height_index_map().keys_confirmed().count(), 0);
+    }
+
+    #[test]
+    fn test_enforce_retention_by_height() {
+        let rng = &mut TestRng::default();
+
+        // Initialize a new transition store.
+        let transition_store = TransitionStore::<_, TransitionMemory<_>>::open(None).unwrap();
+        // Initialize a new transaction store, which retains the blocks after height 255.
+        let policy = RetentionPolicy::KeepAfterHeight(255);
+        let transaction_store =
+            TransactionStore::<_, TransactionMemory<_>>::open_with_policy(transition_store, policy).unwrap();
+
+        // Insert the transactions at heights whose little-endian encodings are not in height order.
+        let execution = ledger_test_helpers::sample_execution_transaction_with_fee(true, rng);
+        let fee = ledger_test_helpers::sample_fee_private_transaction(rng);
+        transaction_store.insert_at_height(&execution, 256).unwrap();
+        transaction_store.insert_at_height(&fee, 255).unwrap();
+
+        // Ensure only the transaction at or below the height is pruned.
+        assert_eq!(transaction_store.enforce_retention(256).unwrap(), vec![fee.id()]);
+        assert_eq!(transaction_store.get_transaction(&execution.id()).unwrap(), Some(execution.clone()));
+
+        // Ensure the height index is cleared when the transaction is removed.
+        transaction_store.remove(&execution.id()).unwrap();
+        assert_eq!(transaction_store.storage.height_index_map().keys_confirmed().count(), 0);
+    }
+
+    #[test]
+    fn test_compact() {
+        let rng = &mut TestRng::default();
+
+        // Initialize a new transition store.
+        let transition_store = TransitionStore::<_, TransitionMemory<_>>::open(None).unwrap();
+        // Initialize a new transaction store.
+        let transaction_store = TransactionStore::<_, TransactionMemory<_>>::open(transition_store).unwrap();
+
+        // Insert a deployment and an execution, and remove the execution.
+        let deployment = ledger_test_helpers::sample_deployment_transaction(true, rng);
+        let execution = ledger_test_helpers::sample_execution_transaction_with_fee(true, rng);
+        transaction_store.insert(&deployment).unwrap();
+        transaction_store.insert(&execution).unwrap();
+        transaction_store.remove(&execution.id()).unwrap();
+
+        // Ensure the store cannot be compacted while an atomic batch is in progress.
+        transaction_store.start_atomic();
+        assert!(transaction_store.compact().is_err());
+        transaction_store.abort_atomic();
+
+        // Compact the store, and ensure the removed entries are reported once.
+        assert!(transaction_store.compact().unwrap() > 0);
+        assert_eq!(transaction_store.compact().unwrap(), 0);
+
+        // Ensure the store is still readable.
+        assert_eq!(transaction_store.get_transaction(&deployment.id()).unwrap(), Some(deployment));
+        assert_eq!(transaction_store.get_transaction(&execution.id()).unwrap(), None);
+        for transition in execution.transitions() {
+            assert!(!transaction_store.transition_store().contains_transition_id(transition.id()).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_rollback() {
+        let rng = &mut TestRng::default();
+
+        // Sample a deployment, and a second edition of its program with a different fee.
+        let deployment_0 = ledger_test_helpers::sample_deployment_transaction(true, rng);
+        let deployment_1 = match ledger_test_helpers::sample_deployment_transaction(false, rng) {
+            Transaction::Deploy(_, _, deployment, fee) => {
+                // Construct the second edition of the program.
+                let verifying_keys = deployment.verifying_keys().clone();
+                let deployment = Deployment::new(1, deployment.program().clone(), verifying_keys).unwrap();
+                let deployment_id = deployment.to_deployment_id().unwrap();
+                // Sign the second edition as a new owner.
+                let private_key = console::account::PrivateKey::new(rng).unwrap();
+                let owner = console::program::ProgramOwner::new(&private_key, deployment_id, rng).unwrap();
+                Transaction::from_deployment(owner, deployment, fee).unwrap()
+            }
+            _ => panic!("Incorrect transaction type"),
+        };
+        let program_id = *deployment_0.deployment().unwrap().program_id();
+        // Sample an execution, and a fee transaction.
+        let execution = ledger_test_helpers::sample_execution_transaction_with_fee(true, rng);
+        let fee = ledger_test_helpers::sample_fee_private_transaction(rng);
+
+        // Initialize a new transition store.
+        let transition_store = TransitionStore::<_, TransitionMemory<_>>::open(None).unwrap();
+        // Initialize a new transaction store.
+        let transaction_store = TransactionStore::<_, TransactionMemory<_>>::open(transition_store).unwrap();
+
+        // Store the orphaned branch, which has the deployment and the execution in its first block,
+        // and the second edition and the fee transaction in its second block.
+        let orphaned = [deployment_0.id(), execution.id(), deployment_1.id(), fee.id()];
+        for transaction in [&deployment_0, &execution, &deployment_1, &fee] {
+            transaction_store.insert(transaction).unwrap();
+        }
+        assert_eq!(transaction_store.deployment_store().get_edition(&program_id).unwrap(), Some(1));
+
+        // Roll back the orphaned branch, where the new branch also has the execution.
+        let keep = HashSet::from([execution.id()]);
+        let report = transaction_store.rollback(&orphaned, &keep).unwrap();
+        assert_eq!(report.removed(), [deployment_0.id(), deployment_1.id(), fee.id()]);
+        assert_eq!(report.retained(), [execution.id()]);
+
+        // Ensure only the execution remains, and the program is no longer deployed.
+        assert_eq!(transaction_store.transaction_ids().collect::<Vec<_>>(), [Cow::Owned(execution.id())]);
+        assert_eq!(transaction_store.get_transaction(&execution.id()).unwrap(), Some(execution.clone()));
+        assert_eq!(transaction_store.deployment_store().get_edition(&program_id).unwrap(), None);
+        assert_eq!(transaction_store.deployment_store().get_program(&program_id).unwrap(), None);
+
+        // Store the orphaned branch again, and roll back its second block.
+        for transaction in [&deployment_0, &deployment_1, &fee] {
+            transaction_store.insert(transaction).unwrap();
+        }
+        let report = transaction_store.rollback(&orphaned[2..], &HashSet::new()).unwrap();
+        assert_eq!(report.removed(), [deployment_1.id(), fee.id()]);
+        assert!(report.retained().is_empty());
+
+        // Ensure the edition of the program is restored to the first deployment.
+        assert_eq!(transaction_store.deployment_store().get_edition(&program_id).unwrap(), Some(0));
+        assert_eq!(transaction_store.get_transaction(&deployment_0.id()).unwrap(), Some(deployment_0));
+        assert_eq!(transaction_store.get_transaction(&deployment_1.id()).unwrap(), None);
+    }
+
+    #[test]
+    fn test_backup_to() {
+        use core::hash::Hasher;
+        use std::collections::hash_map::DefaultHasher;
+
+        let rng = &mut TestRng::default();
+
+        // Computes a checksum of the given store, over its serialized transaction types and transactions.
+        let checksum = |store: &TransactionStore<_, TransactionMemory<_>>| {
+            let mut hasher = DefaultHasher::new();
+            for (key_bytes, value_bytes) in store.storage.id_map().raw_iter() {
+                hasher.write(&key_bytes);
+                hasher.write(&value_bytes);
+            }
+            for transaction_id in store.transaction_ids() {
+                let transaction = store.get_transaction(&transaction_id).unwrap().unwrap();
+                hasher.write(&transaction.to_bytes_le().unwrap());
+            }
+            hasher.finish()
+        };
+
+        // Initialize a new transaction store, and a second one to back it up into.
+        let transaction_store =
+            TransactionStore::<_, TransactionMemory<_>>::open(TransitionStore::open(None).unwrap()).unwrap();
+        let backup = TransactionStore::<_, TransactionMemory<_>>::open(TransitionStore::open(None).unwrap()).unwrap();
+
+        // Insert the transactions.
+        let execution = ledger_test_helpers::sample_execution_transaction_with_fee(true, rng);
+        for transaction in [
+            ledger_test_helpers::sample_deployment_transaction(true, rng),
+            execution.clone(),
+            ledger_test_helpers::sample_fee_public_transaction(rng),
+        ] {
+            transaction_store.insert_at_height(&transaction, 1).unwrap();
+        }
+
+        // Back up the store, and ensure the checksums match.
+        assert_ne!(checksum(&transaction_store), checksum(&backup));
+        transaction_store.backup_to(&backup).unwrap();
+        assert_eq!(checksum(&transaction_store), checksum(&backup));
+
+        // Ensure the membership filters of the backup include the copied serial numbers.
+        for serial_number in execution.serial_numbers() {
+            assert!(backup.transition_store().contains_serial_number(serial_number).unwrap());
+        }
+
+        // Ensure an entry of another value type is rejected.
+        let (key_bytes, value_bytes) = transaction_store.storage.size_map().raw_iter().next().unwrap();
+        assert!(backup.storage.id_map().insert_raw(&key_bytes, &value_bytes).is_err());
+        assert_eq!(checksum(&transaction_store), checksum(&backup));
+    }
+
     #[test]
     fn test_find_transaction_id() {
         let rng = &mut TestRng::default();
@@ -567,4 +2099,345 @@ mod tests {
             }
         }
     }
+    #[test]
+    fn test_get_program_by_transaction_id() {
+        let rng = &mut TestRng::default();
+
+        // Sample a deployment and an execution.
+        let deployment_transaction = ledger_test_helpers::sample_deployment_transaction(true, rng);
+        let execution_transaction = ledger_test_helpers::sample_execution_transaction_with_fee(true, rng);
+
+        // Initialize a new transition store.
+        let transition_store = TransitionStore::<_, TransitionMemory<_>>::open(None).unwrap();
+        // Initialize a new transaction store.
+        let transaction_store = TransactionStore::<_, TransactionMemory<_>>::open(transition_store).unwrap();
+
+        // Ensure the program is not found.
+        let candidate = transaction_store.get_program_by_transaction_id(&deployment_transaction.id()).unwrap();
+        assert_eq!(None, candidate);
+
+        // Insert the transactions.
+        transaction_store.insert(&deployment_transaction).unwrap();
+        transaction_store.insert(&execution_transaction).unwrap();
+
+        // Ensure the program is found by its deployment transaction ID, and matches the lookup by program ID.
+        let program = deployment_transaction.deployment().unwrap().program();
+        let candidate = transaction_store.get_program_by_transaction_id(&deployment_transaction.id()).unwrap();
+        assert_eq!(Some(program), candidate.as_ref());
+        assert_eq!(candidate, transaction_store.get_program(program.id()).unwrap());
+
+        // Ensure an execution does not deploy a program.
+        let candidate = transaction_store.get_program_by_transaction_id(&execution_transaction.id()).unwrap();
+        assert_eq!(None, candidate);
+    }
+
+    #[test]
+    fn test_find_program_from_str() {
+        let rng = &mut TestRng::default();
+
+        // Sample a deployment.
+        let transaction = ledger_test_helpers::sample_deployment_transaction(true, rng);
+        let program = transaction.deployment().unwrap().program().clone();
+        let name = program.id().to_string();
+
+        // Initialize a new transition store.
+        let transition_store = TransitionStore::<_, TransitionMemory<_>>::open(None).unwrap();
+        // Initialize a new transaction store.
+        let transaction_store = TransactionStore::<_, TransactionMemory<_>>::open(transition_store).unwrap();
+
+        // Returns `true` if the given error is a `StorageError::NotFound`.
+        let is_not_found =
+            |error: Error| matches!(error.downcast_ref::<StorageError>(), Some(StorageError::NotFound(_)));
+
+        // Ensure the program is not deployed.
+        assert!(is_not_found(transaction_store.find_deployment_id_from_str(&name).unwrap_err()));
+        assert!(is_not_found(transaction_store.get_program_from_str(&name).unwrap_err()));
+
+        // Insert the deployment.
+        transaction_store.insert(&transaction).unwrap();
+
+        // Ensure the program is found by its name, regardless of case.
+        for candidate in [name.clone(), name.to_uppercase()] {
+            assert_eq!(transaction_store.find_deployment_id_from_str(&candidate).unwrap(), transaction.id());
+            assert_eq!(transaction_store.get_program_from_str(&candidate).unwrap(), program);
+        }
+
+        // Ensure another program is not deployed.
+        assert!(is_not_found(transaction_store.find_deployment_id_from_str("missing.aleo").unwrap_err()));
+
+        // Ensure malformed names, including ones with the wrong network suffix, are distinguished.
+        let too_long = format!("{}.aleo", "a".repeat(64));
+        for name in ["", "token", "token.", ".aleo", "token.eth", "token.aleo.aleo", "0token.aleo", too_long.as_str()] {
+            for error in [
+                transaction_store.find_deployment_id_from_str(name).unwrap_err(),
+                transaction_store.get_program_from_str(name).unwrap_err(),
+            ] {
+                match error.downcast_ref::<StorageError>() {
+                    Some(StorageError::MalformedProgramName { name: candidate, .. }) => assert_eq!(candidate, name),
+                    _ => panic!("Expected a malformed name error for '{name}'"),
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_subscribe() {
+        let rng = &mut TestRng::default();
+
+        // Initialize a new transition store.
+        let transition_store = TransitionStore::<_, TransitionMemory<_>>::open(None).unwrap();
+        // Initialize a new transaction store.
+        let transaction_store = TransactionStore::<_, TransactionMemory<_>>::open(transition_store).unwrap();
+        let receiver = transaction_store.subscribe();
+
+        // Sample the transactions.
+        let deployment = ledger_test_helpers::sample_deployment_transaction(true, rng);
+        let execution = ledger_test_helpers::sample_execution_transaction_with_fee(true, rng);
+        let inserted_deployment =
+            TransactionEvent::Inserted { id: deployment.id(), transaction_type: TransactionType::Deploy };
+        let inserted_execution =
+            TransactionEvent::Inserted { id: execution.id(), transaction_type: TransactionType::Execute };
+
+        // Ensure a write outside of an atomic batch is delivered immediately.
+        transaction_store.insert(&deployment).unwrap();
+        assert_eq!(receiver.try_recv(), Ok(inserted_deployment));
+        assert!(receiver.try_recv().is_err());
+
+        // Ensure the events of an aborted batch are never delivered.
+        transaction_store.start_atomic();
+        transaction_store.insert(&execution).unwrap();
+        transaction_store.remove(&deployment.id()).unwrap();
+        transaction_store.abort_atomic();
+        assert!(receiver.try_recv().is_err());
+        assert!(transaction_store.get_transaction(&execution.id()).unwrap().is_none());
+
+        // Ensure the events of a rewound checkpoint are never delivered.
+        transaction_store.start_atomic();
+        transaction_store.insert(&execution).unwrap();
+        transaction_store.atomic_checkpoint();
+        transaction_store.remove(&deployment.id()).unwrap();
+        transaction_store.atomic_rewind();
+        assert!(receiver.try_recv().is_err());
+
+        // Ensure the events of a batch are delivered once it is finished, in the order they were written.
+        transaction_store.remove(&deployment.id()).unwrap();
+        assert!(receiver.try_recv().is_err());
+        transaction_store.finish_atomic().unwrap();
+        assert_eq!(receiver.try_recv(), Ok(inserted_execution));
+        assert_eq!(receiver.try_recv(), Ok(TransactionEvent::Removed { id: deployment.id() }));
+        assert!(receiver.try_recv().is_err());
+        assert!(transaction_store.get_transaction(&deployment.id()).unwrap().is_none());
+
+        // Ensure a removal is delivered.
+        transaction_store.remove(&execution.id()).unwrap();
+        assert_eq!(receiver.try_recv(), Ok(TransactionEvent::Removed { id: execution.id() }));
+        assert!(receiver.try_recv().is_err());
+
+        // Ensure a rejection is delivered as such, and not as an insertion.
+        transaction_store.insert_rejected(&execution, RejectionReason::FinalizeFailed).unwrap();
+        assert_eq!(receiver.try_recv(), Ok(TransactionEvent::Rejected { id: execution.id() }));
+        transaction_store.remove(&execution.id()).unwrap();
+        assert_eq!(receiver.try_recv(), Ok(TransactionEvent::Removed { id: execution.id() }));
+        assert!(receiver.try_recv().is_err());
+    }
+
+    #[test]
+    fn test_subscribe_slow_subscriber() {
+        let rng = &mut TestRng::default();
+
+        // Initialize a new transition store.
+        let transition_store = TransitionStore::<_, TransitionMemory<_>>::open(None).unwrap();
+        // Initialize a new transaction store.
+        let transaction_store = TransactionStore::<_, TransactionMemory<_>>::open(transition_store).unwrap();
+
+        // Subscribe, without receiving any events, and subscribe with a receiver that is dropped.
+        let slow = transaction_store.subscribe();
+        drop(transaction_store.subscribe());
+
+        // Ensure the writer is not blocked once the subscriber falls behind.
+        let transaction = ledger_test_helpers::sample_fee_public_transaction(rng);
+        for _ in 0..TRANSACTION_EVENT_CAPACITY {
+            transaction_store.insert(&transaction).unwrap();
+            transaction_store.remove(&transaction.id()).unwrap();
+        }
+
+        // Ensure the subscriber receives the earliest events, and the rest are dropped.
+        let events = slow.try_iter().collect::<Vec<_>>();
+        assert_eq!(events.len(), TRANSACTION_EVENT_CAPACITY);
+        let inserted = TransactionEvent::Inserted { id: transaction.id(), transaction_type: TransactionType::Fee };
+        assert_eq!(events[0], inserted);
+        assert_eq!(events[1], TransactionEvent::Removed { id: transaction.id() });
+
+        // Ensure the subscriber receives new events once it has caught up.
+        transaction_store.insert(&transaction).unwrap();
+        assert_eq!(slow.try_iter().collect::<Vec<_>>(), vec![inserted]);
+    }
+
+    #[test]
+    fn test_check_integrity() {
+        let rng = &mut TestRng::default();
+
+        // Initialize a new transition store.
+        let transition_store = TransitionStore::<_, TransitionMemory<_>>::open(None).unwrap();
+        // Initialize a new transaction store.
+        let transaction_store = TransactionStore::<_, TransactionMemory<_>>::open(transition_store).unwrap();
+
+        // Returns the violations found at the given depth, in any order.
+        let check = |depth| {
+            let report = transaction_store.check_integrity(depth).unwrap();
+            assert_eq!(report.depth(), depth);
+            report.violations().iter().cloned().collect::<HashSet<_>>()
+        };
+
+        // Insert the transactions.
+        let deployment = ledger_test_helpers::sample_deployment_transaction(true, rng);
+        let execution = ledger_test_helpers::sample_execution_transaction_with_fee(true, rng);
+        let fee = ledger_test_helpers::sample_fee_private_transaction(rng);
+        for transaction in [&deployment, &execution, &fee] {
+            transaction_store.insert(transaction).unwrap();
+        }
+        assert!(transaction_store.check_integrity(IntegrityDepth::Shallow).unwrap().is_ok());
+        assert!(transaction_store.check_integrity(IntegrityDepth::Deep).unwrap().is_ok());
+
+        // Add a deployment, an execution, and a fee to the ID map, which are missing from their stores.
+        let id_map = transaction_store.storage.id_map();
+        let (dangling_deployment, dangling_execution) = (Uniform::rand(rng), Uniform::rand(rng));
+        let dangling_fee = Uniform::rand(rng);
+        id_map.insert(dangling_deployment, TransactionType::Deploy).unwrap();
+        id_map.insert(dangling_execution, TransactionType::Execute).unwrap();
+        id_map.insert(dangling_fee, TransactionType::Fee).unwrap();
+        // Remove the deployment from the ID map.
+        id_map.remove(&deployment.id()).unwrap();
+        // Remove a transition of the execution, and the fee transition of the fee transaction.
+        let transition_id = *execution.transitions().next().unwrap().id();
+        let fee_transition_id = *fee.fee_transition().unwrap().id();
+        transaction_store.transition_store().remove(&transition_id).unwrap();
+        transaction_store.transition_store().remove(&fee_transition_id).unwrap();
+
+        // Ensure the shallow check finds the ID map violations.
+        let untracked = IntegrityViolation::UntrackedTransaction {
+            transaction_id: deployment.id(),
+            transaction_type: TransactionType::Deploy,
+        };
+        let shallow = HashSet::from([
+            IntegrityViolation::DanglingTransaction {
+                transaction_id: dangling_deployment,
+                transaction_type: TransactionType::Deploy,
+            },
+            IntegrityViolation::DanglingTransaction {
+                transaction_id: dangling_execution,
+                transaction_type: TransactionType::Execute,
+            },
+            IntegrityViolation::DanglingTransaction {
+                transaction_id: dangling_fee,
+                transaction_type: TransactionType::Fee,
+            },
+            untracked.clone(),
+        ]);
+        assert_eq!(check(IntegrityDepth::Shallow), shallow);
+
+        // Ensure the deep check also finds the missing transitions.
+        let mut deep = shallow.clone();
+        deep.insert(IntegrityViolation::MissingTransition { transaction_id: execution.id(), transition_id });
+        deep.insert(IntegrityViolation::MissingTransition {
+            transaction_id: fee.id(),
+            transition_id: fee_transition_id,
+        });
+        assert_eq!(check(IntegrityDepth::Deep), deep);
+
+        // Repair the store, which removes the dangling transactions from the ID map.
+        let report = transaction_store.check_integrity(IntegrityDepth::Deep).unwrap();
+        let removed = transaction_store.repair(&report).unwrap();
+        assert_eq!(removed.len(), 3);
+        for dangling in [dangling_deployment, dangling_execution, dangling_fee] {
+            assert!(removed.contains(&dangling));
+            assert!(!id_map.contains_key_confirmed(&dangling).unwrap());
+        }
+
+        // Ensure the other violations remain, and that repairing again removes nothing.
+        let remaining = deep.difference(&shallow).cloned().chain([untracked]).collect::<HashSet<_>>();
+        assert_eq!(check(IntegrityDepth::Deep), remaining);
+        assert!(transaction_store.repair(&report).unwrap().is_empty());
+
+        // Restore the deployment in the ID map, and ensure the shallow check passes.
+        id_map.insert(deployment.id(), TransactionType::Deploy).unwrap();
+        assert!(transaction_store.check_integrity(IntegrityDepth::Shallow).unwrap().is_ok());
+    }
+
+    #[test]
+    fn test_repair_height_index() {
+        let rng = &mut TestRng::default();
+
+        // Initialize a new transition store.
+        let transition_store = TransitionStore::<_, TransitionMemory<_>>::open(None).unwrap();
+        // Initialize a new transaction store, which retains the blocks after height 10.
+        let policy = RetentionPolicy::KeepAfterHeight(10);
+        let transaction_store =
+            TransactionStore::<_, TransactionMemory<_>>::open_with_policy(transition_store, policy).unwrap();
+
+        // Insert a fee transaction at height 5, and remove it from the fee store, leaving it dangling.
+        let fee = ledger_test_helpers::sample_fee_private_transaction(rng);
+        transaction_store.insert_at_height(&fee, 5).unwrap();
+        transaction_store.storage.fee_store().remove(&fee.id()).unwrap();
+
+        // Repair the store, and ensure the height index no longer refers to the transaction.
+        let report = transaction_store.check_integrity(IntegrityDepth::Shallow).unwrap();
+        assert_eq!(transaction_store.repair(&report).unwrap(), vec![fee.id()]);
+        assert!(!transaction_store.storage.height_map().contains_key_confirmed(&fee.id()).unwrap());
+        assert_eq!(transaction_store.storage.height_index_map().keys_confirmed().count(), 0);
+
+        // Ensure the retention policy can still be enforced.
+        assert!(transaction_store.enforce_retention(20).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_storage_errors() {
+        let rng = &mut TestRng::default();
+
+        // Initialize a new transition store.
+        let transition_store = TransitionStore::<_, TransitionMemory<_>>::open(None).unwrap();
+        // Initialize a new transaction store.
+        let transaction_store = TransactionStore::<_, TransactionMemory<_>>::open(transition_store).unwrap();
+
+        // Returns the storage error of the given result.
+        fn kind<T: Debug>(result: Result<T>) -> StorageError {
+            result.unwrap_err().downcast_ref::<StorageError>().cloned().expect("Expected a storage error")
+        }
+
+        // Insert the transactions.
+        let deployment = ledger_test_helpers::sample_deployment_transaction(true, rng);
+        let execution = ledger_test_helpers::sample_execution_transaction_with_fee(true, rng);
+        transaction_store.insert(&deployment).unwrap();
+        transaction_store.insert(&execution).unwrap();
+
+        // Ensure a deployment is not returned for an execution, and vice versa.
+        let expected = StorageError::TypeMismatch {
+            transaction_id: execution.id().to_string(),
+            expected: TransactionType::Deploy,
+            found: TransactionType::Execute,
+        };
+        assert_eq!(kind(transaction_store.get_deployment(&execution.id())), expected);
+        let expected = StorageError::TypeMismatch {
+            transaction_id: deployment.id().to_string(),
+            expected: TransactionType::Execute,
+            found: TransactionType::Deploy,
+        };
+        assert_eq!(kind(transaction_store.get_execution(&deployment.id())), expected);
+
+        // Ensure the stores reject a transaction of the wrong type.
+        let error = kind(transaction_store.storage.deployment_store().insert(&execution));
+        assert!(matches!(error, StorageError::TypeMismatch { expected: TransactionType::Deploy, .. }));
+        let error = kind(transaction_store.storage.execution_store().insert(&deployment));
+        assert!(matches!(error, StorageError::TypeMismatch { expected: TransactionType::Execute, .. }));
+
+        // Ensure an unknown transaction is not found.
+        let unknown = Uniform::rand(rng);
+        assert!(matches!(kind(transaction_store.get_deployment(&unknown)), StorageError::NotFound(_)));
+        assert!(matches!(kind(transaction_store.get_execution(&unknown)), StorageError::NotFound(_)));
+        assert!(matches!(kind(transaction_store.remove(&unknown)), StorageError::NotFound(_)));
+
+        // Ensure a fee transaction which is missing its fee is corrupted.
+        transaction_store.storage.id_map().insert(unknown, TransactionType::Fee).unwrap();
+        assert!(matches!(kind(transaction_store.get_transaction(&unknown)), StorageError::Corrupted(_)));
+    }
 }
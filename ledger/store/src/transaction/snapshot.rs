@@ -0,0 +1,282 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use crate::helpers::memory::TransactionMemory;
+
+use ledger_block::Transition;
+
+/// A read-only, point-in-time copy of the committed state of an in-memory transaction store.
+#[derive(Clone)]
+pub struct TransactionSnapshot<N: Network> {
+    /// The copy of the store.
+    store: TransactionStore<N, TransactionMemory<N>>,
+}
+
+impl<N: Network> TransactionStore<N, TransactionMemory<N>> {
+    /// Returns a read-only, point-in-time copy of the committed state of the store, which is independent of it.
+    /// The copy reflects each atomic batch either in full or not at all: the maps are snapshotted while
+    /// no batch is being committed, and a batch which is still in progress is not reflected in the copy.
+    /// The copy shares the entries of the store, so taking it neither copies the maps nor waits for a batch.
+    /// Note: The copy does not reflect any writes to the store after it is taken.
+    pub fn snapshot(&self) -> TransactionSnapshot<N> {
+        TransactionSnapshot { store: Self::from(self.storage.snapshot()).with_size_limits(self.limits) }
+    }
+}
+
+impl<N: Network> TransactionSnapshot<N> {
+    /// Returns the size limits of the store that was copied.
+    pub fn size_limits(&self) -> &SizeLimits {
+        self.store.size_limits()
+    }
+
+    /// Returns the transition for the given `transition ID`.
+    pub fn get_transition(&self, transition_id: &N::TransitionID) -> Result<Option<Transition<N>>> {
+        self.store.transition_store().get_transition(transition_id)
+    }
+
+    /// Returns `true` if the given transition ID exists.
+    pub fn contains_transition_id(&self, transition_id: &N::TransitionID) -> Result<bool> {
+        self.store.transition_store().contains_transition_id(transition_id)
+    }
+
+    /// Checks that the maps of the copy agree with one another, as in `TransactionStore::check_integrity`.
+    pub fn check_integrity(&self, depth: IntegrityDepth) -> Result<IntegrityReport<N>> {
+        self.store.check_integrity(depth)
+    }
+
+    /// Returns the transaction for the given `transaction ID`.
+    pub fn get_transaction(&self, transaction_id: &N::TransactionID) -> Result<Option<Transaction<N>>> {
+        self.store.get_transaction(transaction_id)
+    }
+
+    /// Returns the transaction for the given `transaction ID`, whether it was accepted or rejected.
+    pub fn get_any_transaction(&self, transaction_id: &N::TransactionID) -> Result<Option<Transaction<N>>> {
+        self.store.get_any_transaction(transaction_id)
+    }
+
+    /// Returns the rejection reason for the given `transaction ID`, if the transaction was rejected.
+    pub fn get_rejection_reason(&self, transaction_id: &N::TransactionID) -> Result<Option<RejectionReason>> {
+        self.store.get_rejection_reason(transaction_id)
+    }
+
+    /// Returns the retention policy.
+    pub fn retention_policy(&self) -> Result<RetentionPolicy> {
+        self.store.retention_policy()
+    }
+
+    /// Returns the size in bytes of the transaction for the given `transaction ID`.
+    pub fn get_transaction_size(&self, transaction_id: &N::TransactionID) -> Result<Option<u64>> {
+        self.store.get_transaction_size(transaction_id)
+    }
+
+    /// Returns the deployment for the given `transaction ID`.
+    pub fn get_deployment(&self, transaction_id: &N::TransactionID) -> Result<Option<Deployment<N>>> {
+        self.store.get_deployment(transaction_id)
+    }
+
+    /// Returns the execution for the given `transaction ID`.
+    pub fn get_execution(&self, transaction_id: &N::TransactionID) -> Result<Option<Execution<N>>> {
+        self.store.get_execution(transaction_id)
+    }
+
+    /// Returns the edition for the given `transaction ID`.
+    pub fn get_edition(&self, transaction_id: &N::TransactionID) -> Result<Option<u16>> {
+        self.store.get_edition(transaction_id)
+    }
+
+    /// Returns the program ID for the given `transaction ID`.
+    pub fn get_program_id(&self, transaction_id: &N::TransactionID) -> Result<Option<ProgramID<N>>> {
+        self.store.get_program_id(transaction_id)
+    }
+
+    /// Returns the program for the given `program ID`.
+    pub fn get_program(&self, program_id: &ProgramID<N>) -> Result<Option<Program<N>>> {
+        self.store.get_program(program_id)
+    }
+
+    /// Returns the program with the given `name`, such as `token.aleo`.
+    /// If the name is malformed, a `StorageError::MalformedProgramName` error is returned,
+    /// and if the program is not deployed, a `StorageError::NotFound` error is returned.
+    pub fn get_program_from_str(&self, name: &str) -> Result<Program<N>> {
+        self.store.get_program_from_str(name)
+    }
+
+    /// Returns the program deployed by the given `transaction ID`.
+    /// If the transaction is not a deployment, `None` is returned.
+    pub fn get_program_by_transaction_id(&self, transaction_id: &N::TransactionID) -> Result<Option<Program<N>>> {
+        self.store.get_program_by_transaction_id(transaction_id)
+    }
+
+    /// Returns the verifying key for the given `(program ID, function name)`.
+    /// If the verifying key does not exist, a `StorageError::MissingVerifyingKey` error is returned.
+    pub fn get_verifying_key(
+        &self,
+        program_id: &ProgramID<N>,
+        function_name: &Identifier<N>,
+    ) -> Result<VerifyingKey<N>> {
+        self.store.get_verifying_key(program_id, function_name)
+    }
+
+    /// Returns the verifying keys for the given `(program ID, function name)` pairs, in the order they are given.
+    /// A missing verifying key is returned as `None`.
+    pub fn get_verifying_keys_batch(
+        &self,
+        keys: &[(ProgramID<N>, Identifier<N>)],
+    ) -> Result<Vec<Option<VerifyingKey<N>>>> {
+        self.store.get_verifying_keys_batch(keys)
+    }
+
+    /// Returns the certificate for the given `(program ID, function name)`.
+    pub fn get_certificate(
+        &self,
+        program_id: &ProgramID<N>,
+        function_name: &Identifier<N>,
+    ) -> Result<Option<Certificate<N>>> {
+        self.store.get_certificate(program_id, function_name)
+    }
+
+    /// Re-checks the stored certificate of each function in the given program against its stored verifying key,
+    /// where `verify` returns `true` if the certificate is valid for the program function and verifying key.
+    pub fn verify_certificates<F>(&self, program_id: &ProgramID<N>, verify: F) -> Result<CertificateReport<N>>
+    where
+        F: Fn(&ProgramID<N>, &Identifier<N>, &VerifyingKey<N>, &Certificate<N>) -> bool,
+    {
+        self.store.verify_certificates(program_id, verify)
+    }
+
+    /// Re-checks the stored certificates of every deployed program, as in `verify_certificates`.
+    pub fn verify_all_certificates<F>(&self, verify: F) -> Result<Vec<CertificateReport<N>>>
+    where
+        F: Fn(&ProgramID<N>, &Identifier<N>, &VerifyingKey<N>, &Certificate<N>) -> bool,
+    {
+        self.store.verify_all_certificates(verify)
+    }
+
+    /// Returns the transaction ID that contains the given `program ID`.
+    pub fn find_transaction_id_from_program_id(&self, program_id: &ProgramID<N>) -> Result<Option<N::TransactionID>> {
+        self.store.find_transaction_id_from_program_id(program_id)
+    }
+
+    /// Returns the ID of the transaction that deployed the program with the given `name`, such as `token.aleo`.
+    /// If the name is malformed, a `StorageError::MalformedProgramName` error is returned,
+    /// and if the program is not deployed, a `StorageError::NotFound` error is returned.
+    pub fn find_deployment_id_from_str(&self, name: &str) -> Result<N::TransactionID> {
+        self.store.find_deployment_id_from_str(name)
+    }
+
+    /// Returns the transaction ID that contains the given `transition ID`.
+    pub fn find_transaction_id_from_transition_id(
+        &self,
+        transition_id: &N::TransitionID,
+    ) -> Result<Option<N::TransactionID>> {
+        self.store.find_transaction_id_from_transition_id(transition_id)
+    }
+
+    /// Returns `true` if the given transaction ID exists.
+    pub fn contains_transaction_id(&self, transaction_id: &N::TransactionID) -> Result<bool> {
+        self.store.contains_transaction_id(transaction_id)
+    }
+
+    /// Returns `true` if the given transaction ID was rejected.
+    pub fn contains_rejected(&self, transaction_id: &N::TransactionID) -> Result<bool> {
+        self.store.contains_rejected(transaction_id)
+    }
+
+    /// Returns `true` if the given program ID exists.
+    pub fn contains_program_id(&self, program_id: &ProgramID<N>) -> Result<bool> {
+        self.store.contains_program_id(program_id)
+    }
+
+    /// Returns the number of deployment and execution transactions, as `(num_deployments, num_executions)`.
+    /// Both counts are read from a single snapshot of the transaction types, so that a concurrent
+    /// insertion or removal is reflected in either both counts or neither.
+    pub fn type_counts(&self) -> Result<(u64, u64)> {
+        self.store.type_counts()
+    }
+
+    /// Returns the statistics of the store. Each map is read in a single pass over a point-in-time view of it,
+    /// so that writers are not blocked while the statistics are computed. For statistics which are consistent
+    /// across the maps while the store is being written to, compute them on a `TransactionStore::snapshot`.
+    pub fn statistics(&self) -> StoreStatistics {
+        self.store.statistics()
+    }
+
+    /// Ensures the transaction ID map agrees with the deployment and execution stores, i.e. that every
+    /// deployment and execution in the ID map is in its respective store, and that no store has an
+    /// entry which is missing from the ID map. This is intended for debug assertions and tests.
+    pub fn assert_consistent(&self) -> Result<()> {
+        self.store.assert_consistent()
+    }
+
+    /// Returns an iterator over the transaction IDs, for all transactions.
+    pub fn transaction_ids(&self) -> impl '_ + Iterator<Item = Cow<'_, N::TransactionID>> {
+        self.store.transaction_ids()
+    }
+
+    /// Returns an iterator over the `(transaction type, transaction ID)`, for all transactions.
+    /// This reads both from a single scan of the transaction ID map.
+    pub fn iter_with_type(&self) -> impl '_ + Iterator<Item = (TransactionType, Cow<'_, N::TransactionID>)> {
+        self.store.iter_with_type()
+    }
+
+    /// Returns an iterator over the `(transaction ID, size in bytes)`, for all transactions.
+    pub fn transaction_sizes(&self) -> impl '_ + Iterator<Item = (Cow<'_, N::TransactionID>, Cow<'_, u64>)> {
+        self.store.transaction_sizes()
+    }
+
+    /// Returns an iterator over the deployment transaction IDs, for all deployments.
+    pub fn deployment_transaction_ids(&self) -> impl '_ + Iterator<Item = Cow<'_, N::TransactionID>> {
+        self.store.deployment_transaction_ids()
+    }
+
+    /// Returns an iterator over the execution transaction IDs, for all executions.
+    pub fn execution_transaction_ids(&self) -> impl '_ + Iterator<Item = Cow<'_, N::TransactionID>> {
+        self.store.execution_transaction_ids()
+    }
+
+    /// Returns an iterator over the program IDs, for all deployments.
+    pub fn program_ids(&self) -> impl '_ + Iterator<Item = Cow<'_, ProgramID<N>>> {
+        self.store.program_ids()
+    }
+
+    /// Returns an iterator over the program IDs that start with the given prefix, ignoring case, for all deployments.
+    pub fn program_ids_with_prefix(&self, prefix: &str) -> impl '_ + Iterator<Item = Cow<'_, ProgramID<N>>> {
+        self.store.program_ids_with_prefix(prefix)
+    }
+
+    /// Returns an iterator over the IDs of the programs that have a deployment of the given edition.
+    pub fn program_ids_by_edition(&self, edition: u16) -> Result<impl '_ + Iterator<Item = Cow<'_, ProgramID<N>>>> {
+        self.store.program_ids_by_edition(edition)
+    }
+
+    /// Returns an iterator over the programs, for all deployments.
+    pub fn programs(&self) -> impl '_ + Iterator<Item = Cow<'_, Program<N>>> {
+        self.store.programs()
+    }
+
+    /// Returns an iterator over the `((program ID, function name, edition), verifying key)`, for all deployments.
+    pub fn verifying_keys(
+        &self,
+    ) -> impl '_ + Iterator<Item = (Cow<'_, (ProgramID<N>, Identifier<N>, u16)>, Cow<'_, VerifyingKey<N>>)> {
+        self.store.verifying_keys()
+    }
+
+    /// Returns an iterator over the `((program ID, function name, edition), certificate)`, for all deployments.
+    pub fn certificates(
+        &self,
+    ) -> impl '_ + Iterator<Item = (Cow<'_, (ProgramID<N>, Identifier<N>, u16)>, Cow<'_, Certificate<N>>)> {
+        self.store.certificates()
+    }
+}
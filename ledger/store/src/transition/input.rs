@@ -14,7 +14,8 @@
 
 use crate::{
     atomic_batch_scope,
-    helpers::{Map, MapRead},
+    helpers::{backup_map, memory::InputMemory, Map, MapRead},
+    StorageError,
 };
 use console::{
     network::prelude::*,
@@ -152,6 +153,33 @@ pub trait InputStorage<N: Network>: Clone + Send + Sync {
         self.external_record_map().finish_atomic()
     }
 
+    /// Reclaims the space left behind by removed entries, and returns an estimate of the number of
+    /// removed entries whose space was reclaimed.
+    fn compact(&self) -> Result<usize> {
+        let mut num_removed = self.id_map().compact()?;
+        num_removed += self.reverse_id_map().compact()?;
+        num_removed += self.constant_map().compact()?;
+        num_removed += self.public_map().compact()?;
+        num_removed += self.private_map().compact()?;
+        num_removed += self.record_map().compact()?;
+        num_removed += self.record_tag_map().compact()?;
+        num_removed += self.external_record_map().compact()?;
+        Ok(num_removed)
+    }
+
+    /// Copies each map of the storage into the `other` storage, which may have a different backend.
+    fn backup_to<S: InputStorage<N>>(&self, other: &S) -> Result<()> {
+        backup_map(self.id_map(), other.id_map())?;
+        backup_map(self.reverse_id_map(), other.reverse_id_map())?;
+        backup_map(self.constant_map(), other.constant_map())?;
+        backup_map(self.public_map(), other.public_map())?;
+        backup_map(self.private_map(), other.private_map())?;
+        backup_map(self.record_map(), other.record_map())?;
+        backup_map(self.record_tag_map(), other.record_tag_map())?;
+        backup_map(self.external_record_map(), other.external_record_map())?;
+        Ok(())
+    }
+
     /// Stores the given `(transition ID, input)` pair into storage.
     fn insert(&self, transition_id: N::TransitionID, inputs: &[Input<N>]) -> Result<()> {
         atomic_batch_scope!(self, {
@@ -268,8 +296,12 @@ pub trait InputStorage<N: Network>: Clone + Send + Sync {
                 (None, None, Some(private), None, None) => into_input!(Input::Private(input_id, private)),
                 (None, None, None, Some(record), None) => into_input!(Input::Record(input_id, record)),
                 (None, None, None, None, Some(_)) => Input::ExternalRecord(input_id),
-                (None, None, None, None, None) => bail!("Missing input '{input_id}' in transition '{transition_id}'"),
-                _ => bail!("Found multiple inputs for the input ID '{input_id}' in transition '{transition_id}'"),
+                (None, None, None, None, None) => bail!(StorageError::Corrupted(format!(
+                    "Missing input '{input_id}' in transition '{transition_id}'"
+                ))),
+                _ => bail!(StorageError::Corrupted(format!(
+                    "Found multiple inputs for the input ID '{input_id}' in transition '{transition_id}'"
+                ))),
             };
 
             Ok(input)
@@ -378,12 +410,30 @@ impl<N: Network, I: InputStorage<N>> InputStore<N, I> {
         self.storage.finish_atomic()
     }
 
+    /// Reclaims the space left behind by removed entries, and returns an estimate of the number of removed entries.
+    pub fn compact(&self) -> Result<usize> {
+        self.storage.compact()
+    }
+
+    /// Copies each map of the store into the `other` store, which may have a different backend.
+    pub(crate) fn backup_to<S: InputStorage<N>>(&self, other: &InputStore<N, S>) -> Result<()> {
+        self.storage.backup_to(&other.storage)
+    }
+
     /// Returns the optional development ID.
     pub fn dev(&self) -> Option<u16> {
         self.storage.dev()
     }
 }
 
+impl<N: Network> InputStore<N, InputMemory<N>> {
+    /// Returns a point-in-time copy of the committed state of the store, which is independent of it.
+    /// Note: The caller must ensure that no atomic batch is committed while the copy is taken.
+    pub(crate) fn snapshot(&self) -> Self {
+        Self::from(self.storage.snapshot())
+    }
+}
+
 impl<N: Network, I: InputStorage<N>> InputStore<N, I> {
     /// Returns the input IDs for the given `transition ID`.
     pub fn get_input_ids(&self, transition_id: &N::TransitionID) -> Result<Vec<Field<N>>> {
@@ -401,6 +451,15 @@ impl<N: Network, I: InputStorage<N>> InputStore<N, I> {
     pub fn find_transition_id(&self, input_id: &Field<N>) -> Result<Option<N::TransitionID>> {
         self.storage.find_transition_id(input_id)
     }
+
+    /// Returns the transition ID that consumes the record with the given `tag`.
+    pub fn find_transition_id_from_tag(&self, tag: &Field<N>) -> Result<Option<N::TransitionID>> {
+        // Retrieve the serial number of the record, which is the ID of the consuming input.
+        match self.record_tag.get_confirmed(tag)? {
+            Some(serial_number) => self.storage.find_transition_id(&serial_number),
+            None => Ok(None),
+        }
+    }
 }
 
 impl<N: Network, I: InputStorage<N>> InputStore<N, I> {
@@ -22,7 +22,8 @@ use crate::{
     atomic_batch_scope,
     cow_to_cloned,
     cow_to_copied,
-    helpers::{Map, MapRead},
+    helpers::{backup_map, memory::TransitionMemory, Map, MapRead, MembershipFilter},
+    StorageError,
 };
 use console::{
     network::prelude::*,
@@ -72,6 +73,10 @@ pub trait TransitionStorage<N: Network>: Clone + Send + Sync {
     fn tcm_map(&self) -> &Self::TCMMap;
     /// Returns the reverse `tcm` map.
     fn reverse_tcm_map(&self) -> &Self::ReverseTCMMap;
+    /// Returns the membership filter of the serial numbers.
+    fn serial_number_filter(&self) -> &MembershipFilter<Field<N>>;
+    /// Returns the membership filter of the record commitments.
+    fn commitment_filter(&self) -> &MembershipFilter<Field<N>>;
 
     /// Returns the optional development ID.
     fn dev(&self) -> Option<u16> {
@@ -163,11 +168,45 @@ pub trait TransitionStorage<N: Network>: Clone + Send + Sync {
         self.reverse_tcm_map().finish_atomic()
     }
 
+    /// Reclaims the space left behind by removed entries, and returns an estimate of the number of
+    /// removed entries whose space was reclaimed.
+    fn compact(&self) -> Result<usize> {
+        let mut num_removed = self.locator_map().compact()?;
+        num_removed += self.input_store().compact()?;
+        num_removed += self.output_store().compact()?;
+        num_removed += self.finalize_map().compact()?;
+        num_removed += self.tpk_map().compact()?;
+        num_removed += self.reverse_tpk_map().compact()?;
+        num_removed += self.tcm_map().compact()?;
+        num_removed += self.reverse_tcm_map().compact()?;
+        Ok(num_removed)
+    }
+
+    /// Copies each map of the storage into the `other` storage, which may have a different backend.
+    fn backup_to<S: TransitionStorage<N>>(&self, other: &S) -> Result<()> {
+        backup_map(self.locator_map(), other.locator_map())?;
+        self.input_store().backup_to(other.input_store())?;
+        self.output_store().backup_to(other.output_store())?;
+        backup_map(self.finalize_map(), other.finalize_map())?;
+        backup_map(self.tpk_map(), other.tpk_map())?;
+        backup_map(self.reverse_tpk_map(), other.reverse_tpk_map())?;
+        backup_map(self.tcm_map(), other.tcm_map())?;
+        backup_map(self.reverse_tcm_map(), other.reverse_tcm_map())?;
+        // Reset the filters, as the serial numbers and commitments were copied without being added to them.
+        other.serial_number_filter().reset();
+        other.commitment_filter().reset();
+        Ok(())
+    }
+
     /// Stores the given `transition` into storage.
     fn insert(&self, transition: &Transition<N>) -> Result<()> {
         atomic_batch_scope!(self, {
             // Retrieve the transition ID.
             let transition_id = *transition.id();
+            // Add the serial numbers and commitments to the filters, before they are stored.
+            // Note: If the atomic batch is aborted, they remain in the filters, which only adds false positives.
+            transition.serial_numbers().for_each(|serial_number| self.serial_number_filter().insert(serial_number));
+            transition.commitments().for_each(|commitment| self.commitment_filter().insert(commitment));
             // Store the program ID and function name.
             self.locator_map().insert(transition_id, (*transition.program_id(), *transition.function_name()))?;
             // Store the inputs.
@@ -219,6 +258,9 @@ pub trait TransitionStorage<N: Network>: Clone + Send + Sync {
             self.tcm_map().remove(transition_id)?;
             // Remove the reverse `tcm` entry.
             self.reverse_tcm_map().remove(&tcm)?;
+            // Record the removal in the filters.
+            self.serial_number_filter().mark_removed();
+            self.commitment_filter().mark_removed();
 
             Ok(())
         })
@@ -242,26 +284,77 @@ pub trait TransitionStorage<N: Network>: Clone + Send + Sync {
         // Retrieve `tcm`.
         let tcm = self.tcm_map().get_confirmed(transition_id)?;
 
-        match (finalize, tpk, tcm) {
-            (Some(finalize), Some(tpk), Some(tcm)) => {
+        // Construct the transition.
+        to_transition(transition_id, program_id, function_name, inputs, outputs, finalize, tpk, tcm).map(Some)
+    }
+
+    /// Returns the transitions for the given `transition IDs`, in the order they were requested.
+    /// The program IDs, function names, finalize inputs, `tpk`s, and `tcm`s are retrieved in one batched read per map.
+    fn get_many(&self, transition_ids: &[N::TransitionID]) -> Result<Vec<Option<Transition<N>>>> {
+        // Retrieve the program IDs and function names.
+        let locators = self.locator_map().get_many_confirmed(transition_ids)?;
+        // Retrieve the finalize inputs.
+        let finalizes = self.finalize_map().get_many_confirmed(transition_ids)?;
+        // Retrieve the `tpk`s.
+        let tpks = self.tpk_map().get_many_confirmed(transition_ids)?;
+        // Retrieve the `tcm`s.
+        let tcms = self.tcm_map().get_many_confirmed(transition_ids)?;
+
+        transition_ids
+            .iter()
+            .zip(locators)
+            .zip(finalizes)
+            .zip(tpks)
+            .zip(tcms)
+            .map(|((((transition_id, locator), finalize), tpk), tcm)| {
+                let (program_id, function_name) = match locator {
+                    Some(locator) => cow_to_cloned!(locator),
+                    None => return Ok(None),
+                };
+                // Retrieve the inputs.
+                let inputs = self.input_store().get_inputs(transition_id)?;
+                // Retrieve the outputs.
+                let outputs = self.output_store().get_outputs(transition_id)?;
                 // Construct the transition.
-                let transition = Transition::new(
-                    program_id,
-                    function_name,
-                    inputs,
-                    outputs,
-                    cow_to_cloned!(finalize),
-                    cow_to_cloned!(tpk),
-                    cow_to_cloned!(tcm),
-                )?;
-                // Ensure the transition ID matches.
-                match transition.id() == transition_id {
-                    true => Ok(Some(transition)),
-                    false => bail!("Mismatch in the transition ID '{transition_id}'"),
-                }
+                to_transition(transition_id, program_id, function_name, inputs, outputs, finalize, tpk, tcm).map(Some)
+            })
+            .collect()
+    }
+}
+
+/// Constructs the transition from its stored parts, ensuring the transition ID matches.
+#[allow(clippy::too_many_arguments)]
+fn to_transition<N: Network>(
+    transition_id: &N::TransitionID,
+    program_id: ProgramID<N>,
+    function_name: Identifier<N>,
+    inputs: Vec<Input<N>>,
+    outputs: Vec<Output<N>>,
+    finalize: Option<Cow<'_, Option<Vec<Value<N>>>>>,
+    tpk: Option<Cow<'_, Group<N>>>,
+    tcm: Option<Cow<'_, Field<N>>>,
+) -> Result<Transition<N>> {
+    match (finalize, tpk, tcm) {
+        (Some(finalize), Some(tpk), Some(tcm)) => {
+            // Construct the transition.
+            let transition = Transition::new(
+                program_id,
+                function_name,
+                inputs,
+                outputs,
+                cow_to_cloned!(finalize),
+                cow_to_cloned!(tpk),
+                cow_to_cloned!(tcm),
+            )?;
+            // Ensure the transition ID matches.
+            match transition.id() == transition_id {
+                true => Ok(transition),
+                false => bail!(StorageError::Corrupted(format!("Mismatch in the transition ID '{transition_id}'"))),
             }
-            _ => bail!("Transition '{transition_id}' is missing some data (possible corruption)"),
         }
+        _ => bail!(StorageError::Corrupted(format!(
+            "Transition '{transition_id}' is missing some data (possible corruption)"
+        ))),
     }
 }
 
@@ -284,6 +377,10 @@ pub struct TransitionStore<N: Network, T: TransitionStorage<N>> {
     tcm: T::TCMMap,
     /// The reverse `tcm` map.
     reverse_tcm: T::ReverseTCMMap,
+    /// The membership filter of the serial numbers.
+    serial_number_filter: MembershipFilter<Field<N>>,
+    /// The membership filter of the record commitments.
+    commitment_filter: MembershipFilter<Field<N>>,
     /// The transition storage.
     storage: T,
 }
@@ -303,6 +400,8 @@ impl<N: Network, T: TransitionStorage<N>> TransitionStore<N, T> {
             reverse_tpk: storage.reverse_tpk_map().clone(),
             tcm: storage.tcm_map().clone(),
             reverse_tcm: storage.reverse_tcm_map().clone(),
+            serial_number_filter: storage.serial_number_filter().clone(),
+            commitment_filter: storage.commitment_filter().clone(),
             storage,
         })
     }
@@ -318,11 +417,14 @@ impl<N: Network, T: TransitionStorage<N>> TransitionStore<N, T> {
             reverse_tpk: storage.reverse_tpk_map().clone(),
             tcm: storage.tcm_map().clone(),
             reverse_tcm: storage.reverse_tcm_map().clone(),
+            serial_number_filter: storage.serial_number_filter().clone(),
+            commitment_filter: storage.commitment_filter().clone(),
             storage,
         }
     }
 
     /// Stores the given `transition` into storage.
+    /// If an output record nonce is already stored for another record, a `StorageError::DuplicateNonce` error is returned.
     pub fn insert(&self, transition: &Transition<N>) -> Result<()> {
         self.storage.insert(transition)
     }
@@ -367,12 +469,30 @@ impl<N: Network, T: TransitionStorage<N>> TransitionStore<N, T> {
         self.storage.finish_atomic()
     }
 
+    /// Reclaims the space left behind by removed entries, and returns an estimate of the number of removed entries.
+    pub fn compact(&self) -> Result<usize> {
+        self.storage.compact()
+    }
+
+    /// Copies each map of the store into the `other` store, which may have a different backend.
+    pub(crate) fn backup_to<S: TransitionStorage<N>>(&self, other: &TransitionStore<N, S>) -> Result<()> {
+        self.storage.backup_to(&other.storage)
+    }
+
     /// Returns the optional development ID.
     pub fn dev(&self) -> Option<u16> {
         self.storage.dev()
     }
 }
 
+impl<N: Network> TransitionStore<N, TransitionMemory<N>> {
+    /// Returns a point-in-time copy of the committed state of the store, which is independent of it.
+    /// Note: The caller must ensure that no atomic batch is committed while the copy is taken.
+    pub(crate) fn snapshot(&self) -> Self {
+        Self::from(self.storage.snapshot())
+    }
+}
+
 impl<N: Network, T: TransitionStorage<N>> TransitionStore<N, T> {
     /// Returns the transition ID that contains the given `input ID` or `output ID`.
     pub fn find_transition_id(&self, id: &Field<N>) -> Result<N::TransitionID> {
@@ -385,7 +505,14 @@ impl<N: Network, T: TransitionStorage<N>> TransitionStore<N, T> {
             return Ok(transition_id);
         }
         // Throw an error.
-        bail!("Failed to find the transition ID for the given input or output ID '{id}'")
+        bail!(StorageError::NotFound(format!(
+            "Failed to find the transition ID for the given input or output ID '{id}'"
+        )))
+    }
+
+    /// Returns the ID of the transition that spends the record with the given `tag`, if the record is spent.
+    pub fn find_spending_transition(&self, tag: &Field<N>) -> Result<Option<N::TransitionID>> {
+        self.inputs.find_transition_id_from_tag(tag)
     }
 }
 
@@ -395,6 +522,38 @@ impl<N: Network, T: TransitionStorage<N>> TransitionStore<N, T> {
         self.storage.get(transition_id)
     }
 
+    /// Returns the transitions for the given `transition IDs`, in the order they were requested.
+    pub fn get_transitions(&self, transition_ids: &[N::TransitionID]) -> Result<Vec<Option<Transition<N>>>> {
+        self.storage.get_many(transition_ids)
+    }
+
+    /// Returns the public inputs to verify the proof for the given `transition ID`, in the order the verifier expects them.
+    /// The `callee_ids` are the transition IDs of the external calls made by the transition, in the order they were called.
+    pub fn get_transition_public_inputs(
+        &self,
+        transition_id: &N::TransitionID,
+        callee_ids: &[N::TransitionID],
+    ) -> Result<Option<Vec<N::Field>>> {
+        // Retrieve the transition.
+        let transition = match self.get_transition(transition_id)? {
+            Some(transition) => transition,
+            None => return Ok(None),
+        };
+        // Retrieve the transitions of the external calls.
+        let callees = callee_ids
+            .iter()
+            .zip(self.get_transitions(callee_ids)?)
+            .map(|(callee_id, callee)| match callee {
+                Some(callee) => Ok(callee),
+                None => bail!(StorageError::Corrupted(format!(
+                    "Failed to get the transition '{callee_id}' called by '{transition_id}'"
+                ))),
+            })
+            .collect::<Result<Vec<_>>>()?;
+        // Construct the public inputs.
+        transition.verifier_inputs(&callees.iter().collect::<Vec<_>>()).map(Some)
+    }
+
     /// Returns the program ID for the given `transition ID`.
     pub fn get_program_id(&self, transition_id: &N::TransitionID) -> Result<Option<ProgramID<N>>> {
         Ok(self.locator.get_confirmed(transition_id)?.map(|locator| match locator {
@@ -435,7 +594,9 @@ impl<N: Network, T: TransitionStorage<N>> TransitionStore<N, T> {
     pub fn get_finalize(&self, transition_id: &N::TransitionID) -> Result<Option<Vec<Value<N>>>> {
         match self.finalize.get_confirmed(transition_id)? {
             Some(finalize) => Ok(cow_to_cloned!(finalize)),
-            None => bail!("Missing transition '{transition_id}' - cannot get finalize inputs"),
+            None => bail!(StorageError::NotFound(format!(
+                "Missing transition '{transition_id}' - cannot get finalize inputs"
+            ))),
         }
     }
 
@@ -447,6 +608,11 @@ impl<N: Network, T: TransitionStorage<N>> TransitionStore<N, T> {
     pub fn get_record(&self, commitment: &Field<N>) -> Result<Option<Record<N, Ciphertext<N>>>> {
         self.outputs.get_record(commitment)
     }
+
+    /// Returns the record ciphertext for the given `commitment`, or `None` if it does not exist or was purged.
+    pub fn get_record_ciphertext(&self, commitment: &Field<N>) -> Result<Option<Record<N, Ciphertext<N>>>> {
+        self.outputs.get_record_ciphertext(commitment)
+    }
 }
 
 impl<N: Network, T: TransitionStorage<N>> TransitionStore<N, T> {
@@ -464,6 +630,10 @@ impl<N: Network, T: TransitionStorage<N>> TransitionStore<N, T> {
 
     /// Returns `true` if the given serial number exists.
     pub fn contains_serial_number(&self, serial_number: &Field<N>) -> Result<bool> {
+        // Skip the lookup if the serial number is definitely absent.
+        if !self.may_contain(&self.serial_number_filter, serial_number, || self.inputs.serial_numbers()) {
+            return Ok(false);
+        }
         self.inputs.contains_serial_number(serial_number)
     }
 
@@ -472,6 +642,13 @@ impl<N: Network, T: TransitionStorage<N>> TransitionStore<N, T> {
         self.inputs.contains_tag(tag)
     }
 
+    /// Returns `true` if the record with the given `tag` is spent.
+    /// Note: The tag is derived from the record commitment and the graph key of the record owner,
+    /// so the spent status can be checked without the serial number.
+    pub fn is_record_spent_by_tag(&self, tag: &Field<N>) -> Result<bool> {
+        self.contains_tag(tag)
+    }
+
     /* Output */
 
     /// Returns `true` if the given output ID exists.
@@ -481,6 +658,10 @@ impl<N: Network, T: TransitionStorage<N>> TransitionStore<N, T> {
 
     /// Returns `true` if the given commitment exists.
     pub fn contains_commitment(&self, commitment: &Field<N>) -> Result<bool> {
+        // Skip the lookup if the commitment is definitely absent.
+        if !self.may_contain(&self.commitment_filter, commitment, || self.outputs.commitments()) {
+            return Ok(false);
+        }
         self.outputs.contains_commitment(commitment)
     }
 
@@ -505,6 +686,20 @@ impl<N: Network, T: TransitionStorage<N>> TransitionStore<N, T> {
     pub fn contains_tcm(&self, tcm: &Field<N>) -> Result<bool> {
         self.reverse_tcm.contains_key_confirmed(tcm)
     }
+
+    /// Returns `false` if the given key is definitely absent from the filtered map.
+    /// If the filter needs to be rebuilt, it is rebuilt from the given keys first.
+    fn may_contain<'a, I: Iterator<Item = Cow<'a, Field<N>>>>(
+        &'a self,
+        filter: &MembershipFilter<Field<N>>,
+        key: &Field<N>,
+        keys: impl FnOnce() -> I,
+    ) -> bool {
+        if filter.needs_rebuild() {
+            filter.rebuild(keys, || self.storage.is_atomic_in_progress());
+        }
+        filter.may_contain(key)
+    }
 }
 
 impl<N: Network, T: TransitionStorage<N>> TransitionStore<N, T> {
@@ -633,6 +828,12 @@ impl<N: Network, T: TransitionStorage<N>> TransitionStore<N, T> {
         self.outputs.records()
     }
 
+    /// Returns an iterator over the `(commitment, record ciphertext)` pairs, for all transition outputs that are
+    /// records which have not been purged.
+    pub fn record_outputs(&self) -> impl '_ + Iterator<Item = (Cow<'_, Field<N>>, Cow<'_, Record<N, Ciphertext<N>>>)> {
+        self.outputs.record_outputs()
+    }
+
     /* Metadata */
 
     /// Returns an iterator over the transition public keys, for all transitions.
@@ -649,7 +850,10 @@ impl<N: Network, T: TransitionStorage<N>> TransitionStore<N, T> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::helpers::memory::TransitionMemory;
+    use crate::helpers::memory::{TransitionMemory, TransitionMemoryLRU};
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
 
     #[test]
     fn test_insert_get_remove() {
@@ -735,4 +939,218 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_spent_by_tag() {
+        let rng = &mut TestRng::default();
+
+        // Sample the transitions that spend records.
+        let transaction = ledger_test_helpers::sample_execution_transaction_with_fee(true, rng);
+        let transitions = transaction.transitions().filter(|transition| transition.tags().next().is_some());
+        let transitions = transitions.cloned().collect::<Vec<_>>();
+        assert!(!transitions.is_empty());
+
+        // Initialize a new transition store.
+        let transition_store = TransitionStore::<CurrentNetwork, TransitionMemory<_>>::open(None).unwrap();
+
+        for transition in transitions {
+            let transition_id = *transition.id();
+            let tags = transition.tags().copied().collect::<Vec<_>>();
+
+            // Ensure the records are unspent.
+            for tag in &tags {
+                assert!(!transition_store.is_record_spent_by_tag(tag).unwrap());
+                assert_eq!(transition_store.find_spending_transition(tag).unwrap(), None);
+            }
+
+            // Spend the records, and ensure the tags resolve to the spending transition.
+            transition_store.insert(&transition).unwrap();
+            for tag in &tags {
+                assert!(transition_store.is_record_spent_by_tag(tag).unwrap());
+                assert_eq!(transition_store.find_spending_transition(tag).unwrap(), Some(transition_id));
+            }
+
+            // Remove the transition, and ensure the records are unspent again.
+            transition_store.remove(&transition_id).unwrap();
+            for tag in &tags {
+                assert!(!transition_store.is_record_spent_by_tag(tag).unwrap());
+                assert_eq!(transition_store.find_spending_transition(tag).unwrap(), None);
+            }
+        }
+    }
+
+    #[test]
+    fn test_membership_filter() {
+        let rng = &mut TestRng::default();
+
+        // Sample the transitions.
+        let transaction_0 = ledger_test_helpers::sample_execution_transaction_with_fee(true, rng);
+        let transaction_1 = ledger_test_helpers::sample_execution_transaction_with_fee(false, rng);
+        let transitions = transaction_0.transitions().chain(transaction_1.transitions()).cloned().collect::<Vec<_>>();
+        let serial_numbers = transitions.iter().flat_map(Transition::serial_numbers).copied().collect::<Vec<_>>();
+        let commitments = transitions.iter().flat_map(Transition::commitments).copied().collect::<Vec<_>>();
+        assert!(!serial_numbers.is_empty());
+        assert!(!commitments.is_empty());
+
+        // Initialize a new transition store.
+        let transition_store = TransitionStore::<CurrentNetwork, TransitionMemory<_>>::open(None).unwrap();
+
+        // Ensure the filters are built on the first membership check.
+        assert!(!transition_store.contains_serial_number(&serial_numbers[0]).unwrap());
+        assert!(!transition_store.contains_commitment(&commitments[0]).unwrap());
+        assert!(!transition_store.serial_number_filter.needs_rebuild());
+        assert!(!transition_store.commitment_filter.needs_rebuild());
+
+        // Insert every transition, and ensure there are no false negatives.
+        for transition in transitions.iter() {
+            transition_store.insert(transition).unwrap();
+        }
+        for serial_number in serial_numbers.iter() {
+            assert!(transition_store.serial_number_filter.may_contain(serial_number));
+            assert!(transition_store.contains_serial_number(serial_number).unwrap());
+        }
+        for commitment in commitments.iter() {
+            assert!(transition_store.commitment_filter.may_contain(commitment));
+            assert!(transition_store.contains_commitment(commitment).unwrap());
+        }
+
+        // Ensure an absent serial number and commitment are reported as absent.
+        assert!(!transition_store.contains_serial_number(&Field::rand(rng)).unwrap());
+        assert!(!transition_store.contains_commitment(&Field::rand(rng)).unwrap());
+
+        // Remove every transition, which eventually rebuilds the filters.
+        for transition in transitions.iter() {
+            transition_store.remove(transition.id()).unwrap();
+        }
+        assert!(transition_store.serial_number_filter.needs_rebuild());
+        assert!(transition_store.commitment_filter.needs_rebuild());
+        for serial_number in serial_numbers.iter() {
+            assert!(!transition_store.contains_serial_number(serial_number).unwrap());
+        }
+        for commitment in commitments.iter() {
+            assert!(!transition_store.contains_commitment(commitment).unwrap());
+        }
+        assert!(!transition_store.serial_number_filter.needs_rebuild());
+        assert!(!transition_store.commitment_filter.needs_rebuild());
+
+        // Select a transition with a record output.
+        let transition = transitions.iter().find(|transition| transition.commitments().next().is_some()).unwrap();
+
+        // Insert the transition in an aborted atomic batch.
+        transition_store.start_atomic();
+        transition_store.insert(transition).unwrap();
+        transition_store.abort_atomic();
+        for commitment in transition.commitments() {
+            assert!(!transition_store.contains_commitment(commitment).unwrap());
+        }
+
+        // Initialize a new transition store, and insert the transition in an atomic batch.
+        let transition_store = TransitionStore::<CurrentNetwork, TransitionMemory<_>>::open(None).unwrap();
+        transition_store.start_atomic();
+        transition_store.insert(transition).unwrap();
+
+        // Ensure the filter is not built while the atomic batch is in progress.
+        let commitment = transition.commitments().next().unwrap();
+        assert!(!transition_store.contains_commitment(commitment).unwrap());
+        assert!(transition_store.commitment_filter.needs_rebuild());
+
+        // Ensure the filter is built once the atomic batch is finished.
+        transition_store.finish_atomic().unwrap();
+        for commitment in transition.commitments() {
+            assert!(transition_store.contains_commitment(commitment).unwrap());
+        }
+        assert!(!transition_store.commitment_filter.needs_rebuild());
+    }
+
+    #[test]
+    fn test_lru_eviction() {
+        let rng = &mut TestRng::default();
+
+        // Sample the transitions.
+        let transaction_0 = ledger_test_helpers::sample_execution_transaction_with_fee(true, rng);
+        let transaction_1 = ledger_test_helpers::sample_execution_transaction_with_fee(false, rng);
+        let transitions = transaction_0.transitions().chain(transaction_1.transitions()).cloned().collect::<Vec<_>>();
+        assert!(transitions.len() > 2);
+
+        // Initialize a new transition store, which retains at most 2 transitions.
+        let transition_store = TransitionMemoryLRU::open_with_capacity(None, 2).unwrap();
+        assert_eq!(transition_store.capacity(), 2);
+        assert!(TransitionMemoryLRU::<CurrentNetwork>::open_with_capacity(None, 0).is_err());
+
+        // Insert the first two transitions.
+        transition_store.insert(&transitions[0]).unwrap();
+        transition_store.insert(&transitions[1]).unwrap();
+        assert_eq!(transition_store.len(), 2);
+
+        // Use the first transition, so that the second is the least-recently-used.
+        assert_eq!(Some(transitions[0].clone()), transition_store.get(transitions[0].id()).unwrap());
+
+        // Insert the third transition, which evicts the second.
+        transition_store.insert(&transitions[2]).unwrap();
+        assert_eq!(transition_store.len(), 2);
+        assert_eq!(None, transition_store.get(transitions[1].id()).unwrap());
+        assert_eq!(Some(transitions[0].clone()), transition_store.get(transitions[0].id()).unwrap());
+        assert_eq!(Some(transitions[2].clone()), transition_store.get(transitions[2].id()).unwrap());
+
+        // Ensure the evicted transition is removed from the underlying maps.
+        assert!(!transition_store.locator_map().contains_key_confirmed(transitions[1].id()).unwrap());
+        assert!(!transition_store.tpk_map().contains_key_confirmed(transitions[1].id()).unwrap());
+
+        // Insert the remaining transitions, ensuring the capacity is never exceeded.
+        for transition in transitions.iter().skip(3) {
+            transition_store.insert(transition).unwrap();
+            assert_eq!(transition_store.len(), 2);
+            assert_eq!(Some(transition.clone()), transition_store.get(transition.id()).unwrap());
+        }
+
+        // Remove a retained transition.
+        let transition_id = *transitions.last().unwrap().id();
+        transition_store.remove(&transition_id).unwrap();
+        assert_eq!(transition_store.len(), 1);
+        assert_eq!(None, transition_store.get(&transition_id).unwrap());
+    }
+
+    #[test]
+    fn test_lru_atomic_batch() {
+        let rng = &mut TestRng::default();
+
+        // Sample the transitions.
+        let transaction_0 = ledger_test_helpers::sample_execution_transaction_with_fee(true, rng);
+        let transaction_1 = ledger_test_helpers::sample_execution_transaction_with_fee(false, rng);
+        let transitions = transaction_0.transitions().chain(transaction_1.transitions()).cloned().collect::<Vec<_>>();
+        assert!(transitions.len() > 2);
+
+        // Initialize a new transition store, which retains at most 2 transitions.
+        let transition_store = TransitionMemoryLRU::open_with_capacity(None, 2).unwrap();
+        transition_store.insert(&transitions[0]).unwrap();
+
+        // Insert and remove transitions in an aborted batch, and ensure the order is unchanged.
+        transition_store.start_atomic();
+        transition_store.insert(&transitions[1]).unwrap();
+        transition_store.insert(&transitions[2]).unwrap();
+        transition_store.remove(transitions[0].id()).unwrap();
+        assert_eq!(transition_store.len(), 1);
+        transition_store.abort_atomic();
+        assert_eq!(transition_store.len(), 1);
+        assert_eq!(None, transition_store.get(transitions[1].id()).unwrap());
+        assert_eq!(Some(transitions[0].clone()), transition_store.get(transitions[0].id()).unwrap());
+
+        // Ensure the first transition is still evicted once the capacity is exceeded.
+        transition_store.insert(&transitions[1]).unwrap();
+        transition_store.insert(&transitions[2]).unwrap();
+        assert_eq!(transition_store.len(), 2);
+        assert_eq!(None, transition_store.get(transitions[0].id()).unwrap());
+
+        // Ensure the updates rewound to a checkpoint are dropped, and the others are applied on finish.
+        transition_store.start_atomic();
+        transition_store.remove(transitions[1].id()).unwrap();
+        transition_store.atomic_checkpoint();
+        transition_store.insert(&transitions[0]).unwrap();
+        transition_store.atomic_rewind();
+        transition_store.finish_atomic().unwrap();
+        assert_eq!(transition_store.len(), 1);
+        assert_eq!(None, transition_store.get(transitions[0].id()).unwrap());
+        assert_eq!(None, transition_store.get(transitions[1].id()).unwrap());
+        assert_eq!(Some(transitions[2].clone()), transition_store.get(transitions[2].id()).unwrap());
+    }
 }
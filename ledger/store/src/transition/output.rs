@@ -14,7 +14,10 @@
 
 use crate::{
     atomic_batch_scope,
-    helpers::{Map, MapRead},
+    cow_to_cloned,
+    cow_to_copied,
+    helpers::{backup_map, memory::OutputMemory, Map, MapRead},
+    StorageError,
 };
 use console::{
     network::prelude::*,
@@ -24,7 +27,7 @@ use console::{
 use ledger_block::Output;
 
 use anyhow::Result;
-use std::borrow::Cow;
+use std::{borrow::Cow, collections::HashSet};
 
 /// A trait for transition output storage.
 pub trait OutputStorage<N: Network>: Clone + Send + Sync {
@@ -40,6 +43,8 @@ pub trait OutputStorage<N: Network>: Clone + Send + Sync {
     type PrivateMap: for<'a> Map<'a, Field<N>, Option<Ciphertext<N>>>;
     /// The mapping of `commitment` to `(checksum, (optional) record ciphertext)`.
     type RecordMap: for<'a> Map<'a, Field<N>, (Field<N>, Option<Record<N, Ciphertext<N>>>)>;
+    /// The mapping of `commitment` to `record ciphertext`, for the records that have not been purged.
+    type RecordCiphertextMap: for<'a> Map<'a, Field<N>, Record<N, Ciphertext<N>>>;
     /// The mapping of `record nonce` to `commitment`.
     type RecordNonceMap: for<'a> Map<'a, Group<N>, Field<N>>;
     /// The mapping of `external hash` to `()`. Note: This is **not** the record commitment.
@@ -60,6 +65,8 @@ pub trait OutputStorage<N: Network>: Clone + Send + Sync {
     fn private_map(&self) -> &Self::PrivateMap;
     /// Returns the record map.
     fn record_map(&self) -> &Self::RecordMap;
+    /// Returns the record ciphertext map.
+    fn record_ciphertext_map(&self) -> &Self::RecordCiphertextMap;
     /// Returns the record nonce map.
     fn record_nonce_map(&self) -> &Self::RecordNonceMap;
     /// Returns the external record map.
@@ -76,6 +83,7 @@ pub trait OutputStorage<N: Network>: Clone + Send + Sync {
         self.public_map().start_atomic();
         self.private_map().start_atomic();
         self.record_map().start_atomic();
+        self.record_ciphertext_map().start_atomic();
         self.record_nonce_map().start_atomic();
         self.external_record_map().start_atomic();
     }
@@ -88,6 +96,7 @@ pub trait OutputStorage<N: Network>: Clone + Send + Sync {
             || self.public_map().is_atomic_in_progress()
             || self.private_map().is_atomic_in_progress()
             || self.record_map().is_atomic_in_progress()
+            || self.record_ciphertext_map().is_atomic_in_progress()
             || self.record_nonce_map().is_atomic_in_progress()
             || self.external_record_map().is_atomic_in_progress()
     }
@@ -100,6 +109,7 @@ pub trait OutputStorage<N: Network>: Clone + Send + Sync {
         self.public_map().atomic_checkpoint();
         self.private_map().atomic_checkpoint();
         self.record_map().atomic_checkpoint();
+        self.record_ciphertext_map().atomic_checkpoint();
         self.record_nonce_map().atomic_checkpoint();
         self.external_record_map().atomic_checkpoint();
     }
@@ -112,6 +122,7 @@ pub trait OutputStorage<N: Network>: Clone + Send + Sync {
         self.public_map().clear_latest_checkpoint();
         self.private_map().clear_latest_checkpoint();
         self.record_map().clear_latest_checkpoint();
+        self.record_ciphertext_map().clear_latest_checkpoint();
         self.record_nonce_map().clear_latest_checkpoint();
         self.external_record_map().clear_latest_checkpoint();
     }
@@ -124,6 +135,7 @@ pub trait OutputStorage<N: Network>: Clone + Send + Sync {
         self.public_map().atomic_rewind();
         self.private_map().atomic_rewind();
         self.record_map().atomic_rewind();
+        self.record_ciphertext_map().atomic_rewind();
         self.record_nonce_map().atomic_rewind();
         self.external_record_map().atomic_rewind();
     }
@@ -136,6 +148,7 @@ pub trait OutputStorage<N: Network>: Clone + Send + Sync {
         self.public_map().abort_atomic();
         self.private_map().abort_atomic();
         self.record_map().abort_atomic();
+        self.record_ciphertext_map().abort_atomic();
         self.record_nonce_map().abort_atomic();
         self.external_record_map().abort_atomic();
     }
@@ -148,12 +161,77 @@ pub trait OutputStorage<N: Network>: Clone + Send + Sync {
         self.public_map().finish_atomic()?;
         self.private_map().finish_atomic()?;
         self.record_map().finish_atomic()?;
+        self.record_ciphertext_map().finish_atomic()?;
         self.record_nonce_map().finish_atomic()?;
         self.external_record_map().finish_atomic()
     }
 
+    /// Reclaims the space left behind by removed entries, and returns an estimate of the number of
+    /// removed entries whose space was reclaimed.
+    fn compact(&self) -> Result<usize> {
+        let mut num_removed = self.id_map().compact()?;
+        num_removed += self.reverse_id_map().compact()?;
+        num_removed += self.constant_map().compact()?;
+        num_removed += self.public_map().compact()?;
+        num_removed += self.private_map().compact()?;
+        num_removed += self.record_map().compact()?;
+        num_removed += self.record_ciphertext_map().compact()?;
+        num_removed += self.record_nonce_map().compact()?;
+        num_removed += self.external_record_map().compact()?;
+        Ok(num_removed)
+    }
+
+    /// Copies each map of the storage into the `other` storage, which may have a different backend.
+    fn backup_to<S: OutputStorage<N>>(&self, other: &S) -> Result<()> {
+        backup_map(self.id_map(), other.id_map())?;
+        backup_map(self.reverse_id_map(), other.reverse_id_map())?;
+        backup_map(self.constant_map(), other.constant_map())?;
+        backup_map(self.public_map(), other.public_map())?;
+        backup_map(self.private_map(), other.private_map())?;
+        backup_map(self.record_map(), other.record_map())?;
+        backup_map(self.record_ciphertext_map(), other.record_ciphertext_map())?;
+        backup_map(self.record_nonce_map(), other.record_nonce_map())?;
+        backup_map(self.external_record_map(), other.external_record_map())?;
+        Ok(())
+    }
+
+    /// Ensures the record nonces in the given outputs are unique, and do not belong to another stored record,
+    /// and otherwise returns a `StorageError::DuplicateNonce` error.
+    /// Note: `VM::check_transaction` and `Block::verify` reject the same duplicates, so a valid block passes it.
+    fn check_nonces(&self, transition_id: N::TransitionID, outputs: &[Output<N>]) -> Result<()> {
+        let mut nonces = HashSet::with_capacity(outputs.len());
+        for output in outputs {
+            if let Output::Record(commitment, _, Some(record)) = output {
+                let nonce = *record.nonce();
+                // Ensure the nonce is unique within the outputs.
+                if !nonces.insert(nonce) {
+                    bail!(StorageError::duplicate_nonce(nonce, transition_id));
+                }
+                // Ensure the nonce does not belong to another record, including one in the current atomic batch.
+                // Note: Storing the same record again, under the same commitment, is not a conflict.
+                if let Some(existing) = self.record_nonce_map().get_speculative(&nonce)? {
+                    let existing = cow_to_copied!(existing);
+                    if existing != *commitment {
+                        let transition_id = match self.reverse_id_map().get_speculative(&existing)? {
+                            Some(transition_id) => cow_to_copied!(transition_id),
+                            None => bail!(StorageError::Corrupted(format!(
+                                "Missing the transition ID for the record '{existing}'"
+                            ))),
+                        };
+                        bail!(StorageError::duplicate_nonce(nonce, transition_id));
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
     /// Stores the given `(transition ID, output)` pair into storage.
+    /// If a record nonce is not unique, a `StorageError::DuplicateNonce` error is returned and nothing is stored.
     fn insert(&self, transition_id: N::TransitionID, outputs: &[Output<N>]) -> Result<()> {
+        // Ensure the record nonces are unique, before any outputs are stored.
+        self.check_nonces(transition_id, outputs)?;
+
         atomic_batch_scope!(self, {
             // Store the output IDs.
             self.id_map().insert(transition_id, outputs.iter().map(Output::id).copied().collect())?;
@@ -171,6 +249,8 @@ pub trait OutputStorage<N: Network>: Clone + Send + Sync {
                         // If the optional record exists, insert the record nonce.
                         if let Some(record) = &optional_record {
                             self.record_nonce_map().insert(*record.nonce(), commitment)?;
+                            // Insert the record ciphertext.
+                            self.record_ciphertext_map().insert(commitment, record.clone())?;
                         }
                         // Insert the record entry.
                         self.record_map().insert(commitment, (checksum, optional_record))?
@@ -213,6 +293,7 @@ pub trait OutputStorage<N: Network>: Clone + Send + Sync {
                 self.public_map().remove(&output_id)?;
                 self.private_map().remove(&output_id)?;
                 self.record_map().remove(&output_id)?;
+                self.record_ciphertext_map().remove(&output_id)?;
                 self.external_record_map().remove(&output_id)?;
             }
 
@@ -272,8 +353,12 @@ pub trait OutputStorage<N: Network>: Clone + Send + Sync {
                 (None, None, Some(private), None, None) => into_output!(Output::Private(output_id, private)),
                 (None, None, None, Some(record), None) => into_output!(Output::Record(output_id, record)),
                 (None, None, None, None, Some(_)) => Output::ExternalRecord(output_id),
-                (None, None, None, None, None) => bail!("Missing output '{output_id}' in transition '{transition_id}'"),
-                _ => bail!("Found multiple outputs for the output ID '{output_id}' in transition '{transition_id}'"),
+                (None, None, None, None, None) => bail!(StorageError::Corrupted(format!(
+                    "Missing output '{output_id}' in transition '{transition_id}'"
+                ))),
+                _ => bail!(StorageError::Corrupted(format!(
+                    "Found multiple outputs for the output ID '{output_id}' in transition '{transition_id}'"
+                ))),
             };
 
             Ok(output)
@@ -299,6 +384,8 @@ pub struct OutputStore<N: Network, O: OutputStorage<N>> {
     private: O::PrivateMap,
     /// The map of record outputs.
     record: O::RecordMap,
+    /// The map of record ciphertexts.
+    record_ciphertext: O::RecordCiphertextMap,
     /// The map of record nonces.
     record_nonce: O::RecordNonceMap,
     /// The map of external record outputs.
@@ -318,6 +405,7 @@ impl<N: Network, O: OutputStorage<N>> OutputStore<N, O> {
             public: storage.public_map().clone(),
             private: storage.private_map().clone(),
             record: storage.record_map().clone(),
+            record_ciphertext: storage.record_ciphertext_map().clone(),
             record_nonce: storage.record_nonce_map().clone(),
             external_record: storage.external_record_map().clone(),
             storage,
@@ -331,6 +419,7 @@ impl<N: Network, O: OutputStorage<N>> OutputStore<N, O> {
             public: storage.public_map().clone(),
             private: storage.private_map().clone(),
             record: storage.record_map().clone(),
+            record_ciphertext: storage.record_ciphertext_map().clone(),
             record_nonce: storage.record_nonce_map().clone(),
             external_record: storage.external_record_map().clone(),
             storage,
@@ -382,12 +471,30 @@ impl<N: Network, O: OutputStorage<N>> OutputStore<N, O> {
         self.storage.finish_atomic()
     }
 
+    /// Reclaims the space left behind by removed entries, and returns an estimate of the number of removed entries.
+    pub fn compact(&self) -> Result<usize> {
+        self.storage.compact()
+    }
+
+    /// Copies each map of the store into the `other` store, which may have a different backend.
+    pub(crate) fn backup_to<S: OutputStorage<N>>(&self, other: &OutputStore<N, S>) -> Result<()> {
+        self.storage.backup_to(&other.storage)
+    }
+
     /// Returns the optional development ID.
     pub fn dev(&self) -> Option<u16> {
         self.storage.dev()
     }
 }
 
+impl<N: Network> OutputStore<N, OutputMemory<N>> {
+    /// Returns a point-in-time copy of the committed state of the store, which is independent of it.
+    /// Note: The caller must ensure that no atomic batch is committed while the copy is taken.
+    pub(crate) fn snapshot(&self) -> Self {
+        Self::from(self.storage.snapshot())
+    }
+}
+
 impl<N: Network, O: OutputStorage<N>> OutputStore<N, O> {
     /// Returns the output IDs for the given `transition ID`.
     pub fn get_output_ids(&self, transition_id: &N::TransitionID) -> Result<Vec<Field<N>>> {
@@ -410,10 +517,18 @@ impl<N: Network, O: OutputStorage<N>> OutputStore<N, O> {
             Ok(Some(Cow::Owned((_, Some(record))))) => Ok(Some(record)),
             Ok(Some(Cow::Borrowed((_, None)))) => Ok(None),
             Ok(Some(Cow::Owned((_, None)))) => Ok(None),
-            Ok(None) => bail!("Record '{commitment}' not found"),
+            Ok(None) => bail!(StorageError::NotFound(format!("Record '{commitment}' not found"))),
             Err(e) => Err(e),
         }
     }
+
+    /// Returns the record ciphertext for the given `commitment`, or `None` if it does not exist or was purged.
+    pub fn get_record_ciphertext(&self, commitment: &Field<N>) -> Result<Option<Record<N, Ciphertext<N>>>> {
+        match self.record_ciphertext.get_confirmed(commitment)? {
+            Some(record) => Ok(Some(cow_to_cloned!(record))),
+            None => Ok(None),
+        }
+    }
 }
 
 impl<N: Network, O: OutputStorage<N>> OutputStore<N, O> {
@@ -526,12 +641,19 @@ impl<N: Network, I: OutputStorage<N>> OutputStore<N, I> {
             _ => None,
         })
     }
+
+    /// Returns an iterator over the `(commitment, record ciphertext)` pairs, for all transition outputs that are
+    /// records which have not been purged. Unlike `records`, this reads from a map that only holds such records.
+    pub fn record_outputs(&self) -> impl '_ + Iterator<Item = (Cow<'_, Field<N>>, Cow<'_, Record<N, Ciphertext<N>>>)> {
+        self.record_ciphertext.iter_confirmed()
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::helpers::memory::OutputMemory;
+    use console::network::Testnet3;
 
     #[test]
     fn test_insert_get_remove() {
@@ -560,6 +682,97 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_duplicate_nonce() {
+        let rng = &mut TestRng::default();
+
+        // Sample a record output.
+        let (transition_id, output) = ledger_test_helpers::sample_outputs()
+            .into_iter()
+            .find(|(_, output)| matches!(output, Output::Record(_, _, Some(_))))
+            .unwrap();
+        let Output::Record(_, checksum, Some(record)) = output.clone() else { unreachable!() };
+        let nonce = *record.nonce();
+
+        // Initialize a new output store, and insert the record output.
+        let output_store = OutputStore::<_, OutputMemory<_>>::open(None).unwrap();
+        output_store.insert(transition_id, &[output.clone()]).unwrap();
+        // Ensure storing the same record output again is not a conflict.
+        output_store.insert(transition_id, &[output.clone()]).unwrap();
+
+        // Ensure a record with the same nonce, in another transition, is rejected.
+        let other_id: <Testnet3 as Network>::TransitionID = Uniform::rand(rng);
+        let duplicate = Output::Record(Uniform::rand(rng), checksum, Some(record));
+        let error = output_store.insert(other_id, &[duplicate.clone()]).unwrap_err();
+        assert_eq!(error.downcast_ref::<StorageError>(), Some(&StorageError::duplicate_nonce(nonce, transition_id)));
+        assert_eq!(output_store.find_transition_id(duplicate.id()).unwrap(), None);
+        assert_eq!(output_store.find_transition_id(output.id()).unwrap(), Some(transition_id));
+
+        // Ensure two records with the same nonce, in the same transition, are rejected.
+        let other_store = OutputStore::<_, OutputMemory<_>>::open(None).unwrap();
+        let error = other_store.insert(other_id, &[output.clone(), duplicate.clone()]).unwrap_err();
+        assert_eq!(error.downcast_ref::<StorageError>(), Some(&StorageError::duplicate_nonce(nonce, other_id)));
+        assert!(!other_store.contains_nonce(&nonce).unwrap());
+
+        // Ensure the nonce can be stored again, once the record output is removed.
+        output_store.remove(&transition_id).unwrap();
+        output_store.insert(other_id, &[duplicate.clone()]).unwrap();
+        assert_eq!(output_store.find_transition_id(duplicate.id()).unwrap(), Some(other_id));
+    }
+
+    #[test]
+    fn test_record_outputs() {
+        // Sample the transition outputs, which mix constant, public, private, and record outputs.
+        let outputs = ledger_test_helpers::sample_outputs();
+
+        // Initialize a new output store.
+        let output_store = OutputStore::<_, OutputMemory<_>>::open(None).unwrap();
+        assert_eq!(output_store.record_outputs().count(), 0);
+
+        // Insert the transition outputs.
+        for (transition_id, output) in outputs.iter() {
+            output_store.insert(*transition_id, &[output.clone()]).unwrap();
+        }
+
+        // Determine the record outputs which have a record ciphertext.
+        let expected = outputs
+            .iter()
+            .filter_map(|(_, output)| match output {
+                Output::Record(commitment, _, Some(record)) => Some((*commitment, record.clone())),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        assert!(!expected.is_empty());
+
+        // Ensure only the record outputs with a record ciphertext are returned.
+        let mut candidate = output_store
+            .record_outputs()
+            .map(|(commitment, record)| (commitment.into_owned(), record.into_owned()))
+            .collect::<Vec<_>>();
+        candidate.sort_by_key(|(commitment, _)| *commitment);
+        let mut expected_sorted = expected.clone();
+        expected_sorted.sort_by_key(|(commitment, _)| *commitment);
+        assert_eq!(expected_sorted, candidate);
+
+        // Ensure the point lookups match.
+        for (_, output) in outputs.iter() {
+            let candidate = output_store.get_record_ciphertext(output.id()).unwrap();
+            match output {
+                Output::Record(_, _, Some(record)) => assert_eq!(Some(record.clone()), candidate),
+                _ => assert_eq!(None, candidate),
+            }
+        }
+
+        // Remove the transition outputs.
+        for (transition_id, _) in outputs.iter() {
+            output_store.remove(transition_id).unwrap();
+        }
+        assert_eq!(output_store.record_outputs().count(), 0);
+        for (commitment, _) in expected.iter() {
+            assert_eq!(None, output_store.get_record_ciphertext(commitment).unwrap());
+        }
+    }
+
     #[test]
     fn test_find_transition_id() {
         // Sample the transition outputs.
@@ -0,0 +1,125 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use std::collections::BTreeMap;
+
+/// The indexed circuits of every function in a program, as computed by `Process::index_program`.
+#[derive(Clone, Debug)]
+pub struct IndexedProgram<N: Network> {
+    /// The program ID.
+    program_id: ProgramID<N>,
+    /// The indexed circuit of each function.
+    circuits: BTreeMap<Identifier<N>, Index<N>>,
+}
+
+impl<N: Network> IndexedProgram<N> {
+    /// Returns the program ID.
+    pub const fn program_id(&self) -> &ProgramID<N> {
+        &self.program_id
+    }
+
+    /// Returns the indexed circuit of each function.
+    pub const fn circuits(&self) -> &BTreeMap<Identifier<N>, Index<N>> {
+        &self.circuits
+    }
+
+    /// Returns the indexed circuit of the given function.
+    pub fn get_circuit(&self, function_name: &Identifier<N>) -> Result<&Index<N>> {
+        self.circuits.get(function_name).ok_or_else(|| anyhow!("Function '{function_name}' is not indexed"))
+    }
+}
+
+impl<N: Network> Process<N> {
+    /// Indexes every function in the given program, and returns the indexed circuits.
+    /// The program must not already be in the process, and its imports must be.
+    ///
+    /// Each function is synthesized once with sampled inputs, and then indexed, so the time is linear in the
+    /// number of functions. For each function, indexing is dominated by arithmetizing the R1CS matrices, which
+    /// takes `O(m log m)` field operations for matrices with `m` non-zero entries. Unlike `Process::deploy`,
+    /// the index is not committed to, so the universal SRS is not loaded.
+    #[inline]
+    pub fn index_program<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
+        &self,
+        program: &Program<N>,
+        rng: &mut R,
+    ) -> Result<IndexedProgram<N>> {
+        let timer = timer!("Process::index_program");
+
+        // Compute the stack.
+        let stack = Stack::new(self, program)?;
+        lap!(timer, "Compute the stack");
+
+        // Index each function.
+        let mut circuits = BTreeMap::new();
+        for function_name in program.functions().keys() {
+            circuits.insert(*function_name, stack.index_function::<A, R>(function_name, rng)?);
+            lap!(timer, "Index the '{function_name}' circuit");
+        }
+
+        finish!(timer);
+
+        Ok(IndexedProgram { program_id: *program.id(), circuits })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+    type CurrentAleo = circuit::network::AleoV0;
+
+    #[test]
+    fn test_index_program() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Initialize the process.
+        let process = Process::<CurrentNetwork>::load()?;
+
+        // Initialize a program with two functions.
+        let program = Program::from_str(
+            r"program index.aleo;
+
+function square:
+    input r0 as field.private;
+    mul r0 r0 into r1;
+    output r1 as field.private;
+
+function add:
+    input r0 as u32.public;
+    input r1 as u32.private;
+    add r0 r1 into r2;
+    output r2 as u32.private;",
+        )?;
+
+        // Index the program.
+        let indexed = process.index_program::<CurrentAleo, _>(&program, rng)?;
+        assert_eq!(indexed.program_id(), program.id());
+        assert_eq!(indexed.circuits().len(), program.functions().len());
+        assert!(indexed.get_circuit(&Identifier::from_str("missing")?).is_err());
+
+        // Ensure each indexed circuit matches the verifying key in a deployment of the program.
+        let deployment = process.deploy::<CurrentAleo, _>(&program, rng)?;
+        for (function_name, (verifying_key, _)) in deployment.verifying_keys() {
+            assert_eq!(indexed.get_circuit(function_name)?.commitment_to_index(), verifying_key.id.0);
+        }
+
+        // Ensure a program which is already in the process is not indexed.
+        assert!(process.index_program::<CurrentAleo, _>(&Program::credits()?, rng).is_err());
+        Ok(())
+    }
+}
@@ -27,6 +27,9 @@ pub use trace::*;
 mod traits;
 pub use traits::*;
 
+mod index;
+pub use index::*;
+
 mod authorize;
 mod deploy;
 mod evaluate;
@@ -73,7 +76,7 @@ use synthesizer_program::{
     RegistersStore,
     StackProgram,
 };
-use synthesizer_snark::{ProvingKey, UniversalSRS, VerifyingKey};
+use synthesizer_snark::{Certificate, Index, ProvingKey, UniversalSRS, VerifyingKey};
 
 use aleo_std::prelude::{finish, lap, timer};
 use indexmap::IndexMap;
@@ -69,48 +69,14 @@ impl<N: Network> Stack<N> {
 
         // Check Verifying Keys //
 
-        let program_id = self.program.id();
-
         // Construct the call stacks and assignments used to verify the certificates.
         let mut call_stacks = Vec::with_capacity(deployment.verifying_keys().len());
 
         // Iterate through the program functions and construct the callstacks and corresponding assignments.
         for function in deployment.program().functions().values() {
-            // Initialize a burner private key.
-            let burner_private_key = PrivateKey::new(rng)?;
-            // Compute the burner address.
-            let burner_address = Address::try_from(&burner_private_key)?;
-            // Retrieve the input types.
-            let input_types = function.input_types();
-            // Sample the inputs.
-            let inputs = input_types
-                .iter()
-                .map(|input_type| match input_type {
-                    ValueType::ExternalRecord(locator) => {
-                        // Retrieve the external stack.
-                        let stack = self.get_external_stack(locator.program_id())?;
-                        // Sample the input.
-                        stack.sample_value(&burner_address, &ValueType::Record(*locator.resource()), rng)
-                    }
-                    _ => self.sample_value(&burner_address, input_type, rng),
-                })
-                .collect::<Result<Vec<_>>>()?;
-            lap!(timer, "Sample the inputs");
-
-            // Compute the request, with a burner private key.
-            let request = Request::sign(
-                &burner_private_key,
-                *program_id,
-                *function.name(),
-                inputs.into_iter(),
-                &input_types,
-                rng,
-            )?;
+            // Sample the call stack and assignments for the function.
+            let (call_stack, assignments) = self.sample_certificate_call_stack(function, rng)?;
             lap!(timer, "Compute the request for {}", function.name());
-            // Initialize the assignments.
-            let assignments = Assignments::<N>::default();
-            // Initialize the call stack.
-            let call_stack = CallStack::CheckDeployment(vec![request], burner_private_key, assignments.clone());
             // Append the function name, callstack, and assignments.
             call_stacks.push((function.name(), call_stack, assignments));
         }
@@ -118,21 +84,7 @@ impl<N: Network> Stack<N> {
         // Verify the certificates.
         cfg_iter!(call_stacks).zip_eq(deployment.verifying_keys()).try_for_each(
             |((function_name, call_stack, assignments), (_, (verifying_key, certificate)))| {
-                // Synthesize the circuit.
-                if let Err(err) = self.execute_function::<A>(call_stack.clone()) {
-                    bail!("Failed to synthesize the circuit for '{function_name}': {err}")
-                }
-                // Check the certificate.
-                match assignments.read().last() {
-                    None => bail!("The assignment for function '{function_name}' is missing in '{program_id}'"),
-                    Some((assignment, _metrics)) => {
-                        // Ensure the certificate is valid.
-                        if !certificate.verify(&function_name.to_string(), assignment, verifying_key) {
-                            bail!("The certificate for function '{function_name}' is invalid in '{program_id}'")
-                        }
-                    }
-                };
-                Ok(())
+                self.check_certificate::<A>(function_name, call_stack.clone(), assignments, verifying_key, certificate)
             },
         )?;
 
@@ -140,4 +92,110 @@ impl<N: Network> Stack<N> {
 
         Ok(())
     }
+
+    /// Checks the certificate of the given function on the given verifying key.
+    #[inline]
+    pub fn verify_certificate<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
+        &self,
+        function_name: &Identifier<N>,
+        verifying_key: &VerifyingKey<N>,
+        certificate: &Certificate<N>,
+        rng: &mut R,
+    ) -> Result<()> {
+        // Retrieve the function.
+        let function = self.get_function(function_name)?;
+        // Sample the call stack and assignments for the function.
+        let (call_stack, assignments) = self.sample_certificate_call_stack(&function, rng)?;
+        // Check the certificate.
+        self.check_certificate::<A>(function_name, call_stack, &assignments, verifying_key, certificate)
+    }
+
+    /// Returns the call stack and assignments used to synthesize the circuit of the given function,
+    /// for checking its certificate. The inputs are sampled with a burner private key.
+    fn sample_certificate_call_stack<R: Rng + CryptoRng>(
+        &self,
+        function: &Function<N>,
+        rng: &mut R,
+    ) -> Result<(CallStack<N>, Assignments<N>)> {
+        // Initialize a burner private key.
+        let burner_private_key = PrivateKey::new(rng)?;
+        // Compute the burner address.
+        let burner_address = Address::try_from(&burner_private_key)?;
+        // Retrieve the input types.
+        let input_types = function.input_types();
+        // Sample the inputs.
+        let inputs = input_types
+            .iter()
+            .map(|input_type| match input_type {
+                ValueType::ExternalRecord(locator) => {
+                    // Retrieve the external stack.
+                    let stack = self.get_external_stack(locator.program_id())?;
+                    // Sample the input.
+                    stack.sample_value(&burner_address, &ValueType::Record(*locator.resource()), rng)
+                }
+                _ => self.sample_value(&burner_address, input_type, rng),
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        // Compute the request, with a burner private key.
+        let request = Request::sign(
+            &burner_private_key,
+            *self.program.id(),
+            *function.name(),
+            inputs.into_iter(),
+            &input_types,
+            rng,
+        )?;
+        // Initialize the assignments.
+        let assignments = Assignments::<N>::default();
+        // Initialize the call stack.
+        let call_stack = CallStack::CheckDeployment(vec![request], burner_private_key, assignments.clone());
+        Ok((call_stack, assignments))
+    }
+
+    /// Synthesizes the circuit of the given function from the call stack, and checks its certificate.
+    fn check_certificate<A: circuit::Aleo<Network = N>>(
+        &self,
+        function_name: &Identifier<N>,
+        call_stack: CallStack<N>,
+        assignments: &Assignments<N>,
+        verifying_key: &VerifyingKey<N>,
+        certificate: &Certificate<N>,
+    ) -> Result<()> {
+        let program_id = self.program.id();
+        // Synthesize the circuit.
+        if let Err(err) = self.execute_function::<A>(call_stack) {
+            bail!("Failed to synthesize the circuit for '{function_name}': {err}")
+        }
+        // Check the certificate.
+        match assignments.read().last() {
+            None => bail!("The assignment for function '{function_name}' is missing in '{program_id}'"),
+            Some((assignment, _metrics)) => {
+                // Ensure the certificate is valid.
+                if !certificate.verify(&function_name.to_string(), assignment, verifying_key) {
+                    bail!("The certificate for function '{function_name}' is invalid in '{program_id}'")
+                }
+            }
+        };
+        Ok(())
+    }
+
+    /// Synthesizes the circuit of the given function with sampled inputs, and returns its indexed circuit.
+    pub fn index_function<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
+        &self,
+        function_name: &Identifier<N>,
+        rng: &mut R,
+    ) -> Result<Index<N>> {
+        // Sample the call stack.
+        let (call_stack, assignments) = self.sample_certificate_call_stack(&self.get_function(function_name)?, rng)?;
+        // Synthesize the circuit.
+        if let Err(err) = self.execute_function::<A>(call_stack) {
+            bail!("Failed to synthesize the circuit for '{function_name}': {err}")
+        }
+        // Index the circuit.
+        match assignments.read().last() {
+            None => bail!("The assignment for function '{function_name}' is missing in '{}'", self.program.id()),
+            Some((assignment, _metrics)) => index_assignment::<N>(assignment),
+        }
+    }
 }
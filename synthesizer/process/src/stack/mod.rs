@@ -62,7 +62,7 @@ use console::{
 };
 use ledger_block::{Deployment, Transition};
 use synthesizer_program::{traits::*, CallOperator, Closure, Function, Instruction, Operand, Program};
-use synthesizer_snark::{Certificate, ProvingKey, UniversalSRS, VerifyingKey};
+use synthesizer_snark::{index_assignment, Certificate, Index, ProvingKey, UniversalSRS, VerifyingKey};
 
 use aleo_std::prelude::{finish, lap, timer};
 use indexmap::IndexMap;
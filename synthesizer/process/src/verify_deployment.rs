@@ -39,6 +39,41 @@ impl<N: Network> Process<N> {
         finish!(timer);
         verification
     }
+
+    /// Checks the certificate of the given function in the `program` on the given verifying key,
+    /// which allows a certificate to be checked before its deployment is submitted.
+    /// If the program already exists in the process, it must be the same program.
+    #[inline]
+    pub fn verify_certificate<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
+        &self,
+        program: &Program<N>,
+        function_name: &Identifier<N>,
+        verifying_key: &VerifyingKey<N>,
+        certificate: &Certificate<N>,
+        rng: &mut R,
+    ) -> Result<()> {
+        let timer = timer!("Process::verify_certificate");
+        // Retrieve the program ID.
+        let program_id = program.id();
+
+        // Check the certificate on the existing stack, or on the stack of the new program.
+        let verification = match self.contains_program(program_id) {
+            true => {
+                ensure!(self.get_program(program_id)? == program, "Program '{program_id}' already exists");
+                self.get_stack(program_id)?.verify_certificate::<A, R>(function_name, verifying_key, certificate, rng)
+            }
+            false => {
+                // Ensure the program is well-formed, by computing the stack.
+                let stack = Stack::new(self, program)?;
+                lap!(timer, "Compute the stack");
+                stack.verify_certificate::<A, R>(function_name, verifying_key, certificate, rng)
+            }
+        };
+        lap!(timer, "Verify the certificate");
+
+        finish!(timer);
+        verification
+    }
 }
 
 #[cfg(test)]
@@ -47,6 +82,58 @@ mod tests {
 
     type CurrentAleo = circuit::network::AleoV0;
 
+    #[test]
+    fn test_verify_certificate() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Initialize the process.
+        let process = Process::load()?;
+
+        // Initialize a program with two functions.
+        let program = Program::from_str(
+            r"program certificate.aleo;
+
+function square:
+    input r0 as field.private;
+    mul r0 r0 into r1;
+    output r1 as field.private;
+
+function double:
+    input r0 as field.private;
+    input r1 as field.public;
+    add r0 r0 into r2;
+    add r2 r1 into r3;
+    output r3 as field.private;",
+        )?;
+
+        // Create a deployment for the program.
+        let deployment = process.deploy::<CurrentAleo, _>(&program, rng)?;
+        let [(square, (square_key, square_certificate)), (double, (double_key, double_certificate))] =
+            deployment.verifying_keys().as_slice()
+        else {
+            bail!("Expected two verifying keys")
+        };
+
+        // Checks the certificate of the given function in the program.
+        let verify = |function_name, verifying_key, certificate, rng: &mut TestRng| {
+            process.verify_certificate::<CurrentAleo, _>(&program, function_name, verifying_key, certificate, rng)
+        };
+
+        // Ensure each certificate is valid before the program is deployed.
+        verify(square, square_key, square_certificate, rng)?;
+        verify(double, double_key, double_certificate, rng)?;
+
+        // Ensure a certificate is invalid for another function, or another verifying key.
+        assert!(verify(square, square_key, double_certificate, rng).is_err());
+        assert!(verify(square, double_key, square_certificate, rng).is_err());
+        assert!(verify(double, square_key, square_certificate, rng).is_err());
+
+        // Ensure a missing function is rejected.
+        let missing = Identifier::from_str("missing")?;
+        assert!(verify(&missing, square_key, square_certificate, rng).is_err());
+        Ok(())
+    }
+
     /// Use `cargo test profiler --features timer` to run this test.
     #[ignore]
     #[test]
@@ -151,26 +151,14 @@ impl<N: Network> Process<N> {
         call_graph: &HashMap<N::TransitionID, Vec<N::TransitionID>>,
         transition_map: &mut HashMap<N::TransitionID, &Transition<N>>,
     ) -> Result<Vec<N::Field>> {
-        // Compute the x- and y-coordinate of `tpk`.
-        let (tpk_x, tpk_y) = transition.tpk().to_xy_coordinates();
-
-        // [Inputs] Construct the verifier inputs to verify the proof.
-        let mut inputs = vec![N::Field::one(), *tpk_x, *tpk_y, **transition.tcm()];
-        // [Inputs] Extend the verifier inputs with the input IDs.
-        inputs.extend(transition.inputs().iter().flat_map(|input| input.verifier_inputs()));
-
-        // If there are function calls, append their inputs and outputs.
-        for transition_id in call_graph.get(transition.id()).unwrap() {
-            // Note that this unwrap is safe, since we are processing transitions in post-order, which implies that all callees have been added to `transition_map`.
-            let transition: &&Transition<N> = transition_map.get(transition_id).unwrap();
-            // [Inputs] Extend the verifier inputs with the input IDs of the external call.
-            inputs.extend(transition.inputs().iter().flat_map(|input| input.verifier_inputs()));
-            // [Inputs] Extend the verifier inputs with the output IDs of the external call.
-            inputs.extend(transition.output_ids().map(|id| **id));
-        }
-
-        // [Inputs] Extend the verifier inputs with the output IDs.
-        inputs.extend(transition.outputs().iter().flat_map(|output| output.verifier_inputs()));
+        // Retrieve the transitions of the external calls, in the order they were called.
+        // Note that this unwrap is safe, since we are processing transitions in post-order, which implies that all callees have been added to `transition_map`.
+        let callees = call_graph
+            .get(transition.id())
+            .unwrap()
+            .iter()
+            .map(|transition_id| *transition_map.get(transition_id).unwrap())
+            .collect::<Vec<_>>();
 
         // Ensure the transition contains finalize inputs, if the function has a finalize scope.
         if let Some((command, logic)) = function.finalize() {
@@ -188,14 +176,6 @@ impl<N: Network> Process<N> {
                     ensure!(finalize.len() == num_operands, "The number of inputs for finalize is incorrect");
                     // Ensure the number of inputs for finalize matches in the finalize logic.
                     ensure!(finalize.len() == num_inputs, "The number of inputs for finalize is incorrect");
-
-                    // Convert the finalize inputs into concatenated bits.
-                    let finalize_bits = finalize.iter().flat_map(ToBits::to_bits_le).collect::<Vec<_>>();
-                    // Compute the checksum of the finalize inputs.
-                    let checksum = N::hash_bhp1024(&finalize_bits)?;
-
-                    // [Inputs] Extend the verifier inputs with the inputs for finalize.
-                    inputs.push(*checksum);
                 }
                 None => bail!("The transition is missing inputs for 'finalize'"),
             }
@@ -208,6 +188,9 @@ impl<N: Network> Process<N> {
             }
         }
 
+        // [Inputs] Construct the verifier inputs to verify the proof.
+        let inputs = transition.verifier_inputs(&callees)?;
+
         #[cfg(debug_assertions)]
         println!("Transition public inputs ({} elements): {:#?}", inputs.len(), inputs);
         Ok(inputs)
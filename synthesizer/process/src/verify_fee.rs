@@ -113,23 +113,16 @@ impl<N: Network> Process<N> {
         }
         lap!(timer, "Verify the outputs");
 
-        // Compute the x- and y-coordinate of `tpk`.
-        let (tpk_x, tpk_y) = fee.tpk().to_xy_coordinates();
+        // Ensure the fee transition does not contain a finalize scope.
+        ensure!(fee.finalize().is_none(), "The fee transition should not contain finalize inputs");
 
         // Construct the public inputs to verify the proof.
-        let mut inputs = vec![N::Field::one(), *tpk_x, *tpk_y, **fee.tcm()];
-        // Extend the inputs with the input IDs.
-        inputs.extend(fee.inputs().iter().flat_map(|input| input.verifier_inputs()));
-        // Extend the inputs with the output IDs.
-        inputs.extend(fee.outputs().iter().flat_map(|output| output.verifier_inputs()));
+        let inputs = fee.verifier_inputs(&[])?;
         lap!(timer, "Construct the verifier inputs");
 
         #[cfg(debug_assertions)]
         println!("Fee public inputs ({} elements): {:#?}", inputs.len(), inputs);
 
-        // Ensure the fee transition does not contain a finalize scope.
-        ensure!(fee.finalize().is_none(), "The fee transition should not contain finalize inputs");
-
         // Retrieve the verifying key.
         let verifying_key = self.get_verifying_key(fee.program_id(), fee.function_name())?;
 
@@ -172,32 +165,17 @@ impl<N: Network> Process<N> {
         );
         lap!(timer, "Verify there are no outputs");
 
-        // Compute the x- and y-coordinate of `tpk`.
-        let (tpk_x, tpk_y) = fee.tpk().to_xy_coordinates();
-
-        // Construct the public inputs to verify the proof.
-        let mut inputs = vec![N::Field::one(), *tpk_x, *tpk_y, **fee.tcm()];
-        // Extend the inputs with the input IDs.
-        inputs.extend(fee.inputs().iter().flat_map(|input| input.verifier_inputs()));
-        lap!(timer, "Construct the verifier inputs");
-
         // Ensure the fee transition contains finalize inputs.
         match fee.finalize() {
-            Some(finalize) => {
-                // Ensure the number of inputs for finalize matches in the finalize logic.
-                ensure!(finalize.len() == 2, "The number of inputs for finalize is incorrect");
-
-                // Convert the finalize inputs into concatenated bits.
-                let finalize_bits = finalize.iter().flat_map(ToBits::to_bits_le).collect::<Vec<_>>();
-                // Compute the checksum of the finalize inputs.
-                let checksum = N::hash_bhp1024(&finalize_bits)?;
-
-                // [Inputs] Extend the verifier inputs with the inputs for finalize.
-                inputs.push(*checksum);
-            }
+            // Ensure the number of inputs for finalize matches in the finalize logic.
+            Some(finalize) => ensure!(finalize.len() == 2, "The number of inputs for finalize is incorrect"),
             None => bail!("The fee transition is missing inputs for 'finalize'"),
         }
 
+        // Construct the public inputs to verify the proof, including the checksum of the inputs for finalize.
+        let inputs = fee.verifier_inputs(&[])?;
+        lap!(timer, "Construct the verifier inputs");
+
         #[cfg(debug_assertions)]
         println!("Fee public inputs ({} elements): {:#?}", inputs.len(), inputs);
 
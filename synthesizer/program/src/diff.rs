@@ -0,0 +1,317 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use console::network::prelude::SerializeStruct;
+
+/// A declaration which is present in both programs, but whose text differs.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ModifiedDeclaration<N: Network> {
+    /// The name of the declaration.
+    name: Identifier<N>,
+    /// The declaration in the old program.
+    old: String,
+    /// The declaration in the new program.
+    new: String,
+}
+
+impl<N: Network> ModifiedDeclaration<N> {
+    /// Returns the name of the declaration.
+    pub const fn name(&self) -> &Identifier<N> {
+        &self.name
+    }
+
+    /// Returns the declaration in the old program.
+    pub fn old_text(&self) -> &str {
+        &self.old
+    }
+
+    /// Returns the declaration in the new program.
+    pub fn new_text(&self) -> &str {
+        &self.new
+    }
+}
+
+/// The changes to one kind of declaration (e.g. functions) between two programs.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DeclarationDiff<N: Network> {
+    /// The names of the declarations only present in the new program.
+    added: Vec<Identifier<N>>,
+    /// The names of the declarations only present in the old program.
+    removed: Vec<Identifier<N>>,
+    /// The declarations present in both programs, whose text differs.
+    modified: Vec<ModifiedDeclaration<N>>,
+}
+
+impl<N: Network> DeclarationDiff<N> {
+    /// Compares the declarations by name, ignoring the order in which they are declared.
+    fn compare<T: Display>(old: &IndexMap<Identifier<N>, T>, new: &IndexMap<Identifier<N>, T>) -> Self {
+        let added = new.keys().filter(|name| !old.contains_key(*name)).copied().collect();
+        let removed = old.keys().filter(|name| !new.contains_key(*name)).copied().collect();
+        let modified = old
+            .iter()
+            .filter_map(|(name, old)| {
+                let (old, new) = (old.to_string(), new.get(name)?.to_string());
+                (old != new).then_some(ModifiedDeclaration { name: *name, old, new })
+            })
+            .collect();
+        Self { added, removed, modified }
+    }
+
+    /// Returns the names of the declarations only present in the new program.
+    pub fn added(&self) -> &[Identifier<N>] {
+        &self.added
+    }
+
+    /// Returns the names of the declarations only present in the old program.
+    pub fn removed(&self) -> &[Identifier<N>] {
+        &self.removed
+    }
+
+    /// Returns the declarations present in both programs, whose text differs.
+    pub fn modified(&self) -> &[ModifiedDeclaration<N>] {
+        &self.modified
+    }
+
+    /// Returns `true` if there are no changes.
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.modified.is_empty()
+    }
+}
+
+/// The changes between two programs, as computed by `Program::diff`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ProgramDiff<N: Network> {
+    /// The changes to the mappings.
+    mappings: DeclarationDiff<N>,
+    /// The changes to the structs.
+    structs: DeclarationDiff<N>,
+    /// The changes to the records.
+    records: DeclarationDiff<N>,
+    /// The changes to the closures.
+    closures: DeclarationDiff<N>,
+    /// The changes to the functions.
+    functions: DeclarationDiff<N>,
+}
+
+impl<N: Network> ProgramDiff<N> {
+    /// Returns the changes to the mappings.
+    pub const fn mappings(&self) -> &DeclarationDiff<N> {
+        &self.mappings
+    }
+
+    /// Returns the changes to the structs.
+    pub const fn structs(&self) -> &DeclarationDiff<N> {
+        &self.structs
+    }
+
+    /// Returns the changes to the records.
+    pub const fn records(&self) -> &DeclarationDiff<N> {
+        &self.records
+    }
+
+    /// Returns the changes to the closures.
+    pub const fn closures(&self) -> &DeclarationDiff<N> {
+        &self.closures
+    }
+
+    /// Returns the changes to the functions.
+    pub const fn functions(&self) -> &DeclarationDiff<N> {
+        &self.functions
+    }
+
+    /// Returns `true` if the programs declare the same items.
+    pub fn is_empty(&self) -> bool {
+        self.mappings.is_empty()
+            && self.structs.is_empty()
+            && self.records.is_empty()
+            && self.closures.is_empty()
+            && self.functions.is_empty()
+    }
+}
+
+impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> ProgramCore<N, Instruction, Command> {
+    /// Returns the mappings, structs, records, closures, and functions that were added, removed,
+    /// or modified in `other` with respect to `self`. Declarations are matched by name, so the
+    /// order in which they are declared does not affect the diff.
+    pub fn diff(&self, other: &Self) -> ProgramDiff<N> {
+        ProgramDiff {
+            mappings: DeclarationDiff::compare(&self.mappings, &other.mappings),
+            structs: DeclarationDiff::compare(&self.structs, &other.structs),
+            records: DeclarationDiff::compare(&self.records, &other.records),
+            closures: DeclarationDiff::compare(&self.closures, &other.closures),
+            functions: DeclarationDiff::compare(&self.functions, &other.functions),
+        }
+    }
+}
+
+impl<N: Network> Serialize for ModifiedDeclaration<N> {
+    /// Serializes the modified declaration into a struct.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut declaration = serializer.serialize_struct("ModifiedDeclaration", 3)?;
+        declaration.serialize_field("name", &self.name)?;
+        declaration.serialize_field("old", &self.old)?;
+        declaration.serialize_field("new", &self.new)?;
+        declaration.end()
+    }
+}
+
+impl<N: Network> Serialize for DeclarationDiff<N> {
+    /// Serializes the declaration diff into a struct.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut diff = serializer.serialize_struct("DeclarationDiff", 3)?;
+        diff.serialize_field("added", &self.added)?;
+        diff.serialize_field("removed", &self.removed)?;
+        diff.serialize_field("modified", &self.modified)?;
+        diff.end()
+    }
+}
+
+impl<N: Network> Serialize for ProgramDiff<N> {
+    /// Serializes the program diff into a struct.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut diff = serializer.serialize_struct("ProgramDiff", 5)?;
+        diff.serialize_field("mappings", &self.mappings)?;
+        diff.serialize_field("structs", &self.structs)?;
+        diff.serialize_field("records", &self.records)?;
+        diff.serialize_field("closures", &self.closures)?;
+        diff.serialize_field("functions", &self.functions)?;
+        diff.end()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Program;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_diff() -> Result<()> {
+        let old = Program::<CurrentNetwork>::from_str(
+            r"program diff.aleo;
+
+struct message:
+    first as field;
+    second as field;
+
+function compute:
+    input r0 as message.private;
+    add r0.first r0.second into r1;
+    output r1 as field.private;
+
+function square:
+    input r0 as field.private;
+    mul r0 r0 into r1;
+    output r1 as field.private;",
+        )?;
+        let new = Program::<CurrentNetwork>::from_str(
+            r"program diff.aleo;
+
+mapping balances:
+    key owner as address.public;
+    value amount as u64.public;
+
+struct message:
+    first as field;
+    second as field;
+
+function compute:
+    input r0 as message.private;
+    sub r0.first r0.second into r1;
+    output r1 as field.private;
+
+function squared:
+    input r0 as field.private;
+    mul r0 r0 into r1;
+    output r1 as field.private;",
+        )?;
+
+        let diff = old.diff(&new);
+        assert!(!diff.is_empty());
+
+        // Check the added mapping.
+        assert_eq!(diff.mappings().added(), &[Identifier::from_str("balances")?]);
+        assert!(diff.mappings().removed().is_empty());
+        assert!(diff.mappings().modified().is_empty());
+
+        // Check the unchanged declarations.
+        assert!(diff.structs().is_empty());
+        assert!(diff.records().is_empty());
+        assert!(diff.closures().is_empty());
+
+        // Check the renamed function is reported as removed and added.
+        assert_eq!(diff.functions().added(), &[Identifier::from_str("squared")?]);
+        assert_eq!(diff.functions().removed(), &[Identifier::from_str("square")?]);
+
+        // Check the changed function carries both versions.
+        let modified = diff.functions().modified();
+        assert_eq!(modified.len(), 1);
+        assert_eq!(modified[0].name(), &Identifier::from_str("compute")?);
+        assert_eq!(modified[0].old_text(), old.get_function(&Identifier::from_str("compute")?)?.to_string());
+        assert_eq!(modified[0].new_text(), new.get_function(&Identifier::from_str("compute")?)?.to_string());
+        assert!(modified[0].old_text().contains("add r0.first r0.second into r1;"));
+        assert!(modified[0].new_text().contains("sub r0.first r0.second into r1;"));
+
+        // Check the diff in the opposite direction.
+        let reverse = new.diff(&old);
+        assert_eq!(reverse.mappings().removed(), diff.mappings().added());
+        assert_eq!(reverse.functions().added(), diff.functions().removed());
+        assert_eq!(reverse.functions().modified()[0].old_text(), modified[0].new_text());
+
+        // Check the diff serializes to JSON.
+        let json = serde_json::to_value(&diff)?;
+        assert_eq!(json["mappings"]["added"], serde_json::json!(["balances"]));
+        assert_eq!(json["functions"]["removed"], serde_json::json!(["square"]));
+        assert_eq!(json["functions"]["modified"][0]["name"], serde_json::json!("compute"));
+        assert_eq!(json["functions"]["modified"][0]["new"], serde_json::json!(modified[0].new_text()));
+        Ok(())
+    }
+
+    #[test]
+    fn test_diff_is_order_insensitive() -> Result<()> {
+        let program = Program::<CurrentNetwork>::from_str(
+            r"program order.aleo;
+
+function foo:
+    input r0 as field.private;
+    output r0 as field.private;
+
+function bar:
+    input r0 as field.private;
+    add r0 r0 into r1;
+    output r1 as field.private;",
+        )?;
+        let reordered = Program::<CurrentNetwork>::from_str(
+            r"program order.aleo;
+
+function bar:
+    input r0 as field.private;
+    add r0 r0 into r1;
+    output r1 as field.private;
+
+function foo:
+    input r0 as field.private;
+    output r0 as field.private;",
+        )?;
+
+        assert!(program.diff(&program).is_empty());
+        assert!(program.diff(&reordered).is_empty());
+        assert!(reordered.diff(&program).is_empty());
+        Ok(())
+    }
+}
@@ -24,6 +24,9 @@ pub type Closure<N> = crate::ClosureCore<N, Instruction<N>>;
 mod closure;
 pub use closure::*;
 
+mod diff;
+pub use diff::*;
+
 pub mod finalize;
 pub use finalize::*;
 
@@ -280,6 +283,68 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Pro
     }
 }
 
+impl<N: Network> Program<N> {
+    /// Returns the functions in the program, ordered so that each function appears after every
+    /// function in this program that it calls.
+    ///
+    /// # Errors
+    /// This method will halt if the functions in the program call each other in a cycle.
+    pub fn functions_in_call_order(&self) -> Result<Vec<&Function<N>>> {
+        // Track whether each visited function has been ordered (`true`) or is still being visited (`false`).
+        let mut visited = IndexMap::with_capacity(self.functions.len());
+        // Initialize the ordered list of functions.
+        let mut ordered = Vec::with_capacity(self.functions.len());
+        // Visit the functions in the order they are declared.
+        for name in self.functions.keys() {
+            self.visit_in_call_order(name, &mut visited, &mut ordered)?;
+        }
+        Ok(ordered)
+    }
+
+    /// Adds the given function to `ordered`, after the functions in this program that it calls.
+    fn visit_in_call_order<'a>(
+        &'a self,
+        name: &Identifier<N>,
+        visited: &mut IndexMap<Identifier<N>, bool>,
+        ordered: &mut Vec<&'a Function<N>>,
+    ) -> Result<()> {
+        match visited.get(name) {
+            // If the function was already ordered, return early.
+            Some(true) => return Ok(()),
+            // If the function is still being visited, the calls form a cycle.
+            Some(false) => {
+                let cycle = visited.iter().filter(|(_, is_ordered)| !**is_ordered).map(|(name, _)| name.to_string());
+                bail!("Found a cycle in the function calls: {} -> {name}", cycle.collect::<Vec<_>>().join(" -> "))
+            }
+            None => (),
+        }
+
+        // Retrieve the function.
+        let function = self.functions.get(name).ok_or_else(|| anyhow!("Function '{name}' is not defined."))?;
+
+        // Mark the function as being visited.
+        visited.insert(*name, false);
+        // Visit the functions in this program that are called by the function.
+        for instruction in function.instructions() {
+            if let Instruction::Call(call) = instruction {
+                let callee = match call.operator() {
+                    CallOperator::Resource(resource) => resource,
+                    CallOperator::Locator(locator) if locator.program_id() == &self.id => locator.resource(),
+                    CallOperator::Locator(_) => continue,
+                };
+                // Closures are not functions, and may not call functions.
+                if self.functions.contains_key(callee) {
+                    self.visit_in_call_order(callee, visited, ordered)?;
+                }
+            }
+        }
+        // Mark the function as ordered.
+        visited.insert(*name, true);
+        ordered.push(function);
+        Ok(())
+    }
+}
+
 impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> ProgramCore<N, Instruction, Command> {
     /// Adds a new import statement to the program.
     ///
@@ -728,6 +793,55 @@ function compute:
         Ok(())
     }
 
+    #[test]
+    fn test_functions_in_call_order() -> Result<()> {
+        // Initialize a program, in which `first` calls `second`, and `second` calls `third`.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program chain.aleo;
+
+function first:
+    input r0 as field.public;
+    call second r0 into r1;
+    output r1 as field.private;
+
+function second:
+    input r0 as field.public;
+    call chain.aleo/third r0 into r1;
+    output r1 as field.private;
+
+function third:
+    input r0 as field.public;
+    add r0 r0 into r1;
+    output r1 as field.private;",
+        )?;
+
+        // Ensure each function is ordered after the functions it calls.
+        let names = program.functions_in_call_order()?.into_iter().map(|function| function.name().to_string());
+        assert_eq!(names.collect::<Vec<_>>(), ["third", "second", "first"]);
+
+        // Initialize a program, in which `first` and `second` call each other.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program cycle.aleo;
+
+function first:
+    input r0 as field.public;
+    call second r0 into r1;
+    output r1 as field.private;
+
+function second:
+    input r0 as field.public;
+    call first r0 into r1;
+    output r1 as field.private;",
+        )?;
+
+        // Ensure the cycle is detected.
+        assert!(program.functions_in_call_order().is_err());
+
+        Ok(())
+    }
+
     #[test]
     fn test_program_import() -> Result<()> {
         // Initialize a new program.
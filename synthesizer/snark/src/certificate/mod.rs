@@ -84,6 +84,12 @@ impl<N: Network> Certificate<N> {
             }
         }
     }
+
+    /// Returns the number of bytes in the byte representation of the certificate, without serializing it.
+    pub fn size_in_bytes(&self) -> usize {
+        // Note: The certificate is preceded by its version.
+        1 + self.certificate.compressed_size()
+    }
 }
 
 impl<N: Network> Deref for Certificate<N> {
@@ -19,6 +19,7 @@
 
 use console::network::{prelude::*, FiatShamir};
 use snarkvm_algorithms::{snark::varuna, traits::SNARK};
+use snarkvm_utilities::CanonicalSerialize;
 
 use once_cell::sync::OnceCell;
 use std::sync::Arc;
@@ -43,6 +44,15 @@ pub use universal_srs::UniversalSRS;
 mod verifying_key;
 pub use verifying_key::VerifyingKey;
 
+/// The indexed circuit of a function.
+pub type Index<N> = varuna::ahp::indexer::Index<<N as Environment>::Field, varuna::VarunaHidingMode>;
+
+/// Returns the indexed circuit for the given assignment.
+/// Unlike `UniversalSRS::to_circuit_key`, this does not commit to the index, so the universal SRS is not loaded.
+pub fn index_assignment<N: Network>(assignment: &circuit::Assignment<N::Field>) -> Result<Index<N>> {
+    varuna::AHPForR1CS::<N::Field, varuna::VarunaHidingMode>::index(assignment)
+}
+
 #[cfg(test)]
 pub(crate) mod test_helpers {
     use super::*;
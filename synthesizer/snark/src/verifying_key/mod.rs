@@ -87,6 +87,12 @@ impl<N: Network> VerifyingKey<N> {
             }
         }
     }
+
+    /// Returns the number of bytes in the byte representation of the verifying key, without serializing it.
+    pub fn size_in_bytes(&self) -> usize {
+        // Note: The verifying key is preceded by its version.
+        1 + self.verifying_key.compressed_size()
+    }
 }
 
 impl<N: Network> Deref for VerifyingKey<N> {
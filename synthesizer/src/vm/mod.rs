@@ -34,6 +34,7 @@ use ledger_query::Query;
 use ledger_store::{
     atomic_finalize,
     BlockStore,
+    CertificateReport,
     ConsensusStorage,
     ConsensusStore,
     FinalizeMode,
@@ -44,6 +45,7 @@ use ledger_store::{
 };
 use synthesizer_process::{Authorization, Process, Trace};
 use synthesizer_program::{FinalizeGlobalState, FinalizeStoreTrait, Program};
+use synthesizer_snark::{Certificate, VerifyingKey};
 
 use aleo_std::prelude::{finish, lap, timer};
 use indexmap::IndexMap;
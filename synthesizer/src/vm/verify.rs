@@ -14,6 +14,9 @@
 
 use super::*;
 
+#[cfg(not(feature = "serial"))]
+use rayon::prelude::*;
+
 /// Ensures the given iterator has no duplicate elements, and that the ledger
 /// does not already contain a given item.
 macro_rules! ensure_is_unique {
@@ -52,6 +55,25 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         self.check_fee(fee, deployment_or_execution_id).map_err(|error| warn!("{error}")).is_ok()
     }
 
+    /// Re-checks the stored certificates of the given program, reporting whether each function is valid.
+    /// Note: The program must be loaded in the process.
+    pub fn verify_certificates(&self, program_id: &ProgramID<N>) -> Result<CertificateReport<N>> {
+        self.transaction_store().verify_certificates(program_id, |_, function_name, verifying_key, certificate| {
+            self.check_certificate(program_id, function_name, verifying_key, certificate)
+                .map_err(|error| warn!("{error}"))
+                .is_ok()
+        })
+    }
+
+    /// Re-checks the stored certificates of every deployed program, as in `verify_certificates`.
+    pub fn verify_all_certificates(&self) -> Result<Vec<CertificateReport<N>>> {
+        self.transaction_store().verify_all_certificates(|program_id, function_name, verifying_key, certificate| {
+            self.check_certificate(program_id, function_name, verifying_key, certificate)
+                .map_err(|error| warn!("{error}"))
+                .is_ok()
+        })
+    }
+
     /// Verifies the transaction in the VM. On failure, returns an error.
     #[inline]
     pub fn check_transaction(&self, transaction: &Transaction<N>, rejected_id: Option<Field<N>>) -> Result<()> {
@@ -96,6 +118,7 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         // Ensure the commitments are unique.
         ensure_is_unique!("commitment", self, contains_commitment, transaction.commitments());
         // Ensure the nonces are unique.
+        // Note: The transition store rejects the same duplicates when the block is stored.
         ensure_is_unique!("nonce", self, contains_nonce, transaction.nonces());
 
         /* Metadata */
@@ -161,6 +184,21 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         Ok(())
     }
 
+    /// Verifies the given transactions in the VM, returning one result per transaction (in order).
+    ///
+    /// Unlike calling `check_transaction` in a loop, the transactions are verified concurrently,
+    /// and a failing transaction does not prevent the remaining transactions from being checked.
+    /// Note: Each execution carries its own Varuna proof, so proofs are still verified one at a time.
+    pub fn check_transactions(&self, transactions: &[(&Transaction<N>, Option<Field<N>>)]) -> Vec<Result<()>> {
+        let timer = timer!("VM::check_transactions");
+        // Verify each transaction independently.
+        let results = cfg_iter!(transactions)
+            .map(|(transaction, rejected_id)| self.check_transaction(transaction, *rejected_id))
+            .collect();
+        finish!(timer);
+        results
+    }
+
     /// Verifies the given deployment. On failure, returns an error.
     #[inline]
     fn check_deployment(&self, deployment: &Deployment<N>) -> Result<()> {
@@ -180,6 +218,39 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         result
     }
 
+    /// Verifies the given certificate for a function of a loaded program. On failure, returns an error.
+    #[inline]
+    fn check_certificate(
+        &self,
+        program_id: &ProgramID<N>,
+        function_name: &Identifier<N>,
+        verifying_key: &VerifyingKey<N>,
+        certificate: &Certificate<N>,
+    ) -> Result<()> {
+        macro_rules! logic {
+            ($process:expr, $network:path, $aleo:path) => {{
+                // Prepare the inputs.
+                let program_id = cast_ref!(&program_id as ProgramID<$network>);
+                let function_name = cast_ref!(&function_name as Identifier<$network>);
+                let verifying_key = cast_ref!(&verifying_key as VerifyingKey<$network>);
+                let certificate = cast_ref!(&certificate as Certificate<$network>);
+                // Verify the certificate.
+                $process.get_stack(program_id)?.verify_certificate::<$aleo, _>(
+                    function_name,
+                    verifying_key,
+                    certificate,
+                    &mut rand::thread_rng(),
+                )
+            }};
+        }
+
+        // Process the logic.
+        let timer = timer!("VM::check_certificate");
+        let result = process!(self, logic).map_err(|error| anyhow!("Certificate verification failed - {error}"));
+        finish!(timer);
+        result
+    }
+
     /// Verifies the given execution. On failure, returns an error.
     #[inline]
     fn check_execution(&self, execution: &Execution<N>) -> Result<()> {
@@ -261,7 +332,8 @@ mod tests {
         account::{Address, ViewKey},
         types::Field,
     };
-    use ledger_block::{Block, Header, Metadata, Transaction};
+    use ledger_block::{Block, Header, Metadata, Output, Transaction, Transition};
+    use ledger_store::StorageError;
 
     type CurrentNetwork = test_helpers::CurrentNetwork;
 
@@ -403,6 +475,112 @@ mod tests {
         assert!(vm.verify_transaction(&valid_transaction, None));
     }
 
+    #[test]
+    fn test_check_transaction_duplicate_nonce() {
+        let rng = &mut TestRng::default();
+        let vm = crate::vm::test_helpers::sample_vm_with_genesis_block(rng);
+
+        // Fetch a valid execution transaction, and a transition of it with an output record.
+        let transaction = crate::vm::test_helpers::sample_execution_transaction_with_private_fee(rng);
+        assert!(vm.check_transaction(&transaction, None).is_ok());
+        let transition = transaction.transitions().find(|transition| transition.records().next().is_some()).unwrap();
+        let (_, record) = transition.records().next().unwrap();
+
+        // Store another transition, with a different commitment for a record with the same nonce.
+        let output = Output::Record(Uniform::rand(rng), Uniform::rand(rng), Some(record.clone()));
+        let (program_id, function_name) = (*transition.program_id(), *transition.function_name());
+        let (tpk, tcm) = (Uniform::rand(rng), Uniform::rand(rng));
+        let other = Transition::new(program_id, function_name, vec![], vec![output], None, tpk, tcm).unwrap();
+        vm.transition_store().insert(&other).unwrap();
+
+        // Ensure the VM rejects the transaction, as the transition store would.
+        let error = vm.check_transaction(&transaction, None).unwrap_err();
+        assert!(error.to_string().contains(&format!("The nonce '{}' already exists", record.nonce())));
+        let error = vm.transition_store().insert(transition).unwrap_err();
+        let expected =
+            StorageError::DuplicateNonce { nonce: record.nonce().to_string(), transition_id: other.id().to_string() };
+        assert_eq!(error.downcast_ref::<StorageError>(), Some(&expected));
+    }
+
+    #[test]
+    fn test_check_transactions() {
+        let rng = &mut TestRng::default();
+        let vm = crate::vm::test_helpers::sample_vm_with_genesis_block(rng);
+
+        // Fetch the valid transactions.
+        let deployment_transaction = crate::vm::test_helpers::sample_deployment_transaction(rng);
+        let private_transaction = crate::vm::test_helpers::sample_execution_transaction_with_private_fee(rng);
+        let public_transaction = crate::vm::test_helpers::sample_execution_transaction_with_public_fee(rng);
+
+        // Construct an execution transaction with an invalid proof, by swapping in the proof of its fee.
+        let invalid_transaction = match &private_transaction {
+            Transaction::Execute(_, execution, Some(fee)) => {
+                let execution = Execution::from(
+                    execution.transitions().cloned(),
+                    execution.global_state_root(),
+                    fee.proof().cloned(),
+                )
+                .unwrap();
+                Transaction::from_execution(execution, Some(fee.clone())).unwrap()
+            }
+            _ => panic!("Expected an execution with a fee"),
+        };
+        // Ensure the invalid transaction fails on its own.
+        assert!(vm.check_transaction(&invalid_transaction, None).is_err());
+
+        // Verify the transactions as a batch.
+        let transactions = [
+            (&deployment_transaction, None),
+            (&private_transaction, None),
+            (&invalid_transaction, None),
+            (&public_transaction, None),
+        ];
+        let results = vm.check_transactions(&transactions);
+        assert_eq!(results.len(), transactions.len());
+
+        // Ensure only the invalid transaction is rejected.
+        assert!(results[0].is_ok());
+        assert!(results[1].is_ok());
+        assert!(results[2].is_err());
+        assert!(results[3].is_ok());
+    }
+
+    #[test]
+    fn test_verify_certificates() {
+        let rng = &mut TestRng::default();
+        let vm = crate::vm::test_helpers::sample_vm_with_genesis_block(rng);
+
+        // Fetch a deployment transaction.
+        let transaction = crate::vm::test_helpers::sample_deployment_transaction(rng);
+        let Transaction::Deploy(id, owner, deployment, fee) = transaction else {
+            panic!("Expected a deployment transaction")
+        };
+        let program_id = *deployment.program_id();
+
+        // Swap in the certificate of the first function for the last function, to mimic a corrupted store.
+        let mut verifying_keys = deployment.verifying_keys().clone();
+        assert!(verifying_keys.len() > 1);
+        let first_certificate = verifying_keys[0].1.1.clone();
+        verifying_keys.last_mut().unwrap().1.1 = first_certificate;
+        let corrupted =
+            Deployment::new(deployment.edition(), deployment.program().clone(), verifying_keys.clone()).unwrap();
+
+        // Store the corrupted deployment, and load the program.
+        vm.transaction_store().insert(&Transaction::Deploy(id, owner, Box::new(corrupted), fee)).unwrap();
+        vm.process().write().add_program(deployment.program()).unwrap();
+
+        // Ensure only the function with the swapped certificate is flagged.
+        let report = vm.verify_certificates(&program_id).unwrap();
+        assert_eq!(report.program_id(), &program_id);
+        assert_eq!(report.functions().len(), verifying_keys.len());
+        assert!(!report.is_valid());
+        let expected = verifying_keys.last().unwrap().0;
+        assert_eq!(report.invalid_functions().collect::<Vec<_>>(), vec![&expected]);
+
+        // Ensure the audit over every deployed program reports the same.
+        assert_eq!(vm.verify_all_certificates().unwrap(), vec![report]);
+    }
+
     #[test]
     fn test_verify_deploy_and_execute() {
         // Initialize the RNG.